@@ -0,0 +1,104 @@
+//! Замеры горячих путей: разбор патч-нотов, тренд строки, тир-лист и сравнение патчей.
+//! `cargo bench --bench hot_paths`; отдельная группа — `cargo bench --bench hot_paths -- tier_list`.
+
+use std::collections::HashSet;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use lol_meta_analyzer_lib::analyzer::Analyzer;
+use lol_meta_analyzer_lib::models::{ChampionStats, LaneRole, PatchData, PatchNoteEntry};
+use lol_meta_analyzer_lib::patch_change_trend::{analyze_change_trend, change_grade};
+use lol_meta_analyzer_lib::scraper::Scraper;
+use lol_meta_analyzer_lib::tier_list::{aggregate, patch_contributions};
+
+/// Разметка как у статей Riot (20 чемпионов, предметы, руны), текст синтетический.
+const PATCH_NOTES_HTML: &str = include_str!("fixtures/patch-notes.html");
+
+/// Окно тир-листа по умолчанию.
+const WINDOW: usize = 20;
+
+fn parsed_notes() -> Vec<PatchNoteEntry> {
+    let scraper = Scraper::new().expect("scraper");
+    scraper.parse_riot_patch_notes_html(PATCH_NOTES_HTML, &HashSet::new(), "en")
+}
+
+fn champion(id: &str, role: LaneRole, win_rate: f64) -> ChampionStats {
+    ChampionStats {
+        id: id.into(),
+        name: id.into(),
+        tier: "A".into(),
+        role,
+        win_rate,
+        pick_rate: 5.0,
+        ban_rate: 2.0,
+        total_matches: 10_000,
+        ..Default::default()
+    }
+}
+
+fn patch(version: &str, notes: Vec<PatchNoteEntry>, win_rate_shift: f64) -> PatchData {
+    let roles = [LaneRole::Top, LaneRole::Jungle, LaneRole::Mid, LaneRole::Adc, LaneRole::Support];
+    let champions = (0..170)
+        .map(|i| champion(&format!("Champion{i}"), roles[i % roles.len()].clone(), 48.0 + (i % 7) as f64 + win_rate_shift))
+        .collect();
+    PatchData {
+        version: version.into(),
+        fetched_at: chrono::Utc::now(),
+        champions,
+        patch_notes: notes,
+        patch_notes_locale: Some("en".into()),
+        ..Default::default()
+    }
+}
+
+fn bench_parse_patch_notes(c: &mut Criterion) {
+    let scraper = Scraper::new().expect("scraper");
+    let slugs = HashSet::new();
+    c.bench_function("parse_riot_patch_notes_html", |b| {
+        b.iter(|| scraper.parse_riot_patch_notes_html(black_box(PATCH_NOTES_HTML), &slugs, "en"))
+    });
+}
+
+fn bench_change_trend(c: &mut Criterion) {
+    let notes = parsed_notes();
+    let lines: Vec<&str> = notes
+        .iter()
+        .flat_map(|n| n.details.iter())
+        .flat_map(|b| b.all_changes())
+        .map(String::as_str)
+        .collect();
+    c.bench_function("analyze_change_trend (all lines of a patch)", |b| {
+        b.iter(|| lines.iter().map(|l| analyze_change_trend(black_box(l))).sum::<i32>())
+    });
+    c.bench_function("change_grade (all lines of a patch)", |b| {
+        b.iter(|| lines.iter().map(|l| change_grade(black_box(l))).max())
+    });
+}
+
+fn bench_tier_list(c: &mut Criterion) {
+    let data = patch("25.22", parsed_notes(), 0.0);
+    c.bench_function("tier_list/patch_contributions", |b| {
+        b.iter(|| patch_contributions(black_box(&data)))
+    });
+    let window: Vec<_> = (0..WINDOW).map(|point| (point, patch_contributions(&data))).collect();
+    c.bench_function("tier_list/aggregate (20 patches)", |b| {
+        b.iter(|| aggregate(black_box(&window), WINDOW, true))
+    });
+}
+
+fn bench_compare_patches(c: &mut Criterion) {
+    let previous = patch("25.21", vec![], 0.0);
+    let current = patch("25.22", parsed_notes(), 0.7);
+    c.bench_function("compare_patches", |b| {
+        b.iter(|| Analyzer::compare_patches(black_box(&current), black_box(&previous)))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_parse_patch_notes,
+    bench_change_trend,
+    bench_tier_list,
+    bench_compare_patches
+);
+criterion_main!(benches);
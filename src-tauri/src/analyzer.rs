@@ -1,16 +1,67 @@
-use crate::models::{ChampionStats, MetaAnalysisDiff, PatchCategory, PatchData};
+use crate::models::{ChampionStats, LaneRole, MetaAnalysisDiff, PatchCategory, PatchData};
+use crate::patch_version::versions_match;
 
 pub struct Analyzer;
 
+/// z для двустороннего 95% интервала.
+const Z_95: f64 = 1.96;
+
+/// Разница двух долей (win rate в процентах): 95% доверительный интервал в п.п.
+/// None, если размер выборки хотя бы одного патча неизвестен.
+fn win_rate_diff_ci(cur_wr: f64, cur_n: u64, prev_wr: f64, prev_n: u64) -> Option<(f64, f64)> {
+    if cur_n == 0 || prev_n == 0 {
+        return None;
+    }
+    let p1 = (cur_wr / 100.0).clamp(0.0, 1.0);
+    let p2 = (prev_wr / 100.0).clamp(0.0, 1.0);
+    let se = (p1 * (1.0 - p1) / cur_n as f64 + p2 * (1.0 - p2) / prev_n as f64).sqrt();
+    let diff = p1 - p2;
+    let round2 = |v: f64| (v * 10000.0).round() / 100.0;
+    Some((round2(diff - Z_95 * se), round2(diff + Z_95 * se)))
+}
+
 impl Analyzer {
+    /// Патч, с которым сравнивается `version` (`patches` — от нового к старому).
+    /// Хотфикс сравнивается со своим патчем; обычный патч — с последним состоянием предыдущего,
+    /// т.е. с его хотфиксом, если он есть, и никогда со своим же хотфиксом.
+    pub fn previous_patch<'a>(patches: &'a [PatchData], version: &str) -> Option<&'a PatchData> {
+        let idx = patches.iter().position(|p| versions_match(&p.version, version))?;
+        let current = &patches[idx];
+        if let Some(parent) = current.parent_version.as_deref() {
+            return patches.iter().find(|p| versions_match(&p.version, parent));
+        }
+        patches[idx + 1..]
+            .iter()
+            .find(|p| !versions_match(p.base_version(), current.base_version()))
+    }
+
     pub fn compare_patches(current: &PatchData, previous: &PatchData) -> Vec<MetaAnalysisDiff> {
         let role_key = |c: &ChampionStats| -> String { format!("{:?}", c.role) };
 
         let mut prev_map: std::collections::HashMap<(String, String), &ChampionStats> =
             std::collections::HashMap::new();
+        let mut prev_rows_per_id: std::collections::HashMap<&str, Vec<&ChampionStats>> =
+            std::collections::HashMap::new();
         for c in &previous.champions {
             prev_map.insert((c.id.clone(), role_key(c)), c);
+            prev_rows_per_id.entry(c.id.as_str()).or_default().push(c);
         }
+        // Прошлый патч мог хранить одну строку на чемпиона, а текущий — по строке на роль (или наоборот):
+        // без точного совпадения роли сравниваем с агрегатом (Unknown) или с единственной строкой чемпиона.
+        let previous_for = |c: &ChampionStats| -> Option<&ChampionStats> {
+            if let Some(p) = prev_map.get(&(c.id.clone(), role_key(c))) {
+                return Some(*p);
+            }
+            let rows = prev_rows_per_id.get(c.id.as_str())?;
+            if let Some(p) = rows.iter().find(|p| p.role == LaneRole::Unknown) {
+                return Some(*p);
+            }
+            let current_rows = current.champions.iter().filter(|x| x.id == c.id).count();
+            if rows.len() == 1 && (current_rows == 1 || c.role == LaneRole::Unknown) {
+                return Some(rows[0]);
+            }
+            None
+        };
 
         let prediction_for = |name: &str| -> Option<String> {
             for note in &current.patch_notes {
@@ -26,13 +77,14 @@ impl Analyzer {
 
         let mut out: Vec<MetaAnalysisDiff> = Vec::new();
         for c in &current.champions {
-            let key = (c.id.clone(), role_key(c));
-            let Some(p) = prev_map.get(&key) else {
+            let Some(p) = previous_for(c) else {
                 continue;
             };
             let win_rate_diff = (c.win_rate - p.win_rate).round();
+            let ci = win_rate_diff_ci(c.win_rate, c.total_matches, p.win_rate, p.total_matches);
             let pick_rate_diff = (c.pick_rate - p.pick_rate).round();
-            if win_rate_diff == 0.0 && pick_rate_diff == 0.0 {
+            let ban_rate_diff = (c.ban_rate - p.ban_rate).round();
+            if win_rate_diff == 0.0 && pick_rate_diff == 0.0 && ban_rate_diff == 0.0 {
                 continue;
             }
             out.push(MetaAnalysisDiff {
@@ -40,8 +92,14 @@ impl Analyzer {
                 role: role_key(c),
                 win_rate_diff,
                 pick_rate_diff,
+                ban_rate_diff,
                 predicted_change: prediction_for(&c.name),
                 champion_image_url: c.image_url.clone(),
+                games_current: c.total_matches,
+                games_previous: p.total_matches,
+                win_rate_diff_ci: ci,
+                significant: ci.is_some_and(|(lo, hi)| lo > 0.0 || hi < 0.0),
+                champion_meta: None,
             });
         }
 
@@ -54,3 +112,90 @@ impl Analyzer {
         out
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{champion, patch_with_stats};
+
+    #[test]
+    fn hotfix_is_diffed_against_its_parent() {
+        let patches: Vec<PatchData> = ["25.23", "25.22b", "25.22"]
+            .into_iter()
+            .map(|v| patch_with_stats(v, vec![]))
+            .collect();
+        let prev = |v: &str| Analyzer::previous_patch(&patches, v).map(|p| p.version.clone());
+        assert_eq!(prev("25.23").as_deref(), Some("25.22b"));
+        assert_eq!(prev("25.22b").as_deref(), Some("25.22"));
+        assert_eq!(prev("25.22"), None);
+    }
+
+    #[test]
+    fn per_role_rows_are_compared_separately() {
+        let prev = patch_with_stats(
+            "25.1",
+            vec![champion("Ahri", LaneRole::Mid, 50.0), champion("Ahri", LaneRole::Support, 48.0)],
+        );
+        let cur = patch_with_stats(
+            "25.2",
+            vec![champion("Ahri", LaneRole::Mid, 47.0), champion("Ahri", LaneRole::Support, 48.0)],
+        );
+        let diffs = Analyzer::compare_patches(&cur, &prev);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].role, "Mid");
+        assert_eq!(diffs[0].win_rate_diff, -3.0);
+    }
+
+    #[test]
+    fn per_role_rows_fall_back_to_aggregate_row() {
+        let prev = patch_with_stats("25.1", vec![champion("Ahri", LaneRole::Unknown, 50.0)]);
+        let cur = patch_with_stats(
+            "25.2",
+            vec![champion("Ahri", LaneRole::Mid, 52.0), champion("Ahri", LaneRole::Support, 46.0)],
+        );
+        let diffs = Analyzer::compare_patches(&cur, &prev);
+        assert_eq!(diffs.len(), 2);
+    }
+
+    #[test]
+    fn ci_unknown_without_samples() {
+        assert_eq!(win_rate_diff_ci(52.0, 0, 50.0, 1000), None);
+    }
+
+    #[test]
+    fn small_sample_ci_crosses_zero() {
+        let (lo, hi) = win_rate_diff_ci(55.0, 40, 50.0, 40).unwrap();
+        assert!(lo < 0.0 && hi > 0.0);
+    }
+
+    #[test]
+    fn large_sample_ci_excludes_zero() {
+        let (lo, hi) = win_rate_diff_ci(52.0, 200_000, 50.0, 200_000).unwrap();
+        assert!(lo > 0.0 && hi > lo);
+    }
+
+    #[test]
+    fn large_samples_mark_diff_significant() {
+        let mut cur = champion("Ahri", LaneRole::Mid, 53.0);
+        cur.total_matches = 200_000;
+        let mut prev = champion("Ahri", LaneRole::Mid, 50.0);
+        prev.total_matches = 200_000;
+        let diffs = Analyzer::compare_patches(&patch_with_stats("25.2", vec![cur]), &patch_with_stats("25.1", vec![prev]));
+        assert!(diffs[0].significant);
+    }
+
+    #[test]
+    fn ban_rate_only_change_is_reported() {
+        let mut banned = champion("Ahri", LaneRole::Mid, 50.0);
+        banned.ban_rate = 12.0;
+        banned.total_matches = 1000;
+        let mut before = champion("Ahri", LaneRole::Mid, 50.0);
+        before.total_matches = 800;
+        let diffs =
+            Analyzer::compare_patches(&patch_with_stats("25.2", vec![banned]), &patch_with_stats("25.1", vec![before]));
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].ban_rate_diff, 12.0);
+        assert_eq!((diffs[0].games_current, diffs[0].games_previous), (1000, 800));
+        assert!(!diffs[0].significant);
+    }
+}
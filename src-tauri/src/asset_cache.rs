@@ -1,16 +1,181 @@
 use anyhow::Result;
+use once_cell::sync::Lazy;
 use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 
+use ts_rs::TS;
+
+use crate::in_flight::InFlight;
 use crate::models::PatchData;
 
 #[derive(Default)]
 pub struct AssetCacheStats {
     pub cached_new: usize,
     pub reused_existing: usize,
+    /// Скачано, но такое содержимое уже лежало в хранилище под другим URL
+    pub deduplicated: usize,
     pub failed: usize,
 }
 
+/// Файлы хранятся один раз на хеш содержимого: `<root>/blobs/<hash>-<len>.<ext>`.
+const BLOBS_DIR: &str = "blobs";
+const BLOB_INDEX_FILE: &str = "index.json";
+
+/// Индекс хранилища: какой файл отдаётся для URL и сколько URL ссылаются на файл.
+#[derive(Default, Serialize, Deserialize)]
+struct BlobIndex {
+    urls: HashMap<String, String>,
+    refs: HashMap<String, u32>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl BlobIndex {
+    /// Привязывает URL к файлу; файл, на который больше никто не ссылается, возвращается для удаления.
+    fn map_url(&mut self, url: &str, blob: &str) -> Option<String> {
+        let previous = self.urls.insert(url.to_string(), blob.to_string());
+        if previous.as_deref() == Some(blob) {
+            return None;
+        }
+        self.dirty = true;
+        *self.refs.entry(blob.to_string()).or_insert(0) += 1;
+        let previous = previous?;
+        let left = self.refs.get_mut(&previous).map(|n| {
+            *n = n.saturating_sub(1);
+            *n
+        });
+        if left == Some(0) {
+            self.refs.remove(&previous);
+            return Some(previous);
+        }
+        None
+    }
+}
+
+/// Индексы по корням кэша; блокировка не держится через await.
+static BLOB_INDEXES: Lazy<Mutex<HashMap<PathBuf, BlobIndex>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Загрузки, идущие прямо сейчас: второй запрос того же URL ждёт первую, а не качает заново.
+static IN_FLIGHT: Lazy<InFlight<String, Option<PathBuf>>> = Lazy::new(InFlight::new);
+
+/// Обращения к хранилищу за сессию: отдано с диска / пришлось качать.
+static LOOKUP_HITS: AtomicU64 = AtomicU64::new(0);
+static LOOKUP_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Состояние хранилища картинок патчей для `get_cache_stats`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct BlobStoreStats {
+    /// URL в индексе
+    pub urls: usize,
+    #[ts(type = "number")]
+    pub blob_files: u64,
+    #[ts(type = "number")]
+    pub blob_bytes: u64,
+    #[ts(type = "number")]
+    pub hits: u64,
+    #[ts(type = "number")]
+    pub misses: u64,
+    pub hit_rate: f64,
+}
+
+pub fn blob_store_stats(root: &Path) -> BlobStoreStats {
+    let urls = with_blob_index(root, |index| index.urls.len());
+    let (mut blob_files, mut blob_bytes) = (0, 0);
+    if let Ok(entries) = std::fs::read_dir(root.join(BLOBS_DIR)) {
+        for entry in entries.flatten() {
+            if entry.file_name() == BLOB_INDEX_FILE {
+                continue;
+            }
+            if let Ok(md) = entry.metadata() {
+                if md.is_file() {
+                    blob_files += 1;
+                    blob_bytes += md.len();
+                }
+            }
+        }
+    }
+    let hits = LOOKUP_HITS.load(Ordering::Relaxed);
+    let misses = LOOKUP_MISSES.load(Ordering::Relaxed);
+    let lookups = hits + misses;
+    BlobStoreStats {
+        urls,
+        blob_files,
+        blob_bytes,
+        hits,
+        misses,
+        hit_rate: if lookups == 0 { 0.0 } else { hits as f64 / lookups as f64 },
+    }
+}
+
+fn with_blob_index<R>(root: &Path, f: impl FnOnce(&mut BlobIndex) -> R) -> R {
+    let mut indexes = BLOB_INDEXES.lock().unwrap_or_else(|e| e.into_inner());
+    let index = indexes.entry(root.to_path_buf()).or_insert_with(|| {
+        std::fs::read(root.join(BLOBS_DIR).join(BLOB_INDEX_FILE))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    });
+    f(index)
+}
+
+fn save_blob_index(root: &Path) {
+    let json = with_blob_index(root, |index| {
+        if !index.dirty {
+            return None;
+        }
+        index.dirty = false;
+        serde_json::to_vec(index).ok()
+    });
+    if let Some(json) = json {
+        let dir = root.join(BLOBS_DIR);
+        let _ = std::fs::create_dir_all(&dir);
+        let _ = std::fs::write(dir.join(BLOB_INDEX_FILE), json);
+    }
+}
+
+/// Удаляет файлы хранилища, на которые не ссылается ни один URL индекса. Пока идут загрузки
+/// или индекс пуст (не прочитался), ничего не трогает. Возвращает (файлов, байт).
+pub fn collect_orphan_blobs(root: &Path) -> (u64, u64) {
+    if !IN_FLIGHT.is_idle() {
+        return (0, 0);
+    }
+    let referenced: Option<HashSet<String>> = with_blob_index(root, |index| {
+        (!index.urls.is_empty()).then(|| index.refs.keys().cloned().collect())
+    });
+    let Some(referenced) = referenced else {
+        return (0, 0);
+    };
+    let (mut files, mut bytes) = (0, 0);
+    let Ok(entries) = std::fs::read_dir(root.join(BLOBS_DIR)) else {
+        return (0, 0);
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name == BLOB_INDEX_FILE || referenced.contains(&name) {
+            continue;
+        }
+        let Ok(md) = entry.metadata() else { continue };
+        if crate::maintenance::is_settled_file(&md) && std::fs::remove_file(entry.path()).is_ok() {
+            files += 1;
+            bytes += md.len();
+        }
+    }
+    (files, bytes)
+}
+
+/// Забыть индекс в памяти — после удаления каталога кэша с диска.
+pub fn forget_blob_index(root: &Path) {
+    BLOB_INDEXES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(root);
+}
+
 fn sanitize_key(input: &str) -> String {
     input
         .chars()
@@ -33,6 +198,19 @@ fn fnv1a32(input: &str) -> u32 {
     hash
 }
 
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn blob_name(bytes: &[u8], ext: &str) -> String {
+    format!("{:016x}-{}.{}", fnv1a64(bytes), bytes.len(), ext)
+}
+
 fn extension_from_url(url: &str) -> &'static str {
     let base = url.split('?').next().unwrap_or(url);
     let lower = base.to_lowercase();
@@ -49,12 +227,36 @@ fn extension_from_url(url: &str) -> &'static str {
     }
 }
 
+/// Путь из старой раскладки (файл на URL в каталоге бакета) — такие файлы продолжаем отдавать.
 fn local_path_for_url(root: &Path, bucket: &str, url: &str) -> PathBuf {
     let ext = extension_from_url(url);
     let hash = fnv1a32(url);
     root.join(bucket).join(format!("{hash:08x}.{ext}"))
 }
 
+/// Скачивает URL в контент-адресное хранилище. `deduplicated` — такое содержимое уже было.
+async fn download_blob(client: &Client, root: &Path, url: &str, deduplicated: &mut bool) -> Option<PathBuf> {
+    crate::maintenance::touch();
+    let resp = client.get(url).send().await.ok()?;
+    if !resp.status().is_success() {
+        return None;
+    }
+    let bytes = crate::http_body::read_bytes(resp, crate::http_body::MAX_IMAGE_BYTES).await.ok()?;
+    let blob = blob_name(&bytes, extension_from_url(url));
+    let dir = root.join(BLOBS_DIR);
+    let path = dir.join(&blob);
+    if path.exists() {
+        *deduplicated = true;
+    } else {
+        std::fs::create_dir_all(&dir).ok()?;
+        std::fs::write(&path, &bytes).ok()?;
+    }
+    if let Some(orphan) = with_blob_index(root, |index| index.map_url(url, &blob)) {
+        let _ = std::fs::remove_file(dir.join(orphan));
+    }
+    Some(path)
+}
+
 async fn cache_remote_url(
     client: &Client,
     root: &Path,
@@ -69,39 +271,42 @@ async fn cache_remote_url(
     if !url.starts_with("http://") && !url.starts_with("https://") {
         return Some(url.to_string());
     }
-    let path = local_path_for_url(root, bucket, url);
-    if path.exists() {
+    let known = with_blob_index(root, |index| index.urls.get(url).cloned())
+        .map(|blob| root.join(BLOBS_DIR).join(blob))
+        .filter(|p| p.exists());
+    let legacy = local_path_for_url(root, bucket, url);
+    if let Some(path) = known.or_else(|| legacy.exists().then_some(legacy)) {
         stats.reused_existing += 1;
+        LOOKUP_HITS.fetch_add(1, Ordering::Relaxed);
         return Some(path.to_string_lossy().into_owned());
     }
-    let resp = match client.get(url).send().await {
-        Ok(r) => r,
-        Err(_) => {
-            stats.failed += 1;
-            return Some(url.to_string());
+    LOOKUP_MISSES.fetch_add(1, Ordering::Relaxed);
+
+    let mut downloaded_here = false;
+    let mut deduplicated = false;
+    let result = IN_FLIGHT
+        .run(url.to_string(), || {
+            downloaded_here = true;
+            download_blob(client, root, url, &mut deduplicated)
+        })
+        .await;
+
+    match result {
+        Some(path) => {
+            if !downloaded_here {
+                stats.reused_existing += 1;
+            } else if deduplicated {
+                stats.deduplicated += 1;
+            } else {
+                stats.cached_new += 1;
+            }
+            Some(path.to_string_lossy().into_owned())
         }
-    };
-    if !resp.status().is_success() {
-        stats.failed += 1;
-        return Some(url.to_string());
-    }
-    let bytes = match resp.bytes().await {
-        Ok(b) => b,
-        Err(_) => {
+        None => {
             stats.failed += 1;
-            return Some(url.to_string());
+            Some(url.to_string())
         }
-    };
-    if std::fs::create_dir_all(path.parent().unwrap_or(root)).is_err() {
-        stats.failed += 1;
-        return Some(url.to_string());
     }
-    if std::fs::write(&path, &bytes).is_err() {
-        stats.failed += 1;
-        return Some(url.to_string());
-    }
-    stats.cached_new += 1;
-    Some(path.to_string_lossy().into_owned())
 }
 
 pub async fn localize_patch_assets(
@@ -149,5 +354,26 @@ pub async fn localize_patch_assets(
             }
         }
     }
+    save_blob_index(root);
     Ok(stats)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blob_index_counts_references_and_releases_orphans() {
+        let bytes = b"icon";
+        assert_eq!(blob_name(bytes, "png"), blob_name(bytes, "png"));
+        assert_ne!(blob_name(bytes, "png"), blob_name(b"other", "png"));
+
+        let mut index = BlobIndex::default();
+        assert_eq!(index.map_url("https://cdn/a.png", "x.png"), None);
+        assert_eq!(index.map_url("https://cdn/b.png", "x.png"), None);
+        assert_eq!(index.refs.get("x.png"), Some(&2));
+        assert_eq!(index.map_url("https://cdn/a.png", "y.png"), None);
+        assert_eq!(index.map_url("https://cdn/b.png", "y.png"), Some("x.png".to_string()));
+        assert_eq!(index.refs.get("y.png"), Some(&2));
+    }
+}
@@ -0,0 +1,180 @@
+//! Полный отчёт по одному чемпиону для OTP-игроков: вся история изменений, хронология по умениям,
+//! таймлайн win/pick/ban rate, текущие сборки и руны, сборки про после патчей, статьи разработчиков —
+//! одним JSON.
+//! Матчапов в отчёте нет: статистику против конкретных соперников приложение не собирает.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::champion_timeline::{build_timeline, TimelinePoint};
+use crate::db::Database;
+use crate::models::{ChampionStats, ChangeType, DevArticle, EntityRef};
+use crate::pro_builds::{self, ProBuildsOverview};
+use crate::ChampionHistoryEntry;
+
+/// Блок изменений без заголовка — базовые характеристики или общий текст записи.
+const GENERAL_BLOCK: &str = "General";
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct AbilityChange {
+    pub patch_version: String,
+    pub date: DateTime<Utc>,
+    pub change_type: ChangeType,
+    /// `ChangeBlock::trend_score` блока
+    pub trend: i32,
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct AbilityTimeline {
+    /// Заголовок блока патч-нотов: «Q — Сфера обмана», «Базовые характеристики»
+    pub ability: String,
+    pub icon_url: Option<String>,
+    /// От старого патча к новому
+    pub changes: Vec<AbilityChange>,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export)]
+pub struct ChampionReport {
+    /// id DDragon
+    pub champion: String,
+    pub generated_at: DateTime<Utc>,
+    pub history: Vec<ChampionHistoryEntry>,
+    pub abilities: Vec<AbilityTimeline>,
+    pub timeline: Vec<TimelinePoint>,
+    /// Патч, из которого взяты `current_stats`
+    pub current_stats_patch: Option<String>,
+    /// По строке на роль: win/pick/ban rate, предметы и руны
+    pub current_stats: Vec<ChampionStats>,
+    /// Что про собирают в первые дни после патчей
+    pub pro_builds: ProBuildsOverview,
+    pub dev_articles: Vec<DevArticle>,
+}
+
+/// Хронология по умениям: блоки записей истории группируются по заголовку (порядок — первого появления).
+pub fn ability_timelines(history: &[ChampionHistoryEntry]) -> Vec<AbilityTimeline> {
+    let mut out: Vec<AbilityTimeline> = Vec::new();
+    for entry in history {
+        for block in &entry.change.details {
+            let lines: Vec<String> = block.all_changes().into_iter().cloned().collect();
+            if lines.is_empty() {
+                continue;
+            }
+            let ability = block
+                .title
+                .as_deref()
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .unwrap_or(GENERAL_BLOCK);
+            let change = AbilityChange {
+                patch_version: entry.patch_version.clone(),
+                date: entry.date,
+                change_type: entry.change.change_type.clone(),
+                trend: block.trend_score(),
+                lines,
+            };
+            match out.iter_mut().find(|a| a.ability.eq_ignore_ascii_case(ability)) {
+                Some(timeline) => {
+                    if timeline.icon_url.is_none() {
+                        timeline.icon_url = block.icon_url.clone();
+                    }
+                    timeline.changes.push(change);
+                }
+                None => out.push(AbilityTimeline {
+                    ability: ability.to_string(),
+                    icon_url: block.icon_url.clone(),
+                    changes: vec![change],
+                }),
+            }
+        }
+    }
+    for timeline in &mut out {
+        timeline.changes.sort_by_key(|c| c.date);
+    }
+    out
+}
+
+/// `champion` — id DDragon или имя (как в истории).
+pub async fn generate(db: &Database, champion: &str) -> anyhow::Result<ChampionReport> {
+    let index = db.get_champion_meta_index().await.unwrap_or_default();
+    let id = index
+        .get(&champion.to_lowercase())
+        .map(|m| m.id.clone())
+        .unwrap_or_else(|| champion.to_string());
+    let entity = EntityRef::Champion { id: id.clone() };
+
+    let history = db.get_entity_history(&entity, None).await?;
+    let patches = db.get_champion_stats_history(&id).await?;
+    // Последний патч с настоящей статистикой, а не заглушкой
+    let current = patches.iter().rev().find(|p| p.stats.iter().any(ChampionStats::has_data));
+    let current_stats_patch = current.map(|p| p.version.clone());
+    let current_stats = current.map(|p| p.stats.clone()).unwrap_or_default();
+    let timeline = build_timeline(patches, &history);
+    let dev_articles = db.get_dev_articles_for_entity(&entity).await.unwrap_or_default();
+    let pro_builds = pro_builds::overview(db, &id).await.unwrap_or_default();
+
+    Ok(ChampionReport {
+        champion: id,
+        generated_at: Utc::now(),
+        abilities: ability_timelines(&history),
+        history,
+        timeline,
+        current_stats_patch,
+        current_stats,
+        pro_builds,
+        dev_articles,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeBlock, PatchCategory, PatchNoteEntry};
+
+    fn entry(version: &str, date: &str, blocks: Vec<(&str, &[&str])>) -> ChampionHistoryEntry {
+        ChampionHistoryEntry::new(
+            version.into(),
+            DateTime::parse_from_rfc3339(date).unwrap().with_timezone(&Utc),
+            "25.S3".into(),
+            PatchNoteEntry {
+                id: "ahri".into(),
+                title: "Ahri".into(),
+                category: PatchCategory::Champions,
+                change_type: ChangeType::Buff,
+                details: blocks
+                    .into_iter()
+                    .map(|(title, changes)| ChangeBlock {
+                        title: (!title.is_empty()).then(|| title.to_string()),
+                        icon_url: None,
+                        changes: changes.iter().map(|s| s.to_string()).collect(),
+                        children: vec![],
+                    })
+                    .collect(),
+                entity_id: Some("champion:Ahri".into()),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn groups_blocks_by_ability_in_patch_order() {
+        let history = vec![
+            entry("25.22", "2025-11-04T18:00:00Z", vec![("Q - Orb of Deception", &["Damage: 40 → 50"])]),
+            entry(
+                "25.20",
+                "2025-10-07T18:00:00Z",
+                vec![("q - orb of deception", &["Mana cost: 60 → 55"]), ("", &["Armor: 21 → 24"])],
+            ),
+        ];
+        let abilities = ability_timelines(&history);
+        assert_eq!(abilities.len(), 2);
+        assert_eq!(abilities[0].ability, "Q - Orb of Deception");
+        let patches: Vec<&str> = abilities[0].changes.iter().map(|c| c.patch_version.as_str()).collect();
+        assert_eq!(patches, ["25.20", "25.22"]);
+        assert_eq!(abilities[1].ability, GENERAL_BLOCK);
+    }
+}
@@ -0,0 +1,158 @@
+//! Таймлайн чемпиона для своих графиков (Python, Excel): по точке на сохранённый патч —
+//! тип и масштаб изменения, суммарный тренд строк и win/pick/ban rate, если статистика есть.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::{ChampionPatchStats, Database};
+use crate::models::{ChangeGrade, ChangeType, EntityRef};
+use crate::ChampionHistoryEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineFormat {
+    Json,
+    Csv,
+}
+
+impl TimelineFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            TimelineFormat::Json => "json",
+            TimelineFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TimelinePoint {
+    pub patch_version: String,
+    pub date: DateTime<Utc>,
+    pub split: String,
+    /// `None` — чемпиона в патче не меняли
+    pub change_type: Option<ChangeType>,
+    pub grade: Option<ChangeGrade>,
+    /// `PatchNoteEntry::trend_score` изменений чемпиона в патче
+    pub trend_score: i32,
+    pub change_lines: u32,
+    /// Роль с наибольшей долей игр; статистика ниже — по ней
+    pub role: Option<String>,
+    pub win_rate: Option<f64>,
+    pub pick_rate: Option<f64>,
+    pub ban_rate: Option<f64>,
+}
+
+/// Точки от старого патча к новому; записи истории сопоставляются с патчами по версии.
+pub fn build_timeline(patches: Vec<ChampionPatchStats>, history: &[ChampionHistoryEntry]) -> Vec<TimelinePoint> {
+    let mut by_version: HashMap<&str, Vec<&ChampionHistoryEntry>> = HashMap::new();
+    for entry in history {
+        by_version.entry(entry.patch_version.as_str()).or_default().push(entry);
+    }
+    patches
+        .into_iter()
+        .map(|patch| {
+            let entries = by_version.get(patch.version.as_str()).map(Vec::as_slice).unwrap_or(&[]);
+            let lines: Vec<&String> = entries
+                .iter()
+                .flat_map(|e| e.change.details.iter().flat_map(|b| b.all_changes()))
+                .collect();
+            let main = patch
+                .stats
+                .iter()
+                .filter(|s| s.has_data())
+                .max_by(|a, b| a.role_share.total_cmp(&b.role_share));
+            TimelinePoint {
+                change_type: entries.first().map(|e| e.change.change_type.clone()),
+                grade: entries.iter().filter_map(|e| e.change.grade).max(),
+                trend_score: entries.iter().map(|e| e.change.trend_score()).sum(),
+                change_lines: lines.len() as u32,
+                role: main.map(|s| format!("{:?}", s.role)),
+                win_rate: main.map(|s| s.win_rate),
+                pick_rate: main.map(|s| s.pick_rate),
+                ban_rate: main.map(|s| s.ban_rate),
+                patch_version: patch.version,
+                date: patch.date,
+                split: patch.split,
+            }
+        })
+        .collect()
+}
+
+pub async fn champion_timeline(db: &Database, champion: &str) -> anyhow::Result<Vec<TimelinePoint>> {
+    let entity = EntityRef::Champion { id: champion.to_string() };
+    let history = db.get_entity_history(&entity, None).await?;
+    let patches = db.get_champion_stats_history(champion).await?;
+    Ok(build_timeline(patches, &history))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn opt<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+pub fn render(points: &[TimelinePoint], format: TimelineFormat) -> anyhow::Result<String> {
+    match format {
+        TimelineFormat::Json => Ok(serde_json::to_string_pretty(points)?),
+        TimelineFormat::Csv => {
+            let mut out = String::from(
+                "patch_version,date,split,change_type,grade,trend_score,change_lines,role,win_rate,pick_rate,ban_rate\n",
+            );
+            for p in points {
+                let row = [
+                    csv_field(&p.patch_version),
+                    p.date.format("%Y-%m-%d").to_string(),
+                    csv_field(&p.split),
+                    opt(p.change_type.as_ref().map(|c| format!("{:?}", c))),
+                    opt(p.grade.map(|g| format!("{:?}", g))),
+                    p.trend_score.to_string(),
+                    p.change_lines.to_string(),
+                    csv_field(p.role.as_deref().unwrap_or("")),
+                    opt(p.win_rate),
+                    opt(p.pick_rate),
+                    opt(p.ban_rate),
+                ];
+                out.push_str(&row.join(","));
+                out.push('\n');
+            }
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_has_a_row_per_patch_and_escapes_fields() {
+        let point = TimelinePoint {
+            patch_version: "25.22".into(),
+            date: DateTime::parse_from_rfc3339("2025-11-04T18:00:00Z").unwrap().with_timezone(&Utc),
+            split: "25.S3".into(),
+            change_type: Some(ChangeType::Buff),
+            grade: Some(ChangeGrade::Major),
+            trend_score: 3,
+            change_lines: 2,
+            role: Some("Mid, \"solo\"".into()),
+            win_rate: Some(51.2),
+            pick_rate: None,
+            ban_rate: None,
+        };
+        let csv = render(&[point], TimelineFormat::Csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1], "25.22,2025-11-04,25.S3,Buff,Major,3,2,\"Mid, \"\"solo\"\"\",51.2,,");
+    }
+}
@@ -0,0 +1,114 @@
+//! Крупные ответы команд кусками через `ipc::Channel`: полный `PatchData` весит мегабайты,
+//! и одна такая сериализация через IPC подвешивает webview. Frontend собирает куски в `invokeChunked`.
+
+use serde::Serialize;
+use tauri::ipc::{Channel, Response};
+use ts_rs::TS;
+
+use crate::packed_ipc::{self, IpcEncoding};
+
+/// Ответ меньше этого размера уходит обычным результатом команды.
+const STREAM_THRESHOLD_BYTES: usize = 256 * 1024;
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Кусок JSON-текста ответа; `index` с нуля.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ResultChunk {
+    pub index: u32,
+    pub data: String,
+}
+
+/// Результат команды: целиком или «отправлено `chunks` кусков в канал».
+#[derive(Debug, Serialize, TS)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+#[ts(export)]
+pub enum ChunkedResult<T> {
+    Inline { value: T },
+    Streamed { chunks: u32 },
+}
+
+/// Делит строку на куски не длиннее `max_bytes`, не разрезая UTF-8 символы; символ шире `max_bytes`
+/// уходит отдельным куском.
+fn split_at_char_boundaries(text: &str, max_bytes: usize) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = max_bytes.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        let (head, tail) = rest.split_at(end);
+        out.push(head);
+        rest = tail;
+    }
+    out
+}
+
+/// `ChunkedResult::Inline` вокруг уже сериализованного значения: JSON не строится второй раз.
+fn inline_json(value_json: &str) -> String {
+    format!(r#"{{"kind":"inline","value":{}}}"#, value_json)
+}
+
+/// Без канала, в MessagePack или для небольшого ответа — `Inline`, иначе JSON уходит кусками в `channel`.
+pub fn deliver<T: Serialize>(
+    value: &T,
+    channel: Option<&Channel<ResultChunk>>,
+    encoding: Option<IpcEncoding>,
+) -> Result<Response, String> {
+    // MessagePack и так дешевле JSON-текста, куски нужны только для JSON
+    let channel = channel.filter(|_| encoding.unwrap_or_default() == IpcEncoding::Json);
+    let Some(channel) = channel else {
+        return packed_ipc::respond(&ChunkedResult::Inline { value }, encoding);
+    };
+    let json = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    if json.len() <= STREAM_THRESHOLD_BYTES {
+        return Ok(Response::new(inline_json(&json)));
+    }
+    let chunks = split_at_char_boundaries(&json, CHUNK_BYTES);
+    for (index, data) in chunks.iter().enumerate() {
+        channel
+            .send(ResultChunk {
+                index: index as u32,
+                data: data.to_string(),
+            })
+            .map_err(|e| e.to_string())?;
+    }
+    packed_ipc::respond(
+        &ChunkedResult::<()>::Streamed {
+            chunks: chunks.len() as u32,
+        },
+        encoding,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_without_breaking_multibyte_chars() {
+        let text = "Ахри: урон 50 → 60";
+        let parts = split_at_char_boundaries(text, 5);
+        assert!(parts.iter().all(|p| p.len() <= 5 && !p.is_empty()));
+        assert_eq!(parts.concat(), text);
+        assert!(split_at_char_boundaries("", 5).is_empty());
+    }
+
+    #[test]
+    fn takes_a_whole_char_when_it_is_wider_than_the_limit() {
+        assert_eq!(split_at_char_boundaries("→a", 2), ["→", "a"]);
+        assert_eq!(split_at_char_boundaries("аб", 0), ["а", "б"]);
+    }
+
+    #[test]
+    fn inline_json_matches_serde_envelope() {
+        let value = vec!["Ахри", "Зед"];
+        let json = serde_json::to_string(&value).unwrap();
+        let expected = serde_json::to_string(&ChunkedResult::Inline { value }).unwrap();
+        assert_eq!(inline_json(&json), expected);
+    }
+}
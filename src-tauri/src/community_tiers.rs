@@ -0,0 +1,268 @@
+//! Тир-лист сообщества со стороннего сайта (op.gg): оценка чемпиона по каждой роли сохраняется по
+//! патчам, а `TierEntry` показывает её смену («S → A») рядом с баффами и нерфами из патч-нотов —
+//! чтобы сверить выводы анализатора с тем, как чемпион играется на самом деле.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
+use crate::models::LaneRole;
+use crate::patch_version::PatchVersion;
+use crate::scraper::Scraper;
+
+pub const OPGG_SOURCE: &str = "opgg";
+/// Ранкед от Изумруда и выше, все регионы.
+const OPGG_URL: &str = "https://lol-web-api.op.gg/api/v1.0/internal/bypass/champions/global/ranked?tier=emerald_plus";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(12 * 60 * 60);
+const FIRST_REFRESH_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// Фоновое обновление включается в настройках (`set_community_tiers_enabled`): без него к op.gg не обращаемся.
+static REFRESH_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CommunityTier {
+    /// Числовой ключ Riot
+    pub champion_key: i32,
+    pub role: LaneRole,
+    /// S+, S, A, B, C, D
+    pub tier: String,
+    /// Место в роли
+    pub rank: Option<u32>,
+    pub pick_rate: f64,
+    pub win_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CommunityTierList {
+    pub source: String,
+    /// Патч по нумерации сайта Riot
+    pub version: String,
+    pub fetched_at: DateTime<Utc>,
+    pub tiers: Vec<CommunityTier>,
+}
+
+/// Оценка чемпиона в его основной роли (с наибольшим пикрейтом) на последнем и предыдущем сохранённых патчах.
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[ts(export)]
+pub struct CommunityTierChange {
+    pub source: String,
+    pub role: LaneRole,
+    pub version: String,
+    pub tier: String,
+    pub previous_version: Option<String>,
+    /// `None`, если в прошлом списке чемпиона в этой роли не было
+    pub previous_tier: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpggTierData {
+    tier: Option<i64>,
+    rank: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct OpggStats {
+    #[serde(default)]
+    win_rate: f64,
+    #[serde(default)]
+    pick_rate: f64,
+    tier_data: Option<OpggTierData>,
+}
+
+#[derive(Deserialize)]
+struct OpggPosition {
+    name: String,
+    stats: OpggStats,
+}
+
+#[derive(Deserialize)]
+struct OpggChampion {
+    id: i32,
+    #[serde(default)]
+    positions: Vec<OpggPosition>,
+}
+
+#[derive(Deserialize, Default)]
+struct OpggMeta {
+    version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpggResponse {
+    data: Vec<OpggChampion>,
+    #[serde(default)]
+    meta: Option<OpggMeta>,
+}
+
+/// op.gg считает тиры от 0 (OP) до 5.
+fn opgg_tier_label(tier: i64) -> Option<&'static str> {
+    ["S+", "S", "A", "B", "C", "D"].get(usize::try_from(tier).ok()?).copied()
+}
+
+fn opgg_role(name: &str) -> Option<LaneRole> {
+    match name.to_ascii_uppercase().as_str() {
+        "TOP" => Some(LaneRole::Top),
+        "JUNGLE" => Some(LaneRole::Jungle),
+        "MID" | "MIDDLE" => Some(LaneRole::Mid),
+        "ADC" | "BOTTOM" => Some(LaneRole::Adc),
+        "SUPPORT" | "UTILITY" => Some(LaneRole::Support),
+        _ => None,
+    }
+}
+
+/// Тиры из ответа op.gg и патч, за который они посчитаны (если сайт его указал).
+pub fn parse_opgg(json: &str) -> Result<(Option<String>, Vec<CommunityTier>)> {
+    let response: OpggResponse = serde_json::from_str(json)?;
+    let version = response
+        .meta
+        .and_then(|m| m.version)
+        .and_then(|v| PatchVersion::parse(&v))
+        .map(|v| v.base().to_string());
+    let tiers = response
+        .data
+        .into_iter()
+        .flat_map(|champion| {
+            champion.positions.into_iter().filter_map(move |position| {
+                let tier_data = position.stats.tier_data?;
+                Some(CommunityTier {
+                    champion_key: champion.id,
+                    role: opgg_role(&position.name)?,
+                    tier: opgg_tier_label(tier_data.tier?)?.to_string(),
+                    rank: tier_data.rank,
+                    pick_rate: position.stats.pick_rate,
+                    win_rate: position.stats.win_rate,
+                })
+            })
+        })
+        .collect();
+    Ok((version, tiers))
+}
+
+/// Для каждого чемпиона из `current` — оценка в основной роли и её значение в `previous`.
+pub fn tier_changes(current: &CommunityTierList, previous: Option<&CommunityTierList>) -> HashMap<i32, CommunityTierChange> {
+    let mut main: HashMap<i32, &CommunityTier> = HashMap::new();
+    for tier in &current.tiers {
+        let best = main.entry(tier.champion_key).or_insert(tier);
+        if tier.pick_rate > best.pick_rate {
+            *best = tier;
+        }
+    }
+    main.into_iter()
+        .map(|(key, tier)| {
+            let previous_tier = previous.and_then(|p| {
+                p.tiers
+                    .iter()
+                    .find(|t| t.champion_key == key && t.role == tier.role)
+                    .map(|t| t.tier.clone())
+            });
+            let change = CommunityTierChange {
+                source: current.source.clone(),
+                role: tier.role.clone(),
+                version: current.version.clone(),
+                tier: tier.tier.clone(),
+                previous_version: previous.map(|p| p.version.clone()),
+                previous_tier,
+            };
+            (key, change)
+        })
+        .collect()
+}
+
+/// Скачивает тир-лист op.gg и сохраняет его за патч сайта (или за последний сохранённый патч).
+pub async fn refresh(scraper: &Scraper, db: &Database) -> Result<CommunityTierList> {
+    let (version, tiers) = parse_opgg(&scraper.fetch_third_party(OPGG_URL).await?)?;
+    if tiers.is_empty() {
+        return Err(anyhow!("op.gg returned no champion tiers"));
+    }
+    let version = match version {
+        Some(v) => v,
+        None => db
+            .get_patches_newest_versions_first(1)
+            .await?
+            .first()
+            .map(|p| p.version.clone())
+            .ok_or_else(|| anyhow!("no downloaded patches to attach community tiers to"))?,
+    };
+    let list = CommunityTierList {
+        source: OPGG_SOURCE.to_string(),
+        version,
+        fetched_at: Utc::now(),
+        tiers,
+    };
+    db.save_community_tiers(&list).await?;
+    Ok(list)
+}
+
+pub fn set_refresh_enabled(enabled: bool) {
+    REFRESH_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Фоновое обновление, пока оно включено: в течение патча тиры меняются, сохраняется последний снимок.
+pub async fn run_refresh_loop(db: Arc<Database>, scraper: Arc<Scraper>) {
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + FIRST_REFRESH_DELAY, REFRESH_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        if !REFRESH_ENABLED.load(Ordering::Relaxed) {
+            continue;
+        }
+        match refresh(&scraper, &db).await {
+            Ok(list) => logger::log_with(
+                LogLevel::Debug,
+                LogSource::Sync,
+                "community tiers refreshed",
+                &[("version", &list.version), ("tiers", &list.tiers.len())],
+            ),
+            Err(e) => logger::log(LogLevel::Warn, LogSource::Sync, &format!("community tiers: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_opgg_positions_and_compares_main_role() {
+        let (version, tiers) = parse_opgg(
+            r#"{
+                "data": [
+                    {"id": 103, "positions": [
+                        {"name": "MID", "stats": {"win_rate": 0.51, "pick_rate": 0.08, "tier_data": {"tier": 1, "rank": 4}}},
+                        {"name": "ADC", "stats": {"win_rate": 0.47, "pick_rate": 0.01, "tier_data": {"tier": 4, "rank": 30}}}
+                    ]},
+                    {"id": 238, "positions": [{"name": "MID", "stats": {"pick_rate": 0.05, "tier_data": {"tier": 0}}}]}
+                ],
+                "meta": {"version": "15.22"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(version.as_deref(), Some("25.22"));
+        assert_eq!(tiers.len(), 3);
+
+        let list = |version: &str, tiers: Vec<CommunityTier>| CommunityTierList {
+            source: OPGG_SOURCE.into(),
+            version: version.into(),
+            fetched_at: Utc::now(),
+            tiers,
+        };
+        let mut previous = tiers.clone();
+        previous[0].tier = "A".into();
+        previous.truncate(2);
+        let changes = tier_changes(&list("25.22", tiers), Some(&list("25.21", previous)));
+        let ahri = &changes[&103];
+        assert_eq!((ahri.role.clone(), ahri.previous_tier.as_deref(), ahri.tier.as_str()), (LaneRole::Mid, Some("A"), "S"));
+        assert_eq!((changes[&238].tier.as_str(), changes[&238].previous_tier.as_deref()), ("S+", None));
+    }
+}
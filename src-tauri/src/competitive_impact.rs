@@ -0,0 +1,114 @@
+//! Режим «влияние на про-сцену»: изменения патча взвешиваются по значимости для соревновательной игры.
+//! Объекты карты, системы, предметы и руны получают повышенный вес целиком, чемпионы — по масштабу правок.
+//! Присутствие в пиках/банах и flex-роли не учитываются: ни один источник статистики их пока не заполняет.
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::{ChangeGrade, ChangeType, PatchCategory, PatchData};
+use crate::patch_change_trend::note_grade;
+
+/// Записей в разделе.
+const TOP_ENTRIES: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+pub enum CompetitiveReason {
+    /// Драконы, Барон, лес и прочие объекты карты
+    Objectives,
+    /// Общие системы игры
+    Systems,
+    /// Предмет или руна, которые берут многие чемпионы
+    SharedItemOrRune,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CompetitiveEntry {
+    pub note_id: String,
+    pub title: String,
+    pub category: PatchCategory,
+    pub entity_id: Option<String>,
+    /// `PatchNoteEntry::trend_score` записи
+    pub trend: i32,
+    /// Итоговая значимость для сортировки
+    pub impact: f64,
+    pub reasons: Vec<CompetitiveReason>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CompetitiveImpact {
+    pub version: String,
+    pub entries: Vec<CompetitiveEntry>,
+}
+
+fn grade_weight(grade: Option<ChangeGrade>) -> f64 {
+    match grade {
+        Some(ChangeGrade::Major) => 3.0,
+        Some(ChangeGrade::Moderate) => 2.0,
+        Some(ChangeGrade::Minor) | None => 1.0,
+    }
+}
+
+pub fn competitive_impact(patch: &PatchData) -> CompetitiveImpact {
+    let mut entries: Vec<CompetitiveEntry> = patch
+        .patch_notes
+        .iter()
+        .filter(|n| !n.category.is_mode_only() && n.change_type != ChangeType::Fix)
+        .filter_map(|note| {
+            let (weight, reason) = match note.category {
+                PatchCategory::Champions => (1.0, None),
+                PatchCategory::Objectives => (2.5, Some(CompetitiveReason::Objectives)),
+                PatchCategory::Systems => (2.0, Some(CompetitiveReason::Systems)),
+                PatchCategory::Items | PatchCategory::Runes | PatchCategory::ItemsRunes => {
+                    (1.5, Some(CompetitiveReason::SharedItemOrRune))
+                }
+                _ => return None,
+            };
+            let trend = note.trend_score();
+            let magnitude = grade_weight(note.grade.or_else(|| note_grade(note))) + (trend.abs() as f64).min(5.0) / 5.0;
+            Some(CompetitiveEntry {
+                note_id: note.id.clone(),
+                title: note.title.clone(),
+                category: note.category.clone(),
+                entity_id: note.entity_id.clone(),
+                trend,
+                impact: ((weight * magnitude) * 100.0).round() / 100.0,
+                reasons: reason.into_iter().collect(),
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| b.impact.total_cmp(&a.impact));
+    entries.truncate(TOP_ENTRIES);
+    CompetitiveImpact {
+        version: patch.version.clone(),
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{note, patch};
+
+    #[test]
+    fn objectives_and_systems_rank_above_champion_changes() {
+        let buff = ChangeType::Buff;
+        let patch = patch(
+            "25.22",
+            vec![
+                note("Ahri", PatchCategory::Champions, buff.clone(), &["Damage: 50 → 60"]),
+                note("Infinity Edge", PatchCategory::Items, buff.clone(), &["Damage: 50 → 60"]),
+                note("Baron Nashor", PatchCategory::Objectives, buff.clone(), &["Health: 9000 → 10800"]),
+                note("Turret plating", PatchCategory::Systems, buff.clone(), &["Gold: 125 → 150"]),
+                note("ARAM", PatchCategory::ModeAram, buff, &["Damage: 50 → 60"]),
+            ],
+        );
+        let impact = competitive_impact(&patch);
+        let order: Vec<&str> = impact.entries.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(order, ["Baron Nashor", "Turret plating", "Infinity Edge", "Ahri"]);
+        assert_eq!(impact.entries[0].reasons, [CompetitiveReason::Objectives]);
+        assert!(impact.entries[3].reasons.is_empty());
+    }
+}
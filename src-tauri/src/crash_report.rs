@@ -0,0 +1,212 @@
+//! Отчёты о сбоях: паника (хук) или ошибка, перехваченная на границе подсистемы — старт БД, загрузка
+//! патча, сохранение при синхронизации. Отчёт пишется в `<app_data>/crash_reports/<id>.json`: сообщение,
+//! место, backtrace, последние строки журнала и снимок состояния; `copy_crash_report` отдаёт его текстом для issue.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::logger;
+use crate::models::events::LogEvent;
+
+pub const CRASH_DIR_NAME: &str = "crash_reports";
+/// Старые отчёты сверх этого числа удаляются.
+const KEEP_REPORTS: usize = 20;
+const LOG_TAIL: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum CrashKind {
+    Panic,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CrashReport {
+    pub id: String,
+    pub kind: CrashKind,
+    /// Подсистема (`startup`, `scraper`, `sync`) или место паники `file:line`
+    pub context: String,
+    pub message: String,
+    pub backtrace: Option<String>,
+    pub app_version: String,
+    pub os: String,
+    /// Снимок состояния: БД, последняя загрузка патча, синхронизация
+    pub status: BTreeMap<String, String>,
+    pub recent_logs: Vec<LogEvent>,
+    pub created_at: DateTime<Utc>,
+}
+
+static DIR: OnceLock<PathBuf> = OnceLock::new();
+static APP_VERSION: OnceLock<String> = OnceLock::new();
+static STATUS: Lazy<Mutex<BTreeMap<String, String>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Каталог отчётов и хук паники; стандартный хук (вывод в stderr) тоже вызывается.
+pub fn install(dir: PathBuf, app_version: String) {
+    let _ = DIR.set(dir);
+    let _ = APP_VERSION.set(app_version);
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic".to_string());
+        let context = info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_default();
+        let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+        save(build(CrashKind::Panic, &context, &message, Some(backtrace)));
+        previous(info);
+    }));
+}
+
+/// Строка снимка состояния, которая попадёт в следующие отчёты.
+pub fn set_status(key: &str, value: impl Display) {
+    if let Ok(mut status) = STATUS.lock() {
+        status.insert(key.to_string(), value.to_string());
+    }
+}
+
+/// Отчёт об ошибке, которая не уронила приложение, но оборвала операцию.
+pub fn capture_error(context: &str, error: &dyn Display) {
+    save(build(CrashKind::Error, context, &error.to_string(), None));
+}
+
+fn build(kind: CrashKind, context: &str, message: &str, backtrace: Option<String>) -> CrashReport {
+    let created_at = Utc::now();
+    CrashReport {
+        id: format!(
+            "{}-{}",
+            created_at.format("%Y%m%d-%H%M%S%3f"),
+            match kind {
+                CrashKind::Panic => "panic",
+                CrashKind::Error => "error",
+            }
+        ),
+        kind,
+        context: context.to_string(),
+        message: message.to_string(),
+        backtrace,
+        app_version: APP_VERSION.get().cloned().unwrap_or_default(),
+        os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+        // Паника могла случиться под блокировкой — тогда снимок пустой, а не взаимная блокировка
+        status: STATUS.try_lock().map(|s| s.clone()).unwrap_or_default(),
+        recent_logs: logger::tail_for_report(LOG_TAIL),
+        created_at,
+    }
+}
+
+fn save(report: CrashReport) {
+    let Some(dir) = DIR.get() else {
+        return;
+    };
+    if let Err(e) = write(dir, &report) {
+        eprintln!("patch-analyzer: crash report: {}", e);
+    }
+}
+
+fn write(dir: &Path, report: &CrashReport) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(format!("{}.json", report.id)), serde_json::to_vec_pretty(report)?)?;
+    let mut names: Vec<PathBuf> = report_files(dir);
+    if names.len() > KEEP_REPORTS {
+        names.sort();
+        for old in &names[..names.len() - KEEP_REPORTS] {
+            let _ = fs::remove_file(old);
+        }
+    }
+    Ok(())
+}
+
+fn report_files(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.extension().is_some_and(|e| e == "json"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Сначала новые.
+fn list_in(dir: &Path) -> Vec<CrashReport> {
+    let mut reports: Vec<CrashReport> = report_files(dir)
+        .iter()
+        .filter_map(|p| serde_json::from_slice(&fs::read(p).ok()?).ok())
+        .collect();
+    reports.sort_by(|a, b| b.id.cmp(&a.id));
+    reports
+}
+
+pub fn list() -> Vec<CrashReport> {
+    DIR.get().map(|dir| list_in(dir)).unwrap_or_default()
+}
+
+/// Отчёт в Markdown для issue на GitHub.
+pub fn render_text(report: &CrashReport) -> String {
+    let mut out = format!(
+        "### Crash report {}\n\n- kind: {:?}\n- context: {}\n- app: {}\n- os: {}\n- time: {}\n\n**Message**\n\n```\n{}\n```\n",
+        report.id,
+        report.kind,
+        report.context,
+        report.app_version,
+        report.os,
+        report.created_at.to_rfc3339(),
+        report.message
+    );
+    if !report.status.is_empty() {
+        out.push_str("\n**Status**\n\n");
+        for (key, value) in &report.status {
+            out.push_str(&format!("- {}: {}\n", key, value));
+        }
+    }
+    if let Some(backtrace) = &report.backtrace {
+        out.push_str(&format!("\n**Backtrace**\n\n```\n{}\n```\n", backtrace.trim_end()));
+    }
+    if !report.recent_logs.is_empty() {
+        out.push_str("\n**Recent log**\n\n```\n");
+        for e in &report.recent_logs {
+            out.push_str(&format!("{} {}\n", e.timestamp.to_rfc3339(), logger::render(e)));
+        }
+        out.push_str("```\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_are_listed_newest_first_and_pruned() {
+        let dir = std::env::temp_dir().join(format!("patch-analyzer-crash-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for i in 0..KEEP_REPORTS + 2 {
+            let mut report = build(CrashKind::Error, "scraper", &format!("boom {}", i), None);
+            report.id = format!("20261017-{:03}-error", i);
+            report.status.insert("db".into(), "ready".into());
+            write(&dir, &report).unwrap();
+        }
+        let reports = list_in(&dir);
+        assert_eq!(reports.len(), KEEP_REPORTS);
+        assert_eq!(reports[0].message, format!("boom {}", KEEP_REPORTS + 1));
+
+        let text = render_text(&reports[0]);
+        assert!(text.contains("- context: scraper\n"));
+        assert!(text.contains("- db: ready\n"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,251 @@
+//! Источники данных о патчах. Встроенный — сайт Riot + статистика через `Scraper`; дополнительные
+//! (зеркала сообщества, частные парсеры) регистрируются в `DataSourceRegistry`, не трогая `scraper.rs`:
+//! в коде — через `register`, без пересборки — JSON-манифестом зеркала в `<app_data>/data_sources/`.
+
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::models::{ChampionStats, PatchData};
+use crate::scraper::Scraper;
+
+/// Встроенный источник; используется, когда команда не указала другой.
+pub const DEFAULT_SOURCE_ID: &str = "riot";
+/// Каталог манифестов зеркал в данных приложения.
+pub const MIRRORS_DIR_NAME: &str = "data_sources";
+
+pub type SourceFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+pub trait DataSource: Send + Sync {
+    /// Стабильный id для команд (`source`)
+    fn id(&self) -> &str;
+    fn name(&self) -> &str;
+    /// Версии от новой к старой
+    fn fetch_available_patches(&self) -> SourceFuture<'_, Vec<String>>;
+    fn fetch_patch<'a>(&'a self, version: &'a str, patch_notes_locale: &'a str) -> SourceFuture<'a, PatchData>;
+    /// Статистика чемпионов; источник без статистики возвращает пустой список.
+    fn fetch_stats<'a>(&'a self, version: &'a str) -> SourceFuture<'a, Vec<ChampionStats>>;
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct DataSourceInfo {
+    pub id: String,
+    pub name: String,
+    pub builtin: bool,
+}
+
+pub struct RiotDataSource {
+    scraper: Arc<Scraper>,
+}
+
+impl RiotDataSource {
+    pub fn new(scraper: Arc<Scraper>) -> Self {
+        Self { scraper }
+    }
+}
+
+impl DataSource for RiotDataSource {
+    fn id(&self) -> &str {
+        DEFAULT_SOURCE_ID
+    }
+
+    fn name(&self) -> &str {
+        "Riot Games"
+    }
+
+    fn fetch_available_patches(&self) -> SourceFuture<'_, Vec<String>> {
+        Box::pin(self.scraper.fetch_available_patches())
+    }
+
+    fn fetch_patch<'a>(&'a self, version: &'a str, patch_notes_locale: &'a str) -> SourceFuture<'a, PatchData> {
+        Box::pin(self.scraper.fetch_current_meta(version, patch_notes_locale))
+    }
+
+    fn fetch_stats<'a>(&'a self, _version: &'a str) -> SourceFuture<'a, Vec<ChampionStats>> {
+        // Сайты статистики отдают только текущий патч
+        Box::pin(async move { Ok(self.scraper.fetch_champion_stats().await) })
+    }
+}
+
+/// Манифест зеркала: JSON с адресами, `{version}` и `{locale}` подставляются.
+/// `patches_url` отдаёт массив версий, `patch_url` — `PatchData`, `stats_url` — массив `ChampionStats`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MirrorManifest {
+    pub id: String,
+    pub name: String,
+    pub patches_url: String,
+    pub patch_url: String,
+    #[serde(default)]
+    pub stats_url: Option<String>,
+}
+
+pub struct MirrorDataSource {
+    manifest: MirrorManifest,
+}
+
+impl MirrorDataSource {
+    pub fn new(manifest: MirrorManifest) -> Self {
+        Self { manifest }
+    }
+}
+
+fn expand_url(template: &str, version: &str, locale: &str) -> String {
+    template.replace("{version}", version).replace("{locale}", locale)
+}
+
+async fn get_json<T: serde::de::DeserializeOwned>(url: String) -> Result<T> {
+    let resp = crate::http_client::api_client()?
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()
+        .with_context(|| format!("GET {}", url))?;
+    crate::http_body::read_json(resp).await
+}
+
+impl DataSource for MirrorDataSource {
+    fn id(&self) -> &str {
+        &self.manifest.id
+    }
+
+    fn name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    fn fetch_available_patches(&self) -> SourceFuture<'_, Vec<String>> {
+        Box::pin(get_json(self.manifest.patches_url.clone()))
+    }
+
+    fn fetch_patch<'a>(&'a self, version: &'a str, patch_notes_locale: &'a str) -> SourceFuture<'a, PatchData> {
+        let url = expand_url(&self.manifest.patch_url, version, patch_notes_locale);
+        Box::pin(async move {
+            let mut patch: PatchData = get_json(url).await?;
+            if patch.version != version {
+                return Err(anyhow!("mirror returned patch {} for {}", patch.version, version));
+            }
+            patch.patch_notes_locale.get_or_insert_with(|| patch_notes_locale.to_string());
+            Ok(patch)
+        })
+    }
+
+    fn fetch_stats<'a>(&'a self, version: &'a str) -> SourceFuture<'a, Vec<ChampionStats>> {
+        let url = self.manifest.stats_url.as_deref().map(|t| expand_url(t, version, ""));
+        Box::pin(async move {
+            match url {
+                Some(url) => get_json(url).await,
+                None => Ok(Vec::new()),
+            }
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct DataSourceRegistry {
+    sources: RwLock<Vec<Arc<dyn DataSource>>>,
+}
+
+impl DataSourceRegistry {
+    pub fn with_builtin(scraper: Arc<Scraper>) -> Self {
+        let registry = Self::default();
+        registry.register(Arc::new(RiotDataSource::new(scraper)));
+        registry
+    }
+
+    /// Источник с уже занятым id заменяет прежний.
+    pub fn register(&self, source: Arc<dyn DataSource>) {
+        let mut sources = self.sources.write().unwrap_or_else(|e| e.into_inner());
+        sources.retain(|s| s.id() != source.id());
+        sources.push(source);
+    }
+
+    /// Убирает все зеркала, остаётся встроенный источник.
+    pub fn remove_mirrors(&self) {
+        self.sources
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|s| s.id() == DEFAULT_SOURCE_ID);
+    }
+
+    pub fn get(&self, id: &str) -> Option<Arc<dyn DataSource>> {
+        self.sources
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|s| s.id() == id)
+            .cloned()
+    }
+
+    pub fn list(&self) -> Vec<DataSourceInfo> {
+        self.sources
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|s| DataSourceInfo {
+                id: s.id().to_string(),
+                name: s.name().to_string(),
+                builtin: s.id() == DEFAULT_SOURCE_ID,
+            })
+            .collect()
+    }
+
+    /// Регистрирует зеркала из `*.json` каталога; встроенный id занять нельзя. Возвращает ошибки по файлам.
+    pub fn load_mirror_manifests(&self, dir: &Path) -> Vec<String> {
+        let mut errors = Vec::new();
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return errors;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let manifest = std::fs::read(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|bytes| Ok(serde_json::from_slice::<MirrorManifest>(&bytes)?));
+            match manifest {
+                Ok(m) if m.id == DEFAULT_SOURCE_ID => {
+                    errors.push(format!("{}: id \"{}\" is reserved", path.display(), m.id))
+                }
+                Ok(m) => self.register(Arc::new(MirrorDataSource::new(m))),
+                Err(e) => errors.push(format!("{}: {:#}", path.display(), e)),
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mirror_manifest_expands_templates_and_replaces_same_id() {
+        let manifest: MirrorManifest = serde_json::from_str(
+            r#"{"id":"mirror","name":"Mirror","patches_url":"https://m/patches.json",
+                "patch_url":"https://m/{version}/{locale}.json"}"#,
+        )
+        .unwrap();
+        assert_eq!(expand_url(&manifest.patch_url, "25.22", "en"), "https://m/25.22/en.json");
+        assert!(manifest.stats_url.is_none());
+
+        let registry = DataSourceRegistry::default();
+        registry.register(Arc::new(MirrorDataSource::new(manifest.clone())));
+        registry.register(Arc::new(MirrorDataSource::new(MirrorManifest {
+            name: "Mirror 2".into(),
+            ..manifest
+        })));
+        let list = registry.list();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].name, "Mirror 2");
+        assert!(!list[0].builtin);
+
+        registry.remove_mirrors();
+        assert!(registry.list().is_empty());
+    }
+}
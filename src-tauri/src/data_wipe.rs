@@ -0,0 +1,194 @@
+//! Полная очистка локальных данных (`wipe_all_data`): таблицы БД, кэши картинок, журнал, отчёты о сбоях,
+//! сгенерированные файлы, скачанные установщики обновлений. Скрипты и манифесты источников пишет сам пользователь —
+//! они удаляются только по отдельному флагу. В режиме `dry_run` только список того, что будет удалено, с размерами.
+//! Настройки интерфейса (localStorage) сбрасывает фронтенд.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::{crash_report, data_source, logger, overlay, patch_feed, script_hooks};
+
+pub const DB_FILE_NAME: &str = "patches.db";
+/// Установщик обновления в кэше приложения: `patch-analyzer-{версия}-setup.exe`.
+const INSTALLER_PREFIX: &str = "patch-analyzer-";
+const INSTALLER_SUFFIX: &str = "-setup.exe";
+
+#[cfg_attr(debug_assertions, allow(dead_code))] // автообновление только в release
+pub fn update_installer_file_name(version: &str) -> String {
+    format!("{INSTALLER_PREFIX}{version}{INSTALLER_SUFFIX}")
+}
+
+fn is_update_installer(name: &str) -> bool {
+    name.len() > INSTALLER_PREFIX.len() + INSTALLER_SUFFIX.len()
+        && name.starts_with(INSTALLER_PREFIX)
+        && name.ends_with(INSTALLER_SUFFIX)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+pub enum WipeKind {
+    /// Все таблицы `patches.db`, включая сохранённый HTML патч-нотов
+    Database,
+    PatchAssets,
+    GameAssetIcons,
+    Logs,
+    CrashReports,
+    PatchFeed,
+    Overlay,
+    Scripts,
+    DataSources,
+    UpdateCache,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct WipeTarget {
+    pub kind: WipeKind,
+    pub path: String,
+    #[ts(type = "number")]
+    pub files: u64,
+    #[ts(type = "number")]
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct WipeReport {
+    pub dry_run: bool,
+    pub targets: Vec<WipeTarget>,
+    #[ts(type = "number")]
+    pub total_bytes: u64,
+    /// Что удалить не получилось (файл занят и т.п.)
+    pub errors: Vec<String>,
+}
+
+fn target(kind: WipeKind, path: PathBuf) -> Option<WipeTarget> {
+    let (files, bytes) = if path.is_dir() {
+        crate::count_files_recursive(&path)
+    } else {
+        (1, std::fs::metadata(&path).ok()?.len())
+    };
+    Some(WipeTarget {
+        kind,
+        path: path.to_string_lossy().into_owned(),
+        files,
+        bytes,
+    })
+}
+
+/// Существующие цели очистки; из `cache_dir` — только скачанные установщики обновлений: в том же каталоге
+/// лежит профиль WebView2 (Windows), его трогать нельзя. `include_extensions` — ещё скрипты и манифесты источников.
+pub fn plan(app_data: &Path, cache_dir: Option<&Path>, include_extensions: bool) -> Vec<WipeTarget> {
+    let extensions = [
+        (WipeKind::Scripts, app_data.join(script_hooks::SCRIPTS_DIR_NAME)),
+        (WipeKind::DataSources, app_data.join(data_source::MIRRORS_DIR_NAME)),
+    ];
+    let mut targets: Vec<WipeTarget> = [
+        (WipeKind::Database, app_data.join(DB_FILE_NAME)),
+        (WipeKind::PatchAssets, app_data.join("patch_assets")),
+        (WipeKind::GameAssetIcons, app_data.join("game_assets_icons")),
+        (WipeKind::Logs, app_data.join(logger::LOG_DIR_NAME)),
+        (WipeKind::CrashReports, app_data.join(crash_report::CRASH_DIR_NAME)),
+        (WipeKind::PatchFeed, app_data.join(patch_feed::FEED_FILE_NAME)),
+        (WipeKind::Overlay, app_data.join(overlay::OVERLAY_FILE_NAME)),
+    ]
+    .into_iter()
+    .chain(extensions.into_iter().filter(|_| include_extensions))
+    .filter_map(|(kind, path)| target(kind, path))
+    .collect();
+    if let Some(Ok(entries)) = cache_dir.map(std::fs::read_dir) {
+        let mut installers: Vec<PathBuf> = entries
+            .flatten()
+            .filter(|e| e.file_type().is_ok_and(|t| t.is_file()))
+            .filter(|e| is_update_installer(&e.file_name().to_string_lossy()))
+            .map(|e| e.path())
+            .collect();
+        installers.sort();
+        targets.extend(installers.into_iter().filter_map(|p| target(WipeKind::UpdateCache, p)));
+    }
+    targets
+}
+
+/// Удаляет файлы и каталоги целей, кроме БД: её очищают запросом, файл держит открытый пул.
+pub fn remove_files(targets: &[WipeTarget]) -> Vec<String> {
+    let mut errors = Vec::new();
+    for t in targets.iter().filter(|t| t.kind != WipeKind::Database) {
+        let path = Path::new(&t.path);
+        let result = if path.is_dir() {
+            std::fs::remove_dir_all(path)
+        } else {
+            std::fs::remove_file(path)
+        };
+        if let Err(e) = result {
+            errors.push(format!("{}: {}", t.path, e));
+        }
+    }
+    errors
+}
+
+pub fn report(dry_run: bool, targets: Vec<WipeTarget>, errors: Vec<String>) -> WipeReport {
+    WipeReport {
+        dry_run,
+        total_bytes: targets.iter().map(|t| t.bytes).sum(),
+        targets,
+        errors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn plans_existing_targets_and_keeps_database_file() {
+        let root = std::env::temp_dir().join(format!("patch-analyzer-wipe-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("patch_assets/ab")).unwrap();
+        fs::write(root.join("patch_assets/ab/icon.png"), [0u8; 10]).unwrap();
+        fs::write(root.join(DB_FILE_NAME), [0u8; 100]).unwrap();
+        fs::write(root.join(patch_feed::FEED_FILE_NAME), "<rss/>").unwrap();
+        let scripts = root.join(script_hooks::SCRIPTS_DIR_NAME);
+        fs::create_dir_all(&scripts).unwrap();
+        fs::write(scripts.join("mine.rhai"), "fn f(x) {}").unwrap();
+
+        let kinds = |targets: &[WipeTarget]| targets.iter().map(|t| t.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds(&plan(&root, None, true)),
+            [WipeKind::Database, WipeKind::PatchAssets, WipeKind::PatchFeed, WipeKind::Scripts]
+        );
+        let targets = plan(&root, None, false);
+        assert_eq!(kinds(&targets), [WipeKind::Database, WipeKind::PatchAssets, WipeKind::PatchFeed]);
+        assert_eq!(report(true, targets.clone(), vec![]).total_bytes, 116);
+
+        assert!(remove_files(&targets).is_empty());
+        assert!(root.join(DB_FILE_NAME).exists());
+        assert!(!root.join("patch_assets").exists() && !root.join(patch_feed::FEED_FILE_NAME).exists());
+        assert!(scripts.join("mine.rhai").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn wipes_only_update_installers_from_cache_dir() {
+        let root = std::env::temp_dir().join(format!("patch-analyzer-wipe-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let (app_data, cache) = (root.join("data"), root.join("cache"));
+        fs::create_dir_all(&app_data).unwrap();
+        fs::create_dir_all(cache.join("EBWebView/Default")).unwrap();
+        fs::write(cache.join("EBWebView/Default/Cookies"), [0u8; 8]).unwrap();
+        fs::write(cache.join(update_installer_file_name("1.4.0")), [0u8; 20]).unwrap();
+        fs::write(cache.join("patch-analyzer-notes.txt"), "keep").unwrap();
+
+        let targets = plan(&app_data, Some(&cache), true);
+        assert_eq!(targets.len(), 1);
+        assert_eq!((targets[0].kind, targets[0].bytes), (WipeKind::UpdateCache, 20));
+
+        assert!(remove_files(&targets).is_empty());
+        assert!(!cache.join(update_installer_file_name("1.4.0")).exists());
+        assert!(cache.join("EBWebView/Default/Cookies").exists());
+        assert!(cache.join("patch-analyzer-notes.txt").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -1,20 +1,97 @@
 use anyhow::Result;
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
-use sqlx::SqlitePool;
+use sqlx::{SqliteConnection, SqliteExecutor, SqlitePool};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 
 use crate::ChampionHistoryEntry;
+use crate::fallback_patches::{FallbackPatch, FallbackPatchSource};
+use crate::watchlist::WatchEntry;
+use crate::patch_revisions::{NoteRevisionChange, PatchRevision};
+use crate::summoner::LinkedSummoner;
+use crate::riot_api::RankedMatch;
+use crate::community_tiers::CommunityTierList;
+use crate::pro_builds::ProBuildsSnapshot;
+use crate::patch_reaction::PatchReaction;
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
 use crate::models::{
-    ChampionStats, ChangeBlock, GameAssetsMeta, IconSourceEntry, MayhemAugmentation, PatchCategory,
-    PatchData, PatchNoteEntry, StaticCatalogRow,
+    ChampionMeta, ChampionStats, ChangeBlock, ChangeGrade, DevArticle, EntityRef, GameAssetsMeta, IconSourceEntry, MayhemAugmentation, PatchCategory,
+    PatchData, PatchNoteEntry, PatchSummary, StaticCatalogRow,
 };
 use crate::patch_version::{
-    cmp_display_patch, display_patch_to_ddragon_major_minor, versions_match,
+    cmp_display_patch, versions_match, PatchVersion,
     DISPLAY_MAJOR_MAP_TO_DDRAGON_FROM,
 };
+use crate::patch_cache::{PatchCache, PatchCacheStats, DEFAULT_PATCH_CACHE_BUDGET_BYTES};
+use crate::tier_list::PatchTierContributions;
 use serde::{Deserialize, Serialize};
 use serde_json;
+use ts_rs::TS;
+
+/// Строка `patches` без data_json: версия, локаль, время загрузки и число записей из сводки.
+#[derive(Debug, Clone)]
+pub struct PatchMeta {
+    pub version: String,
+    pub patch_notes_locale: String,
+    /// RFC 3339, как в колонке fetched_at
+    pub fetched_at: String,
+    /// `None` — сводка ещё не посчитана (строки до появления patch_summaries)
+    pub total_notes: Option<u32>,
+}
+
+/// План выполнения одного из типовых запросов (`EXPLAIN QUERY PLAN`).
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct QueryPlan {
+    pub name: String,
+    pub sql: String,
+    pub plan: Vec<String>,
+    /// Есть шаг с полным проходом таблицы без индекса
+    pub full_scan: bool,
+}
+
+/// Типовые запросы приложения с подставленными значениями — для диагностики индексов.
+const DIAGNOSTIC_QUERIES: &[(&str, &str)] = &[
+    (
+        "patch_by_version",
+        "SELECT patch_notes_locale, fetched_at FROM patches WHERE version = '25.22' \
+         ORDER BY CASE patch_notes_locale WHEN 'ru' THEN 0 WHEN 'en' THEN 1 ELSE 2 END, fetched_at DESC LIMIT 1",
+    ),
+    (
+        "versions_for_locale",
+        "SELECT DISTINCT version FROM patches WHERE patch_notes_locale = 'ru'",
+    ),
+    (
+        "patch_meta_with_summary",
+        "SELECT p.version, p.patch_notes_locale, p.fetched_at, json_extract(s.summary_json, '$.total_notes') \
+         FROM patches p LEFT JOIN patch_summaries s \
+         ON s.version = p.version AND s.patch_notes_locale = p.patch_notes_locale",
+    ),
+    (
+        "recent_patches_by_fetched_at",
+        "SELECT version FROM patches ORDER BY fetched_at DESC LIMIT 20",
+    ),
+    (
+        "stale_tier_contributions",
+        "SELECT p.version FROM patches p LEFT JOIN tier_contributions t \
+         ON t.version = p.version AND t.patch_notes_locale = p.patch_notes_locale \
+         WHERE t.version IS NULL OR t.algo_version != 1",
+    ),
+    (
+        "dev_articles_newest",
+        "SELECT data_json FROM dev_articles ORDER BY COALESCE(published_at, fetched_at) DESC",
+    ),
+];
+
+/// Шаг плана — полный проход таблицы (`SCAN p`), а не поиск или обход по индексу.
+fn is_full_scan_step(detail: &str) -> bool {
+    detail.starts_with("SCAN ") && !detail.contains(" INDEX ") && !detail.contains("json_each")
+}
+
+/// Версия алгоритма `tier_list::patch_contributions`: при изменении подсчёта вклады пересчитываются.
+const TIER_CONTRIBUTIONS_ALGO_VERSION: i64 = 1;
 
 pub const AUGMENTS_CATALOG_KEY_ARAM_MAYHEM_EN: &str = "aram_mayhem_en";
 pub const AUGMENTS_CATALOG_KEY_ARAM_MAYHEM_RU: &str = "aram_mayhem_ru";
@@ -75,7 +152,7 @@ fn normalize_patch_locale(locale: &str) -> &'static str {
 fn patch_note_to_effect_html(note: &PatchNoteEntry) -> String {
     let mut parts: Vec<String> = Vec::new();
     for b in &note.details {
-        for c in &b.changes {
+        for c in b.all_changes() {
             let t = c.trim();
             if !t.is_empty() {
                 parts.push(html_escape_min(t));
@@ -173,7 +250,7 @@ fn wiki_catalog_effect_text(entry: &PatchNoteEntry) -> String {
         parts.push(sum.to_string());
     }
     for b in &entry.details {
-        for c in &b.changes {
+        for c in b.all_changes() {
             let t = c.trim();
             if !t.is_empty() {
                 parts.push(t.to_string());
@@ -307,38 +384,115 @@ fn enrich_patch_notes_with_wiki_augments(
                 title: Some(WIKI_AUGMENT_DETAIL_TITLE.to_string()),
                 icon_url,
                 changes: vec![wiki_text],
+                children: vec![],
             },
         );
     }
 }
 
+/// Совпадение записи с искомым именем (`search` уже в нижнем регистре) в любой из локалей.
+pub(crate) fn note_matches_name(note: &PatchNoteEntry, search: &str) -> bool {
+    if let Some(entity_id) = note.entity_id.as_deref() {
+        let entity_id = entity_id.to_lowercase();
+        let bare = entity_id.split_once(':').map(|(_, id)| id).unwrap_or("");
+        if entity_id == search || bare == search {
+            return true;
+        }
+    }
+    note.id.to_lowercase() == search
+        || note.title.to_lowercase() == search
+        || note.title_en.as_deref().is_some_and(|t| t.to_lowercase() == search)
+        || note.title_ru.as_deref().is_some_and(|t| t.to_lowercase() == search)
+}
+
+/// Запись патч-нота из SQL-отбора истории: version, locale, fetched_at, published_at, season, JSON записи.
+type HistoryNoteRow = (String, String, String, Option<String>, Option<String>, String);
+/// Строка списка патчей: version, locale, fetched_at, published_at, season.
+type PatchMetaRow = (String, String, String, Option<String>, Option<String>);
+
+/// Дата патча для таймлайнов: публикация статьи, иначе время загрузки.
+fn patch_row_date(published_at: Option<&str>, fetched_at: &str) -> chrono::DateTime<chrono::Utc> {
+    let parse = |d: &str| {
+        chrono::DateTime::parse_from_rfc3339(d)
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+    };
+    published_at
+        .and_then(parse)
+        .or_else(|| parse(fetched_at))
+        .unwrap_or_else(chrono::Utc::now)
+}
+
+/// Статистика чемпиона в одном сохранённом патче (по строке на роль; пусто — данных нет).
+pub struct ChampionPatchStats {
+    pub version: String,
+    pub date: chrono::DateTime<chrono::Utc>,
+    pub split: String,
+    pub stats: Vec<ChampionStats>,
+}
+
 pub struct Database {
     pool: SqlitePool,
+    /// Общий для всех команд LRU разобранных патчей; блокировка не держится через await
+    patch_cache: Mutex<PatchCache>,
 }
 
+/// Текущая версия формата `patches.data_json`.
+/// 0 — строки без поля `schema_version` (включая самый старый вариант — голый `Vec<ChampionStats>`).
+pub const PATCH_JSON_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Serialize, Deserialize)]
 struct PatchJsonContent {
+    #[serde(default)]
+    schema_version: u32,
     champions: Vec<ChampionStats>,
     patch_notes: Vec<PatchNoteEntry>,
     #[serde(default)]
     banner_url: Option<String>,
     #[serde(default)]
     patch_notes_locale: Option<String>,
+    #[serde(default)]
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    season: Option<String>,
 }
 
-fn deserialize_stored_json(data: &str) -> Option<PatchJsonContent> {
-    if let Ok(c) = serde_json::from_str::<PatchJsonContent>(data) {
-        return Some(c);
+/// Разбирает сохранённый JSON любой известной версии и поднимает его до `PATCH_JSON_SCHEMA_VERSION`.
+/// Строки из более новой версии приложения не читаются, чтобы не потерять незнакомые поля при пересохранении.
+fn decode_stored_patch_json(data: &str) -> Result<PatchJsonContent> {
+    let mut value: serde_json::Value = serde_json::from_str(data)?;
+    if value.is_array() {
+        // v0 (самые ранние сборки): в data_json лежал только список чемпионов.
+        value = serde_json::json!({ "champions": value, "patch_notes": [] });
+    }
+    let version = value
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+    if version > PATCH_JSON_SCHEMA_VERSION {
+        return Err(anyhow::anyhow!(
+            "patch data schema v{} is newer than supported v{}",
+            version,
+            PATCH_JSON_SCHEMA_VERSION
+        ));
     }
-    if let Ok(champions) = serde_json::from_str::<Vec<ChampionStats>>(data) {
-        return Some(PatchJsonContent {
-            champions,
-            patch_notes: vec![],
-            banner_url: None,
-            patch_notes_locale: None,
-        });
+    // Миграции по шагам: v0 → v1 не меняет форму, только проставляет версию.
+    // Следующие изменения модели добавляют сюда свои шаги `if version < N { ... }`.
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert(
+            "schema_version".to_string(),
+            serde_json::Value::from(PATCH_JSON_SCHEMA_VERSION),
+        );
     }
-    None
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Списочные запросы пропускают нечитаемую строку с предупреждением в логе: одна битая строка
+/// не должна скрывать всю историю. Запрос одного патча (`get_patch*`) возвращает ошибку вызывающему.
+fn skip_unreadable_patch_row(error: &anyhow::Error) {
+    logger::log(LogLevel::Warn, LogSource::Db, &format!("skip unreadable patch row: {}", error));
 }
 
 fn patch_data_from_stored_row(
@@ -347,22 +501,20 @@ fn patch_data_from_stored_row(
     date_str: &str,
     locale: Option<&str>,
 ) -> Result<PatchData> {
-    let content: PatchJsonContent = match serde_json::from_str(data) {
-        Ok(c) => c,
-        Err(_) => {
-            let champions: Vec<ChampionStats> = serde_json::from_str(data).unwrap_or_default();
-            PatchJsonContent {
-                champions,
-                patch_notes: vec![],
-                banner_url: None,
-                patch_notes_locale: None,
-            }
-        }
-    };
+    let mut content = decode_stored_patch_json(data)
+        .map_err(|e| anyhow::anyhow!("patch {} ({}): {}", ver, locale.unwrap_or("?"), e))?;
+    crate::patch_change_trend::fill_note_grades(&mut content.patch_notes);
     let date = chrono::DateTime::parse_from_rfc3339(date_str)
         .map(|dt| dt.with_timezone(&chrono::Utc))
         .unwrap_or_else(|_| chrono::Utc::now());
+    let split = crate::patch_version::season_split(
+        &ver,
+        content.season.as_deref(),
+        content.published_at.unwrap_or(date),
+    );
     Ok(PatchData {
+        parent_version: crate::patch_version::hotfix_parent(&ver),
+        split,
         version: ver,
         fetched_at: date,
         champions: content.champions,
@@ -371,6 +523,10 @@ fn patch_data_from_stored_row(
         patch_notes_locale: content
             .patch_notes_locale
             .or_else(|| locale.map(|s| normalize_patch_locale(s).to_string())),
+        published_at: content.published_at,
+        author: content.author,
+        season: content.season,
+        machine_translation: None,
     })
 }
 
@@ -414,6 +570,13 @@ impl Database {
         .execute(&pool)
         .await?;
 
+        // Список версий локали (`SELECT DISTINCT version ... WHERE patch_notes_locale = ?`) без прохода таблицы
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_patches_locale_version ON patches (patch_notes_locale, version);"#,
+        )
+        .execute(&pool)
+        .await?;
+
         sqlx::query(
             r#"
             CREATE TABLE IF NOT EXISTS augments_catalog (
@@ -477,7 +640,216 @@ impl Database {
         .execute(&pool)
         .await?;
 
-        Ok(Self { pool })
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS patch_summaries (
+                version TEXT NOT NULL,
+                patch_notes_locale TEXT NOT NULL,
+                summary_json TEXT NOT NULL,
+                PRIMARY KEY (version, patch_notes_locale)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS tier_contributions (
+                version TEXT NOT NULL,
+                patch_notes_locale TEXT NOT NULL,
+                algo_version INTEGER NOT NULL,
+                data_json TEXT NOT NULL,
+                fetched_at TEXT NOT NULL,
+                PRIMARY KEY (version, patch_notes_locale)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS dev_articles (
+                url TEXT PRIMARY KEY NOT NULL,
+                locale TEXT NOT NULL,
+                published_at TEXT,
+                data_json TEXT NOT NULL,
+                fetched_at TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"CREATE INDEX IF NOT EXISTS idx_dev_articles_date ON dev_articles (COALESCE(published_at, fetched_at) DESC);"#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Пользовательские теги записей: не кэш, при очистке кэша не удаляются.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS note_tags (
+                version TEXT NOT NULL,
+                note_id TEXT NOT NULL,
+                tags_json TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                PRIMARY KEY (version, note_id)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Прежние версии патчей, статьи которых Riot исправил после публикации.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS patch_revisions (
+                version TEXT NOT NULL,
+                patch_notes_locale TEXT NOT NULL,
+                revision INTEGER NOT NULL,
+                previous_json TEXT NOT NULL,
+                changes_json TEXT NOT NULL,
+                detected_at TEXT NOT NULL,
+                PRIMARY KEY (version, patch_notes_locale, revision)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Отслеживаемые сущности: не кэш, при очистке кэша не удаляются.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS watchlist (
+                entity_json TEXT PRIMARY KEY NOT NULL,
+                min_grade TEXT,
+                added_at TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Привязанный аккаунт игрока: одна строка.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS linked_summoner (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                data_json TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Тир-листы сторонних сайтов по патчам: старые патчи заново не скачать, поэтому это не кэш.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS community_tiers (
+                version TEXT NOT NULL,
+                source TEXT NOT NULL,
+                fetched_at TEXT NOT NULL,
+                data_json TEXT NOT NULL,
+                PRIMARY KEY (version, source)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Сборки про по чемпиону и патчу; собираются только в первые дни патча, поэтому не кэш.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pro_builds (
+                champion TEXT NOT NULL,
+                patch_version TEXT NOT NULL,
+                fetched_at TEXT NOT NULL,
+                data_json TEXT NOT NULL,
+                PRIMARY KEY (champion, patch_version)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Кэш реакции Reddit на патч.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS patch_reactions (
+                version TEXT NOT NULL,
+                patch_notes_locale TEXT NOT NULL,
+                fetched_at TEXT NOT NULL,
+                data_json TEXT NOT NULL,
+                PRIMARY KEY (version, patch_notes_locale)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Память машинного перевода: оригинальная строка патч-нотов и её перевод.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS machine_translations (
+                source_locale TEXT NOT NULL,
+                target_locale TEXT NOT NULL,
+                original TEXT NOT NULL,
+                translated TEXT NOT NULL,
+                provider TEXT NOT NULL,
+                translated_at TEXT NOT NULL,
+                PRIMARY KEY (source_locale, target_locale, original)
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Импортированные рейтинговые матчи привязанного аккаунта.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS ranked_matches (
+                match_id TEXT PRIMARY KEY NOT NULL,
+                puuid TEXT NOT NULL,
+                game_start TEXT NOT NULL,
+                data_json TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Ротации магазина, о конце которых уже напомнили.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS store_reminders (
+                rotation_key TEXT PRIMARY KEY NOT NULL,
+                reminded_at TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Запасной список патчей: строки `ddragon` — кэш, `manual` — добавленные пользователем.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS fallback_patches (
+                version TEXT PRIMARY KEY NOT NULL,
+                source TEXT NOT NULL,
+                added_at TEXT NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self {
+            pool,
+            patch_cache: Mutex::new(PatchCache::new(DEFAULT_PATCH_CACHE_BUDGET_BYTES)),
+        })
     }
 
     async fn ensure_patches_schema(pool: &SqlitePool) -> Result<()> {
@@ -555,47 +927,100 @@ impl Database {
             .unwrap_or(false)
     }
 
-    fn is_better_equivalent_patch_row(
-        candidate: &(String, String, String, String),
-        current: &(String, String, String, String),
-    ) -> bool {
-        let candidate_modern = Self::prefers_modern_display_patch(&candidate.0);
-        let current_modern = Self::prefers_modern_display_patch(&current.0);
+    /// `candidate` и `current` — (version, fetched_at) эквивалентных строк одного патча.
+    fn is_better_equivalent_patch_row(candidate: (&str, &str), current: (&str, &str)) -> bool {
+        let candidate_modern = Self::prefers_modern_display_patch(candidate.0);
+        let current_modern = Self::prefers_modern_display_patch(current.0);
         if candidate_modern != current_modern {
             return candidate_modern;
         }
-        if candidate.3 != current.3 {
-            return candidate.3 > current.3;
+        if candidate.1 != current.1 {
+            return candidate.1 > current.1;
         }
-        cmp_display_patch(&candidate.0, &current.0).is_gt()
+        cmp_display_patch(candidate.0, current.0).is_gt()
     }
 
-    /// Строки патчей в порядке **убывания игровой версии** (не по времени загрузки),
-    /// с дедупликацией эквивалентных отображений одной версии (например, 16.8 и 26.8).
-    async fn fetch_version_ordered_rows(
-        &self,
-        limit: Option<i64>,
-    ) -> Result<Vec<(String, String, String, String)>> {
-        let all_rows: Vec<(String, String, String, String)> = sqlx::query_as(
-            "SELECT version, patch_notes_locale, data_json, fetched_at FROM patches",
+    fn patch_cache(&self) -> std::sync::MutexGuard<'_, PatchCache> {
+        self.patch_cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Разобранный патч строки (version, locale): из LRU, иначе из data_json с записью в LRU.
+    async fn load_patch_row(&self, version: &str, locale: &str, fetched_at: &str) -> Result<Option<Arc<PatchData>>> {
+        if let Some(patch) = self.patch_cache().get(version, locale, fetched_at) {
+            return Ok(Some(patch));
+        }
+        let row: Option<(String, String)> = sqlx::query_as(
+            "SELECT data_json, fetched_at FROM patches WHERE version = ? AND patch_notes_locale = ?",
         )
-            .fetch_all(&self.pool)
-            .await?;
+        .bind(version)
+        .bind(locale)
+        .fetch_optional(&self.pool)
+        .await?;
+        let Some((data, fetched_at)) = row else {
+            return Ok(None);
+        };
+        let patch = Arc::new(patch_data_from_stored_row(
+            version.to_string(),
+            &data,
+            &fetched_at,
+            Some(locale),
+        )?);
+        self.patch_cache().insert(
+            version,
+            locale,
+            &fetched_at,
+            patch.clone(),
+            PatchCache::estimate_bytes(data.len()),
+        );
+        Ok(Some(patch))
+    }
+
+    pub fn patch_cache_stats(&self) -> PatchCacheStats {
+        self.patch_cache().stats()
+    }
+
+    pub fn set_patch_cache_budget(&self, budget_bytes: usize) {
+        self.patch_cache().set_budget(budget_bytes);
+    }
 
-        let mut by_equivalent: HashMap<(i32, i32), (String, String, String, String)> =
-            HashMap::new();
+    /// Патчи в порядке **убывания игровой версии** (не по времени загрузки),
+    /// с дедупликацией эквивалентных отображений одной версии (например, 16.8 и 26.8).
+    /// data_json читается только для выбранных строк, которых нет в LRU; нечитаемые строки пропускаются.
+    async fn load_version_ordered_patches(&self, limit: Option<i64>) -> Result<Vec<Arc<PatchData>>> {
+        let metas = self.list_patch_meta().await?;
+        let take = match limit {
+            Some(limit) if limit > 0 => limit as usize,
+            _ => metas.len(),
+        };
+        let mut out = Vec::with_capacity(take.min(metas.len()));
+        for meta in metas.into_iter().take(take) {
+            match self
+                .load_patch_row(&meta.version, &meta.patch_notes_locale, &meta.fetched_at)
+                .await
+            {
+                Ok(Some(patch)) => out.push(patch),
+                Ok(None) => {}
+                Err(e) => skip_unreadable_patch_row(&e),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Сортировка по убыванию версии с дедупликацией эквивалентов; `key` — (version, fetched_at) строки.
+    fn order_version_rows<T>(all_rows: Vec<T>, key: impl Fn(&T) -> (&str, &str)) -> Vec<T> {
+        let mut by_equivalent: HashMap<PatchVersion, T> = HashMap::new();
         let mut passthrough = Vec::new();
 
         for row in all_rows {
-            if let Some(key) = display_patch_to_ddragon_major_minor(&row.0) {
-                match by_equivalent.get(&key) {
+            if let Some(version) = PatchVersion::parse(key(&row).0) {
+                match by_equivalent.get(&version) {
                     Some(existing) => {
-                        if Self::is_better_equivalent_patch_row(&row, existing) {
-                            by_equivalent.insert(key, row);
+                        if Self::is_better_equivalent_patch_row(key(&row), key(existing)) {
+                            by_equivalent.insert(version, row);
                         }
                     }
                     None => {
-                        by_equivalent.insert(key, row);
+                        by_equivalent.insert(version, row);
                     }
                 }
             } else {
@@ -603,41 +1028,180 @@ impl Database {
             }
         }
 
-        let mut out: Vec<(String, String, String, String)> = by_equivalent.into_values().collect();
+        let mut out: Vec<T> = by_equivalent.into_values().collect();
         out.extend(passthrough);
-        out.sort_by(|a, b| cmp_display_patch(&b.0, &a.0).then_with(|| b.3.cmp(&a.3)));
-        if let Some(limit) = limit {
-            if limit > 0 && (out.len() as i64) > limit {
-                out.truncate(limit as usize);
-            }
+        out.sort_by(|a, b| {
+            let (va, fa) = key(a);
+            let (vb, fb) = key(b);
+            cmp_display_patch(vb, va).then_with(|| fb.cmp(fa))
+        });
+        out
+    }
+
+    /// Метаданные патчей без data_json, по одной строке на версию, от новой к старой.
+    pub async fn list_patch_meta(&self) -> Result<Vec<PatchMeta>> {
+        let rows: Vec<(String, String, String, Option<i64>)> = sqlx::query_as(
+            r#"
+            SELECT p.version, p.patch_notes_locale, p.fetched_at,
+                   json_extract(s.summary_json, '$.total_notes')
+            FROM patches p
+            LEFT JOIN patch_summaries s
+                ON s.version = p.version AND s.patch_notes_locale = p.patch_notes_locale
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let metas = rows
+            .into_iter()
+            .map(|(version, patch_notes_locale, fetched_at, total_notes)| PatchMeta {
+                version,
+                patch_notes_locale,
+                fetched_at,
+                total_notes: total_notes.map(|n| n as u32),
+            })
+            .collect();
+        Ok(Self::order_version_rows(metas, |m: &PatchMeta| {
+            (m.version.as_str(), m.fetched_at.as_str())
+        }))
+    }
+
+    /// Метаданные патча в локали `locale` (с учётом эквивалентных записей версии).
+    pub async fn get_patch_meta_resolving_with_locale(
+        &self,
+        version: &str,
+        locale: &str,
+    ) -> Result<Option<PatchMeta>> {
+        let locale = normalize_patch_locale(locale);
+        let rows: Vec<(String, String, Option<i64>)> = sqlx::query_as(
+            r#"
+            SELECT p.version, p.fetched_at, json_extract(s.summary_json, '$.total_notes')
+            FROM patches p
+            LEFT JOIN patch_summaries s
+                ON s.version = p.version AND s.patch_notes_locale = p.patch_notes_locale
+            WHERE p.patch_notes_locale = ?
+            "#,
+        )
+        .bind(locale)
+        .fetch_all(&self.pool)
+        .await?;
+        let found = rows
+            .iter()
+            .find(|(v, _, _)| v == version)
+            .or_else(|| rows.iter().find(|(v, _, _)| versions_match(v, version)));
+        Ok(found.map(|(v, fetched_at, total_notes)| PatchMeta {
+            version: v.clone(),
+            patch_notes_locale: locale.to_string(),
+            fetched_at: fetched_at.clone(),
+            total_notes: total_notes.map(|n| n as u32),
+        }))
+    }
+
+    /// Обновляет статистику планировщика; вызывать после массовой загрузки патчей.
+    pub async fn analyze(&self) -> Result<()> {
+        sqlx::query("ANALYZE").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// Планы типовых запросов — видно, какие из них проходят таблицу целиком.
+    pub async fn explain_queries(&self) -> Result<Vec<QueryPlan>> {
+        let mut out = Vec::with_capacity(DIAGNOSTIC_QUERIES.len());
+        for (name, sql) in DIAGNOSTIC_QUERIES {
+            let rows: Vec<(i64, i64, i64, String)> = sqlx::query_as(&format!("EXPLAIN QUERY PLAN {}", sql))
+                .fetch_all(&self.pool)
+                .await?;
+            let plan: Vec<String> = rows.into_iter().map(|(_, _, _, detail)| detail).collect();
+            out.push(QueryPlan {
+                name: name.to_string(),
+                sql: sql.to_string(),
+                full_scan: plan.iter().any(|d| is_full_scan_step(d)),
+                plan,
+            });
         }
         Ok(out)
     }
 
     pub async fn clear_database(&self) -> Result<()> {
         sqlx::query("DELETE FROM patches").execute(&self.pool).await?;
+        self.patch_cache().clear();
+        sqlx::query("DELETE FROM patch_revisions")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM patch_summaries")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM tier_contributions")
+            .execute(&self.pool)
+            .await?;
         sqlx::query("DELETE FROM skin_spotlight_cache")
             .execute(&self.pool)
             .await?;
+        sqlx::query("DELETE FROM patch_reactions").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// Сжатие файла БД; долгое, поэтому только из фонового обслуживания.
+    pub async fn vacuum(&self) -> Result<()> {
         sqlx::query("VACUUM").execute(&self.pool).await?;
         Ok(())
     }
 
+    /// Удаляет записи кэша Skin Spotlight старше `max_age_days`; возвращает число строк.
+    pub async fn prune_skin_spotlight_cache(&self, max_age_days: i64) -> Result<u64> {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::days(max_age_days)).to_rfc3339();
+        let res = sqlx::query("DELETE FROM skin_spotlight_cache WHERE updated_at < ?")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
+        Ok(res.rows_affected())
+    }
+
     pub async fn clear_all_cached_data(&self) -> Result<()> {
         sqlx::query("DELETE FROM patches").execute(&self.pool).await?;
+        self.patch_cache().clear();
+        sqlx::query("DELETE FROM patch_revisions")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM patch_summaries")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("DELETE FROM tier_contributions")
+            .execute(&self.pool)
+            .await?;
         sqlx::query("DELETE FROM skin_spotlight_cache")
             .execute(&self.pool)
             .await?;
+        sqlx::query("DELETE FROM patch_reactions").execute(&self.pool).await?;
         sqlx::query("DELETE FROM static_catalog")
             .execute(&self.pool)
             .await?;
         sqlx::query("DELETE FROM augments_catalog")
             .execute(&self.pool)
             .await?;
+        sqlx::query("DELETE FROM dev_articles")
+            .execute(&self.pool)
+            .await?;
         sqlx::query("DELETE FROM game_assets_meta")
             .execute(&self.pool)
             .await?;
-        Ok(())
+        sqlx::query("DELETE FROM fallback_patches WHERE source = 'ddragon'")
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Все таблицы, включая теги записей; для полной очистки данных.
+    pub async fn wipe_all_tables(&self) -> Result<()> {
+        self.clear_all_cached_data().await?;
+        sqlx::query("DELETE FROM note_tags").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM fallback_patches").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM watchlist").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM store_reminders").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM linked_summoner").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM ranked_matches").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM community_tiers").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM pro_builds").execute(&self.pool).await?;
+        sqlx::query("DELETE FROM machine_translations").execute(&self.pool).await?;
+        Ok(())
     }
 
     pub async fn list_cached_patch_locales(&self) -> Result<Vec<String>> {
@@ -752,6 +1316,23 @@ impl Database {
         Ok(())
     }
 
+    /// ChampionMeta по id / en / ru имени в нижнем регистре.
+    pub async fn get_champion_meta_index(&self) -> Result<HashMap<String, ChampionMeta>> {
+        let rows = self.get_static_catalog_kind("champion").await?;
+        let mut out = HashMap::new();
+        for row in &rows {
+            let Some(meta) = ChampionMeta::from_catalog_row(row) else {
+                continue;
+            };
+            for name in [&meta.id, &meta.name_en, &meta.name_ru] {
+                if !name.is_empty() {
+                    out.insert(name.to_lowercase(), meta.clone());
+                }
+            }
+        }
+        Ok(out)
+    }
+
     pub async fn get_static_catalog_kind(&self, kind: &str) -> Result<Vec<StaticCatalogRow>> {
         let rows: Vec<(
             String,
@@ -806,6 +1387,13 @@ impl Database {
             .collect())
     }
 
+    /// `icon_sources` всех строк каталога — для поиска неиспользуемых файлов иконок.
+    pub async fn static_catalog_icon_sources(&self) -> Result<Vec<String>> {
+        Ok(sqlx::query_scalar("SELECT icon_sources FROM static_catalog")
+            .fetch_all(&self.pool)
+            .await?)
+    }
+
     pub async fn static_catalog_count(&self) -> Result<i64> {
         let c: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM static_catalog")
             .fetch_one(&self.pool)
@@ -852,6 +1440,546 @@ impl Database {
         Ok(())
     }
 
+    /// `tags = None` снимает пользовательские теги (запись вернётся к автоматическим).
+    pub async fn set_note_tags(
+        &self,
+        version: &str,
+        note_id: &str,
+        tags: Option<&[String]>,
+    ) -> Result<()> {
+        let Some(tags) = tags else {
+            sqlx::query("DELETE FROM note_tags WHERE version = ? AND note_id = ?")
+                .bind(version)
+                .bind(note_id)
+                .execute(&self.pool)
+                .await?;
+            return Ok(());
+        };
+        let mut normalized: Vec<String> = Vec::new();
+        for t in tags.iter().map(|t| crate::patch_tags::normalize_tag(t)) {
+            if !t.is_empty() && !normalized.contains(&t) {
+                normalized.push(t);
+            }
+        }
+        sqlx::query(
+            r#"
+            INSERT INTO note_tags (version, note_id, tags_json, updated_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(version, note_id) DO UPDATE SET
+                tags_json = excluded.tags_json,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(version)
+        .bind(note_id)
+        .bind(serde_json::to_string(&normalized)?)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Запасной список патчей, новые первыми.
+    pub async fn get_fallback_patches(&self) -> Result<Vec<FallbackPatch>> {
+        let rows: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT version, source, added_at FROM fallback_patches")
+                .fetch_all(&self.pool)
+                .await?;
+        let mut out: Vec<FallbackPatch> = rows
+            .into_iter()
+            .map(|(version, source, added_at)| FallbackPatch {
+                version,
+                source: FallbackPatchSource::from_db(&source),
+                added_at: chrono::DateTime::parse_from_rfc3339(&added_at)
+                    .map(|d| d.with_timezone(&chrono::Utc))
+                    .unwrap_or_else(|_| chrono::Utc::now()),
+            })
+            .collect();
+        out.sort_by_key(|p| std::cmp::Reverse(PatchVersion::parse(&p.version)));
+        Ok(out)
+    }
+
+    /// Заменяет строки из DDragon; добавленные вручную номера остаются ручными.
+    pub async fn replace_ddragon_fallback_patches(&self, versions: &[String]) -> Result<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM fallback_patches WHERE source = 'ddragon'")
+            .execute(&mut *tx)
+            .await?;
+        for version in versions {
+            sqlx::query(
+                "INSERT INTO fallback_patches (version, source, added_at) VALUES (?, 'ddragon', ?) ON CONFLICT(version) DO NOTHING",
+            )
+            .bind(version)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn add_manual_fallback_patch(&self, version: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO fallback_patches (version, source, added_at)
+            VALUES (?, 'manual', ?)
+            ON CONFLICT(version) DO UPDATE SET source = 'manual'
+            "#,
+        )
+        .bind(version)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn remove_fallback_patch(&self, version: &str) -> Result<()> {
+        sqlx::query("DELETE FROM fallback_patches WHERE version = ?")
+            .bind(version)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Сохраняет прежнюю версию патча перед записью исправленной; возвращает номер правки.
+    pub async fn save_patch_revision(&self, previous: &PatchData, changes: &[NoteRevisionChange]) -> Result<PatchRevision> {
+        let locale = normalize_patch_locale(previous.patch_notes_locale.as_deref().unwrap_or("ru"));
+        let last: Option<i64> = sqlx::query_scalar(
+            "SELECT MAX(revision) FROM patch_revisions WHERE version = ? AND patch_notes_locale = ?",
+        )
+        .bind(&previous.version)
+        .bind(locale)
+        .fetch_one(&self.pool)
+        .await?;
+        let revision = PatchRevision {
+            version: previous.version.clone(),
+            patch_notes_locale: locale.to_string(),
+            revision: last.unwrap_or(0) as u32 + 1,
+            detected_at: chrono::Utc::now(),
+            changes: changes.to_vec(),
+        };
+        sqlx::query(
+            r#"
+            INSERT INTO patch_revisions (version, patch_notes_locale, revision, previous_json, changes_json, detected_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&revision.version)
+        .bind(locale)
+        .bind(revision.revision as i64)
+        .bind(serde_json::to_string(previous)?)
+        .bind(serde_json::to_string(&revision.changes)?)
+        .bind(revision.detected_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(revision)
+    }
+
+    /// Замеченные правки статьи патча, последние первыми.
+    pub async fn get_patch_revisions(&self, version: &str) -> Result<Vec<PatchRevision>> {
+        let rows: Vec<(String, String, i64, String, String)> = sqlx::query_as(
+            r#"
+            SELECT version, patch_notes_locale, revision, changes_json, detected_at
+            FROM patch_revisions
+            WHERE version = ?
+            ORDER BY detected_at DESC, revision DESC
+            "#,
+        )
+        .bind(version)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(version, patch_notes_locale, revision, changes_json, detected_at)| PatchRevision {
+                version,
+                patch_notes_locale,
+                revision: revision as u32,
+                detected_at: chrono::DateTime::parse_from_rfc3339(&detected_at)
+                    .map(|d| d.with_timezone(&chrono::Utc))
+                    .unwrap_or_else(|_| chrono::Utc::now()),
+                changes: serde_json::from_str(&changes_json).unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    pub async fn get_linked_summoner(&self) -> Result<Option<LinkedSummoner>> {
+        let row: Option<String> = sqlx::query_scalar("SELECT data_json FROM linked_summoner WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(row.and_then(|json| serde_json::from_str(&json).ok()))
+    }
+
+    /// `None` отвязывает аккаунт.
+    pub async fn set_linked_summoner(&self, summoner: Option<&LinkedSummoner>) -> Result<()> {
+        match summoner {
+            Some(summoner) => {
+                sqlx::query(
+                    "INSERT INTO linked_summoner (id, data_json) VALUES (1, ?) ON CONFLICT(id) DO UPDATE SET data_json = excluded.data_json",
+                )
+                .bind(serde_json::to_string(summoner)?)
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM linked_summoner").execute(&self.pool).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Заменяет снимок источника за этот патч.
+    pub async fn save_community_tiers(&self, list: &CommunityTierList) -> Result<()> {
+        sqlx::query("INSERT OR REPLACE INTO community_tiers (version, source, fetched_at, data_json) VALUES (?, ?, ?, ?)")
+            .bind(&list.version)
+            .bind(&list.source)
+            .bind(list.fetched_at.to_rfc3339())
+            .bind(serde_json::to_string(list)?)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Снимки источника от нового патча к старому.
+    pub async fn get_community_tier_lists(&self, source: &str) -> Result<Vec<CommunityTierList>> {
+        let rows: Vec<String> = sqlx::query_scalar("SELECT data_json FROM community_tiers WHERE source = ?")
+            .bind(source)
+            .fetch_all(&self.pool)
+            .await?;
+        let mut lists: Vec<CommunityTierList> = rows.iter().filter_map(|json| serde_json::from_str(json).ok()).collect();
+        lists.sort_by(|a, b| cmp_display_patch(&b.version, &a.version));
+        Ok(lists)
+    }
+
+    /// Заменяет снимок сборок про за патч.
+    pub async fn save_pro_builds(&self, snapshot: &ProBuildsSnapshot) -> Result<()> {
+        sqlx::query("INSERT OR REPLACE INTO pro_builds (champion, patch_version, fetched_at, data_json) VALUES (?, ?, ?, ?)")
+            .bind(snapshot.champion.to_lowercase())
+            .bind(&snapshot.patch_version)
+            .bind(snapshot.fetched_at.to_rfc3339())
+            .bind(serde_json::to_string(snapshot)?)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Снимки сборок про чемпиона (id DDragon) от нового патча к старому.
+    pub async fn get_pro_builds(&self, champion: &str) -> Result<Vec<ProBuildsSnapshot>> {
+        let rows: Vec<String> = sqlx::query_scalar("SELECT data_json FROM pro_builds WHERE champion = ?")
+            .bind(champion.to_lowercase())
+            .fetch_all(&self.pool)
+            .await?;
+        let mut snapshots: Vec<ProBuildsSnapshot> = rows.iter().filter_map(|json| serde_json::from_str(json).ok()).collect();
+        snapshots.sort_by(|a, b| cmp_display_patch(&b.patch_version, &a.patch_version));
+        Ok(snapshots)
+    }
+
+    pub async fn get_patch_reaction(&self, version: &str, patch_notes_locale: &str) -> Result<Option<PatchReaction>> {
+        let row: Option<String> =
+            sqlx::query_scalar("SELECT data_json FROM patch_reactions WHERE version = ? AND patch_notes_locale = ?")
+                .bind(version)
+                .bind(patch_notes_locale)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(row.and_then(|json| serde_json::from_str(&json).ok()))
+    }
+
+    pub async fn save_patch_reaction(&self, reaction: &PatchReaction) -> Result<()> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO patch_reactions (version, patch_notes_locale, fetched_at, data_json) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&reaction.version)
+        .bind(&reaction.patch_notes_locale)
+        .bind(reaction.fetched_at.to_rfc3339())
+        .bind(serde_json::to_string(reaction)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Сохранённые переводы «оригинал → перевод» для пары локалей.
+    pub async fn get_machine_translations(&self, source_locale: &str, target_locale: &str) -> Result<HashMap<String, String>> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT original, translated FROM machine_translations WHERE source_locale = ? AND target_locale = ?",
+        )
+        .bind(source_locale)
+        .bind(target_locale)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    pub async fn save_machine_translations(
+        &self,
+        source_locale: &str,
+        target_locale: &str,
+        provider: &str,
+        pairs: &[(String, String)],
+    ) -> Result<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+        for (original, translated) in pairs {
+            sqlx::query(
+                "INSERT OR REPLACE INTO machine_translations (source_locale, target_locale, original, translated, provider, translated_at) VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(source_locale)
+            .bind(target_locale)
+            .bind(original)
+            .bind(translated)
+            .bind(provider)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_ranked_match_ids(&self, puuid: &str) -> Result<HashSet<String>> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT match_id FROM ranked_matches WHERE puuid = ?")
+            .bind(puuid)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(ids.into_iter().collect())
+    }
+
+    pub async fn save_ranked_match(&self, puuid: &str, game: &RankedMatch) -> Result<()> {
+        sqlx::query("INSERT OR REPLACE INTO ranked_matches (match_id, puuid, game_start, data_json) VALUES (?, ?, ?, ?)")
+            .bind(&game.match_id)
+            .bind(puuid)
+            .bind(game.game_start.to_rfc3339())
+            .bind(serde_json::to_string(game)?)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Матчи аккаунта от старых к новым.
+    pub async fn get_ranked_matches(&self, puuid: &str) -> Result<Vec<RankedMatch>> {
+        let rows: Vec<String> =
+            sqlx::query_scalar("SELECT data_json FROM ranked_matches WHERE puuid = ? ORDER BY game_start")
+                .bind(puuid)
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(rows.iter().filter_map(|json| serde_json::from_str(json).ok()).collect())
+    }
+
+    pub async fn clear_ranked_matches(&self) -> Result<()> {
+        sqlx::query("DELETE FROM ranked_matches").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// Отмечает напоминание о ротации; `false`, если о ней уже напоминали.
+    pub async fn mark_store_reminder(&self, rotation_key: &str) -> Result<bool> {
+        let result = sqlx::query("INSERT OR IGNORE INTO store_reminders (rotation_key, reminded_at) VALUES (?, ?)")
+            .bind(rotation_key)
+            .bind(chrono::Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Отслеживаемые сущности в порядке добавления.
+    pub async fn get_watchlist(&self) -> Result<Vec<WatchEntry>> {
+        let rows: Vec<(String, Option<String>, String)> =
+            sqlx::query_as("SELECT entity_json, min_grade, added_at FROM watchlist ORDER BY added_at ASC")
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|(entity_json, min_grade, added_at)| {
+                Some(WatchEntry {
+                    entity: serde_json::from_str(&entity_json).ok()?,
+                    min_grade: min_grade.and_then(|g| serde_json::from_str(&g).ok()),
+                    added_at: chrono::DateTime::parse_from_rfc3339(&added_at)
+                        .map(|d| d.with_timezone(&chrono::Utc))
+                        .unwrap_or_else(|_| chrono::Utc::now()),
+                })
+            })
+            .collect())
+    }
+
+    /// Добавляет сущность или меняет её порог; `watch: false` — убирает из списка.
+    pub async fn set_watch(&self, entity: &EntityRef, watch: bool, min_grade: Option<ChangeGrade>) -> Result<()> {
+        let entity_json = serde_json::to_string(entity)?;
+        if !watch {
+            sqlx::query("DELETE FROM watchlist WHERE entity_json = ?")
+                .bind(&entity_json)
+                .execute(&self.pool)
+                .await?;
+            return Ok(());
+        }
+        let min_grade = min_grade.map(|g| serde_json::to_string(&g)).transpose()?;
+        sqlx::query(
+            r#"
+            INSERT INTO watchlist (entity_json, min_grade, added_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT(entity_json) DO UPDATE SET min_grade = excluded.min_grade
+            "#,
+        )
+        .bind(&entity_json)
+        .bind(min_grade)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Пользовательские теги: версия → (id записи → теги).
+    pub async fn get_user_note_tags(&self) -> Result<HashMap<String, HashMap<String, Vec<String>>>> {
+        let rows: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT version, note_id, tags_json FROM note_tags")
+                .fetch_all(&self.pool)
+                .await?;
+        let mut out: HashMap<String, HashMap<String, Vec<String>>> = HashMap::new();
+        for (version, note_id, tags_json) in rows {
+            let tags: Vec<String> = serde_json::from_str(&tags_json).unwrap_or_default();
+            out.entry(version).or_default().insert(note_id, tags);
+        }
+        Ok(out)
+    }
+
+    async fn apply_note_tags(&self, patch: &mut PatchData) {
+        let user = self.get_user_note_tags().await.unwrap_or_default();
+        let empty = HashMap::new();
+        let for_version = user.get(&patch.version).unwrap_or(&empty);
+        crate::patch_tags::apply_tags(&mut patch.patch_notes, for_version);
+    }
+
+    pub async fn save_dev_article(&self, article: &DevArticle) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO dev_articles (url, locale, published_at, data_json, fetched_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(url) DO UPDATE SET
+                locale = excluded.locale,
+                published_at = excluded.published_at,
+                data_json = excluded.data_json,
+                fetched_at = excluded.fetched_at
+            "#,
+        )
+        .bind(&article.url)
+        .bind(&article.locale)
+        .bind(article.published_at.map(|d| d.to_rfc3339()))
+        .bind(serde_json::to_string(article)?)
+        .bind(article.fetched_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn list_dev_article_urls(&self) -> Result<HashSet<String>> {
+        let urls: Vec<String> = sqlx::query_scalar("SELECT url FROM dev_articles")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(urls.into_iter().collect())
+    }
+
+    /// Статьи разработчиков, новые сначала (`limit <= 0` — все).
+    pub async fn get_dev_articles(&self, limit: i64) -> Result<Vec<DevArticle>> {
+        let rows: Vec<String> = sqlx::query_scalar(
+            "SELECT data_json FROM dev_articles ORDER BY COALESCE(published_at, fetched_at) DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let mut out: Vec<DevArticle> = rows
+            .iter()
+            .filter_map(|j| serde_json::from_str(j).ok())
+            .collect();
+        if limit > 0 {
+            out.truncate(limit as usize);
+        }
+        Ok(out)
+    }
+
+    /// Поиск по заголовку и тексту статей (без учёта регистра).
+    pub async fn search_dev_articles(&self, query: &str) -> Result<Vec<DevArticle>> {
+        let q = query.trim().to_lowercase();
+        if q.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(self
+            .get_dev_articles(-1)
+            .await?
+            .into_iter()
+            .filter(|a| a.plain_text().to_lowercase().contains(&q))
+            .collect())
+    }
+
+    /// Статьи, упоминающие сущность (по `mentions`), по возрастанию даты — для таймлайна.
+    pub async fn get_dev_articles_for_entity(&self, entity: &EntityRef) -> Result<Vec<DevArticle>> {
+        let key = entity.key().to_lowercase();
+        let mut out: Vec<DevArticle> = self
+            .get_dev_articles(-1)
+            .await?
+            .into_iter()
+            .filter(|a| {
+                a.mentions.iter().any(|m| {
+                    let m = m.to_lowercase();
+                    let matches_kind = EntityRef::from_entity_id(&m)
+                        .is_some_and(|r| std::mem::discriminant(&r) == std::mem::discriminant(entity));
+                    matches_kind && m.split_once(':').is_some_and(|(_, id)| id == key)
+                })
+            })
+            .collect();
+        out.sort_by_key(|a| a.published_at.unwrap_or(a.fetched_at));
+        Ok(out)
+    }
+
+    /// Сплиты сохранённых патчей, новые сначала (из tier_contributions, без data_json).
+    pub async fn list_season_splits(&self) -> Result<Vec<String>> {
+        let mut splits: Vec<String> = Vec::new();
+        for patch in self.get_tier_contributions().await? {
+            if !splits.contains(&patch.split) {
+                splits.push(patch.split);
+            }
+        }
+        Ok(splits)
+    }
+
+    /// Все записи указанных категорий (по возрастанию даты патча); `split` — только патчи сплита.
+    pub async fn get_category_history(
+        &self,
+        categories: &[PatchCategory],
+        split: Option<&str>,
+    ) -> Result<Vec<ChampionHistoryEntry>> {
+        let mut out = Vec::new();
+        for patch in self.load_version_ordered_patches(None).await? {
+            if split.is_some_and(|s| s != patch.split) {
+                continue;
+            }
+            let date = patch.release_date();
+            for note in patch.patch_notes.iter().filter(|n| categories.contains(&n.category)) {
+                out.push(ChampionHistoryEntry::new(patch.version.clone(), date, patch.split.clone(), note.clone()));
+            }
+        }
+        out.sort_by_key(|e| e.date);
+        Ok(out)
+    }
+
+    /// Все записи с тегом `tag` (по возрастанию даты патча).
+    pub async fn get_notes_by_tag(&self, tag: &str) -> Result<Vec<ChampionHistoryEntry>> {
+        let tag = crate::patch_tags::normalize_tag(tag);
+        let user = self.get_user_note_tags().await?;
+        let empty = HashMap::new();
+        let mut out = Vec::new();
+        for patch in self.load_version_ordered_patches(None).await? {
+            let mut patch = (*patch).clone();
+            let date = patch.release_date();
+            crate::patch_tags::apply_tags(
+                &mut patch.patch_notes,
+                user.get(&patch.version).unwrap_or(&empty),
+            );
+            for note in patch.patch_notes {
+                if note.tags.contains(&tag) {
+                    out.push(ChampionHistoryEntry::new(patch.version.clone(), date, patch.split.clone(), note));
+                }
+            }
+        }
+        out.sort_by_key(|e| e.date);
+        Ok(out)
+    }
+
     pub async fn get_augments_catalog(
         &self,
         key: &str,
@@ -966,10 +2094,35 @@ impl Database {
             enrich_patch_notes_with_wiki_augments(&mut patch.patch_notes, &entries_en, &entries_ru);
         }
         let _ = crate::patch_icons::enrich_patch_data_icons(self, &mut patch).await;
+        self.apply_note_tags(&mut patch).await;
         Ok(patch)
     }
 
     pub async fn save_patch(&self, patch: &PatchData) -> Result<()> {
+        self.save_patches(std::slice::from_ref(patch)).await
+    }
+
+    /// Сохраняет патчи одной транзакцией: при полной синхронизации и импорте это один fsync на пачку,
+    /// а не три на каждый патч. Запросы одинаковые, поэтому sqlx переиспользует подготовленные выражения.
+    pub async fn save_patches(&self, patches: &[PatchData]) -> Result<()> {
+        if patches.is_empty() {
+            return Ok(());
+        }
+        let mut tx = self.pool.begin().await?;
+        let mut saved = Vec::with_capacity(patches.len());
+        for patch in patches {
+            saved.push((&patch.version, Self::write_patch(&mut tx, patch).await?));
+        }
+        tx.commit().await?;
+        let mut cache = self.patch_cache();
+        for (version, locale) in saved {
+            cache.invalidate(version, locale);
+        }
+        Ok(())
+    }
+
+    /// Строка патча, его сводка и вклады в тир-лист. Возвращает локаль строки.
+    async fn write_patch(conn: &mut SqliteConnection, patch: &PatchData) -> Result<&'static str> {
         let locale = normalize_patch_locale(patch.patch_notes_locale.as_deref().unwrap_or("ru"));
         let patch_notes: Vec<PatchNoteEntry> = patch
             .patch_notes
@@ -978,10 +2131,14 @@ impl Database {
             .cloned()
             .collect();
         let content = PatchJsonContent {
+            schema_version: PATCH_JSON_SCHEMA_VERSION,
             champions: patch.champions.clone(),
             patch_notes,
             banner_url: patch.banner_url.clone(),
             patch_notes_locale: patch.patch_notes_locale.clone(),
+            published_at: patch.published_at,
+            author: patch.author.clone(),
+            season: patch.season.clone(),
         };
         let json_data = serde_json::to_string(&content)?;
         let date_str = patch.fetched_at.to_rfc3339();
@@ -999,37 +2156,151 @@ impl Database {
         .bind(locale)
         .bind(date_str)
         .bind(json_data)
-        .execute(&self.pool)
+        .execute(&mut *conn)
         .await?;
 
+        Self::save_patch_summary(&mut *conn, &crate::patch_summary::summarize(patch), locale).await?;
+        Self::save_tier_contributions(&mut *conn, patch, locale).await?;
+
+        Ok(locale)
+    }
+
+    async fn save_tier_contributions(
+        executor: impl SqliteExecutor<'_>,
+        patch: &PatchData,
+        locale: &str,
+    ) -> Result<()> {
+        let split = if patch.split.is_empty() {
+            crate::patch_version::season_split(&patch.version, patch.season.as_deref(), patch.release_date())
+        } else {
+            patch.split.clone()
+        };
+        let stored = PatchTierContributions {
+            version: patch.version.clone(),
+            split,
+            contributions: crate::tier_list::patch_contributions(patch),
+        };
+        sqlx::query(
+            r#"
+            INSERT INTO tier_contributions (version, patch_notes_locale, algo_version, data_json, fetched_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(version, patch_notes_locale) DO UPDATE SET
+                algo_version = excluded.algo_version,
+                data_json = excluded.data_json,
+                fetched_at = excluded.fetched_at
+            "#,
+        )
+        .bind(&patch.version)
+        .bind(locale)
+        .bind(TIER_CONTRIBUTIONS_ALGO_VERSION)
+        .bind(serde_json::to_string(&stored)?)
+        .bind(patch.fetched_at.to_rfc3339())
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Вклады патчей в тир-лист, по одной строке на версию, от новой к старой.
+    /// Патчи без актуальных вкладов (старые строки или смена алгоритма) досчитываются один раз.
+    pub async fn get_tier_contributions(&self) -> Result<Vec<PatchTierContributions>> {
+        let stale: Vec<(String, String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT p.version, p.patch_notes_locale, p.data_json, p.fetched_at
+            FROM patches p
+            LEFT JOIN tier_contributions t
+                ON t.version = p.version AND t.patch_notes_locale = p.patch_notes_locale
+            WHERE t.version IS NULL OR t.algo_version != ?
+            "#,
+        )
+        .bind(TIER_CONTRIBUTIONS_ALGO_VERSION)
+        .fetch_all(&self.pool)
+        .await?;
+        for (ver, loc, data, date_str) in stale {
+            match patch_data_from_stored_row(ver, &data, &date_str, Some(&loc)) {
+                Ok(patch) => Self::save_tier_contributions(&self.pool, &patch, &loc).await?,
+                Err(e) => skip_unreadable_patch_row(&e),
+            }
+        }
+
+        let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+            "SELECT version, patch_notes_locale, data_json, fetched_at FROM tier_contributions",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(Self::order_version_rows(rows, |r| (r.0.as_str(), r.3.as_str()))
+            .into_iter()
+            .filter_map(|(_, _, data, _)| serde_json::from_str(&data).ok())
+            .collect())
+    }
+
+    async fn save_patch_summary(
+        executor: impl SqliteExecutor<'_>,
+        summary: &PatchSummary,
+        locale: &str,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO patch_summaries (version, patch_notes_locale, summary_json)
+            VALUES (?, ?, ?)
+            ON CONFLICT(version, patch_notes_locale) DO UPDATE SET
+                summary_json = excluded.summary_json
+            "#,
+        )
+        .bind(&summary.version)
+        .bind(locale)
+        .bind(serde_json::to_string(summary)?)
+        .execute(executor)
+        .await?;
         Ok(())
     }
 
+    /// Сводка патча (ru, затем en). Для строк, сохранённых до появления сводок, считается и сохраняется.
+    pub async fn get_patch_summary(&self, version: &str) -> Result<Option<PatchSummary>> {
+        let stored: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT summary_json FROM patch_summaries
+            WHERE version = ?
+            ORDER BY CASE patch_notes_locale WHEN 'ru' THEN 0 WHEN 'en' THEN 1 ELSE 2 END
+            LIMIT 1
+            "#,
+        )
+        .bind(version)
+        .fetch_optional(&self.pool)
+        .await?;
+        if let Some(summary) = stored.and_then(|j| serde_json::from_str(&j).ok()) {
+            return Ok(Some(summary));
+        }
+        let Some(patch) = self.get_patch_resolving(version).await? else {
+            return Ok(None);
+        };
+        let summary = crate::patch_summary::summarize(&patch);
+        let locale = normalize_patch_locale(patch.patch_notes_locale.as_deref().unwrap_or("ru"));
+        Self::save_patch_summary(&self.pool, &summary, locale).await?;
+        Ok(Some(summary))
+    }
+
     pub async fn get_patch_for_locale(&self, version: &str, locale: &str) -> Result<Option<PatchData>> {
         let locale = normalize_patch_locale(locale);
-        let row: Option<(String, String, String, String)> = sqlx::query_as(
-            "SELECT version, patch_notes_locale, data_json, fetched_at FROM patches WHERE version = ? AND patch_notes_locale = ?",
+        let fetched_at: Option<String> = sqlx::query_scalar(
+            "SELECT fetched_at FROM patches WHERE version = ? AND patch_notes_locale = ?",
         )
         .bind(version)
         .bind(locale)
         .fetch_optional(&self.pool)
         .await?;
-
-        if let Some((ver, loc, data, date_str)) = row {
-            return Ok(Some(patch_data_from_stored_row(
-                ver,
-                &data,
-                &date_str,
-                Some(&loc),
-            )?));
-        }
-        Ok(None)
+        let Some(fetched_at) = fetched_at else {
+            return Ok(None);
+        };
+        Ok(self
+            .load_patch_row(version, locale, &fetched_at)
+            .await?
+            .map(|p| (*p).clone()))
     }
 
     pub async fn get_patch(&self, version: &str) -> Result<Option<PatchData>> {
-        let row: Option<(String, String, String, String)> = sqlx::query_as(
+        let row: Option<(String, String)> = sqlx::query_as(
             r#"
-            SELECT version, patch_notes_locale, data_json, fetched_at
+            SELECT patch_notes_locale, fetched_at
             FROM patches
             WHERE version = ?
             ORDER BY CASE patch_notes_locale WHEN 'ru' THEN 0 WHEN 'en' THEN 1 ELSE 2 END, fetched_at DESC
@@ -1039,21 +2310,16 @@ impl Database {
         .bind(version)
         .fetch_optional(&self.pool)
         .await?;
-        if let Some((ver, loc, data, date_str)) = row {
-            return Ok(Some(patch_data_from_stored_row(
-                ver,
-                &data,
-                &date_str,
-                Some(&loc),
-            )?));
-        }
-        Ok(None)
+        let Some((loc, fetched_at)) = row else {
+            return Ok(None);
+        };
+        Ok(self
+            .load_patch_row(version, &loc, &fetched_at)
+            .await?
+            .map(|p| (*p).clone()))
     }
 
     pub async fn patch_exists_resolving(&self, version: &str) -> Result<bool> {
-        if self.get_patch(version).await?.is_some() {
-            return Ok(true);
-        }
         let all: Vec<String> = sqlx::query_scalar("SELECT DISTINCT version FROM patches")
             .fetch_all(&self.pool)
             .await?;
@@ -1062,7 +2328,7 @@ impl Database {
 
     pub async fn patch_exists_resolving_with_locale(&self, version: &str, locale: &str) -> Result<bool> {
         Ok(self
-            .get_patch_resolving_with_locale(version, locale)
+            .get_patch_meta_resolving_with_locale(version, locale)
             .await?
             .is_some())
     }
@@ -1129,83 +2395,216 @@ impl Database {
 
     /// Последние `limit` патчей по **номеру версии** (самый новый игровой патч первым).
     pub async fn get_patches_newest_versions_first(&self, limit: i64) -> Result<Vec<PatchData>> {
-        let rows = self.fetch_version_ordered_rows(Some(limit)).await?;
-        let mut result = Vec::with_capacity(rows.len());
-        for (ver, loc, data, date_str) in rows {
-            result.push(patch_data_from_stored_row(ver, &data, &date_str, Some(&loc))?);
-        }
-        Ok(result)
+        Ok(self
+            .load_version_ordered_patches(Some(limit))
+            .await?
+            .into_iter()
+            .map(|p| (*p).clone())
+            .collect())
     }
 
+    /// Последние `limit` патчей по дате выхода (published_at, иначе fetched_at).
     pub async fn get_recent_patches(&self, limit: i64) -> Result<Vec<PatchData>> {
-        self.get_patches_newest_versions_first(limit).await
+        let mut patches = self.get_patches_newest_versions_first(-1).await?;
+        patches.sort_by_key(|p| std::cmp::Reverse(p.release_date()));
+        if limit > 0 {
+            patches.truncate(limit as usize);
+        }
+        Ok(patches)
     }
 
-    fn collect_note_history<F>(
-        rows: Vec<(String, String, String, String)>,
-        filter: F,
-    ) -> Result<Vec<ChampionHistoryEntry>>
-    where
-        F: Fn(&PatchNoteEntry, &str) -> bool,
-    {
-        let mut history = Vec::new();
-        for (ver, _loc, data, date_str) in rows {
-            let content = match deserialize_stored_json(&data) {
-                Some(c) => c,
-                None => continue,
-            };
-            let date = chrono::DateTime::parse_from_rfc3339(&date_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .unwrap_or_else(|_| chrono::Utc::now());
-
-            for note in content.patch_notes {
-                if filter(&note, &ver) {
-                    history.push(ChampionHistoryEntry {
-                        patch_version: ver.clone(),
-                        date,
-                        change: note,
-                    });
+    /// Проставляет entity_id / title_en / title_ru в уже сохранённых патчах по текущему static_catalog.
+    pub async fn backfill_entity_ids(&self) -> Result<usize> {
+        let items = self.get_static_catalog_kind("item").await?;
+        let champs = self.get_static_catalog_kind("champion").await?;
+        let runes = self.get_static_catalog_kind("rune").await?;
+        if items.is_empty() && champs.is_empty() && runes.is_empty() {
+            return Ok(0);
+        }
+        let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+            "SELECT version, patch_notes_locale, data_json, fetched_at FROM patches",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let mut updated = Vec::new();
+        for (ver, loc, data, date_str) in rows {
+            let mut patch = match patch_data_from_stored_row(ver, &data, &date_str, Some(&loc)) {
+                Ok(patch) => patch,
+                Err(e) => {
+                    skip_unreadable_patch_row(&e);
+                    continue;
                 }
+            };
+            let has_unresolved = patch.patch_notes.iter().any(|n| n.entity_id.is_none());
+            if !has_unresolved
+                || !crate::patch_icons::resolve_identity_with_catalog(&mut patch, &items, &champs, &runes)
+            {
+                continue;
             }
+            updated.push(patch);
         }
-        history.sort_by(|a, b| a.date.cmp(&b.date));
-        Ok(history)
+        self.save_patches(&updated).await?;
+        if !updated.is_empty() {
+            self.analyze().await?;
+        }
+        Ok(updated.len())
     }
 
-    async fn get_history_for_category(
+    /// История изменений сущности по всем сохранённым патчам (по возрастанию даты).
+    /// `split` ("25.S2") — только патчи этого сплита.
+    pub async fn get_entity_history(
         &self,
-        name: &str,
-        category: PatchCategory,
+        entity: &EntityRef,
+        split: Option<&str>,
     ) -> Result<Vec<ChampionHistoryEntry>> {
-        let rows = self.fetch_version_ordered_rows(None).await?;
-        let search = name.to_lowercase();
-        Self::collect_note_history(rows, move |note, _ver| {
-            note.category == category
-                && (note.id.to_lowercase() == search || note.title.to_lowercase() == search)
-        })
-    }
+        if let EntityRef::Objective { id } = entity {
+            return crate::objectives::history(self, id, split).await;
+        }
+        if let EntityRef::PlayerSystem { id } = entity {
+            return crate::player_systems::history(self, id, split).await;
+        }
+        let search = entity.key().to_lowercase();
+        let fix_ids = self.fix_mention_ids(entity, &search).await?;
+        // Отбор записей идёт в SQLite (json_each по patch_notes), в Rust разбираются только найденные.
+        // Исправления ошибок с упоминаниями отбираются все (их немного) и сверяются по id из каталога.
+        // lower() в SQLite понимает лишь ASCII, поэтому для кириллического ключа SQL отбирает все записи,
+        // а точное сравнение всегда делает note_matches_name.
+        let rows: Vec<HistoryNoteRow> = sqlx::query_as(
+            r#"
+            SELECT p.version, p.patch_notes_locale, p.fetched_at,
+                   json_extract(p.data_json, '$.published_at'),
+                   json_extract(p.data_json, '$.season'),
+                   n.value
+            FROM patches p, json_each(p.data_json, '$.patch_notes') n
+            WHERE ?2 = 0
+               OR lower(json_extract(n.value, '$.id')) = ?1
+               OR lower(json_extract(n.value, '$.title')) = ?1
+               OR lower(json_extract(n.value, '$.title_en')) = ?1
+               OR lower(json_extract(n.value, '$.title_ru')) = ?1
+               OR lower(json_extract(n.value, '$.entity_id')) = ?1
+               OR lower(json_extract(n.value, '$.entity_id')) LIKE '%:' || ?1
+               OR (?3 = 1 AND json_array_length(n.value, '$.mentions') > 0)
+            "#,
+        )
+        .bind(&search)
+        .bind(search.is_ascii())
+        .bind(!fix_ids.is_empty())
+        .fetch_all(&self.pool)
+        .await?;
 
-    pub async fn get_champion_history(&self, champion_name: &str) -> Result<Vec<ChampionHistoryEntry>> {
-        self.get_history_for_category(champion_name, PatchCategory::Champions)
-            .await
+        // Строки патчей, которые видит остальное приложение: одна на версию, эквиваленты схлопнуты
+        let chosen: HashSet<(String, String)> = self
+            .list_patch_meta()
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m.patch_notes_locale))
+            .collect();
+        let mut history = Vec::new();
+        for (version, locale, fetched_at, published_at, season, note_json) in rows {
+            if !chosen.contains(&(version.clone(), locale)) {
+                continue;
+            }
+            let Ok(mut note) = serde_json::from_str::<PatchNoteEntry>(&note_json) else {
+                continue;
+            };
+            let own = entity.matches_category(&note.category) && note_matches_name(&note, &search);
+            let fix = note.category == PatchCategory::BugFixes
+                && note.mentions.iter().any(|m| fix_ids.contains(&m.to_lowercase()));
+            if !own && !fix {
+                continue;
+            }
+            let date = patch_row_date(published_at.as_deref(), &fetched_at);
+            let patch_split = crate::patch_version::season_split(&version, season.as_deref(), date);
+            if split.is_some_and(|s| s != patch_split) {
+                continue;
+            }
+            if note.grade.is_none() {
+                note.grade = crate::patch_change_trend::note_grade(&note);
+            }
+            history.push(ChampionHistoryEntry::new(version, date, patch_split, note));
+        }
+        history.sort_by_key(|e| e.date);
+        Ok(history)
     }
 
-    pub async fn get_item_history(&self, item_name: &str) -> Result<Vec<ChampionHistoryEntry>> {
-        let rows = self.fetch_version_ordered_rows(None).await?;
-        let search = item_name.to_lowercase();
-        Self::collect_note_history(rows, move |note, _ver| {
-            (note.category == PatchCategory::Items || note.category == PatchCategory::ItemsRunes)
-                && (note.id.to_lowercase() == search || note.title.to_lowercase() == search)
-        })
+    /// id сущности (`champion:ahri`, `item:3031`) для поиска исправлений ошибок по `mentions`:
+    /// ключ истории может быть id или названием на любом языке, поэтому сверяется со static_catalog.
+    async fn fix_mention_ids(&self, entity: &EntityRef, search: &str) -> Result<HashSet<String>> {
+        let kind = match entity {
+            EntityRef::Champion { .. } => "champion",
+            EntityRef::Item { .. } => "item",
+            _ => return Ok(HashSet::new()),
+        };
+        let mut ids: HashSet<String> = self
+            .get_static_catalog_kind(kind)
+            .await?
+            .iter()
+            .filter(|r| [&r.stable_id, &r.name_en, &r.name_ru].iter().any(|n| n.to_lowercase() == search))
+            .filter_map(crate::patch_icons::entity_id_for_row)
+            .map(|id| id.to_lowercase())
+            .collect();
+        ids.insert(format!("{}:{}", kind, search));
+        Ok(ids)
     }
 
-    pub async fn get_rune_history(&self, rune_name: &str) -> Result<Vec<ChampionHistoryEntry>> {
-        let rows = self.fetch_version_ordered_rows(None).await?;
-        let search = rune_name.to_lowercase();
-        Self::collect_note_history(rows, move |note, _ver| {
-            (note.category == PatchCategory::Runes || note.category == PatchCategory::ItemsRunes)
-                && (note.id.to_lowercase() == search || note.title.to_lowercase() == search)
-        })
+    /// Все сохранённые патчи (по одному на версию, от старого к новому) со статистикой чемпиона
+    /// `champion` (id DDragon или имя). Отбор строк как в `get_entity_history`.
+    pub async fn get_champion_stats_history(&self, champion: &str) -> Result<Vec<ChampionPatchStats>> {
+        let search = champion.to_lowercase();
+        let patches: Vec<PatchMetaRow> = sqlx::query_as(
+            r#"
+            SELECT version, patch_notes_locale, fetched_at,
+                   json_extract(data_json, '$.published_at'),
+                   json_extract(data_json, '$.season')
+            FROM patches
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let stat_rows: Vec<(String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT p.version, p.patch_notes_locale, c.value
+            FROM patches p, json_each(p.data_json, '$.champions') c
+            WHERE ?2 = 0
+               OR lower(json_extract(c.value, '$.id')) = ?1
+               OR lower(json_extract(c.value, '$.name')) = ?1
+            "#,
+        )
+        .bind(&search)
+        .bind(search.is_ascii())
+        .fetch_all(&self.pool)
+        .await?;
+
+        let chosen: HashSet<(String, String)> = self
+            .list_patch_meta()
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m.patch_notes_locale))
+            .collect();
+        let mut stats: HashMap<String, Vec<ChampionStats>> = HashMap::new();
+        for (version, locale, stat_json) in stat_rows {
+            if !chosen.contains(&(version.clone(), locale)) {
+                continue;
+            }
+            let Ok(stat) = serde_json::from_str::<ChampionStats>(&stat_json) else {
+                continue;
+            };
+            if stat.id.to_lowercase() == search || stat.name.to_lowercase() == search {
+                stats.entry(version).or_default().push(stat);
+            }
+        }
+        let mut out: Vec<ChampionPatchStats> = patches
+            .into_iter()
+            .filter(|(version, locale, ..)| chosen.contains(&(version.clone(), locale.clone())))
+            .map(|(version, _, fetched_at, published_at, season)| {
+                let date = patch_row_date(published_at.as_deref(), &fetched_at);
+                let split = crate::patch_version::season_split(&version, season.as_deref(), date);
+                let stats = stats.remove(&version).unwrap_or_default();
+                ChampionPatchStats { version, date, split, stats }
+            })
+            .collect();
+        out.sort_by_key(|p| p.date);
+        Ok(out)
     }
 }
 
@@ -1221,28 +2620,27 @@ mod tests {
             title: "Fan the Hammer".into(),
             image_url: Some("https://wiki.example/fth.png".into()),
             category: PatchCategory::ModeAramAugments,
-            change_type: ChangeType::None,
             summary: "Gold · Set 1".into(),
             details: vec![ChangeBlock {
                 title: None,
                 icon_url: None,
                 changes: vec!["Full wiki effect text.".into()],
+                children: vec![],
             }],
-            icon_candidates: None,
+            ..Default::default()
         }];
         let mut notes = vec![PatchNoteEntry {
             id: "n1".into(),
             title: "Fan the Hammer".into(),
-            image_url: None,
             category: PatchCategory::ModeAramChaos,
             change_type: ChangeType::Nerf,
-            summary: String::new(),
             details: vec![ChangeBlock {
                 title: None,
                 icon_url: None,
                 changes: vec!["Damage 10 ⇒ 8".into()],
+                children: vec![],
             }],
-            icon_candidates: None,
+            ..Default::default()
         }];
         enrich_patch_notes_with_wiki_augments(&mut notes, &wiki, &[]);
         assert_eq!(
@@ -1295,4 +2693,84 @@ mod tests {
         };
         assert!(!augment_row_matches_icon_url(&item, "https://raw.communitydragon.org/p/CantTouchThis_small.png"));
     }
+
+    #[test]
+    fn full_scan_steps_exclude_index_lookups() {
+        assert!(is_full_scan_step("SCAN p"));
+        assert!(!is_full_scan_step("SCAN patches USING COVERING INDEX idx_patches_locale_version"));
+        assert!(!is_full_scan_step("SEARCH patches USING INDEX sqlite_autoindex_patches_1 (version=?)"));
+    }
+
+    #[test]
+    fn order_version_rows_dedupes_equivalents_newest_first() {
+        let rows = vec![
+            ("25.9", "2025-05-01T00:00:00Z"),
+            ("15.10", "2025-05-20T00:00:00Z"),
+            ("25.10", "2025-05-15T00:00:00Z"),
+            ("25.10b", "2025-05-22T00:00:00Z"),
+        ];
+        let ordered = Database::order_version_rows(rows, |r| (r.0, r.1));
+        let versions: Vec<&str> = ordered.iter().map(|r| r.0).collect();
+        assert_eq!(versions, vec!["25.10b", "25.10", "25.9"]);
+    }
+
+    #[test]
+    fn decode_legacy_champion_array_row() {
+        let c = decode_stored_patch_json("[]").unwrap();
+        assert_eq!(c.schema_version, PATCH_JSON_SCHEMA_VERSION);
+        assert!(c.champions.is_empty() && c.patch_notes.is_empty());
+    }
+
+    #[test]
+    fn decode_unversioned_object_row_and_reject_newer_schema() {
+        let c = decode_stored_patch_json(r#"{"champions":[],"patch_notes":[],"banner_url":"b"}"#)
+            .unwrap();
+        assert_eq!(c.schema_version, PATCH_JSON_SCHEMA_VERSION);
+        assert_eq!(c.banner_url.as_deref(), Some("b"));
+        let newer = format!(
+            r#"{{"schema_version":{},"champions":[],"patch_notes":[]}}"#,
+            PATCH_JSON_SCHEMA_VERSION + 1
+        );
+        assert!(decode_stored_patch_json(&newer).is_err());
+        assert!(decode_stored_patch_json("not json").is_err());
+    }
+
+    #[test]
+    fn legacy_rune_names_load_as_rune_entries() {
+        let c = decode_stored_patch_json(
+            r#"[{"id":"Ahri","name":"Ahri","tier":"?","role":"Mid","win_rate":50.0,"pick_rate":0.0,
+                "ban_rate":0.0,"image_url":null,"core_items":[],"popular_runes":["Electrocute"]}]"#,
+        )
+        .unwrap();
+        let rune = &c.champions[0].popular_runes[0];
+        assert_eq!(rune.name, "Electrocute");
+        assert!(rune.id.is_none());
+    }
+
+    #[tokio::test]
+    async fn corrupt_row_is_skipped_in_lists_and_reported_on_lookup() {
+        let dir = std::env::temp_dir().join(format!("patch-analyzer-db-corrupt-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db = Database::open(&dir.join("patches.db")).await.unwrap();
+        let patch = |version: &str| PatchData {
+            version: version.into(),
+            fetched_at: chrono::Utc::now(),
+            patch_notes_locale: Some("en".into()),
+            ..Default::default()
+        };
+        db.save_patches(&[patch("25.21"), patch("25.22")]).await.unwrap();
+        sqlx::query("UPDATE patches SET data_json = 'not json' WHERE version = '25.22'")
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let listed = db.get_patches_newest_versions_first(10).await.unwrap();
+        let versions: Vec<&str> = listed.iter().map(|p| p.version.as_str()).collect();
+        assert_eq!(versions, vec!["25.21"]);
+        assert!(db.get_patch("25.22").await.is_err());
+        assert!(db.get_patch("25.21").await.unwrap().is_some());
+
+        db.pool.close().await;
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
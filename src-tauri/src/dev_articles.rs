@@ -0,0 +1,235 @@
+//! Статьи разработчиков Riot (раздел /news/dev/): облегчённый разбор —
+//! заголовки, абзацы и картинки, без структуры патч-нотов.
+
+use anyhow::Result;
+use chrono::Utc;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::db::Database;
+use crate::models::{DevArticle, DevArticleKind, DevArticleSection, StaticCatalogRow};
+use crate::scraper::Scraper;
+
+const LEAGUE_ORIGIN: &str = "https://www.leagueoflegends.com";
+
+/// Контейнеры текста статьи в порядке предпочтения.
+const ARTICLE_BODY_SELECTORS: &[&str] = &[
+    "#patch-notes-container",
+    "[data-testid='rich-text-html']",
+    "article",
+    "main",
+    "body",
+];
+
+pub fn dev_news_url(locale: &str) -> String {
+    format!(
+        "{}/{}/news/dev/",
+        LEAGUE_ORIGIN,
+        crate::scraper::riot_news_region_path(locale)
+    )
+}
+
+/// Ссылки на статьи со страницы-списка /news/dev/ (абсолютные, без дублей, в порядке страницы).
+pub fn parse_dev_article_list(html: &str) -> Vec<String> {
+    let document = Html::parse_document(html);
+    let Ok(a_sel) = Selector::parse("a[href]") else {
+        return vec![];
+    };
+    let mut out: Vec<String> = Vec::new();
+    for a in document.select(&a_sel) {
+        let Some(href) = a.value().attr("href") else {
+            continue;
+        };
+        let Some(pos) = href.find("/news/dev/") else {
+            continue;
+        };
+        // Сама страница-список и пагинация
+        if href[pos + "/news/dev/".len()..].trim_matches('/').is_empty() {
+            continue;
+        }
+        let url = if href.starts_with("http") {
+            href.to_string()
+        } else {
+            format!("{}{}", LEAGUE_ORIGIN, href)
+        };
+        if !out.contains(&url) {
+            out.push(url);
+        }
+    }
+    out
+}
+
+pub fn dev_article_kind(title: &str) -> DevArticleKind {
+    let t = title.to_lowercase();
+    if t.contains("quick gameplay thoughts") || t.contains("короткие мысли") || t.contains("мысли об игровом процессе")
+    {
+        DevArticleKind::QuickGameplayThoughts
+    } else {
+        DevArticleKind::DevBlog
+    }
+}
+
+fn element_text(el: ElementRef<'_>) -> String {
+    el.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn parse_dev_article_html(html: &str, url: &str, locale: &str) -> DevArticle {
+    let meta = Scraper::extract_article_meta(html);
+    let document = Html::parse_document(html);
+
+    let title = Selector::parse("meta[property='og:title']")
+        .ok()
+        .and_then(|sel| document.select(&sel).next())
+        .and_then(|m| m.value().attr("content"))
+        .map(|s| s.trim().to_string())
+        .or_else(|| {
+            let sel = Selector::parse("h1, title").ok()?;
+            document.select(&sel).next().map(element_text)
+        })
+        .unwrap_or_default();
+
+    let body = ARTICLE_BODY_SELECTORS
+        .iter()
+        .filter_map(|s| Selector::parse(s).ok())
+        .find_map(|sel| document.select(&sel).next());
+
+    let mut sections: Vec<DevArticleSection> = Vec::new();
+    let mut current = DevArticleSection::default();
+    if let Some(body) = body {
+        for node in body.descendants() {
+            let Some(el) = ElementRef::wrap(node) else {
+                continue;
+            };
+            match el.value().name() {
+                "h2" | "h3" | "h4" => {
+                    let heading = element_text(el);
+                    if heading.is_empty() {
+                        continue;
+                    }
+                    if current.heading.is_some() || !current.paragraphs.is_empty() || !current.images.is_empty() {
+                        sections.push(std::mem::take(&mut current));
+                    }
+                    current.heading = Some(heading);
+                }
+                "p" | "li" => {
+                    let text = element_text(el);
+                    // Абзац внутри пункта списка уже учтён
+                    let nested = el
+                        .ancestors()
+                        .filter_map(ElementRef::wrap)
+                        .take_while(|a| a.id() != body.id())
+                        .any(|a| matches!(a.value().name(), "p" | "li"));
+                    if !text.is_empty() && !nested {
+                        current.paragraphs.push(text);
+                    }
+                }
+                "img" => {
+                    if let Some(src) = el.value().attr("src").filter(|s| s.starts_with("http")) {
+                        let src = Scraper::clean_cdn_image_url(src);
+                        if !current.images.contains(&src) {
+                            current.images.push(src);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    if current.heading.is_some() || !current.paragraphs.is_empty() || !current.images.is_empty() {
+        sections.push(current);
+    }
+
+    DevArticle {
+        url: url.to_string(),
+        kind: dev_article_kind(&title),
+        title,
+        locale: if locale == "en" { "en" } else { "ru" }.to_string(),
+        published_at: meta.published_at,
+        author: meta.author,
+        banner_url: meta.banner_url,
+        sections,
+        mentions: vec![],
+        fetched_at: Utc::now(),
+    }
+}
+
+/// Загружает новые статьи, проставляет упоминания по static_catalog и сохраняет. Возвращает число новых.
+pub async fn sync_dev_articles(scraper: &Scraper, db: &Database, locale: &str, limit: usize) -> Result<usize> {
+    let known = db.list_dev_article_urls().await?;
+    let articles = scraper.fetch_dev_articles(locale, limit, &known).await?;
+    let champions = db.get_static_catalog_kind("champion").await.unwrap_or_default();
+    let count = articles.len();
+    for mut article in articles {
+        resolve_dev_article_mentions(&mut article, &champions);
+        db.save_dev_article(&article).await?;
+    }
+    Ok(count)
+}
+
+/// Заполняет `mentions` чемпионами из static_catalog, чьё имя (en/ru) встречается в тексте целым словом.
+pub fn resolve_dev_article_mentions(article: &mut DevArticle, champions: &[StaticCatalogRow]) {
+    let text = article.plain_text();
+    let mut mentions = Vec::new();
+    for row in champions {
+        let found = [&row.name_en, &row.name_ru]
+            .into_iter()
+            .filter(|n| !n.is_empty())
+            .any(|name| {
+                Regex::new(&format!(r"(?i)(^|[^\p{{L}}]){}($|[^\p{{L}}])", regex::escape(name)))
+                    .map(|re| re.is_match(&text))
+                    .unwrap_or(false)
+            });
+        if found {
+            mentions.push(format!("champion:{}", row.stable_id));
+        }
+    }
+    article.mentions = mentions;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quick_gameplay_thoughts_article() {
+        let html = r#"<html><head>
+<meta property="og:title" content="Quick Gameplay Thoughts: March 7">
+<meta property="article:published_time" content="2025-03-07T20:00:00.000Z">
+</head><body><article>
+<h2>Jungle changes</h2>
+<p>We're looking at Lee Sin and Viego early clears.</p>
+<img src="https://cdn.example/img.png">
+<h2>Mid lane</h2>
+<ul><li><p>Ahri is in a good spot.</p></li></ul>
+</article></body></html>"#;
+        let mut a = parse_dev_article_html(html, "https://x/news/dev/qgt/", "en");
+        assert_eq!(a.kind, DevArticleKind::QuickGameplayThoughts);
+        assert_eq!(a.sections.len(), 2);
+        assert_eq!(a.sections[0].heading.as_deref(), Some("Jungle changes"));
+        assert_eq!(a.sections[0].images.len(), 1);
+        assert_eq!(a.sections[1].paragraphs, vec!["Ahri is in a good spot.".to_string()]);
+
+        let champ = |id: &str, en: &str| StaticCatalogRow {
+            kind: "champion".into(),
+            stable_id: id.into(),
+            name_ru: String::new(),
+            name_en: en.into(),
+            riot_augment_id: None,
+            cd_meta: None,
+            icon_sources: vec![],
+            source: "ddragon".into(),
+        };
+        resolve_dev_article_mentions(&mut a, &[champ("LeeSin", "Lee Sin"), champ("Ahri", "Ahri"), champ("Vi", "Vi")]);
+        assert_eq!(a.mentions, vec!["champion:LeeSin".to_string(), "champion:Ahri".to_string()]);
+    }
+
+    #[test]
+    fn dev_article_list_skips_index_links() {
+        let html = r#"<a href="/en-gb/news/dev/">Dev</a><a href="/en-gb/news/dev/dev-update-march/">A</a>
+<a href="/en-gb/news/dev/dev-update-march/">A</a><a href="/en-gb/news/game-updates/patch-25-5-notes/">P</a>"#;
+        assert_eq!(
+            parse_dev_article_list(html),
+            vec!["https://www.leagueoflegends.com/en-gb/news/dev/dev-update-march/".to_string()]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Построчное сравнение одной сущности в двух патчах (`get_entity_diff`): блоки изменений из
+//! патч-нотов и статистика чемпиона по ролям. Строки, которые есть только во второй версии, —
+//! `Added`, только в первой — `Removed`, общие — `Same`.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{ChampionStats, ChangeBlock, EntityRef};
+use crate::patch_version::versions_match;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum DiffOp {
+    Same,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct DiffLine {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct EntityDiff {
+    pub entity: EntityRef,
+    pub version_a: String,
+    pub version_b: String,
+    /// Есть ли записи о сущности в патч-нотах версий
+    pub in_a: bool,
+    pub in_b: bool,
+    pub changes: Vec<DiffLine>,
+    /// Только для чемпионов с сохранённой статистикой
+    pub stats: Vec<DiffLine>,
+}
+
+/// Заголовки блоков — отдельными строками, вложенные блоки с отступом.
+pub fn block_lines(blocks: &[ChangeBlock]) -> Vec<String> {
+    fn walk(block: &ChangeBlock, depth: usize, out: &mut Vec<String>) {
+        let indent = "  ".repeat(depth);
+        if let Some(title) = block.title.as_deref().filter(|t| !t.trim().is_empty()) {
+            out.push(format!("{}{}:", indent, title.trim()));
+        }
+        for change in &block.changes {
+            out.push(format!("{}- {}", indent, change.trim()));
+        }
+        for child in &block.children {
+            walk(child, depth + 1, out);
+        }
+    }
+    let mut out = Vec::new();
+    for block in blocks {
+        walk(block, 0, &mut out);
+    }
+    out
+}
+
+/// По строке на показатель роли, роли по алфавиту.
+pub fn stats_lines(stats: &[ChampionStats]) -> Vec<String> {
+    let mut rows: Vec<&ChampionStats> = stats.iter().filter(|s| s.has_data()).collect();
+    rows.sort_by_key(|s| format!("{:?}", s.role));
+    let mut out = Vec::new();
+    for s in rows {
+        let role = format!("{:?}", s.role);
+        out.push(format!("{} tier: {}", role, s.tier));
+        out.push(format!("{} win rate: {:.2}%", role, s.win_rate));
+        out.push(format!("{} pick rate: {:.2}%", role, s.pick_rate));
+        out.push(format!("{} ban rate: {:.2}%", role, s.ban_rate));
+    }
+    out
+}
+
+/// Разница по наибольшей общей подпоследовательности строк.
+pub fn diff_lines(a: &[String], b: &[String]) -> Vec<DiffLine> {
+    let (n, m) = (a.len(), b.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let line = |op, text: &String| DiffLine { op, text: text.clone() };
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::with_capacity(n.max(m));
+    while i < n && j < m {
+        if a[i] == b[j] {
+            out.push(line(DiffOp::Same, &a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(line(DiffOp::Removed, &a[i]));
+            i += 1;
+        } else {
+            out.push(line(DiffOp::Added, &b[j]));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|t| line(DiffOp::Removed, t)));
+    out.extend(b[j..].iter().map(|t| line(DiffOp::Added, t)));
+    out
+}
+
+pub async fn entity_diff(db: &Database, entity: EntityRef, version_a: &str, version_b: &str) -> anyhow::Result<EntityDiff> {
+    let history = db.get_entity_history(&entity, None).await?;
+    let notes = |version: &str| -> Option<Vec<String>> {
+        let entries: Vec<_> = history.iter().filter(|e| versions_match(&e.patch_version, version)).collect();
+        (!entries.is_empty()).then(|| entries.iter().flat_map(|e| block_lines(&e.change.details)).collect())
+    };
+    let (notes_a, notes_b) = (notes(version_a), notes(version_b));
+
+    let stats = match &entity {
+        EntityRef::Champion { id } => {
+            let patches = db.get_champion_stats_history(id).await?;
+            let lines = |version: &str| {
+                patches
+                    .iter()
+                    .find(|p| versions_match(&p.version, version))
+                    .map(|p| stats_lines(&p.stats))
+                    .unwrap_or_default()
+            };
+            diff_lines(&lines(version_a), &lines(version_b))
+        }
+        _ => Vec::new(),
+    };
+
+    Ok(EntityDiff {
+        in_a: notes_a.is_some(),
+        in_b: notes_b.is_some(),
+        changes: diff_lines(&notes_a.unwrap_or_default(), &notes_b.unwrap_or_default()),
+        stats,
+        entity,
+        version_a: version_a.to_string(),
+        version_b: version_b.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(title: Option<&str>, changes: &[&str], children: Vec<ChangeBlock>) -> ChangeBlock {
+        ChangeBlock {
+            title: title.map(str::to_string),
+            icon_url: None,
+            changes: changes.iter().map(|s| s.to_string()).collect(),
+            children,
+        }
+    }
+
+    #[test]
+    fn diff_marks_moved_lines() {
+        let a = block_lines(&[block(
+            Some("Q"),
+            &["Damage: 40 → 50", "Cooldown: 8"],
+            vec![block(Some("Empowered"), &["Heal: 10"], vec![])],
+        )]);
+        assert_eq!(a, ["Q:", "- Damage: 40 → 50", "- Cooldown: 8", "  Empowered:", "  - Heal: 10"]);
+        let b = block_lines(&[block(Some("Q"), &["Damage: 50 → 60", "Cooldown: 8"], vec![])]);
+
+        let diff = diff_lines(&a, &b);
+        let ops: Vec<(DiffOp, &str)> = diff.iter().map(|l| (l.op, l.text.as_str())).collect();
+        assert_eq!(
+            ops,
+            [
+                (DiffOp::Same, "Q:"),
+                (DiffOp::Removed, "- Damage: 40 → 50"),
+                (DiffOp::Added, "- Damage: 50 → 60"),
+                (DiffOp::Same, "- Cooldown: 8"),
+                (DiffOp::Removed, "  Empowered:"),
+                (DiffOp::Removed, "  - Heal: 10"),
+            ]
+        );
+    }
+}
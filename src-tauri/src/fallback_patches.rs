@@ -0,0 +1,87 @@
+//! Запасной список патчей на случай, когда DDragon недоступен. Номера из `versions.json` в нумерации
+//! сайта (15.22 → 25.22) кэшируются в БД при каждом успешном обновлении; пользователь может добавить
+//! свои номера в настройках — например, вышедший патч, которого ещё нет в DDragon.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::patch_version::{ddragon_pair_to_display, PatchVersion};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum FallbackPatchSource {
+    Ddragon,
+    Manual,
+}
+
+impl FallbackPatchSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ddragon => "ddragon",
+            Self::Manual => "manual",
+        }
+    }
+
+    pub fn from_db(value: &str) -> Self {
+        if value == "manual" {
+            Self::Manual
+        } else {
+            Self::Ddragon
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct FallbackPatch {
+    pub version: String,
+    pub source: FallbackPatchSource,
+    pub added_at: DateTime<Utc>,
+}
+
+/// Номер в каноничной записи сайта ("15.22.1" → "25.22"); `None` — не номер патча.
+pub fn normalize(version: &str) -> Option<String> {
+    PatchVersion::parse(version)
+        .filter(|v| v.major > 0 && v.minor >= 0)
+        .map(|v| v.to_string())
+}
+
+/// Версии DDragon без повторов и сборок, новые первыми.
+pub fn from_ddragon_versions(versions: &[String]) -> Vec<String> {
+    let mut patches: Vec<String> = Vec::new();
+    for patch in versions.iter().filter_map(|v| normalize(v)) {
+        if !patches.contains(&patch) {
+            patches.push(patch);
+        }
+    }
+    sort_newest_first(&mut patches);
+    patches
+}
+
+pub fn sort_newest_first(patches: &mut [String]) {
+    patches.sort_by_key(|p| std::cmp::Reverse(PatchVersion::parse(p)));
+}
+
+/// Последний рубеж, если в БД ещё ничего нет: 25.14–25.24.
+pub fn builtin() -> Vec<String> {
+    (14..=24).rev().map(|minor| ddragon_pair_to_display(15, minor)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ddragon_versions_map_to_site_numbering() {
+        let versions: Vec<String> = ["15.22.1", "15.21.1", "15.22.2", "lolpatch_7.17", "16.1.1", "0.151.2"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(from_ddragon_versions(&versions), ["26.1", "25.22", "25.21"]);
+        assert_eq!(normalize(" 25.S1.2 ").as_deref(), Some("25.2"));
+        assert_eq!(normalize("latest"), None);
+        assert_eq!(builtin().first().map(String::as_str), Some("25.24"));
+    }
+}
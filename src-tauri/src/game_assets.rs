@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use serde_json::{json, Value};
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 use crate::db::Database;
 use crate::models::{IconSourceEntry, StaticCatalogRow};
@@ -70,8 +71,7 @@ async fn fetch_json(client: &reqwest::Client, url: &str) -> Result<Value> {
     if !resp.status().is_success() {
         anyhow::bail!("HTTP {} for {}", resp.status(), url);
     }
-    let v: Value = resp.json().await.with_context(|| format!("json {}", url))?;
-    Ok(v)
+    crate::http_body::read_json(resp).await
 }
 
 pub async fn try_seed_manifest_meta(db: &Database) -> Result<()> {
@@ -119,11 +119,12 @@ async fn cache_https_icon_to_dir(
             url: Some(path.to_string_lossy().into_owned()),
         });
     }
+    crate::maintenance::touch();
     let resp = client.get(https_url).send().await.ok()?;
     if !resp.status().is_success() {
         return None;
     }
-    let bytes = resp.bytes().await.ok()?;
+    let bytes = crate::http_body::read_bytes(resp, crate::http_body::MAX_IMAGE_BYTES).await.ok()?;
     std::fs::create_dir_all(&kind_dir).ok()?;
     std::fs::write(&path, &bytes).ok()?;
     Some(IconSourceEntry {
@@ -132,6 +133,42 @@ async fn cache_https_icon_to_dir(
     })
 }
 
+/// Удаляет иконки `<dir>/<kind>/*.png`, на которые не ссылается ни одна строка каталога.
+/// При пустом каталоге (ещё не загружен) ничего не трогает. Возвращает (файлов, байт).
+pub async fn collect_unused_icons(db: &Database, dir: &Path) -> Result<(u64, u64)> {
+    let referenced: HashSet<PathBuf> = db
+        .static_catalog_icon_sources()
+        .await?
+        .iter()
+        .filter_map(|json| serde_json::from_str::<Vec<IconSourceEntry>>(json).ok())
+        .flatten()
+        .filter(|s| s.t == "file")
+        .filter_map(|s| s.url.map(PathBuf::from))
+        .collect();
+    if referenced.is_empty() {
+        return Ok((0, 0));
+    }
+    let (mut files, mut bytes) = (0, 0);
+    let Ok(kinds) = std::fs::read_dir(dir) else {
+        return Ok((0, 0));
+    };
+    for kind in kinds.flatten() {
+        let Ok(entries) = std::fs::read_dir(kind.path()) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if referenced.contains(&path) {
+                continue;
+            }
+            let Ok(md) = entry.metadata() else { continue };
+            if crate::maintenance::is_settled_file(&md) && std::fs::remove_file(&path).is_ok() {
+                files += 1;
+                bytes += md.len();
+            }
+        }
+    }
+    Ok((files, bytes))
+}
+
 fn prepend_file_source(icon_sources: &mut Vec<IconSourceEntry>, file_source: Option<IconSourceEntry>) {
     let Some(f) = file_source else {
         return;
@@ -244,7 +281,16 @@ pub async fn refresh_game_assets(
                 name_ru,
                 name_en,
                 riot_augment_id: None,
-                cd_meta: Some(json!({"key": champ_key})),
+                cd_meta: Some(json!({
+                    "key": champ_key,
+                    "tags": val_ru.get("tags").cloned().unwrap_or(Value::Null),
+                    "partype": val_en.and_then(|v| v.get("partype")).cloned().unwrap_or(Value::Null),
+                    "attackrange": val_ru
+                        .get("stats")
+                        .and_then(|s| s.get("attackrange"))
+                        .cloned()
+                        .unwrap_or(Value::Null),
+                })),
                 icon_sources,
                 source: "ddragon".into(),
             });
@@ -270,7 +316,7 @@ pub async fn refresh_game_assets(
         .send()
         .await
     {
-        Ok(r) if r.status().is_success() => r.json().await.unwrap_or(json!([])),
+        Ok(r) if r.status().is_success() => crate::http_body::read_json(r).await.unwrap_or(json!([])),
         _ => json!([]),
     };
 
@@ -351,6 +397,19 @@ pub async fn refresh_game_assets(
                 }
                 _ => {}
             }
+            // Цена, статы и сборка из DDragon — для ItemStat::from_catalog_row
+            let val_en = data_en.get(id_s);
+            let dd_item = json!({
+                "gold": val_ru.get("gold").cloned().unwrap_or(Value::Null),
+                "stats": val_en.and_then(|v| v.get("stats")).cloned().unwrap_or(Value::Null),
+                "from": val_ru.get("from").cloned().unwrap_or(Value::Null),
+            });
+            match cd_meta.as_mut().and_then(|m| m.as_object_mut()) {
+                Some(obj) => {
+                    obj.insert("dd".to_string(), dd_item);
+                }
+                None => cd_meta = Some(json!({ "dd": dd_item })),
+            }
             rows.push(StaticCatalogRow {
                 kind: "item".into(),
                 stable_id: id_s.clone(),
@@ -446,7 +505,7 @@ pub async fn refresh_game_assets(
                         name_ru,
                         name_en,
                         riot_augment_id: None,
-                        cd_meta: Some(json!({"style": sk, "key": rkey, "id": rid})),
+                        cd_meta: Some(json!({"style": sk, "key": rkey, "id": rid, "slot": sj})),
                         icon_sources,
                         source: "ddragon".into(),
                     });
@@ -463,7 +522,7 @@ pub async fn refresh_game_assets(
         .send()
         .await
     {
-        Ok(r) if r.status().is_success() => r.json().await.unwrap_or(json!([])),
+        Ok(r) if r.status().is_success() => crate::http_body::read_json(r).await.unwrap_or(json!([])),
         _ => json!([]),
     };
 
@@ -630,6 +689,7 @@ pub async fn refresh_game_assets(
     db.upsert_static_rows(&rows).await?;
     db.set_game_assets_meta(Some(&ver), Some(&chrono::Utc::now().to_rfc3339()))
         .await?;
+    let _ = db.backfill_entity_ids().await;
 
     Ok(())
 }
@@ -0,0 +1,91 @@
+//! Чтение тел HTTP-ответов с потолком размера. Страницы Riot весят по несколько МБ, а `text()`/`json()`
+//! читают тело целиком без ограничений. Лимит считается по распакованным байтам (gzip/brotli),
+//! тело читается по кускам и обрывается, как только превысит лимит.
+
+use anyhow::{bail, Context, Result};
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+
+/// HTML и JSON: самые тяжёлые патч-ноты с картинками в разметке укладываются с большим запасом.
+pub const MAX_PAGE_BYTES: usize = 16 * 1024 * 1024;
+/// Иконки, баннеры и картинки патч-нотов.
+pub const MAX_IMAGE_BYTES: usize = 12 * 1024 * 1024;
+/// Установщик обновления пишется сразу в файл, в памяти не держится.
+#[cfg(not(debug_assertions))]
+pub const MAX_INSTALLER_BYTES: u64 = 512 * 1024 * 1024;
+
+fn append_limited(buf: &mut Vec<u8>, chunk: &[u8], limit: usize) -> Result<()> {
+    if buf.len() + chunk.len() > limit {
+        bail!("response body exceeds {} bytes", limit);
+    }
+    buf.extend_from_slice(chunk);
+    Ok(())
+}
+
+/// Тело ответа целиком, но не больше `limit` байт.
+pub async fn read_bytes(mut resp: Response, limit: usize) -> Result<Vec<u8>> {
+    let declared = resp.content_length().unwrap_or(0);
+    if declared > limit as u64 {
+        bail!("{}: Content-Length {} exceeds {} bytes", resp.url(), declared, limit);
+    }
+    let mut buf = Vec::with_capacity(declared as usize);
+    while let Some(chunk) = resp.chunk().await? {
+        append_limited(&mut buf, &chunk, limit).with_context(|| resp.url().to_string())?;
+    }
+    Ok(buf)
+}
+
+/// Страница как текст (UTF-8; битые последовательности заменяются).
+pub async fn read_text(resp: Response) -> Result<String> {
+    let bytes = read_bytes(resp, MAX_PAGE_BYTES).await?;
+    Ok(String::from_utf8(bytes).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()))
+}
+
+pub async fn read_json<T: DeserializeOwned>(resp: Response) -> Result<T> {
+    let url = resp.url().to_string();
+    let bytes = read_bytes(resp, MAX_PAGE_BYTES).await?;
+    serde_json::from_slice(&bytes).with_context(|| format!("json {}", url))
+}
+
+/// Пишет тело в `path` по кускам через `<path>.part`; недокачанный файл не остаётся под итоговым именем.
+#[cfg(not(debug_assertions))]
+pub async fn download_to_file(mut resp: Response, path: &std::path::Path, limit: u64) -> Result<u64> {
+    let part = path.with_extension("part");
+    use tokio::io::AsyncWriteExt;
+
+    let mut file = tokio::fs::File::create(&part).await?;
+    let mut written = 0u64;
+    let result: Result<()> = async {
+        while let Some(chunk) = resp.chunk().await? {
+            written += chunk.len() as u64;
+            if written > limit {
+                bail!("{}: download exceeds {} bytes", resp.url(), limit);
+            }
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+        Ok(())
+    }
+    .await;
+    drop(file);
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&part).await;
+        return Err(e);
+    }
+    tokio::fs::rename(&part, path).await?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_stops_at_limit() {
+        let mut buf = Vec::new();
+        append_limited(&mut buf, b"abcd", 6).unwrap();
+        append_limited(&mut buf, b"ef", 6).unwrap();
+        assert!(append_limited(&mut buf, b"g", 6).is_err());
+        assert_eq!(buf, b"abcdef");
+    }
+}
@@ -0,0 +1,143 @@
+//! Общие настройки HTTP-клиентов: пул соединений с keep-alive, HTTP/2 и кэш DNS.
+//! Полная синхронизация ходит на одни и те же хосты (Riot, DDragon, CommunityDragon) десятки раз подряд,
+//! без этого каждая загрузка заново резолвит имя и делает TLS-рукопожатие.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
+
+/// Сколько держать простаивающее соединение (между патчами при синхронизации паузы до десятков секунд).
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+const POOL_MAX_IDLE_PER_HOST: usize = 8;
+const TCP_KEEPALIVE: Duration = Duration::from_secs(60);
+const HTTP2_KEEPALIVE: Duration = Duration::from_secs(30);
+/// Адреса CDN меняются редко, а запросов за синхронизацию — сотни.
+const DNS_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Default)]
+struct DnsCache {
+    entries: HashMap<String, (Instant, Vec<SocketAddr>)>,
+}
+
+impl DnsCache {
+    fn get(&self, host: &str, now: Instant) -> Option<Vec<SocketAddr>> {
+        self.entries
+            .get(host)
+            .filter(|(resolved_at, _)| now.duration_since(*resolved_at) < DNS_TTL)
+            .map(|(_, addrs)| addrs.clone())
+    }
+
+    /// Пустой ответ не кэшируется: следующий запрос резолвит имя заново. Устаревшие записи
+    /// удаляются при вставке, чтобы кэш не рос за долгую сессию.
+    fn insert(&mut self, host: String, addrs: Vec<SocketAddr>, now: Instant) {
+        self.entries
+            .retain(|_, (resolved_at, _)| now.duration_since(*resolved_at) < DNS_TTL);
+        if !addrs.is_empty() {
+            self.entries.insert(host, (now, addrs));
+        }
+    }
+}
+
+/// Системный резолвер с кэшем на `DNS_TTL`; один на все клиенты. Ошибки резолва не кэшируются.
+#[derive(Clone, Default)]
+struct CachingResolver {
+    cache: Arc<Mutex<DnsCache>>,
+}
+
+impl Resolve for CachingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let cache = self.cache.clone();
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let cached = cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .get(&host, Instant::now());
+            let addrs = match cached {
+                Some(addrs) => addrs,
+                None => {
+                    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+                    cache
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(host, addrs.clone(), Instant::now());
+                    addrs
+                }
+            };
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+static RESOLVER: Lazy<Arc<CachingResolver>> = Lazy::new(|| Arc::new(CachingResolver::default()));
+
+/// Builder с общим пулом/keep-alive/HTTP2/DNS; заголовки и таймауты вызывающий добавляет сам.
+pub fn tuned_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+        .pool_max_idle_per_host(POOL_MAX_IDLE_PER_HOST)
+        .tcp_keepalive(TCP_KEEPALIVE)
+        .http2_adaptive_window(true)
+        .http2_keep_alive_interval(HTTP2_KEEPALIVE)
+        .http2_keep_alive_while_idle(true)
+        .gzip(true)
+        .brotli(true)
+        .dns_resolver(RESOLVER.clone())
+}
+
+static API_CLIENT: Lazy<Result<reqwest::Client, String>> = Lazy::new(|| {
+    tuned_builder()
+        .user_agent("PatchAnalyzer/1.0 (Tauri)")
+        .connect_timeout(Duration::from_secs(20))
+        .timeout(Duration::from_secs(60))
+        .build()
+        .map_err(|e| {
+            logger::log(LogLevel::Error, LogSource::App, &format!("http client init failed: {}", e));
+            e.to_string()
+        })
+});
+
+/// Клиент для API и лент вне сайтов Riot (YouTube): один на приложение, соединения переиспользуются.
+/// Ошибка сборки (например, нет TLS-бэкенда) возвращается каждому вызову, а не роняет приложение.
+pub fn api_client() -> anyhow::Result<&'static reqwest::Client> {
+    API_CLIENT
+        .as_ref()
+        .map_err(|e| anyhow::anyhow!("http client init failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dns_cache_expires_after_ttl() {
+        let mut cache = DnsCache::default();
+        let now = Instant::now();
+        let addrs = vec![SocketAddr::from(([127, 0, 0, 1], 0))];
+        cache.insert("ddragon.leagueoflegends.com".into(), addrs.clone(), now);
+        assert_eq!(cache.get("ddragon.leagueoflegends.com", now + Duration::from_secs(1)), Some(addrs));
+        assert_eq!(cache.get("ddragon.leagueoflegends.com", now + DNS_TTL), None);
+        assert_eq!(cache.get("www.leagueoflegends.com", now), None);
+    }
+
+    #[test]
+    fn dns_cache_skips_empty_results_and_prunes_expired_entries() {
+        let mut cache = DnsCache::default();
+        let now = Instant::now();
+        cache.insert("unresolved.example".into(), vec![], now);
+        assert!(cache.entries.is_empty());
+
+        let addrs = vec![SocketAddr::from(([127, 0, 0, 1], 0))];
+        cache.insert("old.example".into(), addrs.clone(), now);
+        cache.insert("new.example".into(), addrs, now + DNS_TTL);
+        assert!(!cache.entries.contains_key("old.example"));
+        assert!(cache.entries.contains_key("new.example"));
+    }
+}
@@ -0,0 +1,99 @@
+//! Каталог строк, которые формирует backend (дайджесты, календарь, сообщения журнала, ошибки),
+//! по ключу и языку. Язык выставляет фронтенд вместе с языком интерфейса (`set_app_language`);
+//! неизвестный ключ возвращается как есть.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    Ru,
+    En,
+}
+
+impl Lang {
+    /// `en`, `en-US`, `en_GB` — английский, всё остальное — русский, как и в интерфейсе по умолчанию.
+    pub fn from_code(code: &str) -> Self {
+        if code.trim().to_ascii_lowercase().starts_with("en") {
+            Lang::En
+        } else {
+            Lang::Ru
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Ru => "ru",
+            Lang::En => "en",
+        }
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_current(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+pub fn current() -> Lang {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Lang::En,
+        _ => Lang::Ru,
+    }
+}
+
+/// Ключ, английский, русский. Подстановки — `{name}`.
+const CATALOG: &[(&str, &str, &str)] = &[
+    ("note.bugfix_title", "Bug fix", "Исправление ошибки"),
+    ("error.app_data_unavailable", "app data directory is unavailable", "каталог данных приложения недоступен"),
+    ("log.fetching_patch", "Fetching patch data for {version} from web...", "Загрузка данных патча {version} с сайта..."),
+    ("log.patch_saved", "Data for {version} fetched and saved.", "Данные патча {version} загружены и сохранены."),
+    ("log.patch_fetch_failed", "Failed to fetch patch {version}: {error}", "Не удалось загрузить патч {version}: {error}"),
+    ("log.patch_imported", "Patch {version} imported from {origin}", "Патч {version} импортирован из {origin}"),
+    ("log.feed_write_failed", "Failed to write patch feed: {error}", "Не удалось записать RSS-ленту: {error}"),
+    ("feed.channel_description", "Digests of patches analyzed by LoL Meta Analyzer", "Сводки патчей, разобранных LoL Meta Analyzer"),
+    ("feed.item_title", "Patch {version}", "Патч {version}"),
+    ("digest.totals", "{notes} changes, {entities} champions/items/runes touched", "изменений: {notes}, затронуто чемпионов/предметов/рун: {entities}"),
+    ("digest.change_types", "buffs: {buffs}, nerfs: {nerfs}, adjusted: {adjusted}, new: {new}", "усилений: {buffs}, ослаблений: {nerfs}, изменений: {adjusted}, новых: {new}"),
+    ("digest.biggest_buff", "biggest buff: {title} ({score})", "главное усиление: {title} ({score})"),
+    ("digest.biggest_nerf", "biggest nerf: {title} ({score})", "главное ослабление: {title} ({score})"),
+    ("calendar.name", "League of Legends patches", "Патчи League of Legends"),
+    ("calendar.patch", "LoL patch {version}", "Патч LoL {version}"),
+    ("calendar.patch_expected", "LoL patch {version} (expected)", "Патч LoL {version} (ожидается)"),
+];
+
+pub fn tr(lang: Lang, key: &'static str) -> &'static str {
+    match CATALOG.iter().find(|(k, _, _)| *k == key) {
+        Some((_, en, ru)) => match lang {
+            Lang::En => en,
+            Lang::Ru => ru,
+        },
+        None => key,
+    }
+}
+
+/// `tr` с подстановкой `{name}` из `args`.
+pub fn trf(lang: Lang, key: &'static str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+    args.iter().fold(tr(lang, key).to_string(), |s, (name, value)| {
+        s.replace(&format!("{{{}}}", name), &value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_has_both_languages_and_substitutes_args() {
+        for (key, en, ru) in CATALOG {
+            assert!(!en.is_empty() && !ru.is_empty(), "{key}");
+            let names = |s: &str| -> Vec<String> {
+                s.split('{').skip(1).filter_map(|p| p.split('}').next()).map(String::from).collect()
+            };
+            assert_eq!(names(en), names(ru), "{key}");
+        }
+        assert_eq!(Lang::from_code("en-US"), Lang::En);
+        assert_eq!(tr(Lang::Ru, "note.bugfix_title"), "Исправление ошибки");
+        assert_eq!(trf(Lang::En, "feed.item_title", &[("version", &"25.22")]), "Patch 25.22");
+        assert_eq!(trf(Lang::Ru, "missing.key", &[]), "missing.key");
+    }
+}
@@ -0,0 +1,79 @@
+//! Склейка одинаковых одновременных запросов: второй вызов с тем же ключом ждёт результат первого,
+//! а не повторяет работу. После завершения ключ освобождается, следующий вызов выполнится заново.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::OnceCell;
+
+pub struct InFlight<K, V> {
+    calls: Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> InFlight<K, V> {
+    pub fn new() -> Self {
+        Self {
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Выполняет `f` или присоединяется к уже идущему вызову с тем же ключом.
+    pub async fn run<F, Fut>(&self, key: K, f: F) -> V
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = V>,
+    {
+        let cell = self
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let value = cell.get_or_init(f).await.clone();
+        let mut calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        if calls.get(&key).is_some_and(|c| Arc::ptr_eq(c, &cell)) {
+            calls.remove(&key);
+        }
+        value
+    }
+
+    /// Ни одного незавершённого вызова.
+    pub fn is_idle(&self) -> bool {
+        self.calls.lock().unwrap_or_else(|e| e.into_inner()).is_empty()
+    }
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> Default for InFlight<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn concurrent_callers_share_one_call() {
+        let in_flight = InFlight::<String, usize>::new();
+        let calls = AtomicUsize::new(0);
+        let fetch = || async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            calls.fetch_add(1, Ordering::SeqCst) + 1
+        };
+        let (a, b) = tokio::join!(
+            in_flight.run("25.22".to_string(), fetch),
+            in_flight.run("25.22".to_string(), fetch),
+        );
+        assert_eq!((a, b), (1, 1));
+        assert!(in_flight.is_idle());
+
+        // Завершённый вызов не кэшируется
+        assert_eq!(in_flight.run("25.22".to_string(), fetch).await, 2);
+    }
+}
@@ -4,18 +4,24 @@ use tauri::tray::{TrayIconBuilder, TrayIconEvent, MouseButton};
 use tauri::image::Image;
 use std::sync::Arc;
 use std::path::PathBuf;
-use tokio::sync::Mutex;
 use crate::db::Database;
-use crate::scraper::Scraper;
+use crate::scraper::{RequestHeaderSettings, ScrapeSettings, Scraper};
+use crate::data_source::DataSourceRegistry;
 use crate::models::{
-    GameAssetsMeta, MayhemAugmentation, MetaAnalysisDiff, PatchCategory, PatchData, PatchNoteEntry,
-    StaticCatalogRow,
+    ChangeGrade, DevArticle, EntityRef, GameAssetsMeta, MayhemAugmentation, MetaAnalysisDiff, PatchCategory,
+    PatchData, PatchNoteEntry, PatchSummary, StaticCatalogRow,
 };
 use crate::analyzer::Analyzer;
+use crate::patch_cache::PatchCacheStats;
+use crate::scrape_profile::{ScrapePhase, ScrapeProfileReport};
+use crate::models::events::{
+    AppEvent, AppReadyEvent, LogEvent, LogLevel, LogSource, NewPatchEvent, StatsUpdatedEvent, SyncProgressEvent, SyncScope,
+    PatchNotesUpdatedEvent, ServerStatusChangedEvent, StoreRotationEndingEvent, WatchlistAlertEvent,
+};
 use std::collections::{HashSet, HashMap};
-use crate::patch_version::versions_match;
 use crate::patch_change_trend::analyze_change_trend;
 use serde::Serialize;
+use ts_rs::TS;
 
 pub mod models;
 pub mod db;
@@ -28,14 +34,53 @@ mod wiki_embed;
 mod game_assets;
 mod patch_icons;
 mod asset_cache;
-mod patch_change_trend;
+pub mod patch_change_trend;
+mod patch_tags;
+mod patch_summary;
+mod dev_articles;
+pub mod tier_list;
+mod patch_cache;
+mod in_flight;
+mod http_body;
+mod http_client;
+mod chunked_result;
+mod packed_ipc;
+mod scrape_profile;
+mod maintenance;
+mod local_api;
+pub mod data_source;
+mod script_hooks;
+mod champion_timeline;
+mod patch_feed;
+mod patch_calendar;
+mod competitive_impact;
+mod champion_report;
+mod overlay;
+mod i18n;
+mod logger;
+mod crash_report;
+mod data_wipe;
+mod fallback_patches;
+mod entity_diff;
+mod watchlist;
+mod upcoming_skins;
+mod patch_revisions;
+mod store_rotations;
+mod riot_api;
+mod summoner;
+mod match_impact;
+mod community_tiers;
+mod pro_builds;
+mod patch_reaction;
+mod machine_translation;
+mod objectives;
+mod player_systems;
+mod parse_coverage;
+mod page_layout;
 pub mod wiki_augment_bundle;
+#[cfg(test)]
+mod test_fixtures;
 
-struct AppState {
-    db: Arc<Database>,
-    scraper: Arc<Scraper>,
-    tier_cache: Mutex<Option<(String, Vec<TierEntry>)>>,
-}
 
 #[cfg(not(debug_assertions))]
 #[derive(serde::Deserialize)]
@@ -51,14 +96,38 @@ struct GithubRelease {
     assets: Vec<GithubReleaseAsset>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, TS)]
+#[ts(export)]
 pub struct ChampionHistoryEntry {
     pub patch_version: String,
     pub date: chrono::DateTime<chrono::Utc>,
+    /// Сезон/сплит патча ("25.S2")
+    pub split: String,
+    /// Сущность записи (по `change.entity_id`), если она распознана
+    pub entity: Option<EntityRef>,
     pub change: PatchNoteEntry,
 }
 
-#[derive(Serialize)]
+impl ChampionHistoryEntry {
+    pub fn new(
+        patch_version: String,
+        date: chrono::DateTime<chrono::Utc>,
+        split: String,
+        change: PatchNoteEntry,
+    ) -> Self {
+        let entity = change.entity_id.as_deref().and_then(EntityRef::from_entity_id);
+        Self {
+            patch_version,
+            date,
+            split,
+            entity,
+            change,
+        }
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export)]
 pub struct ChampionListItem {
     name: String,
     name_en: String,
@@ -67,26 +136,6 @@ pub struct ChampionListItem {
     id: String,
 }
 
-#[derive(Serialize, Clone)]
-pub struct TierEntry {
-    pub name: String,
-    pub category: PatchCategory,
-    pub buffs: u32,
-    pub nerfs: u32,
-    pub adjusted: u32,
-    pub icon_url: Option<String>,
-}
-
-#[derive(Serialize, Clone)]
-struct PreviousPatchSavedPayload {
-    version: String,
-    processed: usize,
-    total: usize,
-    downloaded: usize,
-    skipped: usize,
-    saved: bool,
-}
-
 #[tauri::command]
 fn analyze_change_trends(texts: Vec<String>) -> Vec<String> {
     texts
@@ -99,8 +148,82 @@ fn analyze_change_trends(texts: Vec<String>) -> Vec<String> {
         .collect()
 }
 
-fn log(_app: &AppHandle, level: &str, message: &str) {
-    println!("[{}] {}", level, message);
+/// Единая точка отправки событий во frontend (имена и payload — `models::events`).
+fn emit_event<E: AppEvent>(app: &AppHandle, event: E) {
+    if let Err(e) = app.emit(E::NAME, event) {
+        eprintln!("[event] {} emit failed: {}", E::NAME, e);
+    }
+}
+
+/// Итог старта backend: `None`, пока БД открывается в фоне.
+#[derive(Default)]
+struct StartupStatus(std::sync::OnceLock<AppReadyEvent>);
+
+/// Для frontend, подписавшегося на `app_ready` уже после события.
+#[tauri::command]
+fn get_startup_status(status: tauri::State<'_, StartupStatus>) -> Option<AppReadyEvent> {
+    status.0.get().cloned()
+}
+
+#[derive(Serialize, TS)]
+#[ts(export)]
+struct AppStatusPayload {
+    startup: Option<AppReadyEvent>,
+    /// Проблема на серверах по последнему опросу Riot API; `None` — всё штатно или ключ не задан
+    server_banner: Option<riot_api::ServerBanner>,
+}
+
+#[tauri::command]
+fn get_app_status(
+    status: tauri::State<'_, StartupStatus>,
+    api: tauri::State<'_, riot_api::RiotApi>,
+) -> AppStatusPayload {
+    AppStatusPayload {
+        startup: status.0.get().cloned(),
+        server_banner: api.last_status().and_then(|s| s.banner()),
+    }
+}
+
+/// Опрос статуса серверов: уведомляет о новых проблемах и о восстановлении работы.
+async fn poll_server_status(app: &AppHandle, scraper: &Scraper) {
+    let api = app.state::<riot_api::RiotApi>();
+    if !api.settings().is_configured() {
+        return;
+    }
+    let previous = api.last_status();
+    match api.platform_status(scraper.http_client(), i18n::current().code()).await {
+        Ok(status) => {
+            let new_issues = riot_api::new_issues(previous.as_ref(), &status);
+            let recovered = status.health == riot_api::ServerHealth::Online
+                && previous.is_some_and(|p| p.health != riot_api::ServerHealth::Online);
+            if !new_issues.is_empty() || recovered {
+                emit_event(app, ServerStatusChangedEvent { status, new_issues });
+            }
+        }
+        Err(e) => logger::log(LogLevel::Debug, LogSource::Api, &format!("server status: {}", e)),
+    }
+}
+
+/// Открывает БД (с переносом legacy-файла из cwd) и создаёт HTTP-клиент.
+async fn init_backend(app_data: &std::path::Path) -> anyhow::Result<(Arc<Database>, Arc<Scraper>)> {
+    tokio::fs::create_dir_all(app_data).await?;
+    let db_path = app_data.join(data_wipe::DB_FILE_NAME);
+    if !tokio::fs::try_exists(&db_path).await.unwrap_or(false) {
+        if let Ok(cwd) = std::env::current_dir() {
+            let legacy = cwd.join("patches.db");
+            if legacy.is_file() {
+                if let Err(e) = tokio::fs::copy(&legacy, &db_path).await {
+                    eprintln!(
+                        "patch-analyzer: migrate patches.db from {:?} failed: {}",
+                        legacy, e
+                    );
+                }
+            }
+        }
+    }
+    let db = Arc::new(Database::open(&db_path).await?);
+    let scraper = Arc::new(Scraper::new()?);
+    Ok((db, scraper))
 }
 
 #[cfg(not(debug_assertions))]
@@ -131,13 +254,13 @@ fn is_release_newer(current: &str, latest: &str) -> bool {
 async fn try_auto_update_from_github(app: AppHandle) {
     let current_version = app.package_info().version.to_string();
     let release_url = "https://api.github.com/repos/RaspizDIYs/patch-analyzer/releases/latest";
-    let client = match reqwest::Client::builder()
+    let client = match http_client::tuned_builder()
         .user_agent(format!("PatchAnalyzer/{current_version}"))
         .build()
     {
         Ok(c) => c,
         Err(e) => {
-            log(&app, "WARN", &format!("auto-update client init failed: {e}"));
+            logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update client init failed: {e}"));
             return;
         }
     };
@@ -148,15 +271,15 @@ async fn try_auto_update_from_github(app: AppHandle) {
         .await
         .and_then(|r| r.error_for_status())
     {
-        Ok(resp) => match resp.json::<GithubRelease>().await {
+        Ok(resp) => match http_body::read_json::<GithubRelease>(resp).await {
             Ok(parsed) => parsed,
             Err(e) => {
-                log(&app, "WARN", &format!("auto-update release json parse failed: {e}"));
+                logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update release json parse failed: {e}"));
                 return;
             }
         },
         Err(e) => {
-            log(&app, "WARN", &format!("auto-update release fetch failed: {e}"));
+            logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update release fetch failed: {e}"));
             return;
         }
     };
@@ -176,61 +299,50 @@ async fn try_auto_update_from_github(app: AppHandle) {
         .or_else(|| release.assets.iter().find(|asset| asset.name.to_lowercase().ends_with(".exe")));
 
     let Some(asset) = selected_asset else {
-        log(&app, "WARN", "auto-update: no .exe installer asset found");
+        logger::log(LogLevel::Warn, LogSource::Update, "auto-update: no .exe installer asset found");
         return;
     };
 
-    let installer_bytes = match client
+    let resp = match client
         .get(&asset.browser_download_url)
         .send()
         .await
         .and_then(|r| r.error_for_status())
     {
-        Ok(resp) => match resp.bytes().await {
-            Ok(b) => b,
-            Err(e) => {
-                log(&app, "WARN", &format!("auto-update installer bytes failed: {e}"));
-                return;
-            }
-        },
+        Ok(resp) => resp,
         Err(e) => {
-            log(&app, "WARN", &format!("auto-update installer download failed: {e}"));
+            logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update installer download failed: {e}"));
             return;
         }
     };
 
     let cache_dir = app.path().app_cache_dir().unwrap_or_else(|_| std::env::temp_dir());
     if let Err(e) = std::fs::create_dir_all(&cache_dir) {
-        log(&app, "WARN", &format!("auto-update cache dir create failed: {e}"));
+        logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update cache dir create failed: {e}"));
         return;
     }
 
-    let installer_path = cache_dir.join(format!("patch-analyzer-{latest_version}-setup.exe"));
-    if let Err(e) = std::fs::write(&installer_path, installer_bytes.as_ref()) {
-        log(&app, "WARN", &format!("auto-update installer save failed: {e}"));
+    let installer_path = cache_dir.join(data_wipe::update_installer_file_name(&latest_version));
+    if let Err(e) =
+        http_body::download_to_file(resp, &installer_path, http_body::MAX_INSTALLER_BYTES).await
+    {
+        logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update installer save failed: {e:#}"));
         return;
     }
 
     let spawn_result = std::process::Command::new(&installer_path).spawn();
     match spawn_result {
         Ok(_) => {
-            log(
-                &app,
-                "INFO",
-                &format!(
-                    "auto-update: launching installer {} ({})",
-                    asset.name,
-                    latest_version
-                ),
+            logger::log_with(
+                LogLevel::Info,
+                LogSource::Update,
+                "auto-update: launching installer",
+                &[("asset", &asset.name), ("version", &latest_version)],
             );
             app.exit(0);
         }
         Err(e) => {
-            log(
-                &app,
-                "WARN",
-                &format!("auto-update installer launch failed: {e}"),
-            );
+            logger::log(LogLevel::Warn, LogSource::Update, &format!("auto-update installer launch failed: {e}"));
         }
     }
 }
@@ -243,18 +355,97 @@ fn patch_assets_cache_dir(app: &AppHandle) -> Option<PathBuf> {
     app.path().app_data_dir().ok().map(|p| p.join("patch_assets"))
 }
 
+fn patch_feed_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|p| p.join(patch_feed::FEED_FILE_NAME))
+}
+
+/// Переписывает RSS-ленту после сохранения патчей; ошибка только в лог.
+async fn refresh_patch_feed(app: &AppHandle, db: &Database) {
+    let Some(path) = patch_feed_path(app) else {
+        return;
+    };
+    if let Err(e) = patch_feed::write_feed(db, &path).await {
+        let error = format!("{:#}", e);
+        logger::log(
+            LogLevel::Warn,
+            LogSource::Feed,
+            &i18n::trf(i18n::current(), "log.feed_write_failed", &[("error", &error)]),
+        );
+    }
+}
+
+/// `new_patch` и, если в патче есть изменения отслеживаемых сущностей, `watchlist_alert`.
+async fn notify_new_patch(app: &AppHandle, db: &Database, version: &str, patch_notes_locale: &str) {
+    emit_event(
+        app,
+        NewPatchEvent {
+            version: version.to_string(),
+            patch_notes_locale: patch_notes_locale.to_string(),
+        },
+    );
+    let watch = match db.get_watchlist().await {
+        Ok(w) if !w.is_empty() => w,
+        _ => return,
+    };
+    let Ok(Some(patch)) = db.get_patch(version).await else {
+        return;
+    };
+    let mut hits = watchlist::matches(&patch, &watch);
+    if let Ok(Some(linked)) = db.get_linked_summoner().await {
+        summoner::lead_with_top(&mut hits, &linked.top_champion_ids());
+    }
+    if !hits.is_empty() {
+        emit_event(app, WatchlistAlertEvent { version: version.to_string(), hits });
+    }
+}
+
+fn overlay_file_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path().app_data_dir().ok().map(|p| p.join(overlay::OVERLAY_FILE_NAME))
+}
+
+fn scripts_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|p| p.join(script_hooks::SCRIPTS_DIR_NAME))
+        .map_err(|e| e.to_string())
+}
+
+/// Запасной список патчей из БД в скрапер.
+async fn load_fallback_patches(scraper: &Scraper, db: &Database) -> anyhow::Result<Vec<fallback_patches::FallbackPatch>> {
+    let patches = db.get_fallback_patches().await?;
+    scraper.set_fallback_patches(patches.iter().map(|p| p.version.clone()).collect());
+    Ok(patches)
+}
+
+/// Обновляет кэш запасного списка из DDragon; при недоступности DDragon остаётся прежний.
+async fn refresh_fallback_patches(scraper: &Scraper, db: &Database) -> anyhow::Result<Vec<fallback_patches::FallbackPatch>> {
+    match scraper.fetch_ddragon_patches().await {
+        Ok(versions) if !versions.is_empty() => {
+            db.replace_ddragon_fallback_patches(&versions).await?;
+            logger::log_with(
+                LogLevel::Debug,
+                LogSource::Update,
+                "fallback patches refreshed",
+                &[("count", &versions.len())],
+            );
+        }
+        Ok(_) => {}
+        Err(e) => logger::log(LogLevel::Warn, LogSource::Update, &format!("fallback patches: {:#}", e)),
+    }
+    load_fallback_patches(scraper, db).await
+}
+
 async fn refresh_augments_catalog_if_needed(
     scraper: &Scraper,
     db: &Database,
     force: bool,
-    app: &AppHandle,
 ) {
     let key_en = db::AUGMENTS_CATALOG_KEY_ARAM_MAYHEM_EN;
     let last = match db.get_augments_catalog(key_en).await {
         Ok(Some((_, t))) => Some(t),
         Ok(None) => None,
         Err(e) => {
-            log(app, "WARN", &format!("augments catalog read: {}", e));
+            logger::log(LogLevel::Warn, LogSource::Augments, &format!("augments catalog read: {}", e));
             None
         }
     };
@@ -264,11 +455,11 @@ async fn refresh_augments_catalog_if_needed(
     match scraper.fetch_aram_mayhem_augmentations_bundle_en().await {
         Ok((entries, detailed)) if !entries.is_empty() => {
             if let Err(e) = db.save_augments_catalog(key_en, &entries).await {
-                log(app, "WARN", &format!("augments catalog save: {}", e));
+                logger::log(LogLevel::Warn, LogSource::Augments, &format!("augments catalog save: {}", e));
             } else {
-                log(
-                    app,
-                    "INFO",
+                logger::log(
+                    LogLevel::Info,
+                    LogSource::Augments,
                     &format!("Augments catalog EN: {} entries", entries.len()),
                 );
             }
@@ -276,17 +467,17 @@ async fn refresh_augments_catalog_if_needed(
                 .save_mayhem_augmentations_page(db::MAYHEM_AUG_PAGE_KEY_EN, &detailed)
                 .await
             {
-                log(app, "WARN", &format!("mayhem aug page EN save: {}", e));
+                logger::log(LogLevel::Warn, LogSource::Augments, &format!("mayhem aug page EN save: {}", e));
             }
             if let Err(e) = db
                 .save_augments_catalog(db::AUGMENTS_CATALOG_KEY_ARAM_MAYHEM_RU, &entries)
                 .await
             {
-                log(app, "WARN", &format!("augments catalog RU save: {}", e));
+                logger::log(LogLevel::Warn, LogSource::Augments, &format!("augments catalog RU save: {}", e));
             } else {
-                log(
-                    app,
-                    "INFO",
+                logger::log(
+                    LogLevel::Info,
+                    LogSource::Augments,
                     &format!("Augments catalog RU (mirror EN): {} entries", entries.len()),
                 );
             }
@@ -294,16 +485,15 @@ async fn refresh_augments_catalog_if_needed(
                 .save_mayhem_augmentations_page(db::MAYHEM_AUG_PAGE_KEY_RU, &detailed)
                 .await
             {
-                log(app, "WARN", &format!("mayhem aug page RU save: {}", e));
+                logger::log(LogLevel::Warn, LogSource::Augments, &format!("mayhem aug page RU save: {}", e));
             }
         }
-        Ok(_) => log(app, "WARN", "augments wiki: empty table"),
-        Err(e) => log(app, "WARN", &format!("augments wiki: {}", e)),
+        Ok(_) => logger::log(LogLevel::Warn, LogSource::Augments, "augments wiki: empty table"),
+        Err(e) => logger::log(LogLevel::Warn, LogSource::Augments, &format!("augments wiki: {}", e)),
     }
 }
 
 const PATCH_NOT_CACHED: &str = "PATCH_NOT_CACHED";
-const PREVIOUS_PATCH_SAVED_EVENT: &str = "previous_patch_saved";
 
 async fn get_or_fetch_patch(
     version: &str,
@@ -353,63 +543,172 @@ async fn get_or_fetch_patch(
     if !allow_network {
         return Err(PATCH_NOT_CACHED.to_string());
     }
-    log(
-        app,
-        "INFO",
-        &format!("Fetching patch data for {} from web...", version),
+    logger::log_with(
+        LogLevel::Info,
+        LogSource::Scraper,
+        &i18n::trf(i18n::current(), "log.fetching_patch", &[("version", &version)]),
+        &[("locale", &patch_notes_locale)],
     );
+    crash_report::set_status("last_fetch", format!("{} ({})", version, patch_notes_locale));
     match scraper
         .fetch_current_meta(version, patch_notes_locale)
         .await
     {
         Ok(mut data) => {
+            let profile = scraper.profile();
+            let _ = profile
+                .time(
+                    version,
+                    patch_notes_locale,
+                    ScrapePhase::Classify,
+                    patch_icons::resolve_patch_note_identity(db, &mut data),
+                )
+                .await;
             if let Some(dir) = patch_assets_cache_dir(app) {
-                let _ = asset_cache::localize_patch_assets(scraper.http_client(), &dir, &mut data).await;
+                let _ = profile
+                    .time(
+                        version,
+                        patch_notes_locale,
+                        ScrapePhase::Assets,
+                        asset_cache::localize_patch_assets(scraper.http_client(), &dir, &mut data),
+                    )
+                    .await;
             }
-            let _ = db.save_patch(&data).await;
-            refresh_augments_catalog_if_needed(scraper, db, force_refresh, app).await;
+            let _ = profile
+                .time(version, patch_notes_locale, ScrapePhase::Save, db.save_patch(&data))
+                .await;
+            refresh_augments_catalog_if_needed(scraper, db, force_refresh).await;
+            refresh_patch_feed(app, db).await;
             let data = db
                 .patch_with_wiki_augment_enrichment(data)
                 .await
                 .map_err(|e| e.to_string())?;
-            log(
-                app,
-                "SUCCESS",
-                &format!("Data for {} fetched and saved.", version),
+            logger::log_with(
+                LogLevel::Success,
+                LogSource::Scraper,
+                &i18n::trf(i18n::current(), "log.patch_saved", &[("version", &version)]),
+                &[("locale", &patch_notes_locale), ("notes", &data.patch_notes.len())],
             );
             Ok(data)
         }
         Err(e) => {
-            log(
-                app,
-                "ERROR",
-                &format!("Failed to fetch patch {}: {}", version, e),
+            logger::log_with(
+                LogLevel::Error,
+                LogSource::Scraper,
+                &i18n::trf(
+                    i18n::current(),
+                    "log.patch_fetch_failed",
+                    &[("version", &version), ("error", &e)],
+                ),
+                &[("locale", &patch_notes_locale)],
             );
+            crash_report::capture_error("scraper", &format!("{} ({}): {:#}", version, patch_notes_locale, e));
             Err(e.to_string())
         }
     }
 }
 
+/// Патча на `locale` нет — перевод патч-нотов с другой локали, если машинный перевод включён.
+async fn machine_translated_patch(
+    version: &str,
+    locale: &str,
+    settings: &machine_translation::TranslationSettings,
+    app: &AppHandle,
+    db: &Database,
+    scraper: &Scraper,
+    allow_network: bool,
+) -> Option<PatchData> {
+    if !settings.is_configured() {
+        return None;
+    }
+    let source_locale = if locale == "en" { "ru" } else { "en" };
+    let source = get_or_fetch_patch(version, source_locale, app, db, scraper, false, allow_network)
+        .await
+        .ok()
+        .filter(|p| !p.patch_notes.is_empty())?;
+    match machine_translation::translate_patch(db, settings, source, locale, allow_network).await {
+        Ok(patch) => Some(patch),
+        Err(e) => {
+            logger::log(LogLevel::Warn, LogSource::Scraper, &format!("machine translation {}: {}", version, e));
+            None
+        }
+    }
+}
+
+/// Прогрев соседних патчей (N-1 и N+1) из кэша: разбор в LRU и, если сеть разрешена, скачивание их иконок,
+/// чтобы переход по таймлайну не ждал ни data_json, ни картинок.
+async fn prefetch_adjacent_patches(
+    app: AppHandle,
+    db: Arc<Database>,
+    scraper: Arc<Scraper>,
+    version: String,
+    locale: &'static str,
+    allow_network: bool,
+) {
+    let Ok(versions) = db.list_cached_patch_versions().await else {
+        return;
+    };
+    for v in patch_version::adjacent_versions(&versions, &version) {
+        let Ok(Some(mut patch)) = db.get_patch_for_locale(&v, locale).await else {
+            continue;
+        };
+        if !allow_network {
+            continue;
+        }
+        let Some(dir) = patch_assets_cache_dir(&app) else {
+            continue;
+        };
+        let Ok(stats) = asset_cache::localize_patch_assets(scraper.http_client(), &dir, &mut patch).await else {
+            continue;
+        };
+        if stats.cached_new + stats.deduplicated > 0 && db.save_patch(&patch).await.is_ok() {
+            // Сохранение сбросило запись в LRU — разбираем заново уже с локальными путями
+            let _ = db.get_patch_for_locale(&v, locale).await;
+        }
+    }
+}
+
 #[tauri::command]
+#[allow(clippy::too_many_arguments)] // аргументы команды приходят из invoke плоским списком
 async fn get_patch_by_version(
     version: String,
     patch_notes_locale: String,
     allow_network: Option<bool>,
+    on_chunk: Option<tauri::ipc::Channel<chunked_result::ResultChunk>>,
+    encoding: Option<packed_ipc::IpcEncoding>,
     app: AppHandle,
-    state: tauri::State<'_, AppState>,
-) -> Result<PatchData, String> {
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    translator: tauri::State<'_, machine_translation::Translator>,
+) -> Result<tauri::ipc::Response, String> {
     let loc = if patch_notes_locale == "en" { "en" } else { "ru" };
     let allow_network = allow_network.unwrap_or(false);
-    get_or_fetch_patch(
+    let patch = match get_or_fetch_patch(
         &version,
         loc,
         &app,
-        state.db.as_ref(),
-        state.scraper.as_ref(),
+        &db,
+        &scraper,
         false,
         allow_network,
     )
     .await
+    {
+        Ok(patch) if !patch.patch_notes.is_empty() => patch,
+        result => match machine_translated_patch(&version, loc, &translator.settings(), &app, &db, &scraper, allow_network).await {
+            Some(patch) => patch,
+            None => result?,
+        },
+    };
+    tauri::async_runtime::spawn(prefetch_adjacent_patches(
+        app.clone(),
+        db.inner().clone(),
+        scraper.inner().clone(),
+        version,
+        loc,
+        allow_network,
+    ));
+    chunked_result::deliver(&patch, on_chunk.as_ref(), encoding)
 }
 
 #[tauri::command]
@@ -418,42 +717,40 @@ async fn analyze_patch(
     force: bool,
     patch_notes_locale: String,
     app: AppHandle,
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
 ) -> Result<Vec<MetaAnalysisDiff>, String> {
     let loc = if patch_notes_locale == "en" { "en" } else { "ru" };
     let current = get_or_fetch_patch(
         &version,
         loc,
         &app,
-        state.db.as_ref(),
-        state.scraper.as_ref(),
+        &db,
+        &scraper,
         force,
         true,
     )
     .await?;
-    let patches = state
-        .db
+    let patches = db
         .get_patches_newest_versions_first(50)
         .await
         .map_err(|e| e.to_string())?;
-    let current_idx = patches
-        .iter()
-        .position(|p| versions_match(&p.version, &version));
-    let previous = current_idx.and_then(|i| patches.get(i + 1));
-
-    if let Some(prev) = previous {
-        Ok(Analyzer::compare_patches(&current, prev))
-    } else {
-        Ok(vec![])
+    let Some(prev) = Analyzer::previous_patch(&patches, &version) else {
+        return Ok(vec![]);
+    };
+    let mut diffs = Analyzer::compare_patches(&current, prev);
+    let meta_index = db.get_champion_meta_index().await.unwrap_or_default();
+    for d in &mut diffs {
+        d.champion_meta = meta_index.get(&d.champion_name.to_lowercase()).cloned();
     }
+    Ok(diffs)
 }
 
 #[tauri::command]
-async fn check_patches_exist(versions: Vec<String>, state: tauri::State<'_, AppState>) -> Result<HashMap<String, bool>, String> {
+async fn check_patches_exist(versions: Vec<String>, db: tauri::State<'_, Arc<Database>>) -> Result<HashMap<String, bool>, String> {
     let mut result = HashMap::new();
     for version in versions {
-        let exists = state
-            .db
+        let exists = db
             .patch_exists_resolving(&version)
             .await
             .map_err(|e| e.to_string())?;
@@ -463,18 +760,18 @@ async fn check_patches_exist(versions: Vec<String>, state: tauri::State<'_, AppS
 }
 
 #[tauri::command]
-async fn get_latest_ddragon_version(state: tauri::State<'_, AppState>) -> Result<Option<String>, String> {
-    state.scraper.fetch_latest_ddragon_version().await.map_err(|e| e.to_string())
+async fn get_latest_ddragon_version(scraper: tauri::State<'_, Arc<Scraper>>) -> Result<Option<String>, String> {
+    scraper.fetch_latest_ddragon_version().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 async fn check_patch_notes_exists(
     version: String,
     patch_notes_locale: String,
-    state: tauri::State<'_, AppState>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
 ) -> Result<bool, String> {
     let loc = if patch_notes_locale == "en" { "en" } else { "ru" };
-    Ok(state.scraper.check_patch_notes_exists(&version, loc).await)
+    Ok(scraper.check_patch_notes_exists(&version, loc).await)
 }
 
 #[tauri::command]
@@ -514,23 +811,173 @@ async fn get_fallback_rune_icon(style_key: String, rune_key: String) -> Result<O
     Ok(None)
 }
 
+/// `source` — id из `list_data_sources`; без него — сайт Riot.
+#[tauri::command]
+async fn get_available_patches(
+    source: Option<String>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    sources: tauri::State<'_, Arc<DataSourceRegistry>>,
+) -> Result<Vec<String>, String> {
+    match source.as_deref() {
+        None | Some(data_source::DEFAULT_SOURCE_ID) => {
+            scraper.fetch_available_patches().await.map_err(|e| e.to_string())
+        }
+        Some(id) => {
+            let source = sources.get(id).ok_or_else(|| format!("unknown data source: {}", id))?;
+            source.fetch_available_patches().await.map_err(|e| e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+async fn get_fallback_patches(
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<fallback_patches::FallbackPatch>, String> {
+    load_fallback_patches(&scraper, &db).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn refresh_fallback_patches_now(
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<fallback_patches::FallbackPatch>, String> {
+    refresh_fallback_patches(&scraper, &db).await.map_err(|e| e.to_string())
+}
+
+/// Ручной номер в нумерации сайта ("25.22") или DDragon ("15.22.1").
+#[tauri::command]
+async fn add_fallback_patch(
+    version: String,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<fallback_patches::FallbackPatch>, String> {
+    let version = fallback_patches::normalize(&version).ok_or_else(|| format!("invalid patch version: {}", version))?;
+    db.add_manual_fallback_patch(&version).await.map_err(|e| e.to_string())?;
+    load_fallback_patches(&scraper, &db).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn remove_fallback_patch(
+    version: String,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<fallback_patches::FallbackPatch>, String> {
+    db.remove_fallback_patch(&version).await.map_err(|e| e.to_string())?;
+    load_fallback_patches(&scraper, &db).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn list_data_sources(sources: tauri::State<'_, Arc<DataSourceRegistry>>) -> Vec<data_source::DataSourceInfo> {
+    sources.list()
+}
+
+/// Загружает патч из указанного источника и сохраняет его как обычный скачанный патч.
+#[tauri::command]
+async fn import_patch_from_source(
+    source: String,
+    version: String,
+    patch_notes_locale: String,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    sources: tauri::State<'_, Arc<DataSourceRegistry>>,
+) -> Result<PatchData, String> {
+    let loc = if patch_notes_locale == "en" { "en" } else { "ru" };
+    let source = sources.get(&source).ok_or_else(|| format!("unknown data source: {}", source))?;
+    let mut data = source.fetch_patch(&version, loc).await.map_err(|e| e.to_string())?;
+    if data.champions.is_empty() {
+        data.champions = source.fetch_stats(&version).await.unwrap_or_default();
+    }
+    store_imported_patch(&app, &db, &scraper, data, source.name()).await
+}
+
+/// Сохраняет импортированный патч как обычный скачанный: сущности, локальные картинки, сводка.
+async fn store_imported_patch(
+    app: &AppHandle,
+    db: &Database,
+    scraper: &Scraper,
+    mut data: PatchData,
+    origin: &str,
+) -> Result<PatchData, String> {
+    patch_icons::resolve_patch_note_identity(db, &mut data)
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(dir) = patch_assets_cache_dir(app) {
+        let _ = asset_cache::localize_patch_assets(scraper.http_client(), &dir, &mut data).await;
+    }
+    db.save_patch(&data).await.map_err(|e| e.to_string())?;
+    logger::log(
+        LogLevel::Success,
+        LogSource::Import,
+        &i18n::trf(
+            i18n::current(),
+            "log.patch_imported",
+            &[("version", &data.version), ("origin", &origin)],
+        ),
+    );
+    refresh_patch_feed(app, db).await;
+    db.patch_with_wiki_augment_enrichment(data)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Разбирает страницу патч-нотов по адресу (зеркало, региональный домен) и сохраняет патч.
+/// `version` — если не указана, берётся из страницы.
+#[tauri::command]
+async fn import_patch_from_url(
+    url: String,
+    version: Option<String>,
+    patch_notes_locale: String,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> Result<PatchData, String> {
+    let url = url.trim();
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("not an http(s) url: {}", url));
+    }
+    let html = scraper.fetch_page(url).await.map_err(|e| format!("{:#}", e))?;
+    let data = scraper
+        .parse_patch_page(&html, version.as_deref(), &patch_notes_locale)
+        .await
+        .map_err(|e| e.to_string())?;
+    store_imported_patch(&app, &db, &scraper, data, url).await
+}
+
+/// То же для сохранённой страницы (HTML-файл на диске).
 #[tauri::command]
-async fn get_available_patches(state: tauri::State<'_, AppState>) -> Result<Vec<String>, String> {
-    state.scraper.fetch_available_patches().await.map_err(|e| e.to_string())
+async fn import_patch_from_file(
+    path: String,
+    version: Option<String>,
+    patch_notes_locale: String,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> Result<PatchData, String> {
+    let len = tokio::fs::metadata(&path).await.map_err(|e| e.to_string())?.len();
+    if len > http_body::MAX_PAGE_BYTES as u64 {
+        return Err(format!("file is larger than {} bytes", http_body::MAX_PAGE_BYTES));
+    }
+    let bytes = tokio::fs::read(&path).await.map_err(|e| e.to_string())?;
+    let html = String::from_utf8_lossy(&bytes);
+    let data = scraper
+        .parse_patch_page(&html, version.as_deref(), &patch_notes_locale)
+        .await
+        .map_err(|e| e.to_string())?;
+    store_imported_patch(&app, &db, &scraper, data, &path).await
 }
 
 #[tauri::command]
-async fn get_latest_patch_data(state: tauri::State<'_, AppState>) -> Result<Option<PatchData>, String> {
-    let recent = state
-        .db
+async fn get_latest_patch_data(db: tauri::State<'_, Arc<Database>>) -> Result<Option<PatchData>, String> {
+    let recent = db
         .get_patches_newest_versions_first(1)
         .await
         .map_err(|e| e.to_string())?;
     let Some(latest) = recent.into_iter().next() else {
         return Ok(None);
     };
-    let enriched = state
-        .db
+    let enriched = db
         .patch_with_wiki_augment_enrichment(latest)
         .await
         .map_err(|e| e.to_string())?;
@@ -538,53 +985,163 @@ async fn get_latest_patch_data(state: tauri::State<'_, AppState>) -> Result<Opti
 }
 
 #[tauri::command]
-async fn get_cached_patch_versions(state: tauri::State<'_, AppState>) -> Result<Vec<String>, String> {
-    state
-        .db
+async fn get_cached_patch_versions(db: tauri::State<'_, Arc<Database>>) -> Result<Vec<String>, String> {
+    db
         .list_cached_patch_versions()
         .await
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn get_champion_history(
-    champion_name: String,
-    state: tauri::State<'_, AppState>,
+async fn get_entity_history(
+    entity: EntityRef,
+    split: Option<String>,
+    db: tauri::State<'_, Arc<Database>>,
 ) -> Result<Vec<ChampionHistoryEntry>, String> {
-    state
-        .db
-        .get_champion_history(&champion_name)
+    db
+        .get_entity_history(&entity, split.as_deref())
         .await
         .map_err(|e| e.to_string())
 }
 
+/// История объекта карты (`baron`, `dragons`, `turrets`…): только строки, относящиеся к нему.
 #[tauri::command]
-async fn get_item_history(
-    item_name: String,
-    state: tauri::State<'_, AppState>,
+async fn get_objective_history(
+    objective: String,
+    split: Option<String>,
+    db: tauri::State<'_, Arc<Database>>,
 ) -> Result<Vec<ChampionHistoryEntry>, String> {
-    state
-        .db
-        .get_item_history(&item_name)
+    objectives::history(&db, &objective, split.as_deref())
         .await
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn get_rune_history(
-    rune_name: String,
-    state: tauri::State<'_, AppState>,
+fn list_objectives() -> Vec<objectives::ObjectiveInfo> {
+    objectives::Objective::ALL.into_iter().map(objectives::Objective::info).collect()
+}
+
+/// История системы ранкеда или поведения (`duo_queue`, `lp_gains`, `honor`…).
+#[tauri::command]
+async fn get_player_system_history(
+    system: String,
+    split: Option<String>,
+    db: tauri::State<'_, Arc<Database>>,
 ) -> Result<Vec<ChampionHistoryEntry>, String> {
-    state
-        .db
-        .get_rune_history(&rune_name)
+    player_systems::history(&db, &system, split.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn list_player_systems() -> Vec<player_systems::PlayerSystemInfo> {
+    player_systems::PlayerSystem::ALL.into_iter().map(player_systems::PlayerSystem::info).collect()
+}
+
+/// Что изменилось у сущности между `version_a` и `version_b`: строки патч-нотов и статистика.
+#[tauri::command]
+async fn get_entity_diff(
+    entity: EntityRef,
+    version_a: String,
+    version_b: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<entity_diff::EntityDiff, String> {
+    entity_diff::entity_diff(&db, entity, &version_a, &version_b)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_watchlist(db: tauri::State<'_, Arc<Database>>) -> Result<Vec<watchlist::WatchEntry>, String> {
+    db.get_watchlist().await.map_err(|e| e.to_string())
+}
+
+/// `watch: false` — убрать из списка; `min_grade` — не сообщать о правках мельче.
+#[tauri::command]
+async fn set_watch(
+    entity: EntityRef,
+    watch: bool,
+    min_grade: Option<ChangeGrade>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<watchlist::WatchEntry>, String> {
+    db.set_watch(&entity, watch, min_grade).await.map_err(|e| e.to_string())?;
+    db.get_watchlist().await.map_err(|e| e.to_string())
+}
+
+/// Изменения отслеживаемых сущностей в патче: сначала любимые чемпионы привязанного аккаунта, затем самые заметные.
+#[tauri::command]
+async fn get_watchlist_digest(
+    version: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<watchlist::WatchHit>, String> {
+    let watch = db.get_watchlist().await.map_err(|e| e.to_string())?;
+    let patch = db
+        .get_patch(&version)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("patch {} is not cached", version))?;
+    let mut hits = watchlist::matches(&patch, &watch);
+    if let Some(linked) = db.get_linked_summoner().await.map_err(|e| e.to_string())? {
+        summoner::lead_with_top(&mut hits, &linked.top_champion_ids());
+    }
+    Ok(hits)
+}
+
+/// Привязывает аккаунт по Riot ID («Name#TAG») или обновляет любимых чемпионов привязанного.
+#[tauri::command]
+async fn link_summoner(
+    riot_id: String,
+    api: tauri::State<'_, riot_api::RiotApi>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<summoner::LinkedSummoner, String> {
+    summoner::link(&api, scraper.http_client(), &db, &riot_id)
         .await
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn get_all_champions(state: tauri::State<'_, AppState>) -> Result<Vec<ChampionListItem>, String> {
-    if let Ok(rows) = state.db.get_static_catalog_kind("champion").await {
+async fn get_linked_summoner(db: tauri::State<'_, Arc<Database>>) -> Result<Option<summoner::LinkedSummoner>, String> {
+    db.get_linked_summoner().await.map_err(|e| e.to_string())
+}
+
+/// Отвязывает аккаунт и удаляет его матчи; добавленные им чемпионы остаются в списке наблюдения.
+#[tauri::command]
+async fn unlink_summoner(db: tauri::State<'_, Arc<Database>>) -> Result<(), String> {
+    db.set_linked_summoner(None).await.map_err(|e| e.to_string())?;
+    db.clear_ranked_matches().await.map_err(|e| e.to_string())
+}
+
+/// Догружает последние `count` рейтинговых матчей привязанного аккаунта (по умолчанию 50).
+#[tauri::command]
+async fn import_ranked_matches(
+    count: Option<u32>,
+    api: tauri::State<'_, riot_api::RiotApi>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<match_impact::MatchImportResult, String> {
+    let summoner = match_impact::linked_summoner(&db).await.map_err(|e| e.to_string())?;
+    let count = count.map_or(match_impact::DEFAULT_IMPORT_COUNT, |c| c as usize);
+    let result = match_impact::import(&api, scraper.http_client(), &db, &summoner, count)
+        .await
+        .map_err(|e| e.to_string())?;
+    logger::log(
+        LogLevel::Info,
+        LogSource::Api,
+        &format!("Imported {} ranked matches ({} stored)", result.imported, result.total),
+    );
+    Ok(result)
+}
+
+#[tauri::command]
+async fn get_patch_impact_report(db: tauri::State<'_, Arc<Database>>) -> Result<match_impact::PatchImpactReport, String> {
+    let summoner = match_impact::linked_summoner(&db).await.map_err(|e| e.to_string())?;
+    match_impact::report(&db, &summoner).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_all_champions(db: tauri::State<'_, Arc<Database>>, scraper: tauri::State<'_, Arc<Scraper>>) -> Result<Vec<ChampionListItem>, String> {
+    if let Ok(rows) = db.get_static_catalog_kind("champion").await {
         if !rows.is_empty() {
             return Ok(rows
                 .into_iter()
@@ -612,7 +1169,7 @@ async fn get_all_champions(state: tauri::State<'_, AppState>) -> Result<Vec<Cham
                 .collect());
         }
     }
-    match state.scraper.fetch_all_champions_ddragon().await {
+    match scraper.fetch_all_champions_ddragon().await {
         Ok(list) => Ok(
             list
                 .into_iter()
@@ -633,40 +1190,41 @@ async fn get_all_champions(state: tauri::State<'_, AppState>) -> Result<Vec<Cham
 async fn refresh_game_assets(
     app: AppHandle,
     force: Option<bool>,
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
 ) -> Result<(), String> {
     let force = force.unwrap_or(true);
     let icon_cache = game_assets_cache_dir(&app);
     let cache = icon_cache.as_deref();
-    game_assets::refresh_game_assets(state.scraper.as_ref(), state.db.as_ref(), cache, force)
+    game_assets::refresh_game_assets(&scraper, &db, cache, force)
         .await
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn get_game_assets_meta(state: tauri::State<'_, AppState>) -> Result<Option<GameAssetsMeta>, String> {
-    state.db.get_game_assets_meta().await.map_err(|e| e.to_string())
+async fn get_game_assets_meta(db: tauri::State<'_, Arc<Database>>) -> Result<Option<GameAssetsMeta>, String> {
+    db.get_game_assets_meta().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 async fn get_static_catalog_rows(
     kind: String,
-    state: tauri::State<'_, AppState>,
-) -> Result<Vec<StaticCatalogRow>, String> {
-    state
-        .db
+    encoding: Option<packed_ipc::IpcEncoding>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<tauri::ipc::Response, String> {
+    let rows = db
         .get_static_catalog_kind(&kind)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    packed_ipc::respond(&rows, encoding)
 }
 
 #[tauri::command]
 async fn get_static_catalog_items_for_maps(
     map_ids: Vec<u32>,
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
 ) -> Result<Vec<StaticCatalogRow>, String> {
-    state
-        .db
+    db
         .filter_static_catalog_items_by_maps(&map_ids)
         .await
         .map_err(|e| e.to_string())
@@ -674,10 +1232,9 @@ async fn get_static_catalog_items_for_maps(
 
 #[tauri::command]
 async fn get_changed_itemsrunes_titles(
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
 ) -> Result<Vec<String>, String> {
-    let patches = state
-        .db
+    let patches = db
         .get_patches_newest_versions_first(20)
         .await
         .map_err(|e| e.to_string())?;
@@ -695,157 +1252,610 @@ async fn get_changed_itemsrunes_titles(
 }
 
 #[tauri::command]
-async fn get_tier_list(
-    window_size: Option<u32>,
-    state: tauri::State<'_, AppState>,
-) -> Result<Vec<TierEntry>, String> {
-    let limit = window_size.unwrap_or(20).clamp(1, 50) as i64;
-    let patches = state
-        .db
-        .get_patches_newest_versions_first(limit)
+async fn set_note_tags(
+    version: String,
+    note_id: String,
+    tags: Option<Vec<String>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<(), String> {
+    db
+        .set_note_tags(&version, &note_id, tags.as_deref())
         .await
-        .map_err(|e| e.to_string())?;
-
-    let mut signature = String::new();
-    signature.push_str(&format!("limit={limit};"));
-    for p in &patches {
-        signature.push_str(&p.version);
-        signature.push('|');
-        signature.push_str(&p.fetched_at.to_rfc3339());
-        signature.push(';');
-    }
+        .map_err(|e| e.to_string())
+}
 
-    {
-        let cache = state.tier_cache.lock().await;
-        if let Some((cached_sig, cached_list)) = cache.as_ref() {
-            if *cached_sig == signature {
-                return Ok(cached_list.clone());
-            }
-        }
-    }
+#[tauri::command]
+async fn get_notes_by_tag(
+    tag: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<ChampionHistoryEntry>, String> {
+    db.get_notes_by_tag(&tag).await.map_err(|e| e.to_string())
+}
 
-    let mut map: HashMap<(String, PatchCategory), TierEntry> = HashMap::new();
+/// Подтягивает новые статьи разработчиков и сохраняет их с упоминаниями чемпионов.
+#[tauri::command]
+async fn sync_dev_articles(
+    locale: Option<String>,
+    limit: Option<u32>,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> Result<usize, String> {
+    let locale = if locale.as_deref() == Some("en") { "en" } else { "ru" };
+    dev_articles::sync_dev_articles(
+        &scraper,
+        &db,
+        locale,
+        limit.unwrap_or(10) as usize,
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
 
-    for patch in patches {
-        for note in patch.patch_notes {
-            if note.category == PatchCategory::UpcomingSkinsChromas
-                || note.category == PatchCategory::ModeAramAugments
-            {
-                continue;
-            }
-            let key = (note.title.clone(), note.category.clone());
-            let entry = map.entry(key).or_insert(TierEntry {
-                name: note.title.clone(),
-                category: note.category.clone(),
-                buffs: 0,
-                nerfs: 0,
-                adjusted: 0,
-                icon_url: None,
-            });
+#[tauri::command]
+async fn get_dev_articles(
+    limit: Option<i64>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<DevArticle>, String> {
+    db
+        .get_dev_articles(limit.unwrap_or(-1))
+        .await
+        .map_err(|e| e.to_string())
+}
 
-            // Сохраняем иконку из патч-нотов (берем последнюю найденную)
-            if let Some(ref icon) = note.image_url {
-                entry.icon_url = Some(icon.clone());
-            }
+#[tauri::command]
+async fn search_dev_articles(
+    query: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<DevArticle>, String> {
+    db.search_dev_articles(&query).await.map_err(|e| e.to_string())
+}
 
-            for block in &note.details {
-                for change in &block.changes {
-                    match analyze_change_trend(change) {
-                        1 => entry.buffs += 1,
-                        -1 => entry.nerfs += 1,
-                        _ => entry.adjusted += 1,
-                    }
-                }
+/// Статьи разработчиков об этой сущности — контекст для таймлайна истории.
+#[tauri::command]
+async fn get_entity_dev_articles(
+    entity: EntityRef,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<DevArticle>, String> {
+    // Фронтенд может прислать имя чемпиона вместо id DDragon
+    let entity = match entity {
+        EntityRef::Champion { id } => {
+            let index = db.get_champion_meta_index().await.unwrap_or_default();
+            let id = index.get(&id.to_lowercase()).map(|m| m.id.clone()).unwrap_or(id);
+            EntityRef::Champion { id }
+        }
+        other => other,
+    };
+    db
+        .get_dev_articles_for_entity(&entity)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_season_splits(db: tauri::State<'_, Arc<Database>>) -> Result<Vec<String>, String> {
+    db.list_season_splits().await.map_err(|e| e.to_string())
+}
+
+/// Таймлайн чемпиона (JSON или CSV) в папку «Загрузки»; возвращает путь к файлу.
+#[tauri::command]
+async fn export_champion_timeline(
+    champion: String,
+    format: champion_timeline::TimelineFormat,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<String, String> {
+    let points = champion_timeline::champion_timeline(&db, &champion)
+        .await
+        .map_err(|e| e.to_string())?;
+    let content = champion_timeline::render(&points, format).map_err(|e| e.to_string())?;
+    let path = download_export_path(&app, "timeline", &champion, format.extension())?;
+    tokio::fs::write(&path, content).await.map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// `<Загрузки>/<prefix>-<name>-<YYYYMMDD>.<ext>`; в имени только буквы и цифры.
+fn download_export_path(app: &AppHandle, prefix: &str, name: &str, ext: &str) -> Result<PathBuf, String> {
+    let dir = app.path().download_dir().map_err(|e| e.to_string())?;
+    let safe: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    Ok(dir.join(format!(
+        "{}-{}-{}.{}",
+        prefix,
+        safe,
+        chrono::Utc::now().format("%Y%m%d"),
+        ext
+    )))
+}
+
+/// Полный отчёт по чемпиону: история, умения, таймлайн статистики, сборки, статьи разработчиков.
+#[tauri::command]
+async fn generate_champion_report(
+    champion: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<champion_report::ChampionReport, String> {
+    champion_report::generate(&db, &champion)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Сборки про на чемпионе по патчам (часть отчёта по чемпиону).
+#[tauri::command]
+async fn get_pro_builds(champion: String, db: tauri::State<'_, Arc<Database>>) -> Result<pro_builds::ProBuildsOverview, String> {
+    pro_builds::overview(&db, &champion).await.map_err(|e| e.to_string())
+}
+
+/// Фоновый сбор сборок про с probuilds.net раз в 6 часов; по умолчанию выключен.
+#[tauri::command]
+fn set_pro_builds_enabled(enabled: bool) {
+    pro_builds::set_ingest_enabled(enabled);
+}
+
+/// Тот же отчёт JSON-файлом в «Загрузки»; возвращает путь.
+#[tauri::command]
+async fn export_champion_report(
+    champion: String,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<String, String> {
+    let report = champion_report::generate(&db, &champion)
+        .await
+        .map_err(|e| e.to_string())?;
+    let content = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    let path = download_export_path(&app, "report", &report.champion, "json")?;
+    tokio::fs::write(&path, content).await.map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Переписывает RSS-ленту патчей сейчас и возвращает путь к файлу для читалки.
+#[tauri::command]
+async fn write_patch_feed(app: AppHandle, db: tauri::State<'_, Arc<Database>>) -> Result<String, String> {
+    let path = patch_feed_path(&app).ok_or_else(|| i18n::tr(i18n::current(), "error.app_data_unavailable"))?;
+    patch_feed::write_feed(&db, &path).await.map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Календарь патчей (.ics) в папку «Загрузки»: вышедшие и `upcoming` ожидаемых; возвращает путь.
+#[tauri::command]
+async fn export_patch_calendar(
+    upcoming: Option<u32>,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<String, String> {
+    let upcoming = upcoming.map_or(patch_calendar::DEFAULT_UPCOMING, |n| n as usize);
+    let events = patch_calendar::patch_schedule(&db, upcoming)
+        .await
+        .map_err(|e| e.to_string())?;
+    let dir = app.path().download_dir().map_err(|e| e.to_string())?;
+    let path = dir.join("lol-patches.ics");
+    tokio::fs::write(&path, patch_calendar::render_ics(&events, i18n::current()))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Предстоящие образы из `patches` последних сохранённых патчей (по умолчанию 4).
+#[tauri::command]
+async fn get_upcoming_skins(
+    patches: Option<u32>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<upcoming_skins::UpcomingSkin>, String> {
+    let patches = patches.map_or(upcoming_skins::DEFAULT_PATCHES, |n| n as usize);
+    upcoming_skins::upcoming_skins(&db, patches)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Ротации магазина и пропуски событий из `patches` последних сохранённых патчей (по умолчанию 4).
+#[tauri::command]
+async fn get_store_rotations(
+    patches: Option<u32>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<store_rotations::StoreRotation>, String> {
+    let patches = patches.map_or(store_rotations::DEFAULT_PATCHES, |n| n as usize);
+    store_rotations::store_rotations(&db, patches)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Напоминает о ротациях магазина, которые скоро закончатся; о каждой — один раз.
+async fn remind_store_rotations(app: &AppHandle, db: &Database) {
+    let rotations = match store_rotations::store_rotations(db, store_rotations::DEFAULT_PATCHES).await {
+        Ok(r) => r,
+        Err(e) => {
+            logger::log(LogLevel::Warn, LogSource::App, &format!("store rotations: {}", e));
+            return;
+        }
+    };
+    for rotation in store_rotations::ending_soon(&rotations, chrono::Utc::now()) {
+        match db.mark_store_reminder(&rotation.key).await {
+            Ok(true) => emit_event(app, StoreRotationEndingEvent { rotation: rotation.clone() }),
+            Ok(false) => {}
+            Err(e) => logger::log(LogLevel::Warn, LogSource::App, &format!("store reminder: {}", e)),
+        }
+    }
+}
+
+/// Раздел «влияние на про-сцену» для патча из кэша.
+#[tauri::command]
+async fn get_competitive_impact(
+    version: String,
+    patch_notes_locale: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<competitive_impact::CompetitiveImpact, String> {
+    let locale = if patch_notes_locale == "en" { "en" } else { "ru" };
+    let patch = db
+        .get_patch_resolving_with_locale(&version, locale)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| PATCH_NOT_CACHED.to_string())?;
+    Ok(competitive_impact::competitive_impact(&patch))
+}
+
+/// Реакция Reddit на патч; кэш живёт `CACHE_HOURS`, `refresh` скачивает заново. Если Reddit
+/// недоступен, отдаётся прежний кэш.
+#[tauri::command]
+async fn get_patch_reaction(
+    version: String,
+    patch_notes_locale: String,
+    refresh: Option<bool>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<patch_reaction::PatchReaction, String> {
+    let locale = if patch_notes_locale == "en" { "en" } else { "ru" };
+    let patch = db
+        .get_patch_resolving_with_locale(&version, locale)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| PATCH_NOT_CACHED.to_string())?;
+    let locale = patch.patch_notes_locale.clone().unwrap_or_else(|| locale.to_string());
+    let cached = db.get_patch_reaction(&patch.version, &locale).await.map_err(|e| e.to_string())?;
+    if let Some(cached) = &cached {
+        let fresh = chrono::Utc::now() - cached.fetched_at < chrono::Duration::hours(patch_reaction::CACHE_HOURS);
+        if fresh && !refresh.unwrap_or(false) {
+            return Ok(cached.clone());
+        }
+    }
+    match patch_reaction::fetch(&patch).await {
+        Ok(reaction) => {
+            db.save_patch_reaction(&reaction).await.map_err(|e| e.to_string())?;
+            Ok(reaction)
+        }
+        Err(e) => cached.ok_or_else(|| e.to_string()),
+    }
+}
+
+#[tauri::command]
+async fn get_patch_summary(
+    version: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Option<PatchSummary>, String> {
+    db.get_patch_summary(&version).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_tier_list(
+    window_size: Option<u32>,
+    include_modes: Option<bool>,
+    split: Option<String>,
+    encoding: Option<packed_ipc::IpcEncoding>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<tauri::ipc::Response, String> {
+    let list = tier_list::load(db.as_ref(), window_size, include_modes, split.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+    packed_ipc::respond(&list, encoding)
+}
+
+/// Скачивает тир-лист op.gg сейчас, не дожидаясь фонового обновления.
+#[tauri::command]
+async fn refresh_community_tiers(
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<community_tiers::CommunityTierList, String> {
+    community_tiers::refresh(&scraper, &db).await.map_err(|e| e.to_string())
+}
+
+/// Фоновое обновление тир-листа op.gg раз в 12 часов; по умолчанию выключено.
+#[tauri::command]
+fn set_community_tiers_enabled(enabled: bool) {
+    community_tiers::set_refresh_enabled(enabled);
+}
+
+/// Сколько скачанных патчей сохранять одной транзакцией при полной синхронизации.
+const SAVE_BATCH_SIZE: usize = 8;
+
+/// Скачивает патч, проставляет сущности и иконки и кэширует картинки. Ошибка уже залогирована.
+async fn download_patch(
+    app: &AppHandle,
+    scraper: &Scraper,
+    db: &Database,
+    version: &str,
+    loc: &str,
+    label: &str,
+) -> Option<PatchData> {
+    logger::log(LogLevel::Info, LogSource::Sync, &format!("Downloading {} patch: {} ...", label, version));
+    let mut data = match scraper.fetch_current_meta(version, loc).await {
+        Ok(data) => data,
+        Err(e) => {
+            logger::log(LogLevel::Error, LogSource::Sync, &format!("Failed to download {}: {}", version, e));
+            crash_report::capture_error("sync", &format!("download {} ({}): {:#}", version, loc, e));
+            return None;
+        }
+    };
+    let profile = scraper.profile();
+    let _ = profile
+        .time(
+            version,
+            loc,
+            ScrapePhase::Classify,
+            patch_icons::resolve_patch_note_identity(db, &mut data),
+        )
+        .await;
+    if let Some(dir) = patch_assets_cache_dir(app) {
+        let _ = profile
+            .time(
+                version,
+                loc,
+                ScrapePhase::Assets,
+                asset_cache::localize_patch_assets(scraper.http_client(), &dir, &mut data),
+            )
+            .await;
+    }
+    Some(data)
+}
+
+/// Сохраняет пачку скачанных патчей одной транзакцией. Результат уже залогирован.
+async fn save_downloaded_patches(
+    app: &AppHandle,
+    db: &Database,
+    scraper: &Scraper,
+    patches: &[PatchData],
+    label: &str,
+) -> bool {
+    let started = std::time::Instant::now();
+    let result = db.save_patches(patches).await;
+    // Пачка пишется одной транзакцией: время делится поровну между её патчами
+    let per_patch = started.elapsed() / patches.len().max(1) as u32;
+    for p in patches {
+        let loc = p.patch_notes_locale.as_deref().unwrap_or("ru");
+        scraper.profile().record(&p.version, loc, ScrapePhase::Save, per_patch);
+    }
+    match result {
+        Ok(()) => {
+            for p in patches {
+                logger::log(LogLevel::Success, LogSource::Sync, &format!("Saved {} patch {}", label, p.version));
             }
+            refresh_patch_feed(app, db).await;
+            true
+        }
+        Err(e) => {
+            let versions: Vec<&str> = patches.iter().map(|p| p.version.as_str()).collect();
+            logger::log(LogLevel::Error, LogSource::Sync, &format!("Failed to save {}: {}", versions.join(", "), e));
+            crash_report::capture_error("sync", &format!("save {}: {:#}", versions.join(", "), e));
+            false
         }
     }
+}
+
+async fn download_and_save_patch(
+    app: &AppHandle,
+    scraper: &Scraper,
+    db: &Database,
+    version: &str,
+    loc: &str,
+    label: &str,
+) -> bool {
+    match download_patch(app, scraper, db, version, loc, label).await {
+        Some(data) => save_downloaded_patches(app, db, scraper, std::slice::from_ref(&data), label).await,
+        None => false,
+    }
+}
 
-    let mut list: Vec<TierEntry> = map.into_values().collect();
-    list.sort_by(|a, b| {
-        let score_a = a.buffs as i32 - a.nerfs as i32;
-        let score_b = b.buffs as i32 - b.nerfs as i32;
-        score_b
-            .cmp(&score_a)
-            .then_with(|| b.buffs.cmp(&a.buffs))
-            .then_with(|| a.nerfs.cmp(&b.nerfs))
-    });
+/// Скачивает заново свежие патчи и сохраняет те, статьи которых изменились после публикации.
+async fn check_patch_note_edits(app: &AppHandle, scraper: &Scraper, db: &Database) -> Vec<patch_revisions::PatchRevision> {
+    let patches = match db.get_patches_newest_versions_first(patch_revisions::RECHECK_PATCHES).await {
+        Ok(p) => p,
+        Err(e) => {
+            logger::log(LogLevel::Warn, LogSource::Sync, &format!("patch notes recheck: {}", e));
+            return Vec::new();
+        }
+    };
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(patch_revisions::RECHECK_WINDOW_DAYS);
+    let mut revisions = Vec::new();
+    for old in patches.into_iter().filter(|p| p.release_date() >= cutoff) {
+        let loc = old.patch_notes_locale.clone().unwrap_or_else(|| "ru".to_string());
+        let Some(new) = download_patch(app, scraper, db, &old.version, &loc, "recheck").await else {
+            continue;
+        };
+        // Пустой разбор — скорее сбой страницы, чем удалённая статья
+        if new.patch_notes.is_empty() {
+            continue;
+        }
+        let changes = patch_revisions::diff_patches(&old, &new);
+        if changes.is_empty() {
+            continue;
+        }
+        let revision = match db.save_patch_revision(&old, &changes).await {
+            Ok(r) => r,
+            Err(e) => {
+                logger::log(LogLevel::Warn, LogSource::Sync, &format!("patch revision {}: {}", old.version, e));
+                continue;
+            }
+        };
+        if !save_downloaded_patches(app, db, scraper, std::slice::from_ref(&new), "revised").await {
+            continue;
+        }
+        logger::log_with(
+            LogLevel::Info,
+            LogSource::Sync,
+            "patch notes edited after publication",
+            &[("version", &old.version), ("revision", &revision.revision), ("notes", &changes.len())],
+        );
+        emit_event(app, PatchNotesUpdatedEvent { revision: revision.clone() });
+        revisions.push(revision);
+    }
+    if !revisions.is_empty() {
+        let _ = db.analyze().await;
+        emit_event(
+            app,
+            StatsUpdatedEvent {
+                versions: revisions.iter().map(|r| r.version.clone()).collect(),
+            },
+        );
+    }
+    revisions
+}
 
-    let mut cache = state.tier_cache.lock().await;
-    *cache = Some((signature, list.clone()));
+/// Внеочередная проверка правок свежих патч-нотов; возвращает найденные правки.
+#[tauri::command]
+async fn check_patch_note_edits_now(
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> Result<Vec<patch_revisions::PatchRevision>, String> {
+    Ok(check_patch_note_edits(&app, &scraper, &db).await)
+}
 
-    Ok(list)
+#[tauri::command]
+async fn get_patch_revisions(
+    version: String,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Vec<patch_revisions::PatchRevision>, String> {
+    db.get_patch_revisions(&version).await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 async fn sync_patch_history(
     patch_notes_locale: String,
     app: AppHandle,
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
 ) -> Result<(), String> {
     let loc = if patch_notes_locale == "en" { "en" } else { "ru" };
-    log(&app, "INFO", "Starting full history sync...");
+    logger::log(LogLevel::Info, LogSource::Sync, "Starting full history sync...");
     
-    let patches_list = state
-        .scraper
+    let patches_list = scraper
         .fetch_available_patches()
         .await
         .map_err(|e| e.to_string())?;
 
-    log(&app, "INFO", &format!("Found {} patches to check.", patches_list.len()));
+    logger::log(LogLevel::Info, LogSource::Sync, &format!("Found {} patches to check.", patches_list.len()));
 
+    let total = patches_list.len();
+    let mut processed = 0usize;
+    let mut skipped = 0usize;
+    let mut saved_versions: Vec<String> = Vec::new();
+    // Частоту запросов к Riot дополнительно ограничивает Scraper
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(scraper.settings().concurrency as usize));
+    let mut downloads = tokio::task::JoinSet::new();
     for version in patches_list {
-        let need_fetch = match state
-            .db
-            .get_patch_resolving_with_locale(&version, loc)
+        let cached = db
+            .get_patch_meta_resolving_with_locale(&version, loc)
             .await
             .ok()
-            .flatten()
-        {
-            Some(p) => p.patch_notes.is_empty(),
-            None => true,
+            .flatten();
+        // Без сводки (старые строки) число записей неизвестно — проверяем по самому патчу
+        let has_notes = match cached.as_ref().map(|m| m.total_notes) {
+            Some(Some(n)) => n > 0,
+            Some(None) => db
+                .get_patch_resolving_with_locale(&version, loc)
+                .await
+                .ok()
+                .flatten()
+                .is_some_and(|p| !p.patch_notes.is_empty()),
+            None => false,
         };
-
-        if need_fetch {
-            log(
+        if has_notes {
+            skipped += 1;
+            processed += 1;
+            emit_event(
                 &app,
-                "INFO",
-                &format!("Downloading missing patch: {} ...", version),
+                SyncProgressEvent {
+                    scope: SyncScope::History,
+                    version,
+                    processed,
+                    total,
+                    downloaded: saved_versions.len(),
+                    skipped,
+                    saved: false,
+                },
             );
-            let fetch_result = state.scraper.fetch_current_meta(&version, loc).await;
+            continue;
+        }
+        let is_new = cached.is_none();
+        let (app, scraper, db, semaphore) =
+            (app.clone(), scraper.inner().clone(), db.inner().clone(), semaphore.clone());
+        downloads.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let data = download_patch(&app, &scraper, &db, &version, loc, "missing").await;
+            (version, is_new, data)
+        });
+    }
 
-            match fetch_result {
-                Ok(mut data) => {
-                    if let Some(dir) = patch_assets_cache_dir(&app) {
-                        let _ = asset_cache::localize_patch_assets(
-                            state.scraper.http_client(),
-                            &dir,
-                            &mut data,
-                        )
-                        .await;
-                    }
-                    if let Err(e) = state.db.save_patch(&data).await {
-                        log(&app, "ERROR", &format!("Failed to save {}: {}", version, e));
-                    } else {
-                        log(&app, "SUCCESS", &format!("Saved patch {}", version));
-                    }
-                }
-                Err(e) => {
-                    log(&app, "ERROR", &format!("Failed to download {}: {}", version, e));
+    // Прогресс — в порядке завершения загрузок; скачанные патчи сохраняются пачками
+    let mut pending: Vec<(String, bool)> = Vec::new();
+    let mut pending_patches: Vec<PatchData> = Vec::new();
+    loop {
+        let joined = downloads.join_next().await;
+        let finished = joined.is_none();
+        let mut failed: Option<String> = None;
+        match joined {
+            Some(Ok((version, is_new, Some(data)))) => {
+                pending.push((version, is_new));
+                pending_patches.push(data);
+            }
+            Some(Ok((version, _, None))) => failed = Some(version),
+            Some(Err(e)) => {
+                processed += 1;
+                logger::log(LogLevel::Error, LogSource::Sync, &format!("Patch download task failed: {}", e));
+            }
+            None => {}
+        }
+        let mut batch = Vec::new();
+        let mut saved = false;
+        if pending.len() >= SAVE_BATCH_SIZE || (finished && !pending.is_empty()) {
+            saved = save_downloaded_patches(&app, &db, &scraper, &pending_patches, "missing").await;
+            pending_patches.clear();
+            batch = std::mem::take(&mut pending);
+        }
+        let results = failed
+            .map(|version| (version, false, false))
+            .into_iter()
+            .chain(batch.into_iter().map(|(version, is_new)| (version, is_new, saved)));
+        for (version, is_new, saved) in results {
+            processed += 1;
+            if saved {
+                if is_new {
+                    notify_new_patch(&app, &db, &version, loc).await;
                 }
+                saved_versions.push(version.clone());
             }
-            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+            emit_event(
+                &app,
+                SyncProgressEvent {
+                    scope: SyncScope::History,
+                    version,
+                    processed,
+                    total,
+                    downloaded: saved_versions.len(),
+                    skipped,
+                    saved,
+                },
+            );
         }
+        if finished {
+            break;
+        }
+    }
+    if !saved_versions.is_empty() {
+        let _ = db.analyze().await;
+        emit_event(&app, StatsUpdatedEvent { versions: saved_versions });
     }
 
-    refresh_augments_catalog_if_needed(
-        state.scraper.as_ref(),
-        state.db.as_ref(),
-        false,
-        &app,
-    )
-    .await;
+    refresh_augments_catalog_if_needed(&scraper, &db, false).await;
 
-    log(&app, "SUCCESS", "History sync completed.");
+    logger::log(LogLevel::Success, LogSource::Sync, "History sync completed.");
     Ok(())
 }
 
@@ -854,50 +1864,45 @@ async fn sync_previous_patch_history_to_limit(
     target_total: Option<u32>,
     patch_notes_locale: String,
     app: AppHandle,
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
 ) -> Result<(), String> {
     let loc = if patch_notes_locale == "en" { "en" } else { "ru" };
     let target_total = target_total.unwrap_or(50).clamp(50, 100) as usize;
     let baseline_recent = 20usize;
 
-    let patches_list = state
-        .scraper
+    let patches_list = scraper
         .fetch_available_patches_with_limit(100)
         .await
         .map_err(|e| e.to_string())?;
 
     if patches_list.len() <= baseline_recent {
-        log(
-            &app,
-            "INFO",
-            "No previous patches available for retrospective sync.",
-        );
+        logger::log(LogLevel::Info, LogSource::Sync, "No previous patches available for retrospective sync.");
         return Ok(());
     }
 
     let end = target_total.min(patches_list.len());
     if end <= baseline_recent {
-        log(&app, "INFO", "Selected target does not require previous patch sync.");
+        logger::log(LogLevel::Info, LogSource::Sync, "Selected target does not require previous patch sync.");
         return Ok(());
     }
     let previous_slice = &patches_list[baseline_recent..end];
 
-    log(
-        &app,
-        "INFO",
-        &format!(
-            "Starting previous patches sync: target_total={}, syncing {} versions.",
-            target_total,
-            previous_slice.len()
-        ),
+    logger::log_with(
+        LogLevel::Info,
+        LogSource::Sync,
+        "Starting previous patches sync.",
+        &[("target_total", &target_total), ("versions", &previous_slice.len())],
     );
 
     let total = previous_slice.len();
+    let mut saved_versions: Vec<String> = Vec::new();
     let mut downloaded = 0usize;
     let mut skipped = 0usize;
-    let _ = app.emit(
-        PREVIOUS_PATCH_SAVED_EVENT,
-        PreviousPatchSavedPayload {
+    emit_event(
+        &app,
+        SyncProgressEvent {
+            scope: SyncScope::Previous,
             version: String::new(),
             processed: 0,
             total,
@@ -908,21 +1913,22 @@ async fn sync_previous_patch_history_to_limit(
     );
 
     for (idx, version) in previous_slice.iter().enumerate() {
-        let already_cached = state
-            .db
+        let already_cached = db
             .patch_exists_resolving(version)
             .await
             .unwrap_or(false);
         if already_cached {
             skipped += 1;
-            log(
-                &app,
-                "INFO",
-                &format!("Skipping already cached previous patch: {}", version),
+            logger::log_with(
+                LogLevel::Info,
+                LogSource::Sync,
+                "Skipping already cached previous patch.",
+                &[("version", version)],
             );
-            let _ = app.emit(
-                PREVIOUS_PATCH_SAVED_EVENT,
-                PreviousPatchSavedPayload {
+            emit_event(
+                &app,
+                SyncProgressEvent {
+                    scope: SyncScope::Previous,
                     version: version.to_string(),
                     processed: idx + 1,
                     total,
@@ -934,41 +1940,26 @@ async fn sync_previous_patch_history_to_limit(
             continue;
         }
 
-        let mut saved = false;
-        log(
+        let saved = download_and_save_patch(
             &app,
-            "INFO",
-            &format!("Downloading previous patch: {} ...", version),
-        );
-        let fetch_result = state.scraper.fetch_current_meta(version, loc).await;
-
-        match fetch_result {
-            Ok(mut data) => {
-                if let Some(dir) = patch_assets_cache_dir(&app) {
-                    let _ = asset_cache::localize_patch_assets(
-                        state.scraper.http_client(),
-                        &dir,
-                        &mut data,
-                    )
-                    .await;
-                }
-                if let Err(e) = state.db.save_patch(&data).await {
-                    log(&app, "ERROR", &format!("Failed to save {}: {}", version, e));
-                } else {
-                    log(&app, "SUCCESS", &format!("Saved previous patch {}", version));
-                    saved = true;
-                    downloaded += 1;
-                }
-            }
-            Err(e) => {
-                log(&app, "ERROR", &format!("Failed to download {}: {}", version, e));
-            }
+            &scraper,
+            &db,
+            version,
+            loc,
+            "previous",
+        )
+        .await;
+        if saved {
+            downloaded += 1;
+            notify_new_patch(&app, &db, version, loc).await;
+            saved_versions.push(version.to_string());
         }
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        tokio::time::sleep(scraper.settings().request_gap()).await;
 
-        let _ = app.emit(
-            PREVIOUS_PATCH_SAVED_EVENT,
-            PreviousPatchSavedPayload {
+        emit_event(
+            &app,
+            SyncProgressEvent {
+                scope: SyncScope::Previous,
                 version: version.to_string(),
                 processed: idx + 1,
                 total,
@@ -978,27 +1969,65 @@ async fn sync_previous_patch_history_to_limit(
             },
         );
     }
+    if !saved_versions.is_empty() {
+        let _ = db.analyze().await;
+        emit_event(&app, StatsUpdatedEvent { versions: saved_versions });
+    }
 
-    refresh_augments_catalog_if_needed(
-        state.scraper.as_ref(),
-        state.db.as_ref(),
-        false,
-        &app,
-    )
-    .await;
+    refresh_augments_catalog_if_needed(&scraper, &db, false).await;
 
-    log(&app, "SUCCESS", "Previous patches sync completed.");
+    logger::log(LogLevel::Success, LogSource::Sync, "Previous patches sync completed.");
     Ok(())
 }
 
 #[tauri::command]
-async fn clear_database(state: tauri::State<'_, AppState>) -> Result<(), String> {
-    state.db.clear_database().await.map_err(|e| e.to_string())?;
-    let mut cache = state.tier_cache.lock().await;
-    *cache = None;
+async fn explain_database_queries(db: tauri::State<'_, Arc<Database>>) -> Result<Vec<db::QueryPlan>, String> {
+    db.explain_queries().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn clear_database(db: tauri::State<'_, Arc<Database>>) -> Result<(), String> {
+    db.clear_database().await.map_err(|e| e.to_string())?;
+    // VACUUM идёт в фоновом обслуживании, интерфейс его не ждёт
+    maintenance::request_vacuum();
     Ok(())
 }
 
+/// Полная очистка локальных данных; `dry_run` — только список того, что будет удалено,
+/// `include_extensions` — удалить и отключить ещё скрипты и зеркала источников.
+#[tauri::command]
+async fn wipe_all_data(
+    dry_run: bool,
+    include_extensions: bool,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+    sources: tauri::State<'_, Arc<DataSourceRegistry>>,
+) -> Result<data_wipe::WipeReport, String> {
+    let app_data = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    let cache_dir = app.path().app_cache_dir().ok();
+    let targets = data_wipe::plan(&app_data, cache_dir.as_deref(), include_extensions);
+    if dry_run {
+        return Ok(data_wipe::report(true, targets, Vec::new()));
+    }
+    db.wipe_all_tables().await.map_err(|e| e.to_string())?;
+    let errors = data_wipe::remove_files(&targets);
+    if include_extensions {
+        script_hooks::unload_all();
+        sources.remove_mirrors();
+    }
+    if let Some(dir) = patch_assets_cache_dir(&app) {
+        asset_cache::forget_blob_index(&dir);
+    }
+    maintenance::request_vacuum();
+    logger::log_with(
+        LogLevel::Success,
+        LogSource::Cache,
+        "wipe_all_data => completed",
+        &[("targets", &targets.len()), ("errors", &errors.len())],
+    );
+    Ok(data_wipe::report(false, targets, errors))
+}
+
 fn count_files_recursive(dir: &std::path::Path) -> (u64, u64) {
     let mut files = 0u64;
     let mut bytes = 0u64;
@@ -1023,18 +2052,24 @@ fn count_files_recursive(dir: &std::path::Path) -> (u64, u64) {
     (files, bytes)
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, TS)]
+#[ts(export)]
 struct CacheStatusPayload {
     patch_versions: usize,
     patch_locales: Vec<String>,
     static_catalog_rows: usize,
+    #[ts(type = "number")]
     patch_asset_files: u64,
+    #[ts(type = "number")]
     patch_asset_bytes: u64,
+    #[ts(type = "number")]
     game_asset_files: u64,
+    #[ts(type = "number")]
     game_asset_bytes: u64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, TS)]
+#[ts(export)]
 struct AssetValidationPayload {
     checked: usize,
     missing: usize,
@@ -1042,19 +2077,16 @@ struct AssetValidationPayload {
 }
 
 #[tauri::command]
-async fn cache_status(app: AppHandle, state: tauri::State<'_, AppState>) -> Result<CacheStatusPayload, String> {
-    let versions = state
-        .db
+async fn cache_status(app: AppHandle, db: tauri::State<'_, Arc<Database>>) -> Result<CacheStatusPayload, String> {
+    let versions = db
         .list_cached_patch_versions()
         .await
         .map_err(|e| e.to_string())?;
-    let locales = state
-        .db
+    let locales = db
         .list_cached_patch_locales()
         .await
         .map_err(|e| e.to_string())?;
-    let static_rows = state
-        .db
+    let static_rows = db
         .static_catalog_count()
         .await
         .map_err(|e| e.to_string())? as usize;
@@ -1078,24 +2110,211 @@ async fn cache_status(app: AppHandle, state: tauri::State<'_, AppState>) -> Resu
         game_asset_bytes,
     };
     if let Ok(s) = serde_json::to_string(&payload) {
-        log(&app, "INFO", &format!("cache_status => {}", s));
+        logger::log(LogLevel::Info, LogSource::Cache, &format!("cache_status => {}", s));
     }
     Ok(payload)
 }
 
+#[derive(Serialize, TS)]
+#[ts(export)]
+struct CacheStatsPayload {
+    patch_cache: PatchCacheStats,
+    /// `None`, если каталог кэша картинок недоступен
+    patch_assets: Option<asset_cache::BlobStoreStats>,
+}
+
+/// Размеры и доля попаданий кэшей: LRU патчей в памяти и хранилище картинок на диске.
+#[tauri::command]
+fn get_cache_stats(app: AppHandle, db: tauri::State<'_, Arc<Database>>) -> CacheStatsPayload {
+    CacheStatsPayload {
+        patch_cache: db.patch_cache_stats(),
+        patch_assets: patch_assets_cache_dir(&app).map(|dir| asset_cache::blob_store_stats(&dir)),
+    }
+}
+
+/// Бюджет памяти LRU патчей; лишние записи вытесняются сразу.
+#[tauri::command]
+fn set_cache_memory_budget(db: tauri::State<'_, Arc<Database>>, megabytes: u32) -> PatchCacheStats {
+    db.set_patch_cache_budget(megabytes as usize * 1024 * 1024);
+    db.patch_cache_stats()
+}
+
+#[tauri::command]
+fn get_scrape_settings(scraper: tauri::State<'_, Arc<Scraper>>) -> ScrapeSettings {
+    scraper.settings()
+}
+
+/// Возвращает действующие настройки: значения вне пределов ограничиваются.
+#[tauri::command]
+fn set_scrape_settings(settings: ScrapeSettings, scraper: tauri::State<'_, Arc<Scraper>>) -> ScrapeSettings {
+    scraper.set_settings(settings)
+}
+
+/// Пробный разбор патча без сохранения: покрытие парсера по категориям, пустые записи,
+/// картинки и нераспознанные разделы.
+#[tauri::command]
+async fn validate_parse(
+    version: String,
+    locale: Option<String>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> Result<parse_coverage::ParseCoverage, String> {
+    let locale = locale.unwrap_or_else(|| i18n::current().code().to_string());
+    scraper.validate_parse(&version, &locale).await.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
-async fn warm_full_cache(app: AppHandle, state: tauri::State<'_, AppState>) -> Result<(), String> {
+fn get_request_header_settings(scraper: tauri::State<'_, Arc<Scraper>>) -> RequestHeaderSettings {
+    scraper.header_settings()
+}
+
+/// Возвращает действующие заголовки: неверные отбрасываются.
+#[tauri::command]
+fn set_request_header_settings(
+    settings: RequestHeaderSettings,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> RequestHeaderSettings {
+    scraper.set_header_settings(settings)
+}
+
+#[tauri::command]
+fn get_riot_api_settings(api: tauri::State<'_, riot_api::RiotApi>) -> riot_api::RiotApiSettings {
+    api.settings()
+}
+
+/// Возвращает действующие настройки: неизвестная платформа заменяется на EUW.
+#[tauri::command]
+fn set_riot_api_settings(
+    settings: riot_api::RiotApiSettings,
+    api: tauri::State<'_, riot_api::RiotApi>,
+) -> riot_api::RiotApiSettings {
+    api.set_settings(settings)
+}
+
+#[tauri::command]
+fn get_translation_settings(
+    translator: tauri::State<'_, machine_translation::Translator>,
+) -> machine_translation::TranslationSettings {
+    translator.settings()
+}
+
+/// Возвращает действующие настройки: адрес без схемы сбрасывается.
+#[tauri::command]
+fn set_translation_settings(
+    settings: machine_translation::TranslationSettings,
+    translator: tauri::State<'_, machine_translation::Translator>,
+) -> machine_translation::TranslationSettings {
+    translator.set_settings(settings)
+}
+
+/// Бесплатная ротация чемпионов из Riot API (нужен ключ в настройках).
+#[tauri::command]
+async fn get_rotation(
+    api: tauri::State<'_, riot_api::RiotApi>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<riot_api::ChampionRotation, String> {
+    api.champion_rotation(scraper.http_client(), &db)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Статус серверов выбранной платформы из Riot API.
+#[tauri::command]
+async fn get_server_status(
+    api: tauri::State<'_, riot_api::RiotApi>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
+) -> Result<riot_api::PlatformStatus, String> {
+    api.platform_status(scraper.http_client(), i18n::current().code())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn list_scripts() -> Vec<script_hooks::ScriptInfo> {
+    script_hooks::list_scripts()
+}
+
+/// Компилирует и подключает скрипт; действует для патчей, разобранных или сохранённых после загрузки.
+#[tauri::command]
+fn load_script(app: AppHandle, name: String, source: String) -> Result<script_hooks::ScriptInfo, String> {
+    script_hooks::load_script(&scripts_dir(&app)?, &name, &source).map_err(|e| format!("{:#}", e))
+}
+
+#[tauri::command]
+fn remove_script(app: AppHandle, name: String) -> Result<(), String> {
+    script_hooks::remove_script(&scripts_dir(&app)?, &name).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_local_api_status(api: tauri::State<'_, local_api::LocalApi>) -> Result<local_api::LocalApiStatus, String> {
+    Ok(api.status().await)
+}
+
+/// Включает/выключает локальный HTTP API; слушает только `127.0.0.1`.
+#[tauri::command]
+async fn set_local_api_enabled(
+    enabled: bool,
+    port: Option<u16>,
+    app: AppHandle,
+    api: tauri::State<'_, local_api::LocalApi>,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<local_api::LocalApiStatus, String> {
+    if !enabled {
+        return Ok(api.stop().await);
+    }
+    api.start(
+        db.inner().clone(),
+        port.unwrap_or(local_api::DEFAULT_LOCAL_API_PORT),
+        patch_assets_cache_dir(&app),
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Запись `overlay.json` для оверлея стрима; при включении файл пишется сразу, возвращается путь.
+#[tauri::command]
+async fn set_overlay_file_enabled(
+    enabled: bool,
+    app: AppHandle,
+    db: tauri::State<'_, Arc<Database>>,
+) -> Result<Option<String>, String> {
+    overlay::set_file_enabled(enabled);
+    if !enabled {
+        return Ok(None);
+    }
+    let path = overlay_file_path(&app).ok_or_else(|| i18n::tr(i18n::current(), "error.app_data_unavailable"))?;
+    overlay::write_file(&db, &path).await.map_err(|e| e.to_string())?;
+    Ok(Some(path.to_string_lossy().into_owned()))
+}
+
+/// Отложенный VACUUM и итоги последнего фонового обслуживания.
+#[tauri::command]
+fn get_maintenance_status() -> maintenance::MaintenanceStatus {
+    maintenance::status()
+}
+
+/// Время этапов загрузки по последним патчам; `reset` — очистить после чтения.
+#[tauri::command]
+fn get_scrape_profile(reset: Option<bool>, scraper: tauri::State<'_, Arc<Scraper>>) -> ScrapeProfileReport {
+    let report = scraper.profile().report();
+    if reset.unwrap_or(false) {
+        scraper.profile().clear();
+    }
+    report
+}
+
+#[tauri::command]
+async fn warm_full_cache(app: AppHandle, db: tauri::State<'_, Arc<Database>>, scraper: tauri::State<'_, Arc<Scraper>>) -> Result<(), String> {
     let cache = game_assets_cache_dir(&app);
     game_assets::refresh_game_assets(
-        state.scraper.as_ref(),
-        state.db.as_ref(),
+        &scraper,
+        &db,
         cache.as_deref(),
         true,
     )
     .await
     .map_err(|e| e.to_string())?;
-    let patches = state
-        .scraper
+    let patches = scraper
         .fetch_available_patches()
         .await
         .map_err(|e| e.to_string())?;
@@ -1106,22 +2325,21 @@ async fn warm_full_cache(app: AppHandle, state: tauri::State<'_, AppState>) -> R
                 version,
                 locale,
                 &app,
-                state.db.as_ref(),
-                state.scraper.as_ref(),
+                &db,
+                &scraper,
                 false,
                 true,
             )
             .await;
         }
     }
-    log(&app, "SUCCESS", "warm_full_cache => completed");
+    logger::log(LogLevel::Success, LogSource::Cache, "warm_full_cache => completed");
     Ok(())
 }
 
 #[tauri::command]
-async fn clear_all_cached_data(app: AppHandle, state: tauri::State<'_, AppState>) -> Result<(), String> {
-    state
-        .db
+async fn clear_all_cached_data(app: AppHandle, db: tauri::State<'_, Arc<Database>>) -> Result<(), String> {
+    db
         .clear_all_cached_data()
         .await
         .map_err(|e| e.to_string())?;
@@ -1129,24 +2347,23 @@ async fn clear_all_cached_data(app: AppHandle, state: tauri::State<'_, AppState>
         let _ = std::fs::remove_dir_all(dir);
     }
     if let Some(dir) = patch_assets_cache_dir(&app) {
-        let _ = std::fs::remove_dir_all(dir);
+        let _ = std::fs::remove_dir_all(&dir);
+        asset_cache::forget_blob_index(&dir);
     }
-    let mut cache = state.tier_cache.lock().await;
-    *cache = None;
-    log(&app, "SUCCESS", "clear_all_cached_data => completed");
+    maintenance::request_vacuum();
+    logger::log(LogLevel::Success, LogSource::Cache, "clear_all_cached_data => completed");
     Ok(())
 }
 
 #[tauri::command]
-async fn validate_cached_assets(app: AppHandle, state: tauri::State<'_, AppState>) -> Result<AssetValidationPayload, String> {
+async fn validate_cached_assets(db: tauri::State<'_, Arc<Database>>) -> Result<AssetValidationPayload, String> {
     let mut checked = 0usize;
     let mut missing = 0usize;
     let mut broken_paths: Vec<String> = Vec::new();
 
     let kinds = ["champion", "item", "rune", "augment", "champion_ability"];
     for kind in kinds {
-        let rows = state
-            .db
+        let rows = db
             .get_static_catalog_kind(kind)
             .await
             .unwrap_or_default();
@@ -1174,7 +2391,7 @@ async fn validate_cached_assets(app: AppHandle, state: tauri::State<'_, AppState
         broken_paths,
     };
     if let Ok(s) = serde_json::to_string(&payload) {
-        log(&app, "INFO", &format!("validate_cached_assets => {}", s));
+        logger::log(LogLevel::Info, LogSource::Cache, &format!("validate_cached_assets => {}", s));
     }
     Ok(payload)
 }
@@ -1182,7 +2399,7 @@ async fn validate_cached_assets(app: AppHandle, state: tauri::State<'_, AppState
 #[tauri::command]
 fn get_database_path(app: AppHandle) -> Result<String, String> {
     let dir = app.path().app_data_dir().map_err(|e| e.to_string())?;
-    Ok(dir.join("patches.db").to_string_lossy().into_owned())
+    Ok(dir.join(data_wipe::DB_FILE_NAME).to_string_lossy().into_owned())
 }
 
 #[tauri::command]
@@ -1199,7 +2416,8 @@ async fn fetch_youtube_feed(channel_id: String) -> Result<Vec<youtube_feed::Yout
     youtube_feed::fetch_youtube_feed_async(s).await
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, TS)]
+#[ts(export)]
 pub struct SkinSpotlightResolveResult {
     pub video_id: Option<String>,
     pub video_title: Option<String>,
@@ -1209,7 +2427,7 @@ pub struct SkinSpotlightResolveResult {
 
 #[tauri::command]
 async fn resolve_skin_spotlight_video(
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
     cache_key: String,
     search_query: String,
     channel_id: String,
@@ -1229,7 +2447,7 @@ async fn resolve_skin_spotlight_video(
         });
     }
 
-    let cached = state.db.get_skin_spotlight_cached(&ck).await;
+    let cached = db.get_skin_spotlight_cached(&ck).await;
     if let Ok(Some((vid, title))) = cached {
         return Ok(SkinSpotlightResolveResult {
             video_id: Some(vid),
@@ -1249,18 +2467,17 @@ async fn resolve_skin_spotlight_video(
         }
     };
 
-    let client = reqwest::Client::builder()
-        .user_agent("PatchAnalyzer/1.0 (Tauri)")
-        .build()
-        .map_err(|e| e.to_string())?;
-
-    let searched =
-        youtube_data_api::search_first_video_in_channel(&client, &api_key, ch, &sq).await;
+    let searched = youtube_data_api::search_first_video_in_channel(
+        http_client::api_client().map_err(|e| e.to_string())?,
+        &api_key,
+        ch,
+        &sq,
+    )
+    .await;
 
     match searched {
         Ok(Some((vid, title))) => {
-            let _ = state
-                .db
+            let _ = db
                 .save_skin_spotlight_cached(&ck, &vid, &title)
                 .await;
             Ok(SkinSpotlightResolveResult {
@@ -1282,6 +2499,52 @@ async fn resolve_skin_spotlight_video(
     }
 }
 
+/// Порог журнала: общий (`source: None`) или для одного источника; `level: None` снимает уровень источника.
+#[tauri::command]
+fn set_log_level(source: Option<LogSource>, level: Option<LogLevel>) -> logger::LogFilter {
+    logger::set_level(source, level)
+}
+
+#[tauri::command]
+fn get_log_filter() -> logger::LogFilter {
+    logger::filter()
+}
+
+/// Сохранённый журнал: последние `limit` записей не ниже `level`, от старых к новым.
+#[tauri::command]
+fn get_recent_logs(level: Option<LogLevel>, limit: Option<usize>) -> Vec<LogEvent> {
+    logger::recent(level, limit.unwrap_or(logger::DEFAULT_RECENT_LOGS))
+}
+
+/// Весь сохранённый журнал в «Загрузки» (JSON Lines); возвращает путь к файлу.
+#[tauri::command]
+fn export_logs(app: AppHandle) -> Result<String, String> {
+    let path = download_export_path(&app, "logs", "app", "jsonl")?;
+    logger::export(&path).map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Сохранённые отчёты о сбоях, сначала новые.
+#[tauri::command]
+fn list_crash_reports() -> Vec<crash_report::CrashReport> {
+    crash_report::list()
+}
+
+/// Отчёт текстом (Markdown) для issue: по `id` или последний; `None` — отчётов нет.
+#[tauri::command]
+fn copy_crash_report(id: Option<String>) -> Option<String> {
+    crash_report::list()
+        .into_iter()
+        .find(|r| id.as_ref().is_none_or(|id| *id == r.id))
+        .map(|r| crash_report::render_text(&r))
+}
+
+/// Язык строк, которые формирует backend (дайджесты, календарь, журнал); вызывается при смене языка интерфейса.
+#[tauri::command]
+fn set_app_language(language: String) {
+    i18n::set_current(i18n::Lang::from_code(&language));
+}
+
 #[tauri::command]
 fn update_tray_menu_labels(app: AppHandle, show: String, quit: String) -> Result<(), String> {
     let show_item = MenuItem::with_id(&app, "Show", show, true, None::<&str>).map_err(|e| e.to_string())?;
@@ -1293,7 +2556,8 @@ fn update_tray_menu_labels(app: AppHandle, show: String, quit: String) -> Result
     tray.set_menu(Some(menu)).map_err(|e| e.to_string())
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, TS)]
+#[ts(export)]
 pub struct MayhemAugmentationsPayload {
     pub entries: Vec<MayhemAugmentation>,
     pub fetched_at: Option<String>,
@@ -1301,7 +2565,7 @@ pub struct MayhemAugmentationsPayload {
 
 #[tauri::command]
 async fn get_mayhem_augmentations_page(
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
     locale: String,
 ) -> Result<MayhemAugmentationsPayload, String> {
     let key = if locale == "en" {
@@ -1315,8 +2579,7 @@ async fn get_mayhem_augmentations_page(
         db::AUGMENTS_CATALOG_KEY_ARAM_MAYHEM_RU
     };
 
-    let page = state
-        .db
+    let page = db
         .get_mayhem_augmentations_page(key)
         .await
         .map_err(|e| e.to_string())?;
@@ -1330,7 +2593,7 @@ async fn get_mayhem_augmentations_page(
         }
     }
 
-    if let Ok(Some((notes, t))) = state.db.get_augments_catalog(cat_key).await {
+    if let Ok(Some((notes, t))) = db.get_augments_catalog(cat_key).await {
         let entries = db::mayhem_rows_from_patch_notes(&notes);
         if !entries.is_empty() {
             return Ok(MayhemAugmentationsPayload {
@@ -1355,10 +2618,11 @@ async fn get_mayhem_augmentations_page(
 
 #[tauri::command]
 async fn refresh_mayhem_augmentations_from_wiki(
-    state: tauri::State<'_, AppState>,
+    db: tauri::State<'_, Arc<Database>>,
+    scraper: tauri::State<'_, Arc<Scraper>>,
 ) -> Result<(), String> {
-    let scraper = state.scraper.clone();
-    let db = state.db.clone();
+    let scraper = scraper.inner().clone();
+    let db = db.inner().clone();
     let (en_notes, en_det) = scraper
         .fetch_aram_mayhem_augmentations_bundle_en()
         .await
@@ -1382,8 +2646,6 @@ async fn refresh_mayhem_augmentations_from_wiki(
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    let scraper = Arc::new(Scraper::new().expect("Failed to init Scraper"));
-
     tauri::Builder::default()
         .plugin(tauri_plugin_window_state::Builder::default().build())
         .plugin(tauri_plugin_autostart::Builder::new().build())
@@ -1393,49 +2655,87 @@ pub fn run() {
                 .path()
                 .app_data_dir()
                 .expect("app_data_dir");
-            std::fs::create_dir_all(&app_data).expect("create_dir app_data");
-            let db_path = app_data.join("patches.db");
-            if !db_path.exists() {
-                if let Ok(cwd) = std::env::current_dir() {
-                    let legacy = cwd.join("patches.db");
-                    if legacy.is_file() {
-                        if let Err(e) = std::fs::copy(&legacy, &db_path) {
-                            eprintln!(
-                                "patch-analyzer: migrate patches.db from {:?} failed: {}",
-                                legacy, e
-                            );
-                        }
-                    }
-                }
-            }
-            let db = Arc::new(
-                tokio::runtime::Runtime::new()
-                    .expect("runtime")
-                    .block_on(Database::open(&db_path))
-                    .expect("Failed to init DB"),
+            app.manage(StartupStatus::default());
+            app.manage(local_api::LocalApi::default());
+            app.manage(riot_api::RiotApi::default());
+            app.manage(machine_translation::Translator::default());
+            logger::init(app.handle().clone(), Some(app_data.join(logger::LOG_DIR_NAME)));
+            crash_report::install(
+                app_data.join(crash_report::CRASH_DIR_NAME),
+                app.package_info().version.to_string(),
             );
 
-            app.manage(AppState {
-                db: db.clone(),
-                scraper: scraper.clone(),
-                tier_cache: Mutex::new(None),
-            });
-
-            let db_spawn = db.clone();
-            let scraper_spawn = scraper.clone();
-            let icon_cache_dir = app_data.join("game_assets_icons");
+            // БД и HTTP-клиент поднимаются в фоне, окно и трей не ждут диска; до `app_ready`
+            // команды с `State<Arc<Database>>` отвечают ошибкой, frontend держит заставку
+            let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
+                let (db, scraper) = match init_backend(&app_data).await {
+                    Ok(pair) => pair,
+                    Err(e) => {
+                        let error = format!("{:#}", e);
+                        eprintln!("patch-analyzer: startup failed: {}", error);
+                        crash_report::set_status("db", "failed");
+                        crash_report::capture_error("startup", &error);
+                        let event = AppReadyEvent { error: Some(error) };
+                        let _ = app_handle.state::<StartupStatus>().0.set(event.clone());
+                        emit_event(&app_handle, event);
+                        return;
+                    }
+                };
+
+                // Независимые состояния без общей блокировки: пул SQLite и HTTP-клиент
+                // сами разделяемы, поэтому фоновая синхронизация не задерживает остальные команды
+                crash_report::set_status("db", "ready");
+                app_handle.manage(db.clone());
+                app_handle.manage(scraper.clone());
+                let sources = DataSourceRegistry::with_builtin(scraper.clone());
+                for error in sources.load_mirror_manifests(&app_data.join(data_source::MIRRORS_DIR_NAME)) {
+                    logger::log(LogLevel::Warn, LogSource::Scraper, &format!("data source manifest: {}", error));
+                }
+                app_handle.manage(Arc::new(sources));
+                for error in script_hooks::load_scripts_dir(&app_data.join(script_hooks::SCRIPTS_DIR_NAME)) {
+                    logger::log(LogLevel::Warn, LogSource::Scripts, &format!("script: {}", error));
+                }
+                let event = AppReadyEvent { error: None };
+                let _ = app_handle.state::<StartupStatus>().0.set(event.clone());
+                emit_event(&app_handle, event);
+
+                tauri::async_runtime::spawn(patch_revisions::run_check_loop(
+                    app_handle.clone(),
+                    db.clone(),
+                    scraper.clone(),
+                ));
+                tauri::async_runtime::spawn(store_rotations::run_reminder_loop(app_handle.clone(), db.clone()));
+                tauri::async_runtime::spawn(riot_api::run_status_loop(app_handle.clone(), scraper.clone()));
+                tauri::async_runtime::spawn(community_tiers::run_refresh_loop(db.clone(), scraper.clone()));
+                tauri::async_runtime::spawn(pro_builds::run_ingest_loop(db.clone(), scraper.clone()));
+                tauri::async_runtime::spawn(overlay::run_file_loop(
+                    db.clone(),
+                    app_data.join(overlay::OVERLAY_FILE_NAME),
+                ));
+                let icon_cache_dir = app_data.join("game_assets_icons");
+                tauri::async_runtime::spawn(maintenance::run_idle_loop(
+                    db.clone(),
+                    maintenance::MaintenanceDirs {
+                        patch_assets: patch_assets_cache_dir(&app_handle),
+                        game_asset_icons: Some(icon_cache_dir.clone()),
+                    },
+                ));
                 tokio::time::sleep(std::time::Duration::from_millis(600)).await;
-                let _ = game_assets::try_seed_manifest_meta(db_spawn.as_ref()).await;
-                if db_spawn.static_catalog_count().await.unwrap_or(0) == 0 {
+                let _ = refresh_fallback_patches(scraper.as_ref(), db.as_ref()).await;
+                let _ = game_assets::try_seed_manifest_meta(db.as_ref()).await;
+                if db.static_catalog_count().await.unwrap_or(0) == 0 {
                     let _ = game_assets::refresh_game_assets(
-                        scraper_spawn.as_ref(),
-                        db_spawn.as_ref(),
+                        scraper.as_ref(),
+                        db.as_ref(),
                         Some(icon_cache_dir.as_path()),
                         true,
                     )
                     .await;
+                } else {
+                    let _ = db.backfill_entity_ids().await;
                 }
+                let _ = dev_articles::sync_dev_articles(scraper.as_ref(), db.as_ref(), "ru", 5).await;
             });
 
             #[cfg(not(debug_assertions))]
@@ -1489,18 +2789,59 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             analyze_patch,
             get_available_patches,
+            list_data_sources,
+            import_patch_from_source,
+            import_patch_from_url,
+            import_patch_from_file,
             get_cached_patch_versions,
             get_latest_patch_data,
             get_patch_by_version,
-            get_champion_history,
-            get_item_history,
-            get_rune_history,
+            get_entity_history,
+            get_objective_history,
+            list_objectives,
+            get_player_system_history,
+            list_player_systems,
+            export_champion_timeline,
+            get_entity_diff,
+            get_watchlist,
+            set_watch,
+            get_watchlist_digest,
+            get_upcoming_skins,
+            get_store_rotations,
+            get_riot_api_settings,
+            set_riot_api_settings,
+            get_translation_settings,
+            set_translation_settings,
+            get_rotation,
+            get_server_status,
+            link_summoner,
+            get_linked_summoner,
+            unlink_summoner,
+            import_ranked_matches,
+            get_patch_impact_report,
+            check_patch_note_edits_now,
+            get_patch_revisions,
+            write_patch_feed,
+            export_patch_calendar,
+            get_competitive_impact,
+            get_patch_reaction,
+            generate_champion_report,
+            export_champion_report,
+            get_pro_builds,
+            set_pro_builds_enabled,
             get_all_champions,
             get_changed_itemsrunes_titles,
             get_tier_list,
+            refresh_community_tiers,
+            set_community_tiers_enabled,
             sync_patch_history,
             sync_previous_patch_history_to_limit,
             clear_database,
+            wipe_all_data,
+            get_fallback_patches,
+            refresh_fallback_patches_now,
+            add_fallback_patch,
+            remove_fallback_patch,
             clear_all_cached_data,
             check_patches_exist,
             get_latest_ddragon_version,
@@ -1510,6 +2851,13 @@ pub fn run() {
             get_database_path,
             exit_app,
             update_tray_menu_labels,
+            set_app_language,
+            set_log_level,
+            get_log_filter,
+            get_recent_logs,
+            export_logs,
+            list_crash_reports,
+            copy_crash_report,
             fetch_youtube_feed,
             resolve_skin_spotlight_video,
             wiki_embed::wiki_embed_open,
@@ -1520,9 +2868,35 @@ pub fn run() {
             warm_full_cache,
             cache_status,
             validate_cached_assets,
+            explain_database_queries,
+            get_startup_status,
+            get_app_status,
+            get_cache_stats,
+            set_cache_memory_budget,
+            get_scrape_profile,
+            get_maintenance_status,
+            get_local_api_status,
+            list_scripts,
+            load_script,
+            remove_script,
+            set_local_api_enabled,
+            set_overlay_file_enabled,
+            get_scrape_settings,
+            set_scrape_settings,
+            get_request_header_settings,
+            set_request_header_settings,
+            validate_parse,
             get_game_assets_meta,
             get_static_catalog_rows,
-            get_static_catalog_items_for_maps
+            get_static_catalog_items_for_maps,
+            set_note_tags,
+            get_notes_by_tag,
+            get_patch_summary,
+            get_season_splits,
+            sync_dev_articles,
+            get_dev_articles,
+            search_dev_articles,
+            get_entity_dev_articles
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
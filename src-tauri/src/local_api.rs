@@ -0,0 +1,449 @@
+//! Локальный HTTP API только для чтения (`127.0.0.1`): патчи, тир-лист и истории сущностей для
+//! оверлеев OBS, таблиц и других программ на этой же машине — без Tauri IPC. По умолчанию выключен.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Component, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::extract::{FromRef, Path, Query, State};
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{oneshot, Mutex};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{EntityRef, PatchData, PatchSummary};
+use crate::champion_timeline::{self, TimelineFormat};
+use crate::models::events::{LogLevel, LogSource};
+use crate::{i18n, logger, overlay, patch_calendar, patch_feed};
+use crate::tier_list;
+use crate::ChampionHistoryEntry;
+
+pub const DEFAULT_LOCAL_API_PORT: u16 = 47800;
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct LocalApiStatus {
+    pub running: bool,
+    pub port: Option<u16>,
+    /// Базовый адрес, например `http://127.0.0.1:47800/api`
+    pub base_url: Option<String>,
+}
+
+struct RunningServer {
+    /// Отличает запуск от следующего на том же порту: упавший сервер сбрасывает только свой статус
+    id: u64,
+    port: u16,
+    shutdown: oneshot::Sender<()>,
+}
+
+/// Запущенный сервер; перезапуск на другом порту останавливает прежний только после того,
+/// как новый порт занят.
+#[derive(Default)]
+pub struct LocalApi {
+    running: Arc<Mutex<Option<RunningServer>>>,
+    next_id: AtomicU64,
+}
+
+impl LocalApi {
+    /// `assets_root` — кэш картинок патчей (`patch_assets`), отдаётся по `/api/assets/...` для оверлея.
+    pub async fn start(&self, db: Arc<Database>, port: u16, assets_root: Option<PathBuf>) -> anyhow::Result<LocalApiStatus> {
+        let mut running = self.running.lock().await;
+        if let Some(server) = running.as_ref() {
+            if server.port == port {
+                return Ok(status_for(Some(port)));
+            }
+        }
+        let listener = tokio::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+            .await
+            .with_context(|| format!("local api: cannot bind 127.0.0.1:{}", port))?;
+        let port = listener.local_addr()?.port();
+        if let Some(server) = running.take() {
+            let _ = server.shutdown.send(());
+        }
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (shutdown, stopped) = oneshot::channel::<()>();
+        let slot = self.running.clone();
+        tokio::spawn(async move {
+            let serve = axum::serve(listener, router(ApiState { db, assets_root, port })).with_graceful_shutdown(async {
+                let _ = stopped.await;
+            });
+            if let Err(e) = serve.await {
+                logger::log(LogLevel::Error, LogSource::Api, &format!("local api: {}", e));
+                let mut running = slot.lock().await;
+                if running.as_ref().is_some_and(|s| s.id == id) {
+                    *running = None;
+                }
+            }
+        });
+        *running = Some(RunningServer { id, port, shutdown });
+        Ok(status_for(Some(port)))
+    }
+
+    pub async fn stop(&self) -> LocalApiStatus {
+        if let Some(server) = self.running.lock().await.take() {
+            let _ = server.shutdown.send(());
+        }
+        status_for(None)
+    }
+
+    pub async fn status(&self) -> LocalApiStatus {
+        status_for(self.running.lock().await.as_ref().map(|s| s.port))
+    }
+}
+
+fn status_for(port: Option<u16>) -> LocalApiStatus {
+    LocalApiStatus {
+        running: port.is_some(),
+        port,
+        base_url: port.map(api_base_url),
+    }
+}
+
+#[derive(Clone)]
+struct ApiState {
+    db: Arc<Database>,
+    assets_root: Option<PathBuf>,
+    /// Порт, на котором слушает сервер: по нему проверяется `Host` и строятся ссылки
+    port: u16,
+}
+
+impl FromRef<ApiState> for Arc<Database> {
+    fn from_ref(state: &ApiState) -> Self {
+        state.db.clone()
+    }
+}
+
+fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/api/health", get(health))
+        .route("/api/patches", get(list_patches))
+        .route("/api/patches/latest", get(latest_patch))
+        .route("/api/patches/{version}", get(patch_by_version))
+        .route("/api/patches/{version}/summary", get(patch_summary))
+        .route("/api/tier-list", get(tier_list_handler))
+        .route("/api/history/{kind}/{id}", get(entity_history))
+        .route("/api/timeline/{champion}", get(champion_timeline_handler))
+        .route("/api/feed.xml", get(patch_feed_handler))
+        .route("/api/calendar.ics", get(patch_calendar_handler))
+        .route("/api/overlay", get(overlay_handler))
+        .route("/api/assets/{*path}", get(asset_handler))
+        .layer(axum::middleware::from_fn_with_state(state.clone(), loopback_only))
+        .with_state(state)
+}
+
+fn api_base_url(port: u16) -> String {
+    format!("http://127.0.0.1:{}/api", port)
+}
+
+/// Origin, которым разрешено читать ответы: локальный файл в OBS (`http://absolute`), страница
+/// с `file://` (`null`) и сам API. Остальным сайтам, открытым в браузере, CORS не выдаётся.
+fn allowed_origin(origin: &str, port: u16) -> bool {
+    origin == "null"
+        || origin == "http://absolute"
+        || origin == format!("http://127.0.0.1:{}", port)
+        || origin == format!("http://localhost:{}", port)
+}
+
+/// Запросы с чужим `Host` отклоняются (защита от DNS rebinding); CORS — только для `allowed_origin`.
+async fn loopback_only(
+    State(state): State<ApiState>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> Response {
+    let host = request.headers().get(header::HOST).and_then(|h| h.to_str().ok());
+    if !matches!(host, Some(h) if h == format!("127.0.0.1:{}", state.port) || h == format!("localhost:{}", state.port)) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({ "error": "host not allowed" })),
+        )
+            .into_response();
+    }
+    let origin = request
+        .headers()
+        .get(header::ORIGIN)
+        .filter(|o| o.to_str().is_ok_and(|o| allowed_origin(o, state.port)))
+        .cloned();
+    let mut resp = next.run(request).await;
+    let headers = resp.headers_mut();
+    headers.insert(header::VARY, HeaderValue::from_static("Origin"));
+    if let Some(origin) = origin {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+    }
+    resp
+}
+
+enum ApiError {
+    NotFound(String),
+    BadRequest(String),
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        ApiError::Internal(e)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::NotFound(m) => (StatusCode::NOT_FOUND, m),
+            ApiError::BadRequest(m) => (StatusCode::BAD_REQUEST, m),
+            ApiError::Internal(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)),
+        };
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+type Db = State<Arc<Database>>;
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "app": "lol-meta-analyzer",
+        "version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+async fn list_patches(State(db): Db) -> ApiResult<Vec<String>> {
+    Ok(Json(db.list_cached_patch_versions().await?))
+}
+
+async fn latest_patch(State(db): Db) -> ApiResult<PatchData> {
+    let Some(latest) = db.get_patches_newest_versions_first(1).await?.into_iter().next() else {
+        return Err(ApiError::NotFound("no cached patches".into()));
+    };
+    Ok(Json(db.patch_with_wiki_augment_enrichment(latest).await?))
+}
+
+#[derive(Deserialize)]
+struct LocaleQuery {
+    locale: Option<String>,
+}
+
+/// Только из кэша: API не запускает загрузку с сайта Riot.
+async fn patch_by_version(
+    State(db): Db,
+    Path(version): Path<String>,
+    Query(q): Query<LocaleQuery>,
+) -> ApiResult<PatchData> {
+    let locale = if q.locale.as_deref() == Some("en") { "en" } else { "ru" };
+    let Some(patch) = db.get_patch_resolving_with_locale(&version, locale).await? else {
+        return Err(ApiError::NotFound(format!("patch {} is not cached", version)));
+    };
+    Ok(Json(db.patch_with_wiki_augment_enrichment(patch).await?))
+}
+
+async fn patch_summary(State(db): Db, Path(version): Path<String>) -> ApiResult<PatchSummary> {
+    db.get_patch_summary(&version)
+        .await?
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("no summary for patch {}", version)))
+}
+
+#[derive(Deserialize)]
+struct TierListQuery {
+    window: Option<u32>,
+    modes: Option<bool>,
+    split: Option<String>,
+}
+
+async fn tier_list_handler(State(db): Db, Query(q): Query<TierListQuery>) -> ApiResult<Vec<tier_list::TierEntry>> {
+    Ok(Json(tier_list::load(&db, q.window, q.modes, q.split.as_deref()).await?))
+}
+
+#[derive(Deserialize)]
+struct SplitQuery {
+    split: Option<String>,
+}
+
+/// `/api/history/champion/Ahri`, `/api/history/item/3031`, `/api/history/system/<ключ>`, `/api/history/objective/baron`,
+/// `/api/history/player_system/duo_queue`.
+async fn entity_history(
+    State(db): Db,
+    Path((kind, id)): Path<(String, String)>,
+    Query(q): Query<SplitQuery>,
+) -> ApiResult<Vec<ChampionHistoryEntry>> {
+    let entity = EntityRef::from_entity_id(&format!("{}:{}", kind, id))
+        .ok_or_else(|| ApiError::BadRequest(format!("unknown entity kind {}", kind)))?;
+    Ok(Json(db.get_entity_history(&entity, q.split.as_deref()).await?))
+}
+
+#[derive(Deserialize)]
+struct TimelineQuery {
+    format: Option<TimelineFormat>,
+}
+
+/// `/api/timeline/Ahri?format=csv` — точки по патчам, как `export_champion_timeline`.
+async fn champion_timeline_handler(
+    State(db): Db,
+    Path(champion): Path<String>,
+    Query(q): Query<TimelineQuery>,
+) -> Result<Response, ApiError> {
+    let format = q.format.unwrap_or(TimelineFormat::Json);
+    let points = champion_timeline::champion_timeline(&db, &champion).await?;
+    let body = champion_timeline::render(&points, format)?;
+    let content_type = match format {
+        TimelineFormat::Json => "application/json",
+        TimelineFormat::Csv => "text/csv; charset=utf-8",
+    };
+    Ok(([(header::CONTENT_TYPE, content_type)], body).into_response())
+}
+
+/// RSS той же ленты, что `feed.xml`, но со ссылками на сводки патчей в этом API.
+async fn patch_feed_handler(State(state): State<ApiState>) -> Result<Response, ApiError> {
+    let base = api_base_url(state.port);
+    let xml = patch_feed::render_rss(
+        &patch_feed::load_summaries(&state.db).await?,
+        Some(&base),
+        i18n::current(),
+    );
+    Ok(([(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")], xml).into_response())
+}
+
+#[derive(Deserialize)]
+struct CalendarQuery {
+    upcoming: Option<usize>,
+}
+
+/// Подписка на календарь: `/api/calendar.ics?upcoming=6`.
+async fn patch_calendar_handler(State(db): Db, Query(q): Query<CalendarQuery>) -> Result<Response, ApiError> {
+    let upcoming = q.upcoming.unwrap_or(patch_calendar::DEFAULT_UPCOMING);
+    let events = patch_calendar::patch_schedule(&db, upcoming).await?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        patch_calendar::render_ics(&events, i18n::current()),
+    )
+        .into_response())
+}
+
+/// Для браузерного источника OBS: картинки из кэша — через `/api/assets`, удалённые — как есть.
+async fn overlay_handler(State(state): State<ApiState>) -> ApiResult<overlay::OverlayHighlights> {
+    let base = api_base_url(state.port);
+    let root = state.assets_root.clone();
+    let icon = |url: &str| -> Option<String> {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            return Some(url.to_string());
+        }
+        let rel = std::path::Path::new(url).strip_prefix(root.as_deref()?).ok()?;
+        let rel: Vec<String> = rel.components().map(|c| c.as_os_str().to_string_lossy().into_owned()).collect();
+        Some(format!("{}/assets/{}", base, rel.join("/")))
+    };
+    overlay::current(&state.db, icon)
+        .await?
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound("no cached patches".into()))
+}
+
+/// Файл из кэша картинок; путь только внутри каталога кэша.
+async fn asset_handler(State(state): State<ApiState>, Path(path): Path<String>) -> Result<Response, ApiError> {
+    let Some(root) = state.assets_root.as_ref() else {
+        return Err(ApiError::NotFound("asset cache is unavailable".into()));
+    };
+    let rel = std::path::Path::new(&path);
+    if !rel.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(ApiError::BadRequest("invalid asset path".into()));
+    }
+    let bytes = tokio::fs::read(root.join(rel))
+        .await
+        .map_err(|_| ApiError::NotFound(format!("asset {} not found", path)))?;
+    let content_type = match rel.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase).as_deref() {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        _ => "application/octet-stream",
+    };
+    Ok(([(header::CONTENT_TYPE, content_type)], bytes).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_reports_loopback_base_url() {
+        let status = status_for(Some(DEFAULT_LOCAL_API_PORT));
+        assert!(status.running);
+        assert_eq!(status.base_url.as_deref(), Some("http://127.0.0.1:47800/api"));
+        assert!(!status_for(None).running);
+    }
+
+    async fn test_db(name: &str) -> (Arc<Database>, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("patch-analyzer-api-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        (Arc::new(Database::open(&dir.join("patches.db")).await.unwrap()), dir)
+    }
+
+    async fn send(app: Router, uri: &str, host: &str, origin: Option<&str>) -> Response {
+        use tower::ServiceExt;
+        let mut request = axum::http::Request::get(uri).header(header::HOST, host);
+        if let Some(origin) = origin {
+            request = request.header(header::ORIGIN, origin);
+        }
+        app.oneshot(request.body(axum::body::Body::empty()).unwrap()).await.unwrap()
+    }
+
+    async fn get_status(app: Router, uri: &str) -> StatusCode {
+        send(app, uri, "127.0.0.1:47800", None).await.status()
+    }
+
+    fn test_router(db: Arc<Database>) -> Router {
+        router(ApiState { db, assets_root: None, port: DEFAULT_LOCAL_API_PORT })
+    }
+
+    #[tokio::test]
+    async fn router_serves_health_and_404s_unknown_paths() {
+        let (db, dir) = test_db("routes").await;
+        let app = test_router(db);
+        assert_eq!(get_status(app.clone(), "/api/health").await, StatusCode::OK);
+        assert_eq!(get_status(app.clone(), "/api/patches/latest").await, StatusCode::NOT_FOUND);
+        assert_eq!(get_status(app, "/api/nope").await, StatusCode::NOT_FOUND);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn rejects_foreign_host_and_limits_cors_to_known_origins() {
+        let (db, dir) = test_db("cors").await;
+        let app = test_router(db);
+        let rebound = send(app.clone(), "/api/health", "evil.example:47800", None).await;
+        assert_eq!(rebound.status(), StatusCode::FORBIDDEN);
+        assert_eq!(send(app.clone(), "/api/health", "localhost:47800", None).await.status(), StatusCode::OK);
+
+        let allow = |resp: &Response| {
+            resp.headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(|v| v.to_str().unwrap().to_string())
+        };
+        let obs = send(app.clone(), "/api/health", "127.0.0.1:47800", Some("http://absolute")).await;
+        assert_eq!(allow(&obs).as_deref(), Some("http://absolute"));
+        let site = send(app, "/api/health", "127.0.0.1:47800", Some("https://evil.example")).await;
+        assert_eq!(site.status(), StatusCode::OK);
+        assert_eq!(allow(&site), None);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn failed_bind_keeps_previous_server_running() {
+        let (db, dir) = test_db("bind").await;
+        let api = LocalApi::default();
+        let first = api.start(db.clone(), 0, None).await.unwrap();
+        let taken = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let busy = taken.local_addr().unwrap().port();
+
+        assert!(api.start(db, busy, None).await.is_err());
+        let status = api.status().await;
+        assert!(status.running);
+        assert_eq!(status.port, first.port);
+
+        api.stop().await;
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,259 @@
+//! Структурированный журнал: уровень, источник и поля контекста. Запись печатается в stdout и уходит
+//! во фронтенд событием `log_message`. Порог задаётся общий и отдельно для источников (`set_log_level`) —
+//! например, DEBUG только для SCRAPER, когда нужно приложить к отчёту подробности неудачного разбора.
+//! Прошедшие фильтр записи дописываются JSON-строками в `<app_data>/logs/app.log`: журнал можно
+//! посмотреть и выгрузить, даже если панель не была открыта.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, RwLock};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tauri::AppHandle;
+use ts_rs::TS;
+
+use crate::models::events::{AppEvent, LogEvent, LogLevel, LogSource};
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct LogFilter {
+    /// Порог для источников без своего уровня
+    pub default_level: LogLevel,
+    pub sources: HashMap<LogSource, LogLevel>,
+}
+
+pub const LOG_DIR_NAME: &str = "logs";
+const LOG_FILE: &str = "app.log";
+const ROTATED_LOG_FILE: &str = "app.1.log";
+/// Больше — файл переименовывается в `app.1.log`, прежний `app.1.log` удаляется.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+pub const DEFAULT_RECENT_LOGS: usize = 200;
+
+static APP: OnceLock<AppHandle> = OnceLock::new();
+static LOG_DIR: OnceLock<PathBuf> = OnceLock::new();
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+static FILTER: Lazy<RwLock<LogFilter>> = Lazy::new(|| {
+    RwLock::new(LogFilter {
+        default_level: LogLevel::Info,
+        sources: HashMap::new(),
+    })
+});
+
+/// До вызова записи только печатаются; `log_dir: None` — без записи на диск.
+pub fn init(app: AppHandle, log_dir: Option<PathBuf>) {
+    let _ = APP.set(app);
+    if let Some(dir) = log_dir {
+        let _ = LOG_DIR.set(dir);
+    }
+}
+
+pub fn filter() -> LogFilter {
+    FILTER.read().map(|f| f.clone()).unwrap_or_else(|e| e.into_inner().clone())
+}
+
+/// `source: None` — общий порог; `level: None` для источника — снять его уровень.
+pub fn set_level(source: Option<LogSource>, level: Option<LogLevel>) -> LogFilter {
+    let mut filter = FILTER.write().unwrap_or_else(|e| e.into_inner());
+    match (source, level) {
+        (None, Some(level)) => filter.default_level = level,
+        (None, None) => {}
+        (Some(source), Some(level)) => {
+            filter.sources.insert(source, level);
+        }
+        (Some(source), None) => {
+            filter.sources.remove(&source);
+        }
+    }
+    filter.clone()
+}
+
+pub fn enabled(level: LogLevel, source: LogSource) -> bool {
+    let filter = FILTER.read().unwrap_or_else(|e| e.into_inner());
+    level >= *filter.sources.get(&source).unwrap_or(&filter.default_level)
+}
+
+/// Имя варианта как в JSON: `WARN`, `SCRAPER`.
+fn label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+pub(crate) fn render(event: &LogEvent) -> String {
+    let mut line = format!("[{}] [{}] {}", label(&event.level), label(&event.source), event.message);
+    for (key, value) in &event.fields {
+        line.push_str(&format!(" {}={}", key, value));
+    }
+    line
+}
+
+fn append(dir: &Path, event: &LogEvent) -> std::io::Result<()> {
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    fs::create_dir_all(dir)?;
+    let path = dir.join(LOG_FILE);
+    if fs::metadata(&path).map(|m| m.len() >= MAX_LOG_BYTES).unwrap_or(false) {
+        fs::rename(&path, dir.join(ROTATED_LOG_FILE))?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    let mut line = serde_json::to_vec(event)?;
+    line.push(b'\n');
+    file.write_all(&line)
+}
+
+/// Записи из файлов журнала от старых к новым; битые строки пропускаются.
+fn read_all(dir: &Path) -> Vec<LogEvent> {
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    read_files(dir)
+}
+
+fn read_files(dir: &Path) -> Vec<LogEvent> {
+    [ROTATED_LOG_FILE, LOG_FILE]
+        .iter()
+        .filter_map(|name| fs::read_to_string(dir.join(name)).ok())
+        .flat_map(|text| {
+            text.lines()
+                .filter_map(|line| serde_json::from_str::<LogEvent>(line).ok())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Последние `limit` записей не ниже `min_level`, от старых к новым.
+fn read_recent(dir: &Path, min_level: Option<LogLevel>, limit: usize) -> Vec<LogEvent> {
+    let mut events: Vec<LogEvent> = read_all(dir)
+        .into_iter()
+        .filter(|e| min_level.is_none_or(|min| e.level >= min))
+        .collect();
+    if events.len() > limit {
+        events.drain(..events.len() - limit);
+    }
+    events
+}
+
+pub fn recent(min_level: Option<LogLevel>, limit: usize) -> Vec<LogEvent> {
+    LOG_DIR
+        .get()
+        .map(|dir| read_recent(dir, min_level, limit))
+        .unwrap_or_default()
+}
+
+/// Хвост журнала для отчёта о сбое: если паника случилась во время записи, блокировка занята — пусто.
+pub fn tail_for_report(limit: usize) -> Vec<LogEvent> {
+    let Some(dir) = LOG_DIR.get() else {
+        return Vec::new();
+    };
+    let Ok(_guard) = WRITE_LOCK.try_lock() else {
+        return Vec::new();
+    };
+    let mut events = read_files(dir);
+    if events.len() > limit {
+        events.drain(..events.len() - limit);
+    }
+    events
+}
+
+/// Весь сохранённый журнал в `dest` (JSON Lines); возвращает число записей.
+pub fn export(dest: &Path) -> anyhow::Result<usize> {
+    let dir = LOG_DIR.get().ok_or_else(|| anyhow::anyhow!("log directory is not set"))?;
+    let events = read_all(dir);
+    let mut out = Vec::new();
+    for event in &events {
+        out.extend(serde_json::to_vec(event)?);
+        out.push(b'\n');
+    }
+    fs::write(dest, out)?;
+    Ok(events.len())
+}
+
+/// Событие из модуля без `AppHandle` (скрейпер); до `init` не отправляется.
+pub fn emit<E: AppEvent>(event: E) {
+    if let Some(app) = APP.get() {
+        crate::emit_event(app, event);
+    }
+}
+
+pub fn log(level: LogLevel, source: LogSource, message: &str) {
+    log_with(level, source, message, &[]);
+}
+
+/// Запись с полями контекста: `&[("version", &version)]`.
+pub fn log_with(level: LogLevel, source: LogSource, message: &str, fields: &[(&str, &dyn Display)]) {
+    if !enabled(level, source) {
+        return;
+    }
+    let event = LogEvent {
+        level,
+        source,
+        message: message.to_string(),
+        fields: fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+        timestamp: chrono::Utc::now(),
+    };
+    println!("{}", render(&event));
+    if let Some(dir) = LOG_DIR.get() {
+        if let Err(e) = append(dir, &event) {
+            eprintln!("patch-analyzer: log file: {}", e);
+        }
+    }
+    if let Some(app) = APP.get() {
+        crate::emit_event(app, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_level_overrides_default_threshold() {
+        set_level(Some(LogSource::Scraper), Some(LogLevel::Debug));
+        assert!(enabled(LogLevel::Debug, LogSource::Scraper));
+        assert!(!enabled(LogLevel::Debug, LogSource::Sync));
+        assert!(enabled(LogLevel::Success, LogSource::Sync));
+        let filter = set_level(Some(LogSource::Scraper), None);
+        assert!(filter.sources.is_empty());
+        assert!(!enabled(LogLevel::Debug, LogSource::Scraper));
+
+        let event = LogEvent {
+            level: LogLevel::Warn,
+            source: LogSource::Scraper,
+            message: "no patch notes".into(),
+            fields: [("version".to_string(), "25.22".to_string())].into_iter().collect(),
+            timestamp: chrono::Utc::now(),
+        };
+        assert_eq!(render(&event), "[WARN] [SCRAPER] no patch notes version=25.22");
+    }
+
+    #[test]
+    fn log_file_keeps_recent_entries_across_rotation() {
+        let dir = std::env::temp_dir().join(format!("patch-analyzer-logs-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let event = |level: LogLevel, message: &str| LogEvent {
+            level,
+            source: LogSource::Sync,
+            message: message.into(),
+            fields: BTreeMap::new(),
+            timestamp: chrono::Utc::now(),
+        };
+        append(&dir, &event(LogLevel::Info, "old")).unwrap();
+        fs::rename(dir.join(LOG_FILE), dir.join(ROTATED_LOG_FILE)).unwrap();
+        append(&dir, &event(LogLevel::Warn, "warn")).unwrap();
+        append(&dir, &event(LogLevel::Info, "new")).unwrap();
+        fs::write(dir.join(LOG_FILE), fs::read_to_string(dir.join(LOG_FILE)).unwrap() + "{broken\n").unwrap();
+
+        let all: Vec<String> = read_recent(&dir, None, 10).into_iter().map(|e| e.message).collect();
+        assert_eq!(all, ["old", "warn", "new"]);
+        let last: Vec<String> = read_recent(&dir, None, 2).into_iter().map(|e| e.message).collect();
+        assert_eq!(last, ["warn", "new"]);
+        let warnings = read_recent(&dir, Some(LogLevel::Warn), 10);
+        assert_eq!(warnings.len(), 1);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,300 @@
+//! Машинный перевод патч-нотов (необязательная функция, включается в настройках): если патча нет на
+//! языке интерфейса, строки изменений берутся из патча на другом языке и переводятся через
+//! LibreTranslate-совместимый API (адрес и ключ задаёт пользователь). Пары «оригинал → перевод»
+//! хранятся в БД и переиспользуются между патчами; переведённый патч помечается `machine_translation`,
+//! а оригиналы отдаются вместе с ним.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::http_body;
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
+use crate::models::{ChangeBlock, PatchData, PatchNoteEntry};
+
+/// Строк в одном запросе к API.
+const BATCH_SIZE: usize = 40;
+
+/// Настройки API перевода; хранятся в настройках фронтенда и передаются при старте (`set_translation_settings`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TranslationSettings {
+    pub enabled: bool,
+    /// Адрес метода `/translate` LibreTranslate-совместимого сервера
+    pub endpoint: String,
+    /// Пустой, если сервер не требует ключа
+    pub api_key: String,
+}
+
+impl TranslationSettings {
+    /// Обрезает пробелы; адрес без схемы считается неверным и сбрасывается.
+    pub fn normalized(self) -> Self {
+        let endpoint = self.endpoint.trim().to_string();
+        Self {
+            enabled: self.enabled,
+            endpoint: if Url::parse(&endpoint).is_ok() { endpoint } else { String::new() },
+            api_key: self.api_key.trim().to_string(),
+        }
+    }
+
+    pub fn is_configured(&self) -> bool {
+        self.enabled && !self.endpoint.is_empty()
+    }
+
+    /// Хост API — так перевод подписывается в интерфейсе.
+    fn provider(&self) -> String {
+        Url::parse(&self.endpoint)
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_string))
+            .unwrap_or_else(|| self.endpoint.clone())
+    }
+}
+
+/// Отметка о машинном переводе патча.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MachineTranslation {
+    /// Локаль патч-нотов, с которой переведено
+    pub source_locale: String,
+    pub provider: String,
+    pub translated_at: DateTime<Utc>,
+    /// Перевод → оригинал для каждой переведённой строки
+    pub originals: HashMap<String, String>,
+    /// Строки, оставшиеся без перевода (нет сети или ошибка API)
+    pub untranslated: u32,
+}
+
+#[derive(Default)]
+pub struct Translator {
+    settings: RwLock<TranslationSettings>,
+}
+
+impl Translator {
+    pub fn settings(&self) -> TranslationSettings {
+        self.settings.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Применяет настройки и возвращает действующие.
+    pub fn set_settings(&self, settings: TranslationSettings) -> TranslationSettings {
+        let settings = settings.normalized();
+        *self.settings.write().unwrap_or_else(|e| e.into_inner()) = settings.clone();
+        settings
+    }
+}
+
+#[derive(Serialize)]
+struct TranslateRequest<'a> {
+    q: &'a [String],
+    source: &'a str,
+    target: &'a str,
+    format: &'static str,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    api_key: &'a str,
+}
+
+#[derive(Deserialize)]
+struct TranslateResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: Vec<String>,
+}
+
+/// Каноничное название сущности на нужном языке (чемпионы, предметы) — его не нужно переводить.
+fn canonical_title<'a>(note: &'a PatchNoteEntry, target_locale: &str) -> Option<&'a str> {
+    match target_locale {
+        "en" => note.title_en.as_deref(),
+        _ => note.title_ru.as_deref(),
+    }
+}
+
+fn push_block_strings<'a>(block: &'a ChangeBlock, seen: &mut HashSet<&'a str>, out: &mut Vec<String>) {
+    for s in block.title.iter().chain(block.changes.iter()) {
+        if !s.trim().is_empty() && seen.insert(s) {
+            out.push(s.clone());
+        }
+    }
+    for child in &block.children {
+        push_block_strings(child, seen, out);
+    }
+}
+
+/// Уникальные строки патча, которые нужно перевести, в порядке документа.
+pub fn collect_strings(notes: &[PatchNoteEntry], target_locale: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for note in notes {
+        let title = canonical_title(note, target_locale).is_none().then_some(&note.title);
+        for s in title.into_iter().chain(std::iter::once(&note.summary)) {
+            if !s.trim().is_empty() && seen.insert(s.as_str()) {
+                out.push(s.clone());
+            }
+        }
+        for block in &note.details {
+            push_block_strings(block, &mut seen, &mut out);
+        }
+    }
+    out
+}
+
+fn translate_in_place(s: &mut String, translations: &HashMap<String, String>, originals: &mut HashMap<String, String>) {
+    if let Some(t) = translations.get(s.as_str()) {
+        originals.insert(t.clone(), std::mem::replace(s, t.clone()));
+    }
+}
+
+fn translate_block(block: &mut ChangeBlock, translations: &HashMap<String, String>, originals: &mut HashMap<String, String>) {
+    for s in block.title.iter_mut().chain(block.changes.iter_mut()) {
+        translate_in_place(s, translations, originals);
+    }
+    for child in &mut block.children {
+        translate_block(child, translations, originals);
+    }
+}
+
+/// Подставляет переводы в записи; возвращает карту «перевод → оригинал».
+pub fn apply(notes: &mut [PatchNoteEntry], target_locale: &str, translations: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut originals = HashMap::new();
+    for note in notes.iter_mut() {
+        match canonical_title(note, target_locale).map(str::to_string) {
+            Some(title) => {
+                originals.insert(title.clone(), std::mem::replace(&mut note.title, title));
+            }
+            None => translate_in_place(&mut note.title, translations, &mut originals),
+        }
+        translate_in_place(&mut note.summary, translations, &mut originals);
+        for block in &mut note.details {
+            translate_block(block, translations, &mut originals);
+        }
+    }
+    originals.retain(|t, o| t != o);
+    originals
+}
+
+async fn translate_batch(settings: &TranslationSettings, source: &str, target: &str, q: &[String]) -> Result<Vec<String>> {
+    let request = TranslateRequest {
+        q,
+        source,
+        target,
+        format: "text",
+        api_key: &settings.api_key,
+    };
+    let resp = crate::http_client::api_client()?
+        .post(&settings.endpoint)
+        .json(&request)
+        .send()
+        .await?
+        .error_for_status()?;
+    let response: TranslateResponse = http_body::read_json(resp).await?;
+    if response.translated_text.len() != q.len() {
+        bail!(
+            "translation API returned {} strings for {}",
+            response.translated_text.len(),
+            q.len()
+        );
+    }
+    Ok(response.translated_text)
+}
+
+/// Переводит патч `source` на `target_locale`: сначала из сохранённых пар, недостающее — через API
+/// (если разрешена сеть). Ошибка API не прерывает перевод: оставшиеся строки остаются на языке оригинала.
+pub async fn translate_patch(
+    db: &Database,
+    settings: &TranslationSettings,
+    mut patch: PatchData,
+    target_locale: &str,
+    allow_network: bool,
+) -> Result<PatchData> {
+    let source_locale = patch.patch_notes_locale.clone().unwrap_or_else(|| "en".to_string());
+    if source_locale == target_locale {
+        bail!("patch {} is already in {}", patch.version, target_locale);
+    }
+    let strings = collect_strings(&patch.patch_notes, target_locale);
+    let mut translations = db.get_machine_translations(&source_locale, target_locale).await?;
+    let missing: Vec<String> = strings.iter().filter(|s| !translations.contains_key(*s)).cloned().collect();
+    let mut untranslated = missing.len();
+    if allow_network && settings.is_configured() {
+        let provider = settings.provider();
+        for batch in missing.chunks(BATCH_SIZE) {
+            let translated = match translate_batch(settings, &source_locale, target_locale, batch).await {
+                Ok(t) => t,
+                Err(e) => {
+                    logger::log(
+                        LogLevel::Warn,
+                        LogSource::Scraper,
+                        &format!("machine translation {}: {}", patch.version, e),
+                    );
+                    break;
+                }
+            };
+            let pairs: Vec<(String, String)> = batch.iter().cloned().zip(translated).collect();
+            db.save_machine_translations(&source_locale, target_locale, &provider, &pairs).await?;
+            untranslated -= pairs.len();
+            translations.extend(pairs);
+        }
+    }
+    let originals = apply(&mut patch.patch_notes, target_locale, &translations);
+    patch.machine_translation = Some(MachineTranslation {
+        source_locale,
+        provider: settings.provider(),
+        translated_at: Utc::now(),
+        originals,
+        untranslated: untranslated as u32,
+    });
+    patch.patch_notes_locale = Some(target_locale.to_string());
+    Ok(patch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeType, PatchCategory};
+
+    #[test]
+    fn translates_strings_and_keeps_originals() {
+        let block = |title: &str, changes: &[&str]| ChangeBlock {
+            title: Some(title.into()),
+            icon_url: None,
+            changes: changes.iter().map(|s| s.to_string()).collect(),
+            children: vec![],
+        };
+        let note = |title: &str, title_ru: Option<&str>, details: Vec<ChangeBlock>| PatchNoteEntry {
+            id: title.to_lowercase(),
+            title: title.into(),
+            category: PatchCategory::Champions,
+            change_type: ChangeType::Buff,
+            summary: "Stronger early".into(),
+            details,
+            title_en: Some(title.into()),
+            title_ru: title_ru.map(str::to_string),
+            ..Default::default()
+        };
+        let mut notes = vec![
+            note("Ahri", Some("Ари"), vec![block("Base Stats", &["Armor: 21 ⇒ 24"])]),
+            note("Smolder", None, vec![block("Base Stats", &["Armor: 21 ⇒ 24", "HP: 600 ⇒ 620"])]),
+        ];
+        let strings = collect_strings(&notes, "ru");
+        assert_eq!(strings, ["Stronger early", "Base Stats", "Armor: 21 ⇒ 24", "Smolder", "HP: 600 ⇒ 620"]);
+
+        let translations: HashMap<String, String> = [
+            ("Stronger early", "Сильнее в начале"),
+            ("Base Stats", "Базовые характеристики"),
+            ("Armor: 21 ⇒ 24", "Броня: 21 ⇒ 24"),
+        ]
+        .into_iter()
+        .map(|(a, b)| (a.to_string(), b.to_string()))
+        .collect();
+        let originals = apply(&mut notes, "ru", &translations);
+        assert_eq!(notes[0].title, "Ари");
+        assert_eq!(notes[1].title, "Smolder");
+        assert_eq!(notes[1].details[0].changes, ["Броня: 21 ⇒ 24", "HP: 600 ⇒ 620"]);
+        assert_eq!(originals["Ари"], "Ahri");
+        assert_eq!(originals["Базовые характеристики"], "Base Stats");
+        assert!(!originals.contains_key("Smolder"));
+    }
+}
@@ -0,0 +1,177 @@
+//! Обслуживание в простое: отложенный VACUUM, чистка устаревших кэшей и неиспользуемых файлов
+//! картинок. Всё это идёт фоновым циклом после `app_ready`, а не внутри команд, которых ждёт интерфейс.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::events::{LogLevel, LogSource};
+use crate::{asset_cache, game_assets, logger};
+
+/// Как часто цикл проверяет, не пора ли обслуживать.
+const TICK: Duration = Duration::from_secs(60);
+/// Простой — столько времени без сетевой загрузки патчей и картинок.
+const IDLE_AFTER: Duration = Duration::from_secs(120);
+/// Чистка кэшей и файлов не чаще; VACUUM по запросу — на первом же простое.
+const PERIODIC_EVERY: Duration = Duration::from_secs(6 * 60 * 60);
+/// Файлы моложе не трогаем: их могла только что записать идущая загрузка.
+const MIN_FILE_AGE: Duration = Duration::from_secs(10 * 60);
+/// Кэш поиска Skin Spotlight старше — удаляется, ролик найдётся заново.
+const SKIN_SPOTLIGHT_MAX_AGE_DAYS: i64 = 30;
+
+static VACUUM_PENDING: AtomicBool = AtomicBool::new(false);
+static LAST_ACTIVITY: Lazy<Mutex<Instant>> = Lazy::new(|| Mutex::new(Instant::now()));
+static LAST_REPORT: Lazy<Mutex<Option<MaintenanceReport>>> = Lazy::new(|| Mutex::new(None));
+
+/// Отмечает сетевую загрузку: обслуживание откладывается до следующего простоя.
+pub fn touch() {
+    *LAST_ACTIVITY.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+}
+
+/// VACUUM после массового удаления — выполнится в простое.
+pub fn request_vacuum() {
+    VACUUM_PENDING.store(true, Ordering::Relaxed);
+}
+
+fn idle_for() -> Duration {
+    LAST_ACTIVITY
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .elapsed()
+}
+
+/// Файл достаточно старый, чтобы его можно было удалить как неиспользуемый.
+pub(crate) fn is_settled_file(md: &std::fs::Metadata) -> bool {
+    md.is_file()
+        && md
+            .modified()
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .is_some_and(|age| age >= MIN_FILE_AGE)
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct MaintenanceReport {
+    pub finished_at: DateTime<Utc>,
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+    pub vacuumed: bool,
+    /// Удалено устаревших строк кэшей в БД
+    #[ts(type = "number")]
+    pub pruned_rows: u64,
+    #[ts(type = "number")]
+    pub blobs_removed: u64,
+    #[ts(type = "number")]
+    pub icons_removed: u64,
+    #[ts(type = "number")]
+    pub bytes_freed: u64,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct MaintenanceStatus {
+    pub vacuum_pending: bool,
+    #[ts(type = "number")]
+    pub idle_secs: u64,
+    pub last_run: Option<MaintenanceReport>,
+}
+
+pub fn status() -> MaintenanceStatus {
+    MaintenanceStatus {
+        vacuum_pending: VACUUM_PENDING.load(Ordering::Relaxed),
+        idle_secs: idle_for().as_secs(),
+        last_run: LAST_REPORT.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+    }
+}
+
+/// Каталоги с файлами, которые чистит обслуживание; `None` — каталог недоступен.
+pub struct MaintenanceDirs {
+    pub patch_assets: Option<PathBuf>,
+    pub game_asset_icons: Option<PathBuf>,
+}
+
+/// Фоновый цикл на всё время работы приложения.
+pub async fn run_idle_loop(db: Arc<Database>, dirs: MaintenanceDirs) {
+    let mut interval = tokio::time::interval(TICK);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut last_periodic: Option<Instant> = None;
+    loop {
+        interval.tick().await;
+        if idle_for() < IDLE_AFTER {
+            continue;
+        }
+        let periodic = last_periodic.is_none_or(|t| t.elapsed() >= PERIODIC_EVERY);
+        if !periodic && !VACUUM_PENDING.load(Ordering::Relaxed) {
+            continue;
+        }
+        let report = run_once(db.as_ref(), &dirs, periodic).await;
+        if periodic {
+            last_periodic = Some(Instant::now());
+        }
+        if !report.errors.is_empty() {
+            logger::log(LogLevel::Warn, LogSource::Maintenance, &report.errors.join("; "));
+        }
+        *LAST_REPORT.lock().unwrap_or_else(|e| e.into_inner()) = Some(report);
+    }
+}
+
+async fn run_once(db: &Database, dirs: &MaintenanceDirs, periodic: bool) -> MaintenanceReport {
+    let started = Instant::now();
+    let mut report = MaintenanceReport {
+        finished_at: Utc::now(),
+        duration_ms: 0,
+        vacuumed: false,
+        pruned_rows: 0,
+        blobs_removed: 0,
+        icons_removed: 0,
+        bytes_freed: 0,
+        errors: Vec::new(),
+    };
+
+    if periodic {
+        match db.prune_skin_spotlight_cache(SKIN_SPOTLIGHT_MAX_AGE_DAYS).await {
+            Ok(n) => report.pruned_rows += n,
+            Err(e) => report.errors.push(format!("prune skin spotlight: {:#}", e)),
+        }
+        if let Some(dir) = &dirs.patch_assets {
+            let (files, bytes) = asset_cache::collect_orphan_blobs(dir);
+            report.blobs_removed += files;
+            report.bytes_freed += bytes;
+        }
+        if let Some(dir) = &dirs.game_asset_icons {
+            match game_assets::collect_unused_icons(db, dir).await {
+                Ok((files, bytes)) => {
+                    report.icons_removed += files;
+                    report.bytes_freed += bytes;
+                }
+                Err(e) => report.errors.push(format!("icon gc: {:#}", e)),
+            }
+        }
+        if report.pruned_rows > 0 {
+            request_vacuum();
+        }
+    }
+
+    if VACUUM_PENDING.swap(false, Ordering::Relaxed) {
+        match db.vacuum().await {
+            Ok(()) => report.vacuumed = true,
+            Err(e) => {
+                VACUUM_PENDING.store(true, Ordering::Relaxed);
+                report.errors.push(format!("vacuum: {:#}", e));
+            }
+        }
+    }
+
+    report.duration_ms = started.elapsed().as_millis() as u64;
+    report.finished_at = Utc::now();
+    report
+}
@@ -0,0 +1,288 @@
+//! «Как патчи повлияли на вас»: рейтинговые матчи привязанного аккаунта (Match-V5) группируются по
+//! патчам, и для каждого чемпиона, которого патч изменил, винрейт игрока на этом патче сравнивается
+//! с винрейтом на предыдущем патче, где он на нём играл. Рядом — классификация анализатора (бафф или
+//! нерф, масштаб правки), так что видно, совпал ли результат с ожиданием.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{ChangeGrade, ChangeType, EntityRef};
+use crate::patch_version::{cmp_display_patch, PatchVersion};
+use crate::riot_api::{champion_catalog, RankedMatch, RiotApi};
+use crate::summoner::LinkedSummoner;
+
+pub const DEFAULT_IMPORT_COUNT: usize = 50;
+/// Сколько игр нужно с каждой стороны патча, чтобы сравнивать винрейт.
+pub const MIN_GAMES: u32 = 3;
+/// Пауза между запросами матчей: ключ разработчика — 100 запросов за 2 минуты.
+const REQUEST_PAUSE: Duration = Duration::from_millis(1250);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+pub struct WinRecord {
+    pub games: u32,
+    pub wins: u32,
+}
+
+impl WinRecord {
+    fn add(&mut self, win: bool) {
+        self.games += 1;
+        self.wins += u32::from(win);
+    }
+
+    /// В процентах.
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.games > 0).then(|| f64::from(self.wins) * 100.0 / f64::from(self.games))
+    }
+}
+
+/// Правка чемпиона в патче по классификации анализатора.
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[ts(export)]
+pub struct ChampionChange {
+    pub change_type: ChangeType,
+    pub grade: Option<ChangeGrade>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ChampionPatchImpact {
+    pub patch_version: String,
+    /// Предыдущий патч, на котором игрок играл этого чемпиона
+    pub previous_version: Option<String>,
+    pub champion_key: i32,
+    pub champion_id: String,
+    pub name_en: Option<String>,
+    pub name_ru: Option<String>,
+    pub change: ChampionChange,
+    pub before: WinRecord,
+    pub after: WinRecord,
+    /// Разница винрейта в процентных пунктах; `None`, если с какой-то стороны меньше `MIN_GAMES` игр
+    pub delta: Option<f64>,
+    /// Совпал ли знак разницы с баффом или нерфом; `None` для прочих правок и без `delta`
+    pub matches_expectation: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PatchImpactReport {
+    pub riot_id: String,
+    pub overall: WinRecord,
+    pub first_game: Option<DateTime<Utc>>,
+    pub last_game: Option<DateTime<Utc>>,
+    /// Патчи с матчами, новые первыми
+    pub patches: Vec<String>,
+    /// Новые патчи первыми, внутри патча — по числу игр
+    pub impacts: Vec<ChampionPatchImpact>,
+    /// Доля правок с `matches_expectation == Some(true)` среди оценённых
+    pub agreement: Option<f64>,
+    pub generated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct MatchImportResult {
+    pub imported: u32,
+    /// Ремейки не сохраняются
+    pub skipped: u32,
+    pub total: u32,
+}
+
+fn base_version(version: &str) -> String {
+    PatchVersion::parse(version).map_or_else(|| version.to_string(), |v| v.base().to_string())
+}
+
+/// `changes` — правки по (патч без хотфикса, id чемпиона в нижнем регистре).
+pub fn correlate(matches: &[RankedMatch], changes: &HashMap<(String, String), ChampionChange>) -> Vec<ChampionPatchImpact> {
+    let mut by_champion: HashMap<String, (&RankedMatch, HashMap<&str, WinRecord>)> = HashMap::new();
+    for game in matches {
+        let (_, patches) = by_champion
+            .entry(game.champion_id.to_lowercase())
+            .or_insert_with(|| (game, HashMap::new()));
+        patches.entry(game.patch_version.as_str()).or_default().add(game.win);
+    }
+
+    let mut impacts = Vec::new();
+    for (champion, (sample, patches)) in by_champion {
+        let mut played: Vec<(&str, WinRecord)> = patches.into_iter().collect();
+        played.sort_by(|a, b| cmp_display_patch(a.0, b.0));
+        for (i, (patch, after)) in played.iter().enumerate() {
+            let Some(change) = changes.get(&(patch.to_string(), champion.clone())) else {
+                continue;
+            };
+            let previous = i.checked_sub(1).map(|p| played[p]);
+            let before = previous.map(|(_, r)| r).unwrap_or_default();
+            let delta = (before.games >= MIN_GAMES && after.games >= MIN_GAMES)
+                .then(|| after.win_rate().unwrap_or(0.0) - before.win_rate().unwrap_or(0.0));
+            let matches_expectation = delta.and_then(|d| match change.change_type {
+                ChangeType::Buff => Some(d > 0.0),
+                ChangeType::Nerf => Some(d < 0.0),
+                _ => None,
+            });
+            impacts.push(ChampionPatchImpact {
+                patch_version: patch.to_string(),
+                previous_version: previous.map(|(v, _)| v.to_string()),
+                champion_key: sample.champion_key,
+                champion_id: sample.champion_id.clone(),
+                name_en: None,
+                name_ru: None,
+                change: change.clone(),
+                before,
+                after: *after,
+                delta,
+                matches_expectation,
+            });
+        }
+    }
+    impacts.sort_by(|a, b| {
+        cmp_display_patch(&b.patch_version, &a.patch_version).then(b.after.games.cmp(&a.after.games))
+    });
+    impacts
+}
+
+pub async fn linked_summoner(db: &Database) -> Result<LinkedSummoner> {
+    db.get_linked_summoner()
+        .await?
+        .ok_or_else(|| anyhow!("Link a Riot ID in settings first"))
+}
+
+/// Догружает матчи, которых ещё нет в БД; между запросами — пауза под лимит ключа.
+pub async fn import(
+    api: &RiotApi,
+    client: &reqwest::Client,
+    db: &Database,
+    summoner: &LinkedSummoner,
+    count: usize,
+) -> Result<MatchImportResult> {
+    let puuid = &summoner.account.puuid;
+    let known = db.get_ranked_match_ids(puuid).await?;
+    let ids = api.ranked_match_ids(client, puuid, count).await?;
+    let mut result = MatchImportResult::default();
+    for (i, id) in ids.iter().filter(|id| !known.contains(*id)).enumerate() {
+        if i > 0 {
+            tokio::time::sleep(REQUEST_PAUSE).await;
+        }
+        match api.ranked_match(client, id, puuid).await? {
+            Some(game) => {
+                db.save_ranked_match(puuid, &game).await?;
+                result.imported += 1;
+            }
+            None => result.skipped += 1,
+        }
+    }
+    result.total = db.get_ranked_match_ids(puuid).await?.len() as u32;
+    Ok(result)
+}
+
+/// Отчёт по сохранённым матчам; классификации берутся из истории правок каждого сыгранного чемпиона.
+pub async fn report(db: &Database, summoner: &LinkedSummoner) -> Result<PatchImpactReport> {
+    let matches = db.get_ranked_matches(&summoner.account.puuid).await?;
+
+    let mut changes = HashMap::new();
+    let mut champions: Vec<&str> = matches.iter().map(|m| m.champion_id.as_str()).collect();
+    champions.sort_unstable();
+    champions.dedup();
+    for champion in champions {
+        let entity = EntityRef::Champion { id: champion.to_string() };
+        for entry in db.get_entity_history(&entity, None).await? {
+            // История идёт по дате: правка основного патча раньше его хотфикса
+            changes
+                .entry((base_version(&entry.patch_version), champion.to_lowercase()))
+                .or_insert(ChampionChange {
+                    change_type: entry.change.change_type,
+                    grade: entry.change.grade,
+                });
+        }
+    }
+
+    let catalog = champion_catalog(db).await?;
+    let mut impacts = correlate(&matches, &changes);
+    for impact in &mut impacts {
+        if let Some(meta) = catalog.iter().find(|c| c.key == impact.champion_key.to_string()) {
+            impact.name_en = Some(meta.name_en.clone());
+            impact.name_ru = Some(meta.name_ru.clone());
+        }
+    }
+
+    let mut overall = WinRecord::default();
+    let mut patches: Vec<String> = Vec::new();
+    for game in &matches {
+        overall.add(game.win);
+        if !patches.contains(&game.patch_version) {
+            patches.push(game.patch_version.clone());
+        }
+    }
+    patches.sort_by(|a, b| cmp_display_patch(b, a));
+    let judged: Vec<bool> = impacts.iter().filter_map(|i| i.matches_expectation).collect();
+    let agreement =
+        (!judged.is_empty()).then(|| judged.iter().filter(|m| **m).count() as f64 / judged.len() as f64);
+
+    Ok(PatchImpactReport {
+        riot_id: format!("{}#{}", summoner.account.game_name, summoner.account.tag_line),
+        overall,
+        first_game: matches.first().map(|m| m.game_start),
+        last_game: matches.last().map(|m| m.game_start),
+        patches,
+        impacts,
+        agreement,
+        generated_at: Utc::now(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(champion: &str, patch: &str, win: bool) -> RankedMatch {
+        RankedMatch {
+            match_id: String::new(),
+            champion_key: 0,
+            champion_id: champion.into(),
+            win,
+            role: None,
+            queue_id: 420,
+            game_start: Utc::now(),
+            game_version: String::new(),
+            patch_version: patch.into(),
+        }
+    }
+
+    #[test]
+    fn compares_win_rate_with_previous_played_patch() {
+        let mut matches = Vec::new();
+        for win in [true, false, false, false] {
+            matches.push(game("Ahri", "25.20", win));
+        }
+        for win in [true, true, true, false] {
+            matches.push(game("Ahri", "25.22", win));
+        }
+        matches.push(game("Zed", "25.21", true));
+        matches.push(game("Zed", "25.22", false));
+
+        let change = |change_type| ChampionChange { change_type, grade: None };
+        let changes = HashMap::from([
+            (("25.22".to_string(), "ahri".to_string()), change(ChangeType::Buff)),
+            (("25.22".to_string(), "zed".to_string()), change(ChangeType::Nerf)),
+            (("25.21".to_string(), "ahri".to_string()), change(ChangeType::Nerf)),
+        ]);
+        let impacts = correlate(&matches, &changes);
+        assert_eq!(impacts.len(), 2);
+
+        let ahri = &impacts[0];
+        assert_eq!(ahri.champion_id, "Ahri");
+        assert_eq!(ahri.previous_version.as_deref(), Some("25.20"));
+        assert_eq!((ahri.before.wins, ahri.after.wins), (1, 3));
+        assert_eq!(ahri.delta, Some(50.0));
+        assert_eq!(ahri.matches_expectation, Some(true));
+
+        // Слишком мало игр — разница не считается
+        assert_eq!((impacts[1].champion_id.as_str(), impacts[1].delta), ("Zed", None));
+        assert_eq!(base_version("25.22b"), "25.22");
+    }
+}
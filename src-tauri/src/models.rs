@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use ts_rs::TS;
+use crate::machine_translation::MachineTranslation;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+pub mod events;
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct PatchData {
     pub version: String,
     pub fetched_at: DateTime<Utc>,
@@ -12,9 +18,39 @@ pub struct PatchData {
     /// "ru" | "en" — с какого региона Riot взяты patch_notes
     #[serde(default)]
     pub patch_notes_locale: Option<String>,
+    /// Дата публикации статьи Riot (article:published_time)
+    #[serde(default)]
+    pub published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Сезон/сплит из заголовка статьи ("25.S1"), если Riot его указывает
+    #[serde(default)]
+    pub season: Option<String>,
+    /// Для хотфикса ("25.22b") — версия родительского патча; выводится из `version`
+    #[serde(default)]
+    pub parent_version: Option<String>,
+    /// Сезон/сплит ("25.S2") — из статьи, номера патча или даты выхода; см. `patch_version::season_split`
+    #[serde(default)]
+    pub split: String,
+    /// Патч переведён машинным переводом с другой локали; в БД не сохраняется
+    #[serde(default)]
+    pub machine_translation: Option<MachineTranslation>,
+}
+
+impl PatchData {
+    /// Дата выхода патча для сортировки и таймлайнов; без даты публикации — момент загрузки.
+    pub fn release_date(&self) -> DateTime<Utc> {
+        self.published_at.unwrap_or(self.fetched_at)
+    }
+
+    /// Версия, под которой патч занимает место в окнах тир-листа и истории: хотфикс — под родителем.
+    pub fn base_version(&self) -> &str {
+        self.parent_version.as_deref().unwrap_or(&self.version)
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct ChampionStats {
     pub id: String,
     pub name: String,
@@ -25,16 +61,209 @@ pub struct ChampionStats {
     pub ban_rate: f64,
     pub image_url: Option<String>,
     pub core_items: Vec<ItemStat>,
-    pub popular_runes: Vec<String>,
+    /// Старые строки хранили здесь только названия рун — они читаются как RuneEntry без id.
+    #[serde(deserialize_with = "deserialize_rune_entries")]
+    pub popular_runes: Vec<RuneEntry>,
+    /// Число матчей, по которым посчитаны win/pick rate (0 — неизвестно)
+    #[serde(default)]
+    #[ts(type = "number")]
+    pub total_matches: u64,
+    /// Доля игр чемпиона в этой роли (0..1). Один чемпион может иметь несколько строк — по строке на роль;
+    /// строка с `LaneRole::Unknown` и долей 1.0 — агрегат по всем ролям.
+    #[serde(default = "default_role_share")]
+    pub role_share: f64,
+}
+
+fn default_role_share() -> f64 {
+    1.0
+}
+
+/// Пустая строка без статистики: win 50%, tier "?", агрегат по ролям.
+impl Default for ChampionStats {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            name: String::new(),
+            tier: "?".into(),
+            role: LaneRole::Unknown,
+            win_rate: 50.0,
+            pick_rate: 0.0,
+            ban_rate: 0.0,
+            image_url: None,
+            core_items: vec![],
+            popular_runes: vec![],
+            total_matches: 0,
+            role_share: default_role_share(),
+        }
+    }
+}
+
+impl ChampionStats {
+    /// Настоящая статистика, а не заглушка источника без данных (win 50%, pick 0, матчей 0).
+    pub fn has_data(&self) -> bool {
+        self.pick_rate > 0.0 || self.total_matches > 0
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TS)]
+#[ts(export)]
+pub struct RuneEntry {
+    /// DDragon id руны ("8112")
+    #[serde(default)]
+    pub id: Option<String>,
+    pub name: String,
+    /// Ключ дерева: "Domination", "Precision", ...
+    #[serde(default)]
+    pub tree: Option<String>,
+    /// 0 — ключевая руна, 1..3 — ряды дерева
+    #[serde(default)]
+    pub slot: Option<u8>,
+    #[serde(default)]
+    pub icon: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl RuneEntry {
+    /// RuneEntry из строки static_catalog (kind = "rune").
+    pub fn from_catalog_row(row: &StaticCatalogRow, prefer_ru: bool) -> Self {
+        let meta = row.cd_meta.as_ref();
+        let name = if prefer_ru && !row.name_ru.is_empty() {
+            row.name_ru.clone()
+        } else {
+            row.name_en.clone()
+        };
+        RuneEntry {
+            id: meta
+                .and_then(|m| m.get("id"))
+                .map(|v| v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string())),
+            name,
+            tree: meta
+                .and_then(|m| m.get("style"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            slot: meta
+                .and_then(|m| m.get("slot"))
+                .and_then(|v| v.as_u64())
+                .map(|v| v as u8),
+            icon: row.icon_sources.iter().find_map(|s| s.url.clone()),
+        }
+    }
+}
+
+fn deserialize_rune_entries<'de, D>(deserializer: D) -> Result<Vec<RuneEntry>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredRune {
+        Entry(RuneEntry),
+        Name(String),
+    }
+    let raw: Vec<StoredRune> = Vec::deserialize(deserializer)?;
+    Ok(raw
+        .into_iter()
+        .map(|r| match r {
+            StoredRune::Entry(e) => e,
+            StoredRune::Name(name) => RuneEntry {
+                id: None,
+                name,
+                tree: None,
+                slot: None,
+                icon: None,
+            },
+        })
+        .collect())
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct ItemStat {
     pub name: String,
     pub image_url: Option<String>,
+    /// DDragon id предмета ("3031")
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Полная стоимость в золоте
+    #[serde(default)]
+    pub gold_total: Option<u32>,
+    /// Строки характеристик: "+40 Attack Damage"
+    #[serde(default)]
+    pub stats: Vec<String>,
+    /// id компонентов, из которых собирается предмет
+    #[serde(default)]
+    pub build_path: Vec<String>,
+}
+
+/// Человекочитаемые названия ключей `stats` из DDragon item.json.
+fn ddragon_item_stat_label(key: &str) -> Option<(&'static str, bool)> {
+    // (название, процентное значение)
+    Some(match key {
+        "FlatPhysicalDamageMod" => ("Attack Damage", false),
+        "FlatMagicDamageMod" => ("Ability Power", false),
+        "FlatHPPoolMod" => ("Health", false),
+        "FlatMPPoolMod" => ("Mana", false),
+        "FlatArmorMod" => ("Armor", false),
+        "FlatSpellBlockMod" => ("Magic Resist", false),
+        "FlatCritChanceMod" => ("Critical Strike Chance", true),
+        "PercentAttackSpeedMod" => ("Attack Speed", true),
+        "PercentMovementSpeedMod" => ("Move Speed", true),
+        "FlatMovementSpeedMod" => ("Move Speed", false),
+        "PercentLifeStealMod" => ("Life Steal", true),
+        "FlatHPRegenMod" => ("Health Regen", false),
+        _ => return None,
+    })
+}
+
+impl ItemStat {
+    /// ItemStat из строки static_catalog (kind = "item"); цена/статы — из `cd_meta.dd`.
+    pub fn from_catalog_row(row: &StaticCatalogRow, prefer_ru: bool) -> Self {
+        let dd = row.cd_meta.as_ref().and_then(|m| m.get("dd"));
+        let gold_total = dd
+            .and_then(|d| d.get("gold"))
+            .and_then(|g| g.get("total"))
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32);
+        let mut stats: Vec<String> = dd
+            .and_then(|d| d.get("stats"))
+            .and_then(|v| v.as_object())
+            .map(|obj| {
+                obj.iter()
+                    .filter_map(|(k, v)| {
+                        let value = v.as_f64()?;
+                        let (label, percent) = ddragon_item_stat_label(k).unwrap_or((k.as_str(), false));
+                        Some(if percent {
+                            format!("+{}% {}", (value * 100.0).round(), label)
+                        } else {
+                            format!("+{} {}", value, label)
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        stats.sort();
+        let build_path = dd
+            .and_then(|d| d.get("from"))
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|x| x.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default();
+        let name = if prefer_ru && !row.name_ru.is_empty() {
+            row.name_ru.clone()
+        } else {
+            row.name_en.clone()
+        };
+        ItemStat {
+            name,
+            image_url: row.icon_sources.iter().find_map(|s| s.url.clone()),
+            id: Some(row.stable_id.clone()),
+            gold_total,
+            stats,
+            build_path,
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct PatchNoteEntry {
     pub id: String,
     pub title: String,
@@ -46,16 +275,68 @@ pub struct PatchNoteEntry {
     /// Приоритетные URL иконок из static_catalog (DDragon / CD / вики); заполняется при отдаче патча.
     #[serde(default)]
     pub icon_candidates: Option<Vec<String>>,
+    /// Каноничные названия из static_catalog (DDragon); заполняются при скрапе независимо от локали патч-нотов.
+    #[serde(default)]
+    pub title_en: Option<String>,
+    #[serde(default)]
+    pub title_ru: Option<String>,
+    /// Теги ("pro-play", "early-game", ...): автоматические или заданные пользователем
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Стабильный id сущности из DDragon: "champion:Ahri", "item:3031", "rune:8112"
+    #[serde(default)]
+    pub entity_id: Option<String>,
+    /// Исходный HTML-фрагмент статьи, из которого разобрана запись (для сверки и повторного разбора)
+    #[serde(default)]
+    pub raw_html: Option<String>,
+    /// Масштаб изменения по величине правок; `None` — записи без строк изменений (скины и т.п.)
+    #[serde(default)]
+    pub grade: Option<ChangeGrade>,
+    /// Сущности, названные в тексте исправления ошибки (`champion:Ahri`, `item:3031`): по ним
+    /// исправление попадает в историю чемпиона или предмета
+    #[serde(default)]
+    pub mentions: Vec<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct ChangeBlock {
     pub title: Option<String>, // Ability name or "Base Stats"
     pub icon_url: Option<String>,
     pub changes: Vec<String>,
+    /// Подразделы блока: базовая/усиленная форма, значения для отдельных режимов и т.п.
+    #[serde(default)]
+    pub children: Vec<ChangeBlock>,
+}
+
+impl ChangeBlock {
+    /// Строки изменений блока вместе со всеми вложенными подблоками (в порядке документа).
+    pub fn all_changes(&self) -> Vec<&String> {
+        let mut out: Vec<&String> = self.changes.iter().collect();
+        for child in &self.children {
+            out.extend(child.all_changes());
+        }
+        out
+    }
+
+    /// Сумма трендов строк блока и подблоков.
+    pub fn trend_score(&self) -> i32 {
+        self.all_changes()
+            .into_iter()
+            .map(|c| crate::patch_change_trend::analyze_change_trend(c))
+            .sum()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+impl PatchNoteEntry {
+    /// Сумма трендов строк изменений: > 0 — усиление, < 0 — ослабление.
+    pub fn trend_score(&self) -> i32 {
+        self.details.iter().map(ChangeBlock::trend_score).sum()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, TS)]
+#[ts(export)]
 pub enum LaneRole {
     Top,
     Jungle,
@@ -65,29 +346,143 @@ pub enum LaneRole {
     Unknown,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, TS)]
+#[ts(export)]
 pub enum ChangeType {
     Buff,
     Nerf,
     Adjusted,
+    /// Полная переработка / VGU: набор удалённых и новых эффектов, а не правки чисел
+    Rework,
     New,
     Removed,
     Fix,
+    #[default]
     None,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Масштаб изменения: срез 5 брони и удаление ключевого эффекта различаются,
+/// даже если оба — Nerf. Порядок вариантов задаёт сравнение (Minor < Major).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, TS)]
+#[ts(export)]
+pub enum ChangeGrade {
+    Minor,
+    Moderate,
+    Major,
+}
+
+/// Статья разработчиков Riot (dev blog, «Quick Gameplay Thoughts») — не патч-ноты,
+/// а контекст о планах: показывается в таймлайне сущностей рядом с изменениями.
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
+pub struct DevArticle {
+    pub url: String,
+    pub title: String,
+    pub kind: DevArticleKind,
+    /// "ru" | "en"
+    pub locale: String,
+    pub published_at: Option<DateTime<Utc>>,
+    pub author: Option<String>,
+    pub banner_url: Option<String>,
+    pub sections: Vec<DevArticleSection>,
+    /// entity_id упомянутых сущностей ("champion:Ahri")
+    #[serde(default)]
+    pub mentions: Vec<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TS)]
+#[ts(export)]
+pub enum DevArticleKind {
+    DevBlog,
+    QuickGameplayThoughts,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, TS)]
+#[ts(export)]
+pub struct DevArticleSection {
+    pub heading: Option<String>,
+    pub paragraphs: Vec<String>,
+    pub images: Vec<String>,
+}
+
+impl DevArticle {
+    /// Весь текст статьи (заголовок, подзаголовки, абзацы) — для поиска и упоминаний.
+    pub fn plain_text(&self) -> String {
+        let mut text = self.title.clone();
+        for section in &self.sections {
+            for part in section.heading.iter().chain(section.paragraphs.iter()) {
+                text.push('\n');
+                text.push_str(part);
+            }
+        }
+        text
+    }
+}
+
+/// Сводка патча для обзорного экрана (считается при сохранении, без полного PatchData).
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
+pub struct PatchSummary {
+    pub version: String,
+    pub patch_notes_locale: Option<String>,
+    pub release_date: DateTime<Utc>,
+    pub total_notes: u32,
+    pub by_category: HashMap<PatchCategory, u32>,
+    pub by_change_type: HashMap<ChangeType, u32>,
+    #[serde(default)]
+    pub by_grade: HashMap<ChangeGrade, u32>,
+    /// Число разных чемпионов/предметов/рун, затронутых патчем
+    pub entities_touched: u32,
+    pub biggest_buff: Option<SummaryHighlight>,
+    pub biggest_nerf: Option<SummaryHighlight>,
+    /// Метрики пользовательских скриптов (хук `after_patch_analyzed`): имя скрипта → метрика → значение
+    #[serde(default)]
+    pub script_scores: HashMap<String, HashMap<String, f64>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TS)]
+#[ts(export)]
+pub struct SummaryHighlight {
+    pub note_id: String,
+    pub title: String,
+    pub category: PatchCategory,
+    pub entity_id: Option<String>,
+    /// `PatchNoteEntry::trend_score` записи
+    pub score: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct MetaAnalysisDiff {
     pub champion_name: String,
     pub role: String,
     pub win_rate_diff: f64,
     pub pick_rate_diff: f64,
+    #[serde(default)]
+    pub ban_rate_diff: f64,
     pub predicted_change: Option<String>,
     pub champion_image_url: Option<String>,
+    /// Матчей в текущем / прошлом патче (0 — неизвестно)
+    #[serde(default)]
+    #[ts(type = "number")]
+    pub games_current: u64,
+    #[serde(default)]
+    #[ts(type = "number")]
+    pub games_previous: u64,
+    /// 95% доверительный интервал для win_rate_diff (в процентных пунктах)
+    #[serde(default)]
+    pub win_rate_diff_ci: Option<(f64, f64)>,
+    /// Интервал не содержит 0; false — разница в пределах шума или выборка неизвестна
+    #[serde(default)]
+    pub significant: bool,
+    #[serde(default)]
+    pub champion_meta: Option<ChampionMeta>,
 }
 
 /// Полная строка таблицы ARAM: Mayhem / Augments (League Wiki).
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct MayhemAugmentation {
     pub id: String,
     pub title: String,
@@ -99,21 +494,24 @@ pub struct MayhemAugmentation {
     pub set_icon_url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct IconSourceEntry {
     pub t: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct GameAssetsMeta {
     pub ddragon_version: Option<String>,
     pub cdragon_synced_at: Option<String>,
     pub catalog_built_at: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
 pub struct StaticCatalogRow {
     pub kind: String,
     pub stable_id: String,
@@ -127,7 +525,139 @@ pub struct StaticCatalogRow {
     pub source: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+/// Справочные данные чемпиона из DDragon (хранятся в `static_catalog.cd_meta`, обновляются вместе с каталогом).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, TS)]
+#[ts(export)]
+pub struct ChampionMeta {
+    /// DDragon id ("MonkeyKing")
+    pub id: String,
+    /// Числовой ключ ("62")
+    pub key: String,
+    pub name_en: String,
+    pub name_ru: String,
+    /// Классы DDragon: Fighter, Mage, Assassin, ...
+    pub tags: Vec<String>,
+    /// partype: Mana, Energy, Fury, "None", ...
+    pub resource_type: Option<String>,
+    /// "melee" | "ranged"
+    pub range_type: Option<String>,
+    /// В DDragon отсутствует; заполняется, если источник её отдаёт
+    pub release_date: Option<String>,
+}
+
+/// Дальность атаки, начиная с которой чемпион считается дальнобойным.
+const RANGED_ATTACK_RANGE_MIN: f64 = 325.0;
+
+impl ChampionMeta {
+    pub fn from_catalog_row(row: &StaticCatalogRow) -> Option<Self> {
+        if row.kind != "champion" {
+            return None;
+        }
+        let meta = row.cd_meta.as_ref();
+        let str_field = |k: &str| {
+            meta.and_then(|m| m.get(k))
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        };
+        let tags = meta
+            .and_then(|m| m.get("tags"))
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|t| t.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default();
+        let range_type = meta
+            .and_then(|m| m.get("attackrange"))
+            .and_then(|v| v.as_f64())
+            .map(|r| if r >= RANGED_ATTACK_RANGE_MIN { "ranged" } else { "melee" }.to_string());
+        Some(ChampionMeta {
+            id: row.stable_id.clone(),
+            key: str_field("key").unwrap_or_default(),
+            name_en: row.name_en.clone(),
+            name_ru: row.name_ru.clone(),
+            tags,
+            resource_type: str_field("partype"),
+            range_type,
+            release_date: str_field("release_date"),
+        })
+    }
+}
+
+/// Ссылка на сущность игры в API (история, поиск и т.п.).
+/// `id` — id DDragon (`Ahri`, `3031`, `8112`); имя в любой локали тоже принимается.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, TS)]
+#[ts(export)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum EntityRef {
+    Champion { id: String },
+    Item { id: String },
+    Rune { id: String },
+    /// Системные изменения (объекты карты, поведение, магазин и пр.) — ключ записи или заголовок
+    System { key: String },
+    /// Объект карты или лес (`baron`, `dragons`, `jungle_camps`…) — см. `objectives::Objective`
+    Objective { id: String },
+    /// Система ранкеда или поведения (`duo_queue`, `lp_gains`, `honor`…) — см. `player_systems::PlayerSystem`
+    #[serde(rename = "player_system")]
+    PlayerSystem { id: String },
+}
+
+impl EntityRef {
+    /// Разбор `PatchNoteEntry::entity_id` вида `champion:Ahri`.
+    pub fn from_entity_id(entity_id: &str) -> Option<Self> {
+        let (kind, id) = entity_id.split_once(':')?;
+        let id = id.to_string();
+        match kind {
+            "champion" => Some(EntityRef::Champion { id }),
+            "item" => Some(EntityRef::Item { id }),
+            "rune" => Some(EntityRef::Rune { id }),
+            "system" => Some(EntityRef::System { key: id }),
+            "objective" => Some(EntityRef::Objective { id }),
+            "player_system" => Some(EntityRef::PlayerSystem { id }),
+            _ => None,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        match self {
+            EntityRef::Champion { id }
+            | EntityRef::Item { id }
+            | EntityRef::Rune { id }
+            | EntityRef::Objective { id }
+            | EntityRef::PlayerSystem { id } => id,
+            EntityRef::System { key } => key,
+        }
+    }
+
+    /// Категории патч-нотов, в которых может встречаться сущность.
+    pub fn matches_category(&self, category: &PatchCategory) -> bool {
+        match self {
+            EntityRef::Champion { .. } => *category == PatchCategory::Champions,
+            EntityRef::Item { .. } => {
+                matches!(category, PatchCategory::Items | PatchCategory::ItemsRunes)
+            }
+            EntityRef::Rune { .. } => {
+                matches!(category, PatchCategory::Runes | PatchCategory::ItemsRunes)
+            }
+            EntityRef::System { .. } => !matches!(
+                category,
+                PatchCategory::Champions
+                    | PatchCategory::Items
+                    | PatchCategory::Runes
+                    | PatchCategory::ItemsRunes
+            ),
+            EntityRef::Objective { .. } => matches!(
+                category,
+                PatchCategory::Objectives | PatchCategory::Systems | PatchCategory::NewContent | PatchCategory::Unknown
+            ),
+            EntityRef::PlayerSystem { .. } => matches!(
+                category,
+                PatchCategory::Ranked | PatchCategory::Behavioral | PatchCategory::Systems
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, TS)]
+#[ts(export)]
 pub enum PatchCategory {
     Champions,
     Items,
@@ -138,12 +668,35 @@ pub enum PatchCategory {
     ModeAram,
     ModeArena,
     Modes,
+    /// Лес и эпические монстры (драконы, Барон, Атахан, личинки)
+    Objectives,
+    /// Ранкед: LP, ограничения дуо, калибровка, подбор игроков
+    Ranked,
+    /// Поведение игроков, наказания, Honor
+    Behavioral,
+    /// Магазин, бандлы, пропуски
+    Store,
     Skins,
     Systems,
     BugFixes,
     NewContent,
     Cosmetics,
     UpcomingSkinsChromas,
+    #[default]
     Unknown,
 }
 
+impl PatchCategory {
+    /// Изменения, затрагивающие только отдельные режимы (не баланс Ущелья).
+    pub fn is_mode_only(&self) -> bool {
+        matches!(
+            self,
+            PatchCategory::ModeAramChaos
+                | PatchCategory::ModeAramAugments
+                | PatchCategory::ModeAram
+                | PatchCategory::ModeArena
+                | PatchCategory::Modes
+        )
+    }
+}
+
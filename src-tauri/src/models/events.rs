@@ -0,0 +1,187 @@
+//! Каталог событий backend -> frontend: имя канала и payload каждого события в одном месте.
+//! Отправлять только через `emit_event` (lib.rs), а не `app.emit("…")` со строкой.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Событие с фиксированным именем канала.
+pub trait AppEvent: Serialize + Clone {
+    const NAME: &'static str;
+}
+
+/// Уровень записи журнала; порядок — по возрастанию важности (для фильтра).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, TS)]
+#[serde(rename_all = "UPPERCASE")]
+#[ts(export)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Success,
+    Warn,
+    Error,
+}
+
+/// Подсистема, написавшая запись.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, TS)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[ts(export)]
+pub enum LogSource {
+    App,
+    Update,
+    Scraper,
+    Sync,
+    Import,
+    Augments,
+    Cache,
+    Db,
+    Feed,
+    Api,
+    Overlay,
+    Maintenance,
+    Scripts,
+}
+
+/// Строка журнала: уровень, источник и поля контекста (версия, адрес и т.п.).
+#[derive(Debug, Serialize, Deserialize, Clone, TS)]
+#[ts(export)]
+pub struct LogEvent {
+    pub level: LogLevel,
+    pub source: LogSource,
+    pub message: String,
+    pub fields: BTreeMap<String, String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl AppEvent for LogEvent {
+    const NAME: &'static str = "log_message";
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum SyncScope {
+    /// Последние патчи (`sync_patch_history`)
+    History,
+    /// Ретроспектива до 100 патчей (`sync_previous_patch_history_to_limit`)
+    Previous,
+}
+
+/// Прогресс синхронизации: шлётся до начала и после каждой версии.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct SyncProgressEvent {
+    pub scope: SyncScope,
+    /// Пусто для стартового события
+    pub version: String,
+    #[ts(type = "number")]
+    pub processed: usize,
+    #[ts(type = "number")]
+    pub total: usize,
+    #[ts(type = "number")]
+    pub downloaded: usize,
+    #[ts(type = "number")]
+    pub skipped: usize,
+    pub saved: bool,
+}
+
+impl AppEvent for SyncProgressEvent {
+    const NAME: &'static str = "sync_progress";
+}
+
+/// В кэш сохранён патч, которого там раньше не было.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct NewPatchEvent {
+    pub version: String,
+    pub patch_notes_locale: String,
+}
+
+impl AppEvent for NewPatchEvent {
+    const NAME: &'static str = "new_patch";
+}
+
+/// В новом патче есть изменения отслеживаемых сущностей (`watchlist`).
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct WatchlistAlertEvent {
+    pub version: String,
+    pub hits: Vec<crate::watchlist::WatchHit>,
+}
+
+impl AppEvent for WatchlistAlertEvent {
+    const NAME: &'static str = "watchlist_alert";
+}
+
+/// Статью патча исправили после публикации: сохранена новая версия, прежняя — в `patch_revisions`.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct PatchNotesUpdatedEvent {
+    pub revision: crate::patch_revisions::PatchRevision,
+}
+
+impl AppEvent for PatchNotesUpdatedEvent {
+    const NAME: &'static str = "patch_notes_updated";
+}
+
+/// Ротация магазина или пропуск события заканчивается в ближайшие дни.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct StoreRotationEndingEvent {
+    pub rotation: crate::store_rotations::StoreRotation,
+}
+
+impl AppEvent for StoreRotationEndingEvent {
+    const NAME: &'static str = "store_rotation_ending";
+}
+
+/// Новые инциденты или технические работы на серверах (или их окончание: `new_issues` пуст).
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct ServerStatusChangedEvent {
+    pub status: crate::riot_api::PlatformStatus,
+    pub new_issues: Vec<crate::riot_api::StatusIssue>,
+}
+
+impl AppEvent for ServerStatusChangedEvent {
+    const NAME: &'static str = "server_status_changed";
+}
+
+/// Сохранённые патчи изменились: тир-лист, история и сводки стоит перезапросить.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct StatsUpdatedEvent {
+    pub versions: Vec<String>,
+}
+
+impl AppEvent for StatsUpdatedEvent {
+    const NAME: &'static str = "stats_updated";
+}
+
+/// Старт backend завершён: БД и скрейпер под управлением Tauri, команды доступны.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct AppReadyEvent {
+    /// Текст ошибки, если БД открыть не удалось (команды тогда недоступны)
+    pub error: Option<String>,
+}
+
+impl AppEvent for AppReadyEvent {
+    const NAME: &'static str = "app_ready";
+}
+
+/// Статья патча скачана, но её вёрстку не узнал ни один парсер (`page_layout`): патч не сохранён.
+#[derive(Debug, Serialize, Clone, TS)]
+#[ts(export)]
+pub struct UnsupportedLayoutEvent {
+    pub version: String,
+    pub patch_notes_locale: String,
+    /// Страницы с нераспознанной вёрсткой
+    pub urls: Vec<String>,
+}
+
+impl AppEvent for UnsupportedLayoutEvent {
+    const NAME: &'static str = "unsupported_layout";
+}
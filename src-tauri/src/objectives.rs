@@ -0,0 +1,274 @@
+//! Объекты карты и лес как отдельные сущности: Барон, драконы, Герольд, личинки Бездны, Атахан,
+//! лесные лагеря, краб и башни. Записи патч-нотов о них обычно общие («Лес», «Объекты карты»),
+//! поэтому изменения разбираются по заголовкам блоков и строкам: строка, где объект назван,
+//! относится к нему, остальные — к объекту из заголовка блока или записи. У каждого объекта своя
+//! история (`get_objective_history`, `EntityRef::Objective`).
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{ChangeBlock, PatchCategory, PatchNoteEntry};
+use crate::ChampionHistoryEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum Objective {
+    Baron,
+    Dragons,
+    ElderDragon,
+    RiftHerald,
+    VoidGrubs,
+    Atakhan,
+    JungleCamps,
+    ScuttleCrab,
+    Turrets,
+}
+
+/// Объект со строками названий для списка в интерфейсе.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ObjectiveInfo {
+    pub objective: Objective,
+    /// Ключ для `EntityRef::Objective`
+    pub id: String,
+    pub name_en: String,
+    pub name_ru: String,
+}
+
+/// Категории, где встречаются изменения объектов.
+const CATEGORIES: [PatchCategory; 4] = [
+    PatchCategory::Objectives,
+    PatchCategory::Systems,
+    PatchCategory::NewContent,
+    PatchCategory::Unknown,
+];
+
+impl Objective {
+    pub const ALL: [Objective; 9] = [
+        Objective::Baron,
+        Objective::Dragons,
+        Objective::ElderDragon,
+        Objective::RiftHerald,
+        Objective::VoidGrubs,
+        Objective::Atakhan,
+        Objective::JungleCamps,
+        Objective::ScuttleCrab,
+        Objective::Turrets,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Objective::Baron => "baron",
+            Objective::Dragons => "dragons",
+            Objective::ElderDragon => "elder_dragon",
+            Objective::RiftHerald => "rift_herald",
+            Objective::VoidGrubs => "void_grubs",
+            Objective::Atakhan => "atakhan",
+            Objective::JungleCamps => "jungle_camps",
+            Objective::ScuttleCrab => "scuttle_crab",
+            Objective::Turrets => "turrets",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|o| o.id().eq_ignore_ascii_case(id.trim()))
+    }
+
+    fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Objective::Baron => ("Baron Nashor", "Барон Нашор"),
+            Objective::Dragons => ("Dragons", "Драконы"),
+            Objective::ElderDragon => ("Elder Dragon", "Древний дракон"),
+            Objective::RiftHerald => ("Rift Herald", "Герольд Ущелья"),
+            Objective::VoidGrubs => ("Voidgrubs", "Личинки Бездны"),
+            Objective::Atakhan => ("Atakhan", "Атахан"),
+            Objective::JungleCamps => ("Jungle camps", "Лесные лагеря"),
+            Objective::ScuttleCrab => ("Scuttle Crab", "Речной краб"),
+            Objective::Turrets => ("Turrets", "Башни"),
+        }
+    }
+
+    /// Подстроки в нижнем регистре (английский и русский текст патч-нотов).
+    fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Objective::Baron => &["baron", "nashor", "барон", "нашор"],
+            Objective::Dragons => &["dragon", "drake", "дракон"],
+            Objective::ElderDragon => &["elder", "древн"],
+            Objective::RiftHerald => &["herald", "герольд"],
+            Objective::VoidGrubs => &["voidgrub", "void grub", "grubs", "личинк"],
+            Objective::Atakhan => &["atakhan", "атахан"],
+            Objective::JungleCamps => &[
+                "jungle camp", "camps", "gromp", "krug", "raptor", "murk wol", "murkwol", "blue sentinel",
+                "red brambleback", "лесные лагеря", "лесных лагер", "лагер", "громп", "круги", "крюги", "хищн",
+                "волк", "синий часовой", "красный древень",
+            ],
+            Objective::ScuttleCrab => &["scuttle", "краб"],
+            Objective::Turrets => &["turret", "tower", "plating", "башн", "турел", "пластин"],
+        }
+    }
+
+    pub fn info(self) -> ObjectiveInfo {
+        let (name_en, name_ru) = self.names();
+        ObjectiveInfo {
+            objective: self,
+            id: self.id().to_string(),
+            name_en: name_en.to_string(),
+            name_ru: name_ru.to_string(),
+        }
+    }
+}
+
+/// Объекты, названные в тексте. Древний дракон не считается правкой обычных драконов.
+fn mentioned(text: &str) -> Vec<Objective> {
+    let lower = text.to_lowercase();
+    let has = |o: Objective| o.keywords().iter().any(|k| lower.contains(k));
+    let elder = has(Objective::ElderDragon) && has(Objective::Dragons);
+    Objective::ALL
+        .into_iter()
+        .filter(|&o| match o {
+            Objective::ElderDragon => elder,
+            Objective::Dragons => !elder && has(o),
+            _ => has(o),
+        })
+        .collect()
+}
+
+/// Блоки записи без вложенности: подблоки идут следом за родителем и наследуют его темы.
+fn flatten<'a, T: Copy>(
+    blocks: &'a [ChangeBlock],
+    context: &[T],
+    mentioned: &impl Fn(&str) -> Vec<T>,
+    out: &mut Vec<(&'a ChangeBlock, Vec<T>)>,
+) {
+    for block in blocks {
+        let own = block.title.as_deref().map(mentioned).unwrap_or_default();
+        let block_context = if own.is_empty() { context.to_vec() } else { own };
+        out.push((block, block_context.clone()));
+        flatten(&block.children, &block_context, mentioned, out);
+    }
+}
+
+/// Делит запись по темам (объектам карты, системам ранкеда): строка, где тема названа, относится к
+/// ней, остальные — к темам заголовка блока или записи. Для каждой темы — копия записи только с её
+/// строками и `entity_id` из `entity_id`.
+pub(crate) fn split_note<T: Copy + Ord>(
+    note: &PatchNoteEntry,
+    mentioned: impl Fn(&str) -> Vec<T>,
+    entity_id: impl Fn(T) -> String,
+) -> Vec<(T, PatchNoteEntry)> {
+    let title_topics = mentioned(&note.title);
+    let mut flat = Vec::new();
+    flatten(&note.details, &title_topics, &mentioned, &mut flat);
+
+    let mut blocks: BTreeMap<T, Vec<ChangeBlock>> = BTreeMap::new();
+    for (block, context) in flat {
+        let mut lines: BTreeMap<T, Vec<String>> = BTreeMap::new();
+        for line in &block.changes {
+            let named = mentioned(line);
+            for topic in if named.is_empty() { &context } else { &named } {
+                lines.entry(*topic).or_default().push(line.clone());
+            }
+        }
+        for (topic, changes) in lines {
+            blocks.entry(topic).or_default().push(ChangeBlock {
+                title: block.title.clone(),
+                icon_url: block.icon_url.clone(),
+                changes,
+                children: vec![],
+            });
+        }
+    }
+    for topic in &title_topics {
+        blocks.entry(*topic).or_default();
+    }
+
+    blocks
+        .into_iter()
+        .map(|(topic, details)| {
+            let mut entry = note.clone();
+            if !title_topics.contains(&topic) {
+                entry.summary = String::new();
+            }
+            entry.details = details;
+            entry.entity_id = Some(entity_id(topic));
+            entry.raw_html = None;
+            entry.grade = crate::patch_change_trend::note_grade(&entry);
+            (topic, entry)
+        })
+        .collect()
+}
+
+/// Записи по объектам: для каждого объекта — копия записи только с относящимися к нему строками.
+pub fn extract(note: &PatchNoteEntry) -> Vec<(Objective, PatchNoteEntry)> {
+    if !CATEGORIES.contains(&note.category) {
+        return Vec::new();
+    }
+    split_note(note, mentioned, |o| format!("objective:{}", o.id()))
+}
+
+/// Затрагивает ли запись объект `objective`.
+pub fn mentions(note: &PatchNoteEntry, objective: Objective) -> bool {
+    extract(note).iter().any(|(o, _)| *o == objective)
+}
+
+/// История объекта по сохранённым патчам, от старых к новым.
+pub async fn history(db: &Database, id: &str, split: Option<&str>) -> Result<Vec<ChampionHistoryEntry>> {
+    let objective = Objective::from_id(id).ok_or_else(|| anyhow!("unknown objective {}", id))?;
+    let notes = db.get_category_history(&CATEGORIES, split).await?;
+    Ok(notes
+        .into_iter()
+        .flat_map(|entry| {
+            extract(&entry.change)
+                .into_iter()
+                .filter(|(o, _)| *o == objective)
+                .map(|(_, note)| ChampionHistoryEntry::new(entry.patch_version.clone(), entry.date, entry.split.clone(), note))
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ChangeType;
+
+    #[test]
+    fn splits_jungle_note_into_objectives() {
+        let block = |title: &str, changes: &[&str]| ChangeBlock {
+            title: Some(title.into()),
+            icon_url: None,
+            changes: changes.iter().map(|s| s.to_string()).collect(),
+            children: vec![],
+        };
+        let note = PatchNoteEntry {
+            id: "patch-jungle".into(),
+            title: "Jungle".into(),
+            category: PatchCategory::Objectives,
+            change_type: ChangeType::Adjusted,
+            summary: "Objectives are worth more.".into(),
+            details: vec![
+                block("Baron Nashor", &["Health: 9000 ⇒ 9500", "Elder Dragon execute threshold: 20% ⇒ 25%"]),
+                block("Epic Monsters", &["Dragon gold: 100 ⇒ 150", "Voidgrubs spawn: 5:00 ⇒ 6:00"]),
+                block("Camps", &["Gromp health: 2050 ⇒ 2200"]),
+            ],
+            ..Default::default()
+        };
+        let by_objective: BTreeMap<Objective, PatchNoteEntry> = extract(&note).into_iter().collect();
+        assert_eq!(
+            by_objective.keys().copied().collect::<Vec<_>>(),
+            [Objective::Baron, Objective::Dragons, Objective::ElderDragon, Objective::VoidGrubs, Objective::JungleCamps]
+        );
+        let baron = &by_objective[&Objective::Baron];
+        assert_eq!(baron.details[0].changes, ["Health: 9000 ⇒ 9500"]);
+        assert_eq!(baron.entity_id.as_deref(), Some("objective:baron"));
+        assert!(baron.summary.is_empty());
+        assert_eq!(by_objective[&Objective::ElderDragon].details[0].title.as_deref(), Some("Baron Nashor"));
+        assert_eq!(by_objective[&Objective::JungleCamps].details[0].changes, ["Gromp health: 2050 ⇒ 2200"]);
+        assert_eq!(Objective::from_id("Rift_Herald"), Some(Objective::RiftHerald));
+    }
+}
@@ -0,0 +1,175 @@
+//! Данные для оверлея стрима (браузерный источник OBS): компактные «главное в текущем патче» —
+//! версия, дни с выхода и по 5 самых заметных усилений и ослаблений с иконками. Отдаются локальным
+//! API (`/api/overlay`) или пишутся в `<app_data>/overlay.json`, если включена запись в файл.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
+use crate::models::{ChangeType, PatchCategory, PatchData, PatchNoteEntry};
+
+pub const OVERLAY_FILE_NAME: &str = "overlay.json";
+const TOP_CHANGES: usize = 5;
+/// Файл переписывается не реже: счётчик дней с выхода должен обновляться без новых патчей.
+const FILE_REFRESH: Duration = Duration::from_secs(5 * 60);
+
+static FILE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct OverlayChange {
+    pub title: String,
+    pub category: PatchCategory,
+    /// `PatchNoteEntry::trend_score` записи
+    pub score: i32,
+    pub icon_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct OverlayHighlights {
+    pub version: String,
+    pub released_at: DateTime<Utc>,
+    pub days_since_release: i64,
+    pub buffs: Vec<OverlayChange>,
+    pub nerfs: Vec<OverlayChange>,
+    pub generated_at: DateTime<Utc>,
+}
+
+fn note_icon(note: &PatchNoteEntry) -> Option<&str> {
+    note.image_url
+        .as_deref()
+        .or_else(|| note.icon_candidates.as_ref().and_then(|c| c.first()).map(String::as_str))
+        .or_else(|| note.details.iter().find_map(|b| b.icon_url.as_deref()))
+}
+
+/// `icon` переводит адрес картинки из патча (удалённый или путь в кэше) в адрес для оверлея.
+pub fn build(patch: &PatchData, now: DateTime<Utc>, icon: impl Fn(&str) -> Option<String>) -> OverlayHighlights {
+    let mut scored: Vec<(&PatchNoteEntry, i32)> = patch
+        .patch_notes
+        .iter()
+        .filter(|n| !n.category.is_mode_only())
+        .map(|n| (n, n.trend_score()))
+        .collect();
+    let to_change = |(note, score): &(&PatchNoteEntry, i32)| OverlayChange {
+        title: note.title.clone(),
+        category: note.category.clone(),
+        score: *score,
+        icon_url: note_icon(note).and_then(&icon),
+    };
+
+    scored.sort_by_key(|(_, s)| std::cmp::Reverse(*s));
+    let buffs = scored
+        .iter()
+        .filter(|(n, s)| n.change_type == ChangeType::Buff && *s > 0)
+        .take(TOP_CHANGES)
+        .map(to_change)
+        .collect();
+    let nerfs = scored
+        .iter()
+        .rev()
+        .filter(|(n, s)| n.change_type == ChangeType::Nerf && *s < 0)
+        .take(TOP_CHANGES)
+        .map(to_change)
+        .collect();
+
+    let released_at = patch.release_date();
+    OverlayHighlights {
+        version: patch.version.clone(),
+        released_at,
+        days_since_release: (now - released_at).num_days().max(0),
+        buffs,
+        nerfs,
+        generated_at: now,
+    }
+}
+
+/// Главное в последнем по номеру патче; `None` — патчей в кэше нет.
+pub async fn current(db: &Database, icon: impl Fn(&str) -> Option<String>) -> anyhow::Result<Option<OverlayHighlights>> {
+    let Some(patch) = db.get_patches_newest_versions_first(1).await?.into_iter().next() else {
+        return Ok(None);
+    };
+    Ok(Some(build(&patch, Utc::now(), icon)))
+}
+
+/// Для файла: картинки из кэша — `file:///`, их открывает и браузерный источник OBS с локальным файлом.
+fn file_icon(url: &str) -> Option<String> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        return Some(url.to_string());
+    }
+    let path = url.replace('\\', "/");
+    Some(format!("file:///{}", path.trim_start_matches('/')))
+}
+
+pub async fn write_file(db: &Database, path: &Path) -> anyhow::Result<()> {
+    let highlights = current(db, file_icon).await?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec_pretty(&highlights)?)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+pub fn set_file_enabled(enabled: bool) {
+    FILE_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Фоновая перезапись файла, пока она включена.
+pub async fn run_file_loop(db: Arc<Database>, path: std::path::PathBuf) {
+    let mut interval = tokio::time::interval(FILE_REFRESH);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        if !FILE_ENABLED.load(Ordering::Relaxed) {
+            continue;
+        }
+        if let Err(e) = write_file(&db, &path).await {
+            logger::log(LogLevel::Warn, LogSource::Overlay, &format!("overlay file: {:#}", e));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{note, patch};
+
+    #[test]
+    fn strongest_changes_first_with_mapped_icons() {
+        let released = DateTime::parse_from_rfc3339("2025-11-04T18:00:00Z").unwrap().with_timezone(&Utc);
+        let champion = PatchCategory::Champions;
+        let patch = PatchData {
+            fetched_at: released,
+            published_at: Some(released),
+            ..patch(
+                "25.22",
+                vec![
+                    PatchNoteEntry {
+                        image_url: Some("/cache/ahri.png".into()),
+                        ..note("Ahri", champion.clone(), ChangeType::Buff, &["Damage: 50 → 60"])
+                    },
+                    note("Zed", champion.clone(), ChangeType::Buff, &["Damage: 50 → 60", "Armor: 30 → 35"]),
+                    note("Yasuo", champion, ChangeType::Nerf, &["Damage: 60 → 50"]),
+                ],
+            )
+        };
+        let now = released + chrono::Duration::days(3) + chrono::Duration::hours(2);
+        let overlay = build(&patch, now, |u| Some(format!("http://host/api/assets{}", u)));
+        assert_eq!(overlay.days_since_release, 3);
+        let buffs: Vec<&str> = overlay.buffs.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(buffs, ["Zed", "Ahri"]);
+        assert_eq!(overlay.buffs[1].icon_url.as_deref(), Some("http://host/api/assets/cache/ahri.png"));
+        assert_eq!(overlay.nerfs[0].title, "Yasuo");
+        assert_eq!(file_icon(r"C:\data\a.png").as_deref(), Some("file:///C:/data/a.png"));
+    }
+}
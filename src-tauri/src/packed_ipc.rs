@@ -0,0 +1,48 @@
+//! Двоичный ответ тяжёлых команд: MessagePack вместо JSON-строки через мост Tauri.
+//! Включается аргументом `encoding: "msgpack"`; без него ответ прежний, JSON.
+
+use serde::{Deserialize, Serialize};
+use tauri::ipc::Response;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum IpcEncoding {
+    #[default]
+    Json,
+    Msgpack,
+}
+
+/// Сериализует ответ в запрошенном формате; MessagePack с именами полей, как в JSON.
+pub fn respond<T: Serialize>(value: &T, encoding: Option<IpcEncoding>) -> Result<Response, String> {
+    match encoding.unwrap_or_default() {
+        IpcEncoding::Json => serde_json::to_string(value)
+            .map(Response::new)
+            .map_err(|e| e.to_string()),
+        IpcEncoding::Msgpack => rmp_serde::to_vec_named(value)
+            .map(Response::new)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ChampionStats, LaneRole};
+    use crate::test_fixtures::champion;
+
+    #[test]
+    fn msgpack_round_trips_models_with_field_names() {
+        let stats = ChampionStats {
+            name: "Ахри".into(),
+            tier: "S".into(),
+            ban_rate: 3.5,
+            total_matches: 12_000,
+            role_share: 0.9,
+            ..champion("Ahri", LaneRole::Mid, 51.2)
+        };
+        let bytes = rmp_serde::to_vec_named(&stats).unwrap();
+        let back: serde_json::Value = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(back, serde_json::to_value(&stats).unwrap());
+    }
+}
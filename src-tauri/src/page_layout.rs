@@ -0,0 +1,71 @@
+//! Поколение вёрстки статьи патча. По нему `Scraper::parse_riot_patch_notes_html` выбирает парсер.
+//! Нераспознанная вёрстка — ошибка и событие `UnsupportedLayoutEvent`, а не пустой патч.
+
+use scraper::{ElementRef, Html, Selector};
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PageLayout {
+    /// `#patch-notes-container` с разделами h2 (`header-primary`) и блоками `.patch-change-block`
+    Current,
+    /// Текст статьи без контейнера: разделы h2, записи h3/h4, изменения списками
+    Legacy,
+    /// Ни одна известная разметка не подошла (например, новый редизайн сайта)
+    Unsupported,
+}
+
+/// Тело статьи старой вёрстки, в порядке предпочтения.
+const LEGACY_BODY_SELECTORS: &[&str] = &["[data-testid='rich-text-html']", ".article-content", "article"];
+
+/// Контейнер текущей вёрстки, если в нём есть разделы h2.
+pub fn current_container(document: &Html) -> Option<ElementRef<'_>> {
+    let container_sel = Selector::parse("#patch-notes-container").ok()?;
+    let h2_sel = Selector::parse("h2").ok()?;
+    document
+        .select(&container_sel)
+        .next()
+        .filter(|c| c.select(&h2_sel).next().is_some())
+}
+
+/// Тело статьи старой вёрстки: первый кандидат с разделами h2 и списками изменений.
+pub fn legacy_body(document: &Html) -> Option<ElementRef<'_>> {
+    let h2_sel = Selector::parse("h2").ok()?;
+    let li_sel = Selector::parse("li").ok()?;
+    LEGACY_BODY_SELECTORS.iter().find_map(|s| {
+        let sel = Selector::parse(s).ok()?;
+        document
+            .select(&sel)
+            .find(|body| body.select(&h2_sel).next().is_some() && body.select(&li_sel).next().is_some())
+    })
+}
+
+pub fn detect(document: &Html) -> PageLayout {
+    if current_container(document).is_some() {
+        PageLayout::Current
+    } else if legacy_body(document).is_some() {
+        PageLayout::Legacy
+    } else {
+        PageLayout::Unsupported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_current_legacy_and_unsupported_layouts() {
+        let current = r#"<div id="patch-notes-container"><header class="header-primary"><h2 id="patch-champions">Champions</h2></header></div>"#;
+        let legacy = r#"<article><h2>Champions</h2><h3>Ahri</h3><ul><li>Q damage: 40 ⇒ 50</li></ul></article>"#;
+        let empty_container = r#"<div id="patch-notes-container"><p>Coming soon</p></div>"#;
+        let redesign = r#"<main><section class="patch-grid"><div class="card">Ahri</div></section></main>"#;
+
+        assert_eq!(detect(&Html::parse_document(current)), PageLayout::Current);
+        assert_eq!(detect(&Html::parse_document(legacy)), PageLayout::Legacy);
+        assert_eq!(detect(&Html::parse_document(empty_container)), PageLayout::Unsupported);
+        assert_eq!(detect(&Html::parse_document(redesign)), PageLayout::Unsupported);
+    }
+}
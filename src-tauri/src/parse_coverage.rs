@@ -0,0 +1,149 @@
+//! Покрытие пробного разбора патча (`validate_parse`): сколько записей найдено по категориям, какие
+//! остались без блоков изменений и картинок, какие разделы статьи парсер не узнал. Ничего не сохраняется —
+//! отчёт нужен, чтобы проверить парсер после изменений вёрстки Riot.
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::{PatchCategory, PatchNoteEntry};
+use crate::page_layout::PageLayout;
+
+/// Раздел статьи (заголовок h2) и категория, которую ему присвоил парсер.
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[ts(export)]
+pub struct ParsedSection {
+    pub id: String,
+    pub title: String,
+    pub category: PatchCategory,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CategoryCoverage {
+    pub category: PatchCategory,
+    pub entries: u32,
+    /// Записи без блоков изменений
+    pub empty_entries: u32,
+    /// Записи с найденной картинкой
+    pub images_resolved: u32,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ParseCoverage {
+    pub version: String,
+    pub locale: String,
+    /// Адрес разобранной статьи
+    pub url: String,
+    /// Какой вёрсткой парсер разбирал статью
+    pub layout: PageLayout,
+    pub total_entries: u32,
+    pub images_resolved: u32,
+    /// По категориям в порядке первой записи
+    pub categories: Vec<CategoryCoverage>,
+    /// Заголовки записей без блоков изменений
+    pub empty_entries: Vec<String>,
+    pub sections: Vec<ParsedSection>,
+    /// Разделы, которым парсер не нашёл категорию: «заголовок (#id)»
+    pub unknown_headings: Vec<String>,
+}
+
+/// Считает покрытие по разобранным записям и разделам статьи.
+pub fn measure(
+    version: &str,
+    locale: &str,
+    url: &str,
+    layout: PageLayout,
+    notes: &[PatchNoteEntry],
+    sections: Vec<ParsedSection>,
+) -> ParseCoverage {
+    let mut categories: Vec<CategoryCoverage> = Vec::new();
+    let mut empty_entries = Vec::new();
+    for note in notes {
+        let idx = match categories.iter().position(|c| c.category == note.category) {
+            Some(i) => i,
+            None => {
+                categories.push(CategoryCoverage {
+                    category: note.category.clone(),
+                    entries: 0,
+                    empty_entries: 0,
+                    images_resolved: 0,
+                });
+                categories.len() - 1
+            }
+        };
+        let c = &mut categories[idx];
+        c.entries += 1;
+        if note.details.iter().all(|b| b.changes.is_empty() && b.children.is_empty()) {
+            c.empty_entries += 1;
+            empty_entries.push(note.title.clone());
+        }
+        if note.image_url.is_some() {
+            c.images_resolved += 1;
+        }
+    }
+    let unknown_headings = sections
+        .iter()
+        .filter(|s| s.category == PatchCategory::Unknown)
+        .map(|s| format!("{} (#{})", s.title, s.id))
+        .collect();
+    ParseCoverage {
+        version: version.to_string(),
+        locale: locale.to_string(),
+        url: url.to_string(),
+        layout,
+        total_entries: notes.len() as u32,
+        images_resolved: categories.iter().map(|c| c.images_resolved).sum(),
+        categories,
+        empty_entries,
+        sections,
+        unknown_headings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::scraper::{patch_note_sections, Scraper};
+
+    #[test]
+    fn reports_categories_empty_entries_and_unknown_headings() {
+        let html = r###"<div id="patch-notes-container">
+<header class="header-primary"><h2 id="patch-champions">Champions</h2></header>
+<div class="content-border"><div class="patch-change-block white-stone"><div>
+<a class="reference-link" href="#"><img src="https://cdn.example.com/ahri.png"></a>
+<h3 class="change-title">Ahri</h3>
+<ul><li>Q damage: 40 ⇒ 50</li></ul>
+</div></div></div>
+<div class="content-border"><div class="patch-change-block white-stone"><div>
+<h3 class="change-title">Zed</h3>
+<p>Zed is getting a small adjustment.</p>
+</div></div></div>
+<header class="header-primary"><h2 id="patch-brand-new-thing">Brand New Thing</h2></header>
+<div class="content-border"><div class="patch-change-block white-stone"><div>
+<h3 class="change-title">Something</h3>
+<ul><li>It exists</li></ul>
+</div></div></div>
+</div>"###;
+        let s = Scraper::new().unwrap();
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        let report = measure(
+            "25.10",
+            "en",
+            "https://example.com",
+            PageLayout::Current,
+            &notes,
+            patch_note_sections(html, &HashSet::new()),
+        );
+
+        assert_eq!(report.total_entries, 3);
+        let champions = &report.categories[0];
+        assert_eq!(champions.category, PatchCategory::Champions);
+        assert_eq!((champions.entries, champions.empty_entries), (2, 1));
+        assert_eq!(report.empty_entries, ["Zed"]);
+        assert_eq!(report.sections.len(), 2);
+        assert_eq!(report.unknown_headings, ["Brand New Thing (#patch-brand-new-thing)"]);
+    }
+}
@@ -0,0 +1,212 @@
+//! LRU разобранных `PatchData` в пределах бюджета памяти: история и тир-лист раз за разом
+//! читают одни и те же data_json.
+//! Ключ — (версия, локаль, fetched_at): пересохранённый патч получает новый fetched_at,
+//! так что устаревшая запись не совпадёт, даже если её забыли сбросить.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::PatchData;
+
+/// Бюджет памяти по умолчанию: окно тир-листа × две локали с запасом.
+pub const DEFAULT_PATCH_CACHE_BUDGET_BYTES: usize = 64 * 1024 * 1024;
+
+/// Разобранный `PatchData` занимает в памяти больше своего data_json: строки, Vec и Option по отдельности.
+const PARSED_SIZE_FACTOR: usize = 2;
+
+type PatchKey = (String, String, String);
+
+struct CachedPatch {
+    patch: Arc<PatchData>,
+    /// Счётчик обращений: у вытесняемой записи он наименьший
+    used: u64,
+    bytes: usize,
+}
+
+/// Снимок состояния LRU для `get_cache_stats`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PatchCacheStats {
+    pub entries: usize,
+    /// Оценка занятой памяти (по длине data_json)
+    #[ts(type = "number")]
+    pub bytes: usize,
+    #[ts(type = "number")]
+    pub budget_bytes: usize,
+    #[ts(type = "number")]
+    pub hits: u64,
+    #[ts(type = "number")]
+    pub misses: u64,
+    #[ts(type = "number")]
+    pub evictions: u64,
+    /// Доля попаданий, 0..1; 0 до первого обращения
+    pub hit_rate: f64,
+}
+
+pub struct PatchCache {
+    budget_bytes: usize,
+    bytes: usize,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    entries: HashMap<PatchKey, CachedPatch>,
+}
+
+impl PatchCache {
+    pub fn new(budget_bytes: usize) -> Self {
+        Self {
+            budget_bytes,
+            bytes: 0,
+            tick: 0,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Оценка памяти разобранного патча по длине его data_json.
+    pub fn estimate_bytes(data_json_len: usize) -> usize {
+        data_json_len.saturating_mul(PARSED_SIZE_FACTOR)
+    }
+
+    pub fn get(&mut self, version: &str, locale: &str, fetched_at: &str) -> Option<Arc<PatchData>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let key = (version.to_string(), locale.to_string(), fetched_at.to_string());
+        match self.entries.get_mut(&key) {
+            Some(entry) => {
+                self.hits += 1;
+                entry.used = tick;
+                Some(entry.patch.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Кладёт патч, вытесняя самые давние записи до укладки в бюджет.
+    /// Патч крупнее всего бюджета не кэшируется.
+    pub fn insert(&mut self, version: &str, locale: &str, fetched_at: &str, patch: Arc<PatchData>, bytes: usize) {
+        self.invalidate(version, locale);
+        if bytes > self.budget_bytes {
+            return;
+        }
+        self.evict_to(self.budget_bytes - bytes);
+        self.tick += 1;
+        self.bytes += bytes;
+        self.entries.insert(
+            (version.to_string(), locale.to_string(), fetched_at.to_string()),
+            CachedPatch {
+                patch,
+                used: self.tick,
+                bytes,
+            },
+        );
+    }
+
+    /// Меняет бюджет; лишнее вытесняется сразу.
+    pub fn set_budget(&mut self, budget_bytes: usize) {
+        self.budget_bytes = budget_bytes;
+        self.evict_to(budget_bytes);
+    }
+
+    fn evict_to(&mut self, limit: usize) {
+        while self.bytes > limit {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.used)
+                .map(|(k, _)| k.clone())
+            else {
+                break;
+            };
+            if let Some(entry) = self.entries.remove(&oldest) {
+                self.bytes -= entry.bytes;
+                self.evictions += 1;
+            }
+        }
+    }
+
+    /// Сбрасывает все записи версии в локали (после сохранения патча).
+    pub fn invalidate(&mut self, version: &str, locale: &str) {
+        let mut freed = 0;
+        self.entries.retain(|(v, l, _), e| {
+            let keep = v != version || l != locale;
+            if !keep {
+                freed += e.bytes;
+            }
+            keep
+        });
+        self.bytes -= freed;
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.bytes = 0;
+    }
+
+    pub fn stats(&self) -> PatchCacheStats {
+        let lookups = self.hits + self.misses;
+        PatchCacheStats {
+            entries: self.entries.len(),
+            bytes: self.bytes,
+            budget_bytes: self.budget_bytes,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            hit_rate: if lookups == 0 { 0.0 } else { self.hits as f64 / lookups as f64 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::patch;
+
+    #[test]
+    fn evicts_least_recently_used_and_invalidates_on_save() {
+        let mut cache = PatchCache::new(200);
+        cache.insert("25.1", "ru", "t1", Arc::new(patch("25.1", vec![])), 100);
+        cache.insert("25.2", "ru", "t1", Arc::new(patch("25.2", vec![])), 100);
+        assert!(cache.get("25.1", "ru", "t1").is_some());
+        cache.insert("25.3", "ru", "t1", Arc::new(patch("25.3", vec![])), 100);
+        assert!(cache.get("25.2", "ru", "t1").is_none());
+        assert!(cache.get("25.1", "ru", "t1").is_some());
+
+        // Пересохранение: старый fetched_at больше не отдаётся
+        cache.insert("25.1", "ru", "t2", Arc::new(patch("25.1", vec![])), 100);
+        assert!(cache.get("25.1", "ru", "t1").is_none());
+        assert!(cache.get("25.1", "ru", "t2").is_some());
+        cache.invalidate("25.1", "ru");
+        assert!(cache.get("25.1", "ru", "t2").is_none());
+    }
+
+    #[test]
+    fn respects_byte_budget_and_reports_stats() {
+        let mut cache = PatchCache::new(250);
+        cache.insert("25.1", "ru", "t1", Arc::new(patch("25.1", vec![])), 100);
+        cache.insert("25.2", "ru", "t1", Arc::new(patch("25.2", vec![])), 100);
+        cache.insert("25.3", "ru", "t1", Arc::new(patch("25.3", vec![])), 300);
+        assert_eq!(cache.stats().entries, 2, "крупнее бюджета не кэшируется");
+
+        cache.insert("25.4", "ru", "t1", Arc::new(patch("25.4", vec![])), 100);
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.bytes, stats.evictions), (2, 200, 1));
+
+        cache.set_budget(100);
+        assert_eq!(cache.stats().bytes, 100);
+        assert!(cache.get("25.4", "ru", "t1").is_some());
+        assert!(cache.get("25.2", "ru", "t1").is_none());
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(stats.hit_rate, 0.5);
+    }
+}
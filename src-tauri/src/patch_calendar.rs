@@ -0,0 +1,179 @@
+//! Календарь патчей в iCalendar (.ics) для командных календарей: вышедшие патчи — по датам
+//! публикации, следующие — по обычному интервалу между последними патчами (помечены «ожидается»).
+//! Расписания Riot приложение не скачивает, поэтому часы техработ в календарь не попадают.
+
+use chrono::{DateTime, Datelike, Duration, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::i18n::{self, Lang};
+use crate::patch_version::{split_hotfix, PatchVersion};
+
+/// Сколько будущих патчей прогнозировать по умолчанию.
+pub const DEFAULT_UPCOMING: usize = 4;
+const MAX_UPCOMING: usize = 12;
+/// Сколько последних интервалов берётся для медианы.
+const CADENCE_SAMPLE: usize = 10;
+const DEFAULT_CADENCE_DAYS: i64 = 14;
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CalendarEvent {
+    pub version: String,
+    pub date: DateTime<Utc>,
+    /// Прогноз по интервалу, а не вышедший патч
+    pub expected: bool,
+}
+
+/// Медиана интервала между патчами в днях, в пределах 7–28; мало данных — две недели.
+fn cadence_days(dates: &[DateTime<Utc>]) -> i64 {
+    let mut gaps: Vec<i64> = dates
+        .windows(2)
+        .map(|w| (w[1] - w[0]).num_days())
+        .filter(|d| *d > 0)
+        .collect();
+    if gaps.len() > CADENCE_SAMPLE {
+        gaps.drain(..gaps.len() - CADENCE_SAMPLE);
+    }
+    if gaps.is_empty() {
+        return DEFAULT_CADENCE_DAYS;
+    }
+    gaps.sort_unstable();
+    gaps[gaps.len() / 2].clamp(7, 28)
+}
+
+/// Следующий номер патча; с началом нового года нумерация Riot начинается с `YY.1`.
+fn next_version(prev: &PatchVersion, date: DateTime<Utc>) -> PatchVersion {
+    let next = PatchVersion {
+        minor: prev.minor + 1,
+        ..prev.base()
+    };
+    let year = date.year() % 100;
+    let season: Option<i32> = next
+        .to_string()
+        .split('.')
+        .next()
+        .and_then(|s| s.parse().ok());
+    match season {
+        Some(season) if year > season => PatchVersion::parse(&format!("{}.1", year)).unwrap_or(next),
+        _ => next,
+    }
+}
+
+/// `released` — версия и дата выхода в любом порядке; хотфиксы в календарь не попадают.
+pub fn build_schedule(released: Vec<(String, DateTime<Utc>)>, upcoming: usize) -> Vec<CalendarEvent> {
+    let mut events: Vec<CalendarEvent> = released
+        .into_iter()
+        .filter(|(v, _)| split_hotfix(v).1.is_none())
+        .map(|(version, date)| CalendarEvent {
+            version,
+            date,
+            expected: false,
+        })
+        .collect();
+    events.sort_by_key(|e| e.date);
+    let dates: Vec<DateTime<Utc>> = events.iter().map(|e| e.date).collect();
+    let cadence = Duration::days(cadence_days(&dates));
+    let Some(mut prev) = events.iter().rev().find_map(|e| PatchVersion::parse(&e.version)) else {
+        return events;
+    };
+    let mut date = dates[dates.len() - 1];
+    for _ in 0..upcoming.min(MAX_UPCOMING) {
+        date += cadence;
+        prev = next_version(&prev, date);
+        events.push(CalendarEvent {
+            version: prev.to_string(),
+            date,
+            expected: true,
+        });
+    }
+    events
+}
+
+/// Экранирование TEXT по RFC 5545.
+fn ics_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// События на весь день; UID стабилен по версии, поэтому повторный импорт обновляет даты.
+pub fn render_ics(events: &[CalendarEvent], lang: Lang) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//LoL Meta Analyzer//Patch calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", ics_text(i18n::tr(lang, "calendar.name"))),
+    ];
+    for e in events {
+        let key = if e.expected { "calendar.patch_expected" } else { "calendar.patch" };
+        let summary = i18n::trf(lang, key, &[("version", &e.version)]);
+        let day = e.date.date_naive();
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:patch-{}@patch-analyzer", e.version),
+            format!("DTSTAMP:{}", stamp),
+            format!("DTSTART;VALUE=DATE:{}", day.format("%Y%m%d")),
+            format!("DTEND;VALUE=DATE:{}", (day + Duration::days(1)).format("%Y%m%d")),
+            format!("SUMMARY:{}", ics_text(&summary)),
+            format!(
+                "STATUS:{}",
+                if e.expected { "TENTATIVE" } else { "CONFIRMED" }
+            ),
+            "TRANSP:TRANSPARENT".to_string(),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines.join("\r\n") + "\r\n"
+}
+
+/// Расписание по сохранённым патчам плюс `upcoming` прогнозных.
+pub async fn patch_schedule(db: &Database, upcoming: usize) -> anyhow::Result<Vec<CalendarEvent>> {
+    let mut released = Vec::new();
+    for version in db.list_cached_patch_versions().await? {
+        if let Some(summary) = db.get_patch_summary(&version).await? {
+            released.push((summary.version, summary.release_date));
+        }
+    }
+    Ok(build_schedule(released, upcoming))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(&format!("{}T18:00:00Z", s))
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn projects_next_patches_by_cadence_and_year_rollover() {
+        let events = build_schedule(
+            vec![
+                ("25.23".into(), day("2025-11-18")),
+                ("25.22".into(), day("2025-11-04")),
+                ("25.22b".into(), day("2025-11-07")),
+                ("25.24".into(), day("2025-12-09")),
+            ],
+            2,
+        );
+        let versions: Vec<&str> = events.iter().map(|e| e.version.as_str()).collect();
+        assert_eq!(versions, ["25.22", "25.23", "25.24", "25.25", "26.1"]);
+        // Медиана интервалов 14 и 21 дней — 21
+        assert_eq!(events[3].date, day("2025-12-30"));
+        assert!(events[4].expected && !events[2].expected);
+
+        let ics = render_ics(&events, Lang::En);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 5);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260120\r\n"));
+        assert!(ics.contains("SUMMARY:LoL patch 26.1 (expected)\r\n"));
+        assert!(render_ics(&events, Lang::Ru).contains("SUMMARY:Патч LoL 26.1 (ожидается)\r\n"));
+    }
+}
@@ -1,5 +1,94 @@
+use once_cell::sync::Lazy;
 use regex::Regex;
 
+use crate::models::{ChangeGrade, ChangeType, PatchNoteEntry};
+
+static ARROW_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s*(?:→|⇒|->)\s*").unwrap());
+static NUM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[-+]?\d+(?:[.,]\d+)?").unwrap());
+static BUFF_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(увеличен|усилен|increased|buffed|new effect|новый эффект)").unwrap());
+static NERF_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(уменьшен|ослаблен|decreased|nerfed|removed|удалено)").unwrap());
+
+/// Относительная правка ниже порога — Minor, ниже второго — Moderate, иначе Major.
+const MINOR_RELATIVE_CHANGE: f64 = 0.15;
+const MODERATE_RELATIVE_CHANGE: f64 = 0.35;
+
+/// Значения до и после стрелки ("40/60/80 ⇒ 50/70/90" -> (180, 210)); ранги суммируются.
+fn arrow_values(text: &str) -> Option<(f64, f64)> {
+    let parts: Vec<&str> = ARROW_RE.split(text).collect();
+    if parts.len() != 2 {
+        return None;
+    }
+    let parse_val = |s: &str| -> f64 {
+        let nums: Vec<f64> = NUM_RE
+            .find_iter(s)
+            .filter_map(|m| m.as_str().replace(',', ".").parse::<f64>().ok())
+            .collect();
+        if nums.is_empty() {
+            f64::NAN
+        } else {
+            nums.iter().sum()
+        }
+    };
+    let from = parse_val(parts[0]);
+    let to = parse_val(parts[1]);
+    (from.is_finite() && to.is_finite()).then_some((from, to))
+}
+
+/// Удаление или появление эффекта — правка, меняющая кит, а не число.
+fn is_kit_change(lower: &str) -> bool {
+    lower.contains("удалено")
+        || lower.contains("removed")
+        || lower.contains("new effect")
+        || lower.contains("новый эффект")
+        || (lower.contains("больше не") && !lower.contains("больше не уменьшается"))
+        || (lower.contains("no longer") && !lower.contains("no longer reduced"))
+}
+
+/// Масштаб одной строки изменений.
+pub fn change_grade(text: &str) -> ChangeGrade {
+    if is_kit_change(&text.to_lowercase()) {
+        return ChangeGrade::Major;
+    }
+    let Some((from, to)) = arrow_values(text) else {
+        return ChangeGrade::Minor;
+    };
+    let relative = if from.abs() > f64::EPSILON {
+        (to - from).abs() / from.abs()
+    } else if to.abs() > f64::EPSILON {
+        1.0
+    } else {
+        0.0
+    };
+    if relative < MINOR_RELATIVE_CHANGE {
+        ChangeGrade::Minor
+    } else if relative < MODERATE_RELATIVE_CHANGE {
+        ChangeGrade::Moderate
+    } else {
+        ChangeGrade::Major
+    }
+}
+
+/// Масштаб записи: переработка — Major, иначе самая крупная из её строк.
+pub fn note_grade(note: &PatchNoteEntry) -> Option<ChangeGrade> {
+    if note.change_type == ChangeType::Rework {
+        return Some(ChangeGrade::Major);
+    }
+    note.details
+        .iter()
+        .flat_map(|b| b.all_changes())
+        .map(|c| change_grade(c))
+        .max()
+}
+
+/// Проставляет `grade` записям, у которых его ещё нет (патчи, сохранённые до появления оценки).
+pub fn fill_note_grades(notes: &mut [PatchNoteEntry]) {
+    for note in notes.iter_mut().filter(|n| n.grade.is_none()) {
+        note.grade = note_grade(note);
+    }
+}
+
 pub fn analyze_change_trend(text: &str) -> i32 {
     let lower = text.to_lowercase();
 
@@ -29,44 +118,37 @@ pub fn analyze_change_trend(text: &str) -> i32 {
         || lower.contains("time")
         || lower.contains("расход маны");
 
-    let arrow_re = Regex::new(r"\s*(?:→|⇒|->)\s*").unwrap();
-    let parts: Vec<&str> = arrow_re.split(text).collect();
-    if parts.len() == 2 {
-        let parse_val = |s: &str| -> f64 {
-            let num_re = Regex::new(r"[-+]?\d+(?:[.,]\d+)?").unwrap();
-            let nums: Vec<f64> = num_re
-                .find_iter(s)
-                .filter_map(|m| m.as_str().replace(',', ".").parse::<f64>().ok())
-                .collect();
-            if nums.is_empty() {
-                f64::NAN
-            } else {
-                nums.iter().sum()
-            }
-        };
-
-        let from = parse_val(parts[0]);
-        let to = parse_val(parts[1]);
-
-        if from.is_finite() && to.is_finite() {
-            if to > from {
-                return if is_inverse { -1 } else { 1 };
-            }
-            if to < from {
-                return if is_inverse { 1 } else { -1 };
-            }
+    if let Some((from, to)) = arrow_values(text) {
+        if to > from {
+            return if is_inverse { -1 } else { 1 };
+        }
+        if to < from {
+            return if is_inverse { 1 } else { -1 };
         }
     }
 
-    let buff_re = Regex::new(r"(увеличен|усилен|increased|buffed|new effect|новый эффект)").unwrap();
-    if buff_re.is_match(&lower) {
+    if BUFF_RE.is_match(&lower) {
         return 1;
     }
 
-    let nerf_re = Regex::new(r"(уменьшен|ослаблен|decreased|nerfed|removed|удалено)").unwrap();
-    if nerf_re.is_match(&lower) {
+    if NERF_RE.is_match(&lower) {
         return -1;
     }
 
     0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grades_by_relative_magnitude_and_kit_changes() {
+        assert_eq!(change_grade("Armor: 35 ⇒ 30"), ChangeGrade::Minor);
+        assert_eq!(change_grade("Damage: 40/60/80 ⇒ 50/75/100"), ChangeGrade::Moderate);
+        assert_eq!(change_grade("Cooldown: 10 ⇒ 6"), ChangeGrade::Major);
+        assert_eq!(change_grade("Passive slow removed"), ChangeGrade::Major);
+        assert_eq!(change_grade("Updated tooltip"), ChangeGrade::Minor);
+        assert_eq!(analyze_change_trend("Cooldown: 10 ⇒ 6"), 1);
+    }
+}
@@ -0,0 +1,171 @@
+//! RSS-лента разобранных патчей: по записи на патч с кратким дайджестом из `PatchSummary`.
+//! Файл `<app_data>/feed.xml` переписывается после сохранения новых патчей; локальный API отдаёт
+//! ту же ленту по `/api/feed.xml`, чтобы подписаться на неё в любой читалке.
+
+use std::path::Path;
+
+use chrono::Utc;
+
+use crate::db::Database;
+use crate::i18n::{self, Lang};
+use crate::models::{ChangeType, PatchSummary};
+
+/// Патчей в ленте — последние по номеру версии.
+pub const FEED_ITEMS: usize = 20;
+pub const FEED_FILE_NAME: &str = "feed.xml";
+const CHANNEL_TITLE: &str = "LoL Meta Analyzer — patches";
+const CHANNEL_LINK: &str = "https://www.leagueoflegends.com/news/tags/patch-notes/";
+
+fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Дайджест патча одной строкой: объём, баланс усилений и ослаблений, самые заметные изменения.
+pub fn digest(summary: &PatchSummary, lang: Lang) -> String {
+    let count = |t: ChangeType| summary.by_change_type.get(&t).copied().unwrap_or(0);
+    let mut parts = vec![
+        i18n::trf(
+            lang,
+            "digest.totals",
+            &[("notes", &summary.total_notes), ("entities", &summary.entities_touched)],
+        ),
+        i18n::trf(
+            lang,
+            "digest.change_types",
+            &[
+                ("buffs", &count(ChangeType::Buff)),
+                ("nerfs", &count(ChangeType::Nerf)),
+                ("adjusted", &count(ChangeType::Adjusted)),
+                ("new", &count(ChangeType::New)),
+            ],
+        ),
+    ];
+    for (key, highlight) in [
+        ("digest.biggest_buff", &summary.biggest_buff),
+        ("digest.biggest_nerf", &summary.biggest_nerf),
+    ] {
+        if let Some(h) = highlight {
+            let score = format!("{:+}", h.score);
+            parts.push(i18n::trf(lang, key, &[("title", &h.title), ("score", &score)]));
+        }
+    }
+    let mut scripts: Vec<_> = summary.script_scores.iter().collect();
+    scripts.sort_by(|a, b| a.0.cmp(b.0));
+    for (script, scores) in scripts {
+        let mut scores: Vec<_> = scores.iter().collect();
+        scores.sort_by(|a, b| a.0.cmp(b.0));
+        let scores: Vec<String> = scores.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        parts.push(format!("{}: {}", script, scores.join(", ")));
+    }
+    parts.join("; ") + "."
+}
+
+/// RSS 2.0. `link_base` — адрес локального API: ссылки записей ведут на сводку патча.
+pub fn render_rss(summaries: &[PatchSummary], link_base: Option<&str>, lang: Lang) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n");
+    out.push_str(&format!("<title>{}</title>\n", escape_xml(CHANNEL_TITLE)));
+    out.push_str(&format!("<link>{}</link>\n", escape_xml(link_base.unwrap_or(CHANNEL_LINK))));
+    out.push_str(&format!(
+        "<description>{}</description>\n",
+        escape_xml(i18n::tr(lang, "feed.channel_description"))
+    ));
+    out.push_str(&format!("<language>{}</language>\n", lang.code()));
+    out.push_str(&format!("<lastBuildDate>{}</lastBuildDate>\n", Utc::now().to_rfc2822()));
+    for s in summaries {
+        let locale = s.patch_notes_locale.as_deref().unwrap_or("ru");
+        out.push_str("<item>\n");
+        out.push_str(&format!(
+            "<title>{}</title>\n",
+            escape_xml(&i18n::trf(lang, "feed.item_title", &[("version", &s.version)]))
+        ));
+        if let Some(base) = link_base {
+            out.push_str(&format!(
+                "<link>{}/patches/{}/summary</link>\n",
+                escape_xml(base),
+                escape_xml(&s.version)
+            ));
+        }
+        out.push_str(&format!(
+            "<guid isPermaLink=\"false\">patch-analyzer:{}:{}</guid>\n",
+            escape_xml(&s.version),
+            locale
+        ));
+        out.push_str(&format!("<pubDate>{}</pubDate>\n", s.release_date.to_rfc2822()));
+        out.push_str(&format!("<description>{}</description>\n", escape_xml(&digest(s, lang))));
+        out.push_str("</item>\n");
+    }
+    out.push_str("</channel>\n</rss>\n");
+    out
+}
+
+/// Сводки последних патчей, от нового к старому; патчи без сводки пропускаются.
+pub async fn load_summaries(db: &Database) -> anyhow::Result<Vec<PatchSummary>> {
+    let mut summaries = Vec::new();
+    for version in db.list_cached_patch_versions().await?.into_iter().take(FEED_ITEMS) {
+        if let Some(summary) = db.get_patch_summary(&version).await? {
+            summaries.push(summary);
+        }
+    }
+    Ok(summaries)
+}
+
+/// Переписывает файл ленты через временный файл: читалка не увидит его наполовину записанным.
+pub async fn write_feed(db: &Database, path: &Path) -> anyhow::Result<()> {
+    let xml = render_rss(&load_summaries(db).await?, None, i18n::current());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("xml.tmp");
+    std::fs::write(&tmp, xml)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PatchCategory, SummaryHighlight};
+
+    #[test]
+    fn rss_item_per_patch_with_escaped_digest() {
+        let summary = PatchSummary {
+            version: "25.22".into(),
+            patch_notes_locale: Some("en".into()),
+            release_date: chrono::DateTime::parse_from_rfc3339("2025-11-04T18:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            total_notes: 3,
+            by_category: Default::default(),
+            by_change_type: [(ChangeType::Buff, 2), (ChangeType::Nerf, 1)].into_iter().collect(),
+            by_grade: Default::default(),
+            entities_touched: 3,
+            biggest_buff: Some(SummaryHighlight {
+                note_id: "kaisa".into(),
+                title: "Kai'Sa & <Co>".into(),
+                category: PatchCategory::Champions,
+                entity_id: None,
+                score: 4,
+            }),
+            biggest_nerf: None,
+            script_scores: Default::default(),
+        };
+        let xml = render_rss(std::slice::from_ref(&summary), Some("http://127.0.0.1:47800/api"), Lang::En);
+        assert_eq!(xml.matches("<item>").count(), 1);
+        assert!(xml.contains("<link>http://127.0.0.1:47800/api/patches/25.22/summary</link>"));
+        assert!(xml.contains("<pubDate>Tue, 4 Nov 2025 18:00:00 +0000</pubDate>"));
+        assert!(xml.contains("buffs: 2, nerfs: 1"));
+        assert!(xml.contains("biggest buff: Kai&apos;Sa &amp; &lt;Co&gt; (+4)"));
+        assert!(digest(&summary, Lang::Ru).contains("главное усиление: Kai'Sa & <Co> (+4)"));
+    }
+}
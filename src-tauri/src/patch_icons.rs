@@ -3,7 +3,7 @@ use anyhow::Result;
 use crate::db::{
     augment_row_matches_icon_url, cd_meta_allows_maps, normalize_augment_lookup_key, Database,
 };
-use crate::models::{PatchCategory, PatchData, PatchNoteEntry, StaticCatalogRow};
+use crate::models::{ItemStat, PatchCategory, PatchData, PatchNoteEntry, RuneEntry, StaticCatalogRow};
 use crate::wiki_augment_bundle::{
     bundle_icon_for_note, bundle_icon_via_catalog_bridge, resolve_augment_title_for_bundle_lookup,
 };
@@ -275,9 +275,203 @@ pub async fn enrich_patch_data_icons(db: &Database, patch: &mut PatchData) -> Re
     for note in &mut patch.patch_notes {
         enrich_one_note(note, &items, &augments, &champs, &runes, &abilities);
     }
+    let prefer_ru = patch.patch_notes_locale.as_deref() != Some("en");
+    for ch in &mut patch.champions {
+        for item in &mut ch.core_items {
+            enrich_core_item(item, &items, prefer_ru);
+        }
+        for rune in &mut ch.popular_runes {
+            enrich_rune_entry(rune, &runes, prefer_ru);
+        }
+    }
+    Ok(())
+}
+
+/// Дополняет руну id, деревом, рядом и иконкой из каталога (по id или названию).
+fn enrich_rune_entry(rune: &mut RuneEntry, runes: &[StaticCatalogRow], prefer_ru: bool) {
+    let name_lower = rune.name.trim().to_lowercase();
+    let row = runes.iter().find(|r| {
+        let full = RuneEntry::from_catalog_row(r, prefer_ru);
+        (rune.id.is_some() && full.id == rune.id)
+            || r.name_en.to_lowercase() == name_lower
+            || r.name_ru.to_lowercase() == name_lower
+    });
+    let Some(row) = row else {
+        return;
+    };
+    let full = RuneEntry::from_catalog_row(row, prefer_ru);
+    rune.id = rune.id.take().or(full.id);
+    rune.tree = rune.tree.take().or(full.tree);
+    rune.slot = rune.slot.or(full.slot);
+    rune.icon = rune.icon.take().or(full.icon);
+}
+
+/// Дополняет core item id, ценой, статами и сборкой из каталога (по id или названию).
+fn enrich_core_item(item: &mut ItemStat, items: &[StaticCatalogRow], prefer_ru: bool) {
+    let name_lower = item.name.trim().to_lowercase();
+    let row = items.iter().find(|r| {
+        item.id.as_deref() == Some(r.stable_id.as_str())
+            || r.name_en.to_lowercase() == name_lower
+            || r.name_ru.to_lowercase() == name_lower
+    });
+    let Some(row) = row else {
+        return;
+    };
+    let full = ItemStat::from_catalog_row(row, prefer_ru);
+    item.id = full.id;
+    if item.image_url.is_none() {
+        item.image_url = full.image_url;
+    }
+    item.gold_total = item.gold_total.or(full.gold_total);
+    if item.stats.is_empty() {
+        item.stats = full.stats;
+    }
+    if item.build_path.is_empty() {
+        item.build_path = full.build_path;
+    }
+}
+
+/// Проставляет `entity_id` и `title_en` / `title_ru` из static_catalog;
+/// без совпадения — исходный заголовок в локали патча.
+pub async fn resolve_patch_note_identity(db: &Database, patch: &mut PatchData) -> Result<()> {
+    let items = db.get_static_catalog_kind("item").await.unwrap_or_default();
+    let champs = db.get_static_catalog_kind("champion").await.unwrap_or_default();
+    let runes = db.get_static_catalog_kind("rune").await.unwrap_or_default();
+    resolve_identity_with_catalog(patch, &items, &champs, &runes);
     Ok(())
 }
 
+/// То же, что `resolve_patch_note_identity`, с уже загруженным каталогом. true — что-то изменилось.
+pub fn resolve_identity_with_catalog(
+    patch: &mut PatchData,
+    items: &[StaticCatalogRow],
+    champs: &[StaticCatalogRow],
+    runes: &[StaticCatalogRow],
+) -> bool {
+    let is_en = patch.patch_notes_locale.as_deref() == Some("en");
+    let mut changed = false;
+    for note in &mut patch.patch_notes {
+        if note.category == PatchCategory::BugFixes && note.mentions.is_empty() {
+            note.mentions = fix_mentions(note, champs, items);
+            changed |= !note.mentions.is_empty();
+        }
+        let before = (note.entity_id.is_some(), note.title_en.is_some(), note.title_ru.is_some());
+        fill_catalog_identity(note, items, champs, runes);
+        let own = if is_en { &mut note.title_en } else { &mut note.title_ru };
+        if own.is_none() {
+            *own = Some(note.title.clone());
+        }
+        changed |= before != (note.entity_id.is_some(), note.title_en.is_some(), note.title_ru.is_some());
+    }
+    changed
+}
+
+/// `needle` встречается в `haystack` целым словом (оба в нижнем регистре).
+fn contains_word(haystack: &str, needle: &str) -> bool {
+    haystack.match_indices(needle).any(|(i, _)| {
+        let before = haystack[..i].chars().next_back();
+        let after = haystack[i + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Чемпионы и предметы, названные (en/ru) в тексте исправления ошибки, — id сущностей для `mentions`.
+/// Одноимённые предметы разных режимов сводятся к одному — с самым коротким id (версия Ущелья).
+pub fn fix_mentions(note: &PatchNoteEntry, champs: &[StaticCatalogRow], items: &[StaticCatalogRow]) -> Vec<String> {
+    let text = std::iter::once(note.summary.as_str())
+        .chain(note.details.iter().flat_map(|b| b.changes.iter().map(String::as_str)))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    let mut found: Vec<(String, &StaticCatalogRow)> = Vec::new();
+    for row in champs.iter().chain(items) {
+        let Some(name) = [&row.name_en, &row.name_ru]
+            .into_iter()
+            .map(|n| n.trim().to_lowercase())
+            .find(|n| n.chars().count() >= 2 && contains_word(&text, n))
+        else {
+            continue;
+        };
+        match found.iter_mut().find(|f| f.1.kind == row.kind && f.0 == name) {
+            Some(f) if row.stable_id.len() < f.1.stable_id.len() => f.1 = row,
+            Some(_) => {}
+            None => found.push((name, row)),
+        }
+    }
+    let mut out: Vec<String> = Vec::new();
+    for (_, row) in found {
+        if let Some(id) = entity_id_for_row(row) {
+            if !out.contains(&id) {
+                out.push(id);
+            }
+        }
+    }
+    out
+}
+
+/// "champion:Ahri" / "item:3031" / "rune:8112" для строки каталога.
+pub fn entity_id_for_row(row: &StaticCatalogRow) -> Option<String> {
+    let id = match row.kind.as_str() {
+        "champion" | "item" => row.stable_id.as_str(),
+        // stable_id руны — "Style:id"
+        "rune" => row.stable_id.rsplit(':').next().unwrap_or(""),
+        _ => return None,
+    };
+    if id.is_empty() {
+        return None;
+    }
+    Some(format!("{}:{}", row.kind, id))
+}
+
+fn catalog_row_for_note<'a>(
+    note: &PatchNoteEntry,
+    items: &'a [StaticCatalogRow],
+    champs: &'a [StaticCatalogRow],
+    runes: &'a [StaticCatalogRow],
+) -> Option<&'a StaticCatalogRow> {
+    let maps = map_ids_for_category(&note.category);
+    let exact_item = || {
+        let lower = note.title.trim().to_lowercase();
+        items.iter().find(|r| {
+            (r.name_ru.to_lowercase() == lower || r.name_en.to_lowercase() == lower)
+                && r.cd_meta
+                    .as_ref()
+                    .map(|m| cd_meta_allows_maps(m, &maps))
+                    .unwrap_or(true)
+        })
+    };
+    match note.category {
+        PatchCategory::Champions => best_champion_match(&note.title, champs),
+        PatchCategory::Runes => best_rune_match(&note.title, runes),
+        PatchCategory::Items => exact_item(),
+        PatchCategory::ItemsRunes => exact_item().or_else(|| best_rune_match(&note.title, runes)),
+        _ => None,
+    }
+}
+
+fn fill_catalog_identity(
+    note: &mut PatchNoteEntry,
+    items: &[StaticCatalogRow],
+    champs: &[StaticCatalogRow],
+    runes: &[StaticCatalogRow],
+) {
+    if note.entity_id.is_some() && note.title_en.is_some() && note.title_ru.is_some() {
+        return;
+    }
+    let Some(row) = catalog_row_for_note(note, items, champs, runes) else {
+        return;
+    };
+    if note.entity_id.is_none() {
+        note.entity_id = entity_id_for_row(row);
+    }
+    if !row.name_en.trim().is_empty() {
+        note.title_en = Some(row.name_en.clone());
+    }
+    if !row.name_ru.trim().is_empty() {
+        note.title_ru = Some(row.name_ru.clone());
+    }
+}
+
 fn push_unique(out: &mut Vec<String>, u: String) {
     if u.is_empty() {
         return;
@@ -295,6 +489,7 @@ fn enrich_one_note(
     runes: &[StaticCatalogRow],
     abilities: &[StaticCatalogRow],
 ) {
+    fill_catalog_identity(note, items, champs, runes);
     let title_lower = note.title.to_lowercase();
     let maps = map_ids_for_category(&note.category);
     let mut candidates: Vec<String> = Vec::new();
@@ -444,3 +639,45 @@ fn best_ability_match<'a>(
         variants.iter().any(|v| v == &ru || v == &en)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeBlock, ChangeType};
+
+    #[test]
+    fn links_bug_fix_to_named_champions_and_items() {
+        let row = |kind: &str, id: &str, en: &str, ru: &str| StaticCatalogRow {
+            kind: kind.into(),
+            stable_id: id.into(),
+            name_ru: ru.into(),
+            name_en: en.into(),
+            riot_augment_id: None,
+            cd_meta: None,
+            icon_sources: vec![],
+            source: "ddragon".into(),
+        };
+        let champs = [row("champion", "Ahri", "Ahri", "Ари"), row("champion", "Vi", "Vi", "Вай")];
+        let items = [
+            row("item", "223031", "Infinity Edge", "Грань бесконечности"),
+            row("item", "3031", "Infinity Edge", "Грань бесконечности"),
+        ];
+        let text = "Исправлена ошибка: Ари с Гранью бесконечности… Fixed Ahri's Q not applying Infinity Edge crits in view.";
+        let note = PatchNoteEntry {
+            id: "fix_0".into(),
+            title: "Исправление ошибки".into(),
+            category: PatchCategory::BugFixes,
+            change_type: ChangeType::Fix,
+            summary: text.into(),
+            details: vec![ChangeBlock {
+                title: None,
+                icon_url: None,
+                changes: vec![text.into()],
+                children: vec![],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(fix_mentions(&note, &champs, &items), ["champion:Ahri", "item:3031"]);
+        assert!(contains_word("vi ult", "vi") && !contains_word("view", "vi"));
+    }
+}
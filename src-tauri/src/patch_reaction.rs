@@ -0,0 +1,349 @@
+//! Реакция сообщества на патч (необязательная функция, включается в настройках): ветка обсуждения
+//! патч-нотов на Reddit (r/leagueoflegends, JSON API), словарная оценка тональности комментариев и
+//! упоминания чемпионов из патча. Итог — оценка реакции по каждой записи чемпиона от −1 до 1;
+//! комментарии с большим рейтингом весят больше.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::http_body;
+use crate::models::{PatchCategory, PatchData};
+use crate::patch_version::PatchVersion;
+
+const SEARCH_URL: &str = "https://www.reddit.com/r/leagueoflegends/search.json";
+const REDDIT_URL: &str = "https://www.reddit.com";
+/// Сколько часов ответ считается свежим.
+pub const CACHE_HOURS: i64 = 6;
+const KEYWORDS_PER_ENTRY: usize = 3;
+
+const POSITIVE: &[&str] = &[
+    "love", "loving", "great", "good", "nice", "fun", "finally", "deserved", "healthy", "happy", "awesome", "amazing",
+    "excited", "fair", "glad", "thanks", "cool", "best", "balanced", "fixed", "better", "enjoy", "hype",
+];
+const NEGATIVE: &[&str] = &[
+    "hate", "bad", "worst", "terrible", "awful", "gutted", "useless", "dead", "unplayable", "overnerfed", "broken",
+    "busted", "overtuned", "annoying", "unfun", "stupid", "trash", "garbage", "disappointed", "sad", "ruined", "why",
+    "worse", "cancer", "toxic", "frustrating",
+];
+const NEGATORS: &[&str] = &["not", "no", "never", "isnt", "dont", "doesnt", "arent", "wasnt", "hardly"];
+/// Слова, которые показываются рядом с оценкой.
+const KEYWORDS: &[&str] = &[
+    "broken", "busted", "op", "overtuned", "gutted", "useless", "dead", "overnerfed", "healthy", "deserved", "finally",
+    "rework", "buff", "nerf", "strong", "weak", "balanced", "unplayable", "fun", "unfun",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct EntryReaction {
+    pub note_id: String,
+    pub title: String,
+    /// Комментариев с упоминанием
+    pub mentions: u32,
+    /// От −1 (недовольство) до 1 (одобрение)
+    pub score: f64,
+    pub positive: u32,
+    pub negative: u32,
+    /// Частые слова из `KEYWORDS`
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PatchReaction {
+    pub version: String,
+    pub patch_notes_locale: String,
+    pub thread_title: String,
+    pub thread_url: String,
+    pub comments_analyzed: u32,
+    pub overall: f64,
+    /// По числу упоминаний
+    pub entries: Vec<EntryReaction>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct RawListing {
+    data: RawListingData,
+}
+
+#[derive(Deserialize)]
+struct RawListingData {
+    children: Vec<RawChild>,
+}
+
+#[derive(Deserialize)]
+struct RawChild {
+    kind: String,
+    data: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct RawPost {
+    id: String,
+    title: String,
+    permalink: String,
+}
+
+/// Комментарий: текст и рейтинг.
+pub type Comment = (String, i64);
+
+fn tokens(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .replace('\'', "")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Тональность комментария: позитивные минус негативные слова; отрицание в двух словах перед словом его переворачивает.
+pub fn sentiment(text: &str) -> i32 {
+    let words = tokens(text);
+    let mut total = 0;
+    for (i, word) in words.iter().enumerate() {
+        let value = if POSITIVE.contains(&word.as_str()) {
+            1
+        } else if NEGATIVE.contains(&word.as_str()) {
+            -1
+        } else {
+            continue;
+        };
+        let negated = words[i.saturating_sub(2)..i].iter().any(|w| NEGATORS.contains(&w.as_str()));
+        total += if negated { -value } else { value };
+    }
+    total
+}
+
+/// Ветка патч-нотов: «Patch 25.22 notes», не баг-мегатред.
+fn pick_thread(posts: &[RawPost], version: &str) -> Option<usize> {
+    let wanted = format!("patch {}", version.to_lowercase());
+    posts.iter().position(|p| {
+        let title = p.title.to_lowercase();
+        title.contains(&wanted) && title.contains("notes") && !title.contains("bug")
+    })
+}
+
+/// Все комментарии дерева ответа Reddit, без «ещё N комментариев».
+fn flatten_comments(listing: &serde_json::Value, out: &mut Vec<Comment>) {
+    let Some(children) = listing.pointer("/data/children").and_then(|c| c.as_array()) else {
+        return;
+    };
+    for child in children {
+        if child.get("kind").and_then(|k| k.as_str()) != Some("t1") {
+            continue;
+        }
+        let data = &child["data"];
+        if let Some(body) = data.get("body").and_then(|b| b.as_str()) {
+            out.push((body.to_string(), data.get("score").and_then(|s| s.as_i64()).unwrap_or(1)));
+        }
+        if let Some(replies) = data.get("replies").filter(|r| r.is_object()) {
+            flatten_comments(replies, out);
+        }
+    }
+}
+
+fn weight(score: i64) -> f64 {
+    1.0 + (score.max(1) as f64).ln()
+}
+
+#[derive(Default)]
+struct Tally {
+    mentions: u32,
+    positive: u32,
+    negative: u32,
+    weighted: f64,
+    weights: f64,
+    keywords: HashMap<String, u32>,
+}
+
+impl Tally {
+    fn add(&mut self, words: &[String], sentiment: i32, weight: f64) {
+        self.mentions += 1;
+        match sentiment.signum() {
+            1 => self.positive += 1,
+            -1 => self.negative += 1,
+            _ => {}
+        }
+        self.weighted += f64::from(sentiment.signum()) * weight;
+        self.weights += weight;
+        for word in words.iter().filter(|w| KEYWORDS.contains(&w.as_str())) {
+            *self.keywords.entry(word.clone()).or_default() += 1;
+        }
+    }
+
+    fn score(&self) -> f64 {
+        if self.weights > 0.0 {
+            self.weighted / self.weights
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Имена записи для поиска в тексте: английский заголовок, заголовок и id DDragon.
+fn mention_regex(patch: &PatchData, note_index: usize) -> Option<Regex> {
+    let note = &patch.patch_notes[note_index];
+    let mut names: Vec<String> = [
+        note.title_en.clone(),
+        Some(note.title.clone()),
+        note.entity_id.as_deref().and_then(|e| e.split(':').nth(1)).map(str::to_string),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|n| n.trim().to_lowercase())
+    .filter(|n| n.chars().count() >= 3)
+    .collect();
+    names.sort();
+    names.dedup();
+    if names.is_empty() {
+        return None;
+    }
+    let alternatives: Vec<String> = names.iter().map(|n| regex::escape(n)).collect();
+    Regex::new(&format!(r"(?i)\b(?:{})\b", alternatives.join("|"))).ok()
+}
+
+/// Оценки по записям чемпионов патча и общая оценка ветки.
+pub fn analyze(patch: &PatchData, comments: &[Comment]) -> (f64, Vec<EntryReaction>) {
+    let champions: Vec<(usize, Regex)> = patch
+        .patch_notes
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| n.category == PatchCategory::Champions)
+        .filter_map(|(i, _)| mention_regex(patch, i).map(|re| (i, re)))
+        .collect();
+    let mut overall = Tally::default();
+    let mut tallies: HashMap<usize, Tally> = HashMap::new();
+    for (body, score) in comments {
+        let words = tokens(body);
+        let value = sentiment(body);
+        let w = weight(*score);
+        overall.add(&words, value, w);
+        for (index, re) in &champions {
+            if re.is_match(body) {
+                tallies.entry(*index).or_default().add(&words, value, w);
+            }
+        }
+    }
+    let mut entries: Vec<EntryReaction> = tallies
+        .into_iter()
+        .map(|(index, tally)| {
+            let note = &patch.patch_notes[index];
+            let mut keywords: Vec<(String, u32)> = tally.keywords.clone().into_iter().collect();
+            keywords.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            EntryReaction {
+                note_id: note.id.clone(),
+                title: note.title.clone(),
+                mentions: tally.mentions,
+                score: tally.score(),
+                positive: tally.positive,
+                negative: tally.negative,
+                keywords: keywords.into_iter().take(KEYWORDS_PER_ENTRY).map(|(k, _)| k).collect(),
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| b.mentions.cmp(&a.mentions).then_with(|| a.title.cmp(&b.title)));
+    (overall.score(), entries)
+}
+
+async fn get_json<T: serde::de::DeserializeOwned>(url: Url) -> Result<T> {
+    let resp = crate::http_client::api_client()?.get(url).send().await?.error_for_status()?;
+    http_body::read_json(resp).await
+}
+
+/// Находит ветку патча, скачивает комментарии и оценивает реакцию.
+pub async fn fetch(patch: &PatchData) -> Result<PatchReaction> {
+    let version = PatchVersion::parse(&patch.version)
+        .map(|v| v.base().to_string())
+        .unwrap_or_else(|| patch.version.clone());
+    let search = Url::parse_with_params(
+        SEARCH_URL,
+        &[
+            ("q", format!("title:\"patch {} notes\"", version)),
+            ("restrict_sr", "1".into()),
+            ("sort", "relevance".into()),
+            ("limit", "10".into()),
+        ],
+    )?;
+    let listing: RawListing = get_json(search).await?;
+    let posts: Vec<RawPost> = listing
+        .data
+        .children
+        .into_iter()
+        .filter(|c| c.kind == "t3")
+        .filter_map(|c| serde_json::from_value(c.data).ok())
+        .collect();
+    let post = pick_thread(&posts, &version)
+        .map(|i| &posts[i])
+        .ok_or_else(|| anyhow!("no patch {} notes thread on r/leagueoflegends", version))?;
+
+    let comments_url = Url::parse_with_params(
+        &format!("{}/comments/{}.json", REDDIT_URL, post.id),
+        &[("sort", "top"), ("limit", "500"), ("depth", "4")],
+    )?;
+    let tree: Vec<serde_json::Value> = get_json(comments_url).await?;
+    let mut comments = Vec::new();
+    if let Some(listing) = tree.get(1) {
+        flatten_comments(listing, &mut comments);
+    }
+    let (overall, entries) = analyze(patch, &comments);
+    Ok(PatchReaction {
+        version: patch.version.clone(),
+        patch_notes_locale: patch.patch_notes_locale.clone().unwrap_or_default(),
+        thread_title: post.title.clone(),
+        thread_url: format!("{}{}", REDDIT_URL, post.permalink),
+        comments_analyzed: comments.len() as u32,
+        overall,
+        entries,
+        fetched_at: Utc::now(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeType, PatchNoteEntry};
+    use crate::test_fixtures::{note, patch};
+
+    fn champion(title: &str, title_en: &str) -> PatchNoteEntry {
+        PatchNoteEntry {
+            title_en: Some(title_en.into()),
+            ..note(title, PatchCategory::Champions, ChangeType::Buff, &[])
+        }
+    }
+
+    #[test]
+    fn scores_champion_mentions_with_negation_and_weights() {
+        assert_eq!(sentiment("Ahri buffs are great, finally"), 2);
+        assert_eq!(sentiment("this is not good"), -1);
+
+        let patch = PatchData {
+            patch_notes_locale: Some("ru".into()),
+            ..patch("25.22", vec![champion("Ари", "Ahri"), champion("Мисс Фортуна", "Miss Fortune")])
+        };
+        let comments: Vec<Comment> = vec![
+            ("Ahri buff is so deserved, love it".into(), 500),
+            ("ahri is going to be broken".into(), 2),
+            ("Miss Fortune got gutted, she is useless now".into(), 40),
+            ("Nice patch overall".into(), 10),
+        ];
+        let (overall, entries) = analyze(&patch, &comments);
+        assert!(overall > 0.0);
+        assert_eq!(entries[0].title, "Ари");
+        assert_eq!((entries[0].mentions, entries[0].positive, entries[0].negative), (2, 1, 1));
+        assert!(entries[0].score > 0.5, "the upvoted comment outweighs");
+        assert_eq!(entries[1].score, -1.0);
+        assert_eq!(entries[1].keywords, ["gutted", "useless"]);
+
+        let posts = vec![
+            RawPost { id: "a".into(), title: "Patch 25.22 Bug Megathread".into(), permalink: String::new() },
+            RawPost { id: "b".into(), title: "Patch 25.22 Notes".into(), permalink: String::new() },
+        ];
+        assert_eq!(pick_thread(&posts, "25.22"), Some(1));
+    }
+}
@@ -0,0 +1,220 @@
+//! Правки статей после публикации: Riot дописывает изменения и исправляет числа. Свежие патчи
+//! периодически скачиваются заново, разобранный результат сравнивается с сохранённым; при разнице
+//! прежняя версия уходит в `patch_revisions`, новая сохраняется, а фронтенд получает
+//! `patch_notes_updated` с перечнем изменившихся записей.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::entity_diff::{block_lines, diff_lines, DiffLine, DiffOp};
+use crate::models::{PatchCategory, PatchData, PatchNoteEntry};
+use crate::scraper::Scraper;
+
+/// Перепроверяются патчи не старше этого срока: позже статьи почти не правят.
+pub const RECHECK_WINDOW_DAYS: i64 = 14;
+/// Сколько последних патчей смотреть (хотфиксы тоже патчи).
+pub const RECHECK_PATCHES: i64 = 3;
+const CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Первая проверка после запуска — когда стартовая синхронизация уже прошла.
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum RevisionChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct NoteRevisionChange {
+    pub kind: RevisionChangeKind,
+    pub note_id: String,
+    pub title: String,
+    /// Построчная разница блоков записи; у `Changed` — только изменившиеся строки
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PatchRevision {
+    pub version: String,
+    pub patch_notes_locale: String,
+    /// 1 — первая замеченная правка
+    pub revision: u32,
+    pub detected_at: DateTime<Utc>,
+    pub changes: Vec<NoteRevisionChange>,
+}
+
+/// Записи сопоставляются по категории и сущности (или заголовку): id записей при новом разборе могут сдвинуться.
+/// У исправлений ошибок заголовок общий ("Bug fix"), поэтому ключ — текст исправления.
+fn note_key(note: &PatchNoteEntry) -> String {
+    let subject = match (&note.entity_id, &note.category) {
+        (Some(id), _) => id.clone(),
+        (None, PatchCategory::BugFixes) => block_lines(&note.details).join("\n"),
+        (None, _) => note.title.trim().to_lowercase(),
+    };
+    format!("{:?}|{}", note.category, subject)
+}
+
+fn all_lines(note: &PatchNoteEntry, op: DiffOp) -> Vec<DiffLine> {
+    block_lines(&note.details)
+        .into_iter()
+        .map(|text| DiffLine { op, text })
+        .collect()
+}
+
+/// Аугменты ARAM в БД не сохраняются (`write_patch`), поэтому в свежем разборе они были бы «новыми».
+fn compared_notes(patch: &PatchData) -> impl Iterator<Item = &PatchNoteEntry> {
+    patch
+        .patch_notes
+        .iter()
+        .filter(|n| n.category != PatchCategory::ModeAramAugments)
+}
+
+/// Что изменилось в записях `new` относительно `old`, в порядке статьи.
+pub fn diff_patches(old: &PatchData, new: &PatchData) -> Vec<NoteRevisionChange> {
+    let old_by_key: HashMap<String, &PatchNoteEntry> = compared_notes(old).map(|n| (note_key(n), n)).collect();
+    let new_keys: std::collections::HashSet<String> = compared_notes(new).map(note_key).collect();
+    let mut changes = Vec::new();
+    for note in compared_notes(new) {
+        let change = match old_by_key.get(&note_key(note)) {
+            None => NoteRevisionChange {
+                kind: RevisionChangeKind::Added,
+                note_id: note.id.clone(),
+                title: note.title.clone(),
+                lines: all_lines(note, DiffOp::Added),
+            },
+            Some(prev) => {
+                let lines: Vec<DiffLine> = diff_lines(&block_lines(&prev.details), &block_lines(&note.details))
+                    .into_iter()
+                    .filter(|l| l.op != DiffOp::Same)
+                    .collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                NoteRevisionChange {
+                    kind: RevisionChangeKind::Changed,
+                    note_id: note.id.clone(),
+                    title: note.title.clone(),
+                    lines,
+                }
+            }
+        };
+        changes.push(change);
+    }
+    for note in compared_notes(old).filter(|n| !new_keys.contains(&note_key(n))) {
+        changes.push(NoteRevisionChange {
+            kind: RevisionChangeKind::Removed,
+            note_id: note.id.clone(),
+            title: note.title.clone(),
+            lines: all_lines(note, DiffOp::Removed),
+        });
+    }
+    changes
+}
+
+/// Фоновая перепроверка свежих патчей.
+pub async fn run_check_loop(app: AppHandle, db: Arc<Database>, scraper: Arc<Scraper>) {
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + FIRST_CHECK_DELAY, CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        crate::check_patch_note_edits(&app, &scraper, &db).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ChangeType;
+    use crate::test_fixtures::{note, patch};
+
+    fn buff(title: &str, changes: &[&str]) -> PatchNoteEntry {
+        note(title, PatchCategory::Champions, ChangeType::Buff, changes)
+    }
+
+    fn fix(id: &str, text: &str) -> PatchNoteEntry {
+        PatchNoteEntry {
+            id: id.into(),
+            ..note("Bug fix", PatchCategory::BugFixes, ChangeType::Fix, &[text])
+        }
+    }
+
+    #[test]
+    fn reports_corrected_added_and_removed_notes() {
+        let old = patch(
+            "25.22",
+            vec![
+                buff("Ahri", &["Damage: 40 → 50"]),
+                buff("Zed", &["Armor: 30 → 32"]),
+                buff("Yasuo", &["Damage: 60 → 55"]),
+            ],
+        );
+        let new = patch(
+            "25.22",
+            vec![
+                buff("Ahri", &["Damage: 40 → 55"]),
+                buff("Zed", &["Armor: 30 → 32"]),
+                buff("Lux", &["Shield: 50 → 60"]),
+            ],
+        );
+        let changes = diff_patches(&old, &new);
+        let summary: Vec<(RevisionChangeKind, &str, usize)> =
+            changes.iter().map(|c| (c.kind, c.title.as_str(), c.lines.len())).collect();
+        assert_eq!(
+            summary,
+            [
+                (RevisionChangeKind::Changed, "Ahri", 2),
+                (RevisionChangeKind::Added, "Lux", 1),
+                (RevisionChangeKind::Removed, "Yasuo", 1),
+            ]
+        );
+        assert_eq!(changes[0].lines[1].text, "- Damage: 40 → 55");
+        assert!(diff_patches(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn bug_fixes_with_shared_title_are_matched_by_text() {
+        let old = patch(
+            "25.22",
+            vec![
+                fix("fix_0", "Ahri's Q no longer misses stationary targets"),
+                fix("fix_1", "Zed's shadow now follows terrain"),
+                fix("fix_2", "Baron buff timer displays correctly"),
+            ],
+        );
+        let reparsed = patch(
+            "25.22",
+            vec![
+                fix("fix_0", "Ahri's Q no longer misses stationary targets"),
+                fix("fix_1", "Zed's shadow now follows terrain"),
+                fix("fix_2", "Baron buff timer displays correctly"),
+            ],
+        );
+        assert!(diff_patches(&old, &reparsed).is_empty());
+
+        let extended = patch(
+            "25.22",
+            vec![
+                fix("fix_0", "Ahri's Q no longer misses stationary targets"),
+                fix("fix_1", "Lux's E no longer hangs in the air"),
+                fix("fix_2", "Zed's shadow now follows terrain"),
+                fix("fix_3", "Baron buff timer displays correctly"),
+            ],
+        );
+        let changes = diff_patches(&old, &extended);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, RevisionChangeKind::Added);
+        assert_eq!(changes[0].note_id, "fix_1");
+    }
+}
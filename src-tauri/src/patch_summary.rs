@@ -0,0 +1,100 @@
+//! Сводная статистика патча (PatchSummary): считается при сохранении и хранится отдельно,
+//! чтобы обзорный экран не тянул весь data_json.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{ChangeGrade, ChangeType, PatchCategory, PatchData, PatchNoteEntry, PatchSummary, SummaryHighlight};
+use crate::patch_change_trend::note_grade;
+
+fn is_entity_category(category: &PatchCategory) -> bool {
+    matches!(
+        category,
+        PatchCategory::Champions | PatchCategory::Items | PatchCategory::Runes | PatchCategory::ItemsRunes
+    )
+}
+
+fn highlight(note: &PatchNoteEntry, score: i32) -> SummaryHighlight {
+    SummaryHighlight {
+        note_id: note.id.clone(),
+        title: note.title.clone(),
+        category: note.category.clone(),
+        entity_id: note.entity_id.clone(),
+        score,
+    }
+}
+
+pub fn summarize(patch: &PatchData) -> PatchSummary {
+    let mut by_category: HashMap<PatchCategory, u32> = HashMap::new();
+    let mut by_change_type: HashMap<ChangeType, u32> = HashMap::new();
+    let mut by_grade: HashMap<ChangeGrade, u32> = HashMap::new();
+    let mut entities: HashSet<String> = HashSet::new();
+    let mut biggest_buff: Option<SummaryHighlight> = None;
+    let mut biggest_nerf: Option<SummaryHighlight> = None;
+
+    for note in &patch.patch_notes {
+        *by_category.entry(note.category.clone()).or_insert(0) += 1;
+        *by_change_type.entry(note.change_type.clone()).or_insert(0) += 1;
+        if let Some(grade) = note.grade.or_else(|| note_grade(note)) {
+            *by_grade.entry(grade).or_insert(0) += 1;
+        }
+
+        if let Some(id) = note.entity_id.as_deref() {
+            entities.insert(id.to_string());
+        } else if is_entity_category(&note.category) {
+            entities.insert(note.title.to_lowercase());
+        }
+
+        let score = note.trend_score();
+        match note.change_type {
+            ChangeType::Buff if score > biggest_buff.as_ref().map_or(0, |h| h.score) => {
+                biggest_buff = Some(highlight(note, score));
+            }
+            ChangeType::Nerf if score < biggest_nerf.as_ref().map_or(0, |h| h.score) => {
+                biggest_nerf = Some(highlight(note, score));
+            }
+            _ => {}
+        }
+    }
+
+    PatchSummary {
+        version: patch.version.clone(),
+        patch_notes_locale: patch.patch_notes_locale.clone(),
+        release_date: patch.release_date(),
+        total_notes: patch.patch_notes.len() as u32,
+        by_category,
+        by_change_type,
+        by_grade,
+        entities_touched: entities.len() as u32,
+        biggest_buff,
+        biggest_nerf,
+        script_scores: crate::script_hooks::after_patch_analyzed(patch),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{note, patch};
+
+    #[test]
+    fn summary_counts_and_highlights() {
+        let champion = PatchCategory::Champions;
+        let patch = patch(
+            "25.10",
+            vec![
+                note("Ahri", champion.clone(), ChangeType::Buff, &["Damage: 50 → 60"]),
+                note("Zed", champion.clone(), ChangeType::Buff, &["Damage: 50 → 60", "Armor: 30 → 35"]),
+                note("Azir", champion.clone(), ChangeType::Nerf, &["Damage: 60 → 50"]),
+                note("zed", champion, ChangeType::Adjusted, &[]),
+            ],
+        );
+        let s = summarize(&patch);
+        assert_eq!(s.total_notes, 4);
+        assert_eq!(s.by_category.get(&PatchCategory::Champions), Some(&4));
+        assert_eq!(s.by_change_type.get(&ChangeType::Buff), Some(&2));
+        assert_eq!(s.entities_touched, 3);
+        assert_eq!(s.by_grade.get(&ChangeGrade::Moderate), Some(&3));
+        assert_eq!(s.biggest_buff.map(|h| h.note_id), Some("Zed".to_string()));
+        assert_eq!(s.biggest_nerf.map(|h| h.note_id), Some("Azir".to_string()));
+    }
+}
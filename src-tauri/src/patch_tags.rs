@@ -0,0 +1,108 @@
+//! Теги записей патч-нотов: автоматические (по формулировкам Riot) и пользовательские.
+//! Пользовательский список из БД полностью заменяет автоматический для записи.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::models::PatchNoteEntry;
+
+pub const TAG_PRO_PLAY: &str = "pro-play";
+pub const TAG_SOLO_QUEUE: &str = "solo-queue";
+pub const TAG_EARLY_GAME: &str = "early-game";
+pub const TAG_LATE_GAME: &str = "late-game";
+pub const TAG_SNOWBALL: &str = "snowball";
+pub const TAG_JUNGLE: &str = "jungle";
+
+fn tag_rules() -> &'static [(&'static str, Regex)] {
+    static RULES: OnceLock<Vec<(&'static str, Regex)>> = OnceLock::new();
+    RULES.get_or_init(|| {
+        let rule = |tag, re: &str| (tag, Regex::new(re).expect("tag regex"));
+        vec![
+            rule(
+                TAG_PRO_PLAY,
+                r"(?i)(pro play|pro-play|competitive|профессиональн|про-сцен|киберспорт)",
+            ),
+            rule(
+                TAG_SOLO_QUEUE,
+                r"(?i)(solo ?queue|solo/duo|low elo|соло-?очеред|одиночн\S* очеред|низк\S* рейтинг)",
+            ),
+            rule(
+                TAG_EARLY_GAME,
+                r"(?i)(early game|early-game|laning|level 1\b|ранн\S* (стади|игр)|на линии|на 1(-м)? уровне)",
+            ),
+            rule(
+                TAG_LATE_GAME,
+                r"(?i)(late game|late-game|scaling|поздн\S* (стади|игр)|масштабир)",
+            ),
+            rule(TAG_SNOWBALL, r"(?i)(snowball|снежн\S* ком|bount|наград\S* за)"),
+            rule(TAG_JUNGLE, r"(?i)(jungle|jungling|лесник|в лесу|монстр)"),
+        ]
+    })
+}
+
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase().replace(' ', "-")
+}
+
+/// Теги по тексту описания и изменений записи.
+pub fn auto_tags(note: &PatchNoteEntry) -> Vec<String> {
+    let mut text = note.summary.clone();
+    for block in &note.details {
+        for change in block.all_changes() {
+            text.push(' ');
+            text.push_str(change);
+        }
+    }
+    tag_rules()
+        .iter()
+        .filter(|(_, re)| re.is_match(&text))
+        .map(|(tag, _)| tag.to_string())
+        .collect()
+}
+
+/// Проставляет теги записям патча: пользовательские (ключ — id записи) или автоматические.
+pub fn apply_tags(notes: &mut [PatchNoteEntry], user_tags: &HashMap<String, Vec<String>>) {
+    for note in notes {
+        if let Some(tags) = user_tags.get(&note.id) {
+            note.tags = tags.clone();
+        } else if note.tags.is_empty() {
+            note.tags = auto_tags(note);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeType, PatchCategory};
+    use crate::test_fixtures::note;
+
+    fn azir(summary: &str, changes: &[&str]) -> PatchNoteEntry {
+        PatchNoteEntry {
+            summary: summary.into(),
+            ..note("Азир", PatchCategory::Champions, ChangeType::Nerf, changes)
+        }
+    }
+
+    #[test]
+    fn auto_tags_pro_play_and_late_game() {
+        let n = azir(
+            "Азир слишком силён на профессиональной сцене, ослабляем его в поздней стадии игры.",
+            &["Урон: 50 → 45"],
+        );
+        let tags = auto_tags(&n);
+        assert!(tags.contains(&TAG_PRO_PLAY.to_string()));
+        assert!(tags.contains(&TAG_LATE_GAME.to_string()));
+        assert!(!tags.contains(&TAG_JUNGLE.to_string()));
+    }
+
+    #[test]
+    fn user_tags_replace_auto_tags() {
+        let mut notes = vec![azir("Competitive nerf.", &[])];
+        let user = HashMap::from([("Азир".to_string(), vec!["meta".to_string()])]);
+        apply_tags(&mut notes, &user);
+        assert_eq!(notes[0].tags, vec!["meta".to_string()]);
+    }
+}
@@ -2,6 +2,9 @@
 //! Смещение +10 к major для DDragon >= 15.
 
 use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Utc};
 
 pub const DD_MAJOR_OFFSET: i32 = 10;
 
@@ -18,41 +21,141 @@ pub fn ddragon_pair_to_display(major: i32, minor: i32) -> String {
     format!("{}.{}", m, minor)
 }
 
+/// Номер патча с игровым порядком. Понимает "25.22", DDragon "15.22" / "15.22.1",
+/// сплитовую запись "25.S1.1" и хотфиксы "25.22b". Разные записи одного патча равны.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PatchVersion {
+    /// Major в нумерации DDragon (сезон 2025 = 15)
+    pub major: i32,
+    pub minor: i32,
+    /// Буква хотфикса в нижнем регистре; `None` < `Some`, поэтому хотфикс идёт сразу после патча
+    pub hotfix: Option<String>,
+}
+
+impl PatchVersion {
+    pub fn parse(version: &str) -> Option<Self> {
+        let (base, hotfix) = split_hotfix(version.trim().trim_start_matches(['v', 'V']));
+        let mut it = base.split('.');
+        let maj: i32 = it.next()?.parse().ok()?;
+        let second = it.next()?;
+        // "25.S2.3" — третий патч второго сплита
+        let minor = match second.strip_prefix(['S', 's']) {
+            Some(split) => {
+                let split: i32 = split.parse().ok().filter(|s| *s >= 1)?;
+                let n: i32 = it.next()?.parse().ok()?;
+                (split - 1) * PATCHES_PER_SPLIT + n
+            }
+            None => second.parse().ok()?,
+        };
+        let major = if maj >= DISPLAY_MAJOR_MAP_TO_DDRAGON_FROM {
+            maj - DD_MAJOR_OFFSET
+        } else {
+            maj
+        };
+        Some(Self {
+            major,
+            minor,
+            hotfix: hotfix.map(str::to_lowercase),
+        })
+    }
+
+    /// Версия без хотфикса.
+    pub fn base(&self) -> Self {
+        Self {
+            hotfix: None,
+            ..self.clone()
+        }
+    }
+}
+
+/// Каноничная display-запись: "25.22", "25.22b".
+impl fmt::Display for PatchVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            ddragon_pair_to_display(self.major, self.minor),
+            self.hotfix.as_deref().unwrap_or("")
+        )
+    }
+}
+
 pub fn versions_match(a: &str, b: &str) -> bool {
     let a = a.trim();
     let b = b.trim();
     if a == b {
         return true;
     }
-    match (
-        display_patch_to_ddragon_major_minor(a),
-        display_patch_to_ddragon_major_minor(b),
-    ) {
+    match (PatchVersion::parse(a), PatchVersion::parse(b)) {
         (Some(pa), Some(pb)) => pa == pb,
         _ => false,
     }
 }
 
+/// (major DDragon, minor) для обычного патча; для хотфикса и нераспознанной строки — `None`.
 pub fn display_patch_to_ddragon_major_minor(display: &str) -> Option<(i32, i32)> {
-    let mut it = display.trim().split('.');
-    let maj: i32 = it.next()?.parse().ok()?;
-    let min: i32 = it.next()?.parse().ok()?;
-    let dd_maj = if maj >= DISPLAY_MAJOR_MAP_TO_DDRAGON_FROM {
-        maj - DD_MAJOR_OFFSET
-    } else {
-        maj
-    };
-    Some((dd_maj, min))
+    PatchVersion::parse(display)
+        .filter(|v| v.hotfix.is_none())
+        .map(|v| (v.major, v.minor))
+}
+
+/// Хотфикс-версия Riot ("25.22b") -> ("25.22", Some("b")); обычная версия -> (версия, None).
+pub fn split_hotfix(version: &str) -> (&str, Option<&str>) {
+    let v = version.trim();
+    let base_len = v.trim_end_matches(|c: char| c.is_ascii_alphabetic()).len();
+    let (base, suffix) = v.split_at(base_len);
+    if suffix.is_empty() || !base.ends_with(|c: char| c.is_ascii_digit()) || !base.contains('.') {
+        return (v, None);
+    }
+    (base, Some(suffix))
+}
+
+/// Родительский патч хотфикса ("25.22b" -> "25.22").
+pub fn hotfix_parent(version: &str) -> Option<String> {
+    match split_hotfix(version) {
+        (base, Some(_)) => Some(base.to_string()),
+        _ => None,
+    }
+}
+
+/// Сплитов в сезоне и патчей в сплите (ранговый год Riot с 2024: три сплита по ~8 патчей).
+/// Это эвристика: Riot не публикует границы сплитов в списке патчей, а реальный сплит бывает
+/// на патч длиннее или короче. Поэтому номер патча — запасной вариант после отметки из статьи.
+const SPLITS_PER_SEASON: i32 = 3;
+const PATCHES_PER_SPLIT: i32 = 8;
+
+/// Сезон/сплит патча в формате "25.S1". Приоритет: отметка из заголовка статьи Riot,
+/// затем номер патча, затем дата выхода (январь–апрель — S1, май–август — S2, далее S3).
+pub fn season_split(version: &str, article_season: Option<&str>, release_date: DateTime<Utc>) -> String {
+    if let Some(s) = article_season.map(str::trim).filter(|s| !s.is_empty()) {
+        return s.to_string();
+    }
+    if let Some(PatchVersion { major, minor, .. }) = PatchVersion::parse(version).filter(|v| v.minor >= 1) {
+        let season = if major >= DDRAGON_MAJOR_USE_SEASON_DISPLAY_FROM {
+            major + DD_MAJOR_OFFSET
+        } else {
+            major
+        };
+        let split = ((minor - 1) / PATCHES_PER_SPLIT + 1).min(SPLITS_PER_SEASON);
+        return format!("{}.S{}", season, split);
+    }
+    let split = ((release_date.month() as i32 - 1) / 4 + 1).min(SPLITS_PER_SEASON);
+    format!("{:02}.S{}", release_date.year() % 100, split)
 }
 
 /// Сравнение display-версий по игровому порядку (без привязки к времени загрузки).
-/// Некорректные строки считаются минимальными.
+/// Хотфикс идёт сразу после своего патча. Некорректные строки считаются минимальными.
 pub fn cmp_display_patch(a: &str, b: &str) -> Ordering {
-    fn key(s: &str) -> (i32, i32) {
-        display_patch_to_ddragon_major_minor(s.trim())
-            .unwrap_or((i32::MIN, i32::MIN))
-    }
-    key(a).cmp(&key(b))
+    PatchVersion::parse(a).cmp(&PatchVersion::parse(b))
+}
+
+/// Соседи `version` на таймлайне (`versions` — от новой к старой): более новый и более старый патч.
+pub fn adjacent_versions(versions: &[String], version: &str) -> Vec<String> {
+    let Some(idx) = versions.iter().position(|v| versions_match(v, version)) else {
+        return Vec::new();
+    };
+    let newer = idx.checked_sub(1).and_then(|i| versions.get(i));
+    newer.into_iter().chain(versions.get(idx + 1)).cloned().collect()
 }
 
 #[cfg(test)]
@@ -115,4 +218,62 @@ mod tests {
         assert_eq!(cmp_display_patch("26.7", "26.8"), Ordering::Less);
         assert_eq!(cmp_display_patch("25.24", "15.24"), Ordering::Equal);
     }
+
+    #[test]
+    fn season_split_from_article_version_or_date() {
+        let date = chrono::DateTime::parse_from_rfc3339("2025-06-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(season_split("25.3", Some("25.S1"), date), "25.S1");
+        assert_eq!(season_split("25.9", None, date), "25.S2");
+        assert_eq!(season_split("15.24b", None, date), "25.S3");
+        assert_eq!(season_split("14.2", None, date), "14.S1");
+        assert_eq!(season_split("unknown", None, date), "25.S2");
+    }
+
+    #[test]
+    fn season_split_boundaries_by_patch_number() {
+        let date = chrono::Utc::now();
+        assert_eq!(season_split("25.1", None, date), "25.S1");
+        assert_eq!(season_split("25.8", None, date), "25.S1");
+        assert_eq!(season_split("25.9", None, date), "25.S2");
+        assert_eq!(season_split("25.16", None, date), "25.S2");
+        assert_eq!(season_split("25.17", None, date), "25.S3");
+        assert_eq!(season_split("25.24", None, date), "25.S3");
+        // Патчей больше 24 — последний сплит, а не несуществующий S4
+        assert_eq!(season_split("25.25", None, date), "25.S3");
+        assert_eq!(season_split("25.8b", None, date), "25.S1");
+    }
+
+    #[test]
+    fn patch_version_orders_split_and_ddragon_forms() {
+        let v = |s: &str| PatchVersion::parse(s).unwrap();
+        assert_eq!(v("25.S1.1"), v("25.1"));
+        assert_eq!(v("15.24.1"), v("25.24"));
+        assert_eq!(v("25.S2.1").to_string(), "25.9");
+        assert!(v("25.S1.3") < v("25.4"));
+        assert!(v("25.22") < v("25.22B") && v("25.22b") < v("25.23"));
+        assert!(v("25.24") < v("26.1"));
+        assert_eq!(PatchVersion::parse("25.S0.1"), None);
+        assert_eq!(PatchVersion::parse("latest"), None);
+        assert!(versions_match("25.S1.2", "15.2"));
+    }
+
+    #[test]
+    fn hotfix_sorts_right_after_parent() {
+        assert_eq!(split_hotfix("25.22b"), ("25.22", Some("b")));
+        assert_eq!(split_hotfix("25.22"), ("25.22", None));
+        assert_eq!(hotfix_parent("25.22b").as_deref(), Some("25.22"));
+        assert_eq!(cmp_display_patch("25.22b", "25.22"), Ordering::Greater);
+        assert_eq!(cmp_display_patch("25.22b", "25.23"), Ordering::Less);
+        assert!(!versions_match("25.22b", "25.22"));
+    }
+
+    #[test]
+    fn adjacent_versions_on_timeline() {
+        let versions: Vec<String> = ["25.23", "25.22b", "25.22", "25.21"].map(String::from).to_vec();
+        assert_eq!(adjacent_versions(&versions, "15.22"), ["25.22b", "25.21"]);
+        assert_eq!(adjacent_versions(&versions, "25.23"), ["25.22b"]);
+        assert!(adjacent_versions(&versions, "25.1").is_empty());
+    }
 }
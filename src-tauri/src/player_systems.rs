@@ -0,0 +1,214 @@
+//! Системы ранкеда и поведения как отдельные сущности: ограничения дуо, начисление LP, калибровка,
+//! снижение ранга, доджи, подбор игроков, честь, наказания, чат и жалобы, Vanguard. Разделы
+//! патч-нотов о них — в основном текст, поэтому строки делятся по упоминаниям тем так же, как
+//! объекты карты (`objectives::split_note`); у каждой темы своя история (`get_player_system_history`,
+//! `EntityRef::PlayerSystem`).
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{PatchCategory, PatchNoteEntry};
+use crate::objectives::split_note;
+use crate::ChampionHistoryEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PlayerSystem {
+    DuoQueue,
+    LpGains,
+    Placements,
+    Decay,
+    Dodging,
+    Matchmaking,
+    RankedRewards,
+    Honor,
+    Penalties,
+    ChatAndReports,
+    Vanguard,
+}
+
+/// Тема со строками названий для списка в интерфейсе.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PlayerSystemInfo {
+    pub system: PlayerSystem,
+    /// Ключ для `EntityRef::PlayerSystem`
+    pub id: String,
+    pub name_en: String,
+    pub name_ru: String,
+}
+
+const CATEGORIES: [PatchCategory; 3] = [PatchCategory::Ranked, PatchCategory::Behavioral, PatchCategory::Systems];
+
+impl PlayerSystem {
+    pub const ALL: [PlayerSystem; 11] = [
+        PlayerSystem::DuoQueue,
+        PlayerSystem::LpGains,
+        PlayerSystem::Placements,
+        PlayerSystem::Decay,
+        PlayerSystem::Dodging,
+        PlayerSystem::Matchmaking,
+        PlayerSystem::RankedRewards,
+        PlayerSystem::Honor,
+        PlayerSystem::Penalties,
+        PlayerSystem::ChatAndReports,
+        PlayerSystem::Vanguard,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            PlayerSystem::DuoQueue => "duo_queue",
+            PlayerSystem::LpGains => "lp_gains",
+            PlayerSystem::Placements => "placements",
+            PlayerSystem::Decay => "decay",
+            PlayerSystem::Dodging => "dodging",
+            PlayerSystem::Matchmaking => "matchmaking",
+            PlayerSystem::RankedRewards => "ranked_rewards",
+            PlayerSystem::Honor => "honor",
+            PlayerSystem::Penalties => "penalties",
+            PlayerSystem::ChatAndReports => "chat_and_reports",
+            PlayerSystem::Vanguard => "vanguard",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.id().eq_ignore_ascii_case(id.trim()))
+    }
+
+    fn names(self) -> (&'static str, &'static str) {
+        match self {
+            PlayerSystem::DuoQueue => ("Duo queue", "Игра в дуо"),
+            PlayerSystem::LpGains => ("LP gains", "Начисление LP"),
+            PlayerSystem::Placements => ("Placements", "Калибровка"),
+            PlayerSystem::Decay => ("Rank decay", "Снижение ранга"),
+            PlayerSystem::Dodging => ("Dodging", "Доджи"),
+            PlayerSystem::Matchmaking => ("Matchmaking and autofill", "Подбор и автозаполнение"),
+            PlayerSystem::RankedRewards => ("Ranked rewards", "Награды ранкеда"),
+            PlayerSystem::Honor => ("Honor", "Честь"),
+            PlayerSystem::Penalties => ("Penalties", "Наказания"),
+            PlayerSystem::ChatAndReports => ("Chat and reports", "Чат и жалобы"),
+            PlayerSystem::Vanguard => ("Vanguard", "Vanguard"),
+        }
+    }
+
+    /// Начала слов в нижнем регистре; пробел в конце — слово целиком (`" lp "`, а не «help»).
+    fn keywords(self) -> &'static [&'static str] {
+        match self {
+            PlayerSystem::DuoQueue => &[" duo", " дуо", " premade", " пати "],
+            PlayerSystem::LpGains => &[" lp ", " league points", " очков лиги", " очки лиги", " mmr "],
+            PlayerSystem::Placements => &[" placement", " provisional", " seeding", " калибров", " отборочн"],
+            PlayerSystem::Decay => &[" decay", " inactiv", " неактивн", " понижени"],
+            PlayerSystem::Dodging => &[" dodg", " додж", " выход из лобби"],
+            PlayerSystem::Matchmaking => &[" matchmaking", " autofill", " подбор", " автозаполн", " автоназнач"],
+            PlayerSystem::RankedRewards => &[" reward", " victorious", " наград", " победоносн"],
+            PlayerSystem::Honor => &[" honor", " чест"],
+            PlayerSystem::Penalties => &[
+                " penalt", " leaver", " afk ", " suspension", " lockout", " queue delay", " наказан", " штраф",
+                " блокировк", " покинувш", " бездейств",
+            ],
+            PlayerSystem::ChatAndReports => &[" chat", " report", " чат", " жалоб", " оскорблен"],
+            PlayerSystem::Vanguard => &[" vanguard"],
+        }
+    }
+
+    pub fn info(self) -> PlayerSystemInfo {
+        let (name_en, name_ru) = self.names();
+        PlayerSystemInfo {
+            system: self,
+            id: self.id().to_string(),
+            name_en: name_en.to_string(),
+            name_ru: name_ru.to_string(),
+        }
+    }
+}
+
+/// Темы, названные в тексте.
+fn mentioned(text: &str) -> Vec<PlayerSystem> {
+    let words: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let words = format!(" {} ", words.split_whitespace().collect::<Vec<_>>().join(" "));
+    PlayerSystem::ALL
+        .into_iter()
+        .filter(|s| s.keywords().iter().any(|k| words.contains(k)))
+        .collect()
+}
+
+/// Записи по темам: для каждой темы — копия записи только с относящимися к ней строками.
+pub fn extract(note: &PatchNoteEntry) -> Vec<(PlayerSystem, PatchNoteEntry)> {
+    if !CATEGORIES.contains(&note.category) {
+        return Vec::new();
+    }
+    split_note(note, mentioned, |s| format!("player_system:{}", s.id()))
+}
+
+/// Затрагивает ли запись тему `system`.
+pub fn mentions(note: &PatchNoteEntry, system: PlayerSystem) -> bool {
+    extract(note).iter().any(|(s, _)| *s == system)
+}
+
+/// История темы по сохранённым патчам, от старых к новым.
+pub async fn history(db: &Database, id: &str, split: Option<&str>) -> Result<Vec<ChampionHistoryEntry>> {
+    let system = PlayerSystem::from_id(id).ok_or_else(|| anyhow!("unknown player system {}", id))?;
+    let notes = db.get_category_history(&CATEGORIES, split).await?;
+    Ok(notes
+        .into_iter()
+        .flat_map(|entry| {
+            extract(&entry.change)
+                .into_iter()
+                .filter(|(s, _)| *s == system)
+                .map(|(_, note)| ChampionHistoryEntry::new(entry.patch_version.clone(), entry.date, entry.split.clone(), note))
+                .collect::<Vec<_>>()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeBlock, ChangeType};
+
+    #[test]
+    fn splits_ranked_section_by_topic() {
+        let note = PatchNoteEntry {
+            id: "ranked".into(),
+            title: "Ranked Updates".into(),
+            category: PatchCategory::Ranked,
+            change_type: ChangeType::Adjusted,
+            details: vec![
+                ChangeBlock {
+                    title: Some("Duo Restrictions".into()),
+                    icon_url: None,
+                    changes: vec![
+                        "Master+ players can no longer queue together.".into(),
+                        "You will now lose less LP after a dodge.".into(),
+                    ],
+                    children: vec![],
+                },
+                ChangeBlock {
+                    title: None,
+                    icon_url: None,
+                    changes: vec!["We'll help you find games faster.".into(), "Честь 5 даёт больше наград.".into()],
+                    children: vec![],
+                },
+            ],
+            ..Default::default()
+        };
+        let topics = extract(&note);
+        let ids: Vec<PlayerSystem> = topics.iter().map(|(s, _)| *s).collect();
+        assert_eq!(
+            ids,
+            [PlayerSystem::DuoQueue, PlayerSystem::LpGains, PlayerSystem::Dodging, PlayerSystem::RankedRewards, PlayerSystem::Honor]
+        );
+        let duo = &topics[0].1;
+        assert_eq!(duo.details[0].changes, ["Master+ players can no longer queue together."]);
+        assert_eq!(duo.entity_id.as_deref(), Some("player_system:duo_queue"));
+        assert!(mentions(&note, PlayerSystem::LpGains));
+        assert!(!mentions(&note, PlayerSystem::Matchmaking));
+    }
+}
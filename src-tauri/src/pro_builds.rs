@@ -0,0 +1,353 @@
+//! Сборки профессиональных игроков (probuilds.net) на чемпионах, изменённых последним патчем: в
+//! первые `INGEST_DAYS` дней после выхода патча игры про скачиваются в фоне и сохраняются по
+//! патчу, а отчёт по чемпиону (`generate_champion_report`, `get_pro_builds`) показывает, какие
+//! предметы про собирают после правок и что изменилось относительно прошлого патча.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
+use crate::models::{EntityRef, PatchCategory, PatchData};
+use crate::scraper::Scraper;
+
+pub const PROBUILDS_SOURCE: &str = "probuilds";
+const PROBUILDS_URL: &str = "https://www.probuilds.net/champions/details/";
+/// Сколько дней после выхода патча собирать игры про.
+pub const INGEST_DAYS: i64 = 7;
+/// Предметов в «основной сборке».
+const CORE_ITEMS: usize = 6;
+/// Аксессуары есть у всех и сборку не описывают.
+const TRINKETS: [&str; 3] = ["3340", "3363", "3364"];
+const INGEST_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+const FIRST_INGEST_DELAY: Duration = Duration::from_secs(10 * 60);
+
+/// Фоновый сбор включается в настройках (`set_pro_builds_enabled`): без него к probuilds.net не обращаемся.
+static INGEST_ENABLED: AtomicBool = AtomicBool::new(false);
+
+static ITEM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"/item/(\d{4,6})\.(?:png|webp)").unwrap());
+static PLAYER_SEL: Lazy<Selector> = Lazy::new(|| Selector::parse(r#"a[href*="/pros/details/"]"#).unwrap());
+static IMG_SEL: Lazy<Selector> = Lazy::new(|| Selector::parse("img").unwrap());
+static WIN_SEL: Lazy<Selector> = Lazy::new(|| Selector::parse(".win, .victory").unwrap());
+static LOSS_SEL: Lazy<Selector> = Lazy::new(|| Selector::parse(".loss, .lose, .defeat").unwrap());
+static TIME_SEL: Lazy<Selector> = Lazy::new(|| Selector::parse("[data-time], time[datetime]").unwrap());
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ProGame {
+    pub player: String,
+    pub win: Option<bool>,
+    pub played_at: Option<DateTime<Utc>>,
+    /// DDragon id предметов в порядке на странице
+    pub items: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ProItemUsage {
+    pub item_id: String,
+    pub name_en: Option<String>,
+    pub name_ru: Option<String>,
+    pub games: u32,
+    /// Доля игр с предметом (0..1)
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ProBuildsSnapshot {
+    /// id DDragon
+    pub champion: String,
+    /// Патч без хотфикса
+    pub patch_version: String,
+    pub source: String,
+    pub fetched_at: DateTime<Utc>,
+    pub games: Vec<ProGame>,
+    pub core_items: Vec<ProItemUsage>,
+}
+
+/// Сборки про по патчам и сдвиг основной сборки между двумя последними.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct ProBuildsOverview {
+    /// Новые патчи первыми
+    pub snapshots: Vec<ProBuildsSnapshot>,
+    /// Вошли в основную сборку на последнем патче
+    pub added: Vec<ProItemUsage>,
+    /// Выпали из неё
+    pub dropped: Vec<ProItemUsage>,
+}
+
+fn parse_time(el: ElementRef) -> Option<DateTime<Utc>> {
+    if let Some(ts) = el.value().attr("data-time").and_then(|t| t.trim().parse::<i64>().ok()) {
+        // Секунды или миллисекунды Unix
+        return if ts > 100_000_000_000 {
+            DateTime::from_timestamp_millis(ts)
+        } else {
+            DateTime::from_timestamp(ts, 0)
+        };
+    }
+    DateTime::parse_from_rfc3339(el.value().attr("datetime")?)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Игры со страницы чемпиона: строка игры — ближайший к ссылке на профиль игрока предок с иконками
+/// предметов. Игры раньше `since` отбрасываются, игры без даты остаются (страница и так о свежих).
+pub fn parse_probuilds(html: &str, since: DateTime<Utc>) -> Vec<ProGame> {
+    let document = Html::parse_document(html);
+    let mut seen = Vec::new();
+    let mut games = Vec::new();
+    for link in document.select(&PLAYER_SEL) {
+        let Some(row) = link
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|el| el.select(&IMG_SEL).any(|img| item_id(img).is_some()))
+        else {
+            continue;
+        };
+        if seen.contains(&row.id()) {
+            continue;
+        }
+        seen.push(row.id());
+
+        let mut items: Vec<String> = Vec::new();
+        for id in row.select(&IMG_SEL).filter_map(item_id) {
+            if !items.contains(&id) && !TRINKETS.contains(&id.as_str()) {
+                items.push(id);
+            }
+        }
+        let class_has = |words: &[&str]| {
+            row.value()
+                .attr("class")
+                .is_some_and(|c| c.split_whitespace().any(|w| words.contains(&w)))
+        };
+        let win = if class_has(&["win", "victory"]) || row.select(&WIN_SEL).next().is_some() {
+            Some(true)
+        } else if class_has(&["loss", "lose", "defeat"]) || row.select(&LOSS_SEL).next().is_some() {
+            Some(false)
+        } else {
+            None
+        };
+        let played_at = row.select(&TIME_SEL).find_map(parse_time);
+        if played_at.is_some_and(|t| t < since) {
+            continue;
+        }
+        let player = link.text().collect::<String>().trim().to_string();
+        games.push(ProGame {
+            player,
+            win,
+            played_at,
+            items,
+        });
+    }
+    games
+}
+
+fn item_id(img: ElementRef) -> Option<String> {
+    ["src", "data-src"]
+        .iter()
+        .filter_map(|attr| img.value().attr(attr))
+        .find_map(|src| ITEM_RE.captures(src))
+        .map(|c| c[1].to_string())
+}
+
+/// Самые частые предметы; `names` — id → (en, ru) из каталога.
+pub fn core_items(games: &[ProGame], names: &HashMap<String, (String, String)>) -> Vec<ProItemUsage> {
+    let mut counts: Vec<(String, u32)> = Vec::new();
+    for item in games.iter().flat_map(|g| &g.items) {
+        match counts.iter_mut().find(|(id, _)| id == item) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((item.clone(), 1)),
+        }
+    }
+    counts.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+    counts
+        .into_iter()
+        .take(CORE_ITEMS)
+        .map(|(item_id, n)| ProItemUsage {
+            name_en: names.get(&item_id).map(|n| n.0.clone()),
+            name_ru: names.get(&item_id).map(|n| n.1.clone()),
+            item_id,
+            games: n,
+            share: f64::from(n) / games.len().max(1) as f64,
+        })
+        .collect()
+}
+
+/// Предметы основной сборки `current`, которых не было в `previous`, и наоборот.
+pub fn build_shift(current: &ProBuildsSnapshot, previous: &ProBuildsSnapshot) -> (Vec<ProItemUsage>, Vec<ProItemUsage>) {
+    let missing = |a: &ProBuildsSnapshot, b: &ProBuildsSnapshot| -> Vec<ProItemUsage> {
+        a.core_items
+            .iter()
+            .filter(|i| !b.core_items.iter().any(|o| o.item_id == i.item_id))
+            .cloned()
+            .collect()
+    };
+    (missing(current, previous), missing(previous, current))
+}
+
+/// Чемпионы с записями в патче (по распознанной сущности).
+fn changed_champions(patch: &PatchData) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for note in patch.patch_notes.iter().filter(|n| n.category == PatchCategory::Champions) {
+        if let Some(EntityRef::Champion { id }) = note.entity_id.as_deref().and_then(EntityRef::from_entity_id) {
+            if !out.contains(&id) {
+                out.push(id);
+            }
+        }
+    }
+    out
+}
+
+async fn item_names(db: &Database) -> Result<HashMap<String, (String, String)>> {
+    Ok(db
+        .get_static_catalog_kind("item")
+        .await?
+        .into_iter()
+        .map(|r| (r.stable_id, (r.name_en, r.name_ru)))
+        .collect())
+}
+
+pub async fn fetch_snapshot(
+    scraper: &Scraper,
+    champion: &str,
+    patch_version: &str,
+    since: DateTime<Utc>,
+    names: &HashMap<String, (String, String)>,
+) -> Result<ProBuildsSnapshot> {
+    let html = scraper.fetch_third_party(&format!("{}{}", PROBUILDS_URL, champion)).await?;
+    let games = parse_probuilds(&html, since);
+    Ok(ProBuildsSnapshot {
+        champion: champion.to_string(),
+        patch_version: patch_version.to_string(),
+        source: PROBUILDS_SOURCE.to_string(),
+        fetched_at: Utc::now(),
+        core_items: core_items(&games, names),
+        games,
+    })
+}
+
+/// Обновляет сборки про для чемпионов последнего патча, пока он свежий; возвращает число сохранённых.
+pub async fn ingest(scraper: &Scraper, db: &Database) -> Result<usize> {
+    let Some(patch) = db.get_patches_newest_versions_first(1).await?.into_iter().next() else {
+        return Ok(0);
+    };
+    let released = patch.release_date();
+    if Utc::now() - released > chrono::Duration::days(INGEST_DAYS) {
+        return Ok(0);
+    }
+    let names = item_names(db).await?;
+    let mut saved = 0;
+    for champion in changed_champions(&patch) {
+        match fetch_snapshot(scraper, &champion, patch.base_version(), released, &names).await {
+            Ok(snapshot) if !snapshot.games.is_empty() => {
+                db.save_pro_builds(&snapshot).await?;
+                saved += 1;
+            }
+            Ok(_) => {}
+            Err(e) => logger::log(LogLevel::Debug, LogSource::Sync, &format!("pro builds {}: {}", champion, e)),
+        }
+    }
+    Ok(saved)
+}
+
+pub async fn overview(db: &Database, champion: &str) -> Result<ProBuildsOverview> {
+    let snapshots = db.get_pro_builds(champion).await?;
+    let (added, dropped) = match snapshots.as_slice() {
+        [current, previous, ..] => build_shift(current, previous),
+        _ => (Vec::new(), Vec::new()),
+    };
+    Ok(ProBuildsOverview {
+        snapshots,
+        added,
+        dropped,
+    })
+}
+
+pub fn set_ingest_enabled(enabled: bool) {
+    INGEST_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Фоновый сбор после выхода патча, пока он включён.
+pub async fn run_ingest_loop(db: Arc<Database>, scraper: Arc<Scraper>) {
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + FIRST_INGEST_DELAY, INGEST_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        if !INGEST_ENABLED.load(Ordering::Relaxed) {
+            continue;
+        }
+        match ingest(&scraper, &db).await {
+            Ok(0) => {}
+            Ok(saved) => logger::log_with(
+                LogLevel::Info,
+                LogSource::Sync,
+                "pro builds updated",
+                &[("champions", &saved)],
+            ),
+            Err(e) => logger::log(LogLevel::Warn, LogSource::Sync, &format!("pro builds: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pro_games_and_core_items() {
+        let html = r#"<html><body><ul>
+            <li class="pro-game win">
+                <a href="/pros/details/Faker">Faker</a>
+                <span data-time="1762300000"></span>
+                <img src="https://ddragon.leagueoflegends.com/cdn/15.22.1/img/item/6655.png">
+                <img data-src="/img/item/3020.webp"><img src="/img/item/3340.png">
+            </li>
+            <li class="pro-game">
+                <div class="result loss"></div>
+                <a href="/pros/details/Chovy">Chovy</a>
+                <img src="/img/item/6655.png"><img src="/img/item/3157.png">
+            </li>
+            <li class="pro-game">
+                <a href="/pros/details/Caps">Caps</a>
+                <span data-time="1700000000000"></span>
+                <img src="/img/item/3089.png">
+            </li>
+        </ul></body></html>"#;
+        let since = DateTime::from_timestamp(1_762_000_000, 0).unwrap();
+        let games = parse_probuilds(html, since);
+        let summary: Vec<(&str, Option<bool>, usize)> =
+            games.iter().map(|g| (g.player.as_str(), g.win, g.items.len())).collect();
+        assert_eq!(summary, [("Faker", Some(true), 2), ("Chovy", Some(false), 2)]);
+
+        let names = HashMap::from([("6655".to_string(), ("Luden's Echo".to_string(), "Эхо Людена".to_string()))]);
+        let core = core_items(&games, &names);
+        assert_eq!((core[0].item_id.as_str(), core[0].share), ("6655", 1.0));
+        assert_eq!(core[0].name_en.as_deref(), Some("Luden's Echo"));
+
+        let snapshot = |patch: &str, core_items| ProBuildsSnapshot {
+            champion: "Ahri".into(),
+            patch_version: patch.into(),
+            source: PROBUILDS_SOURCE.into(),
+            fetched_at: Utc::now(),
+            games: vec![],
+            core_items,
+        };
+        let previous = snapshot("25.21", core_items(&games[1..], &names));
+        let (added, dropped) = build_shift(&snapshot("25.22", core_items(&games[..1], &names)), &previous);
+        assert_eq!(added.iter().map(|i| i.item_id.as_str()).collect::<Vec<_>>(), ["3020"]);
+        assert_eq!(dropped.iter().map(|i| i.item_id.as_str()).collect::<Vec<_>>(), ["3157"]);
+    }
+}
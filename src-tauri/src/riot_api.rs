@@ -0,0 +1,647 @@
+//! Официальный Riot Games API с ключом пользователя (developer.riotgames.com): бесплатная ротация
+//! чемпионов, статус серверов платформы, аккаунт по Riot ID, мастерство чемпионов и рейтинговые матчи
+//! (Match-V5). Дополняет разобранные с сайта данные; без ключа команды
+//! возвращают ошибку, а индикатор статуса в шапке скрыт. Статус опрашивается в фоне: новые
+//! инциденты и технические работы приходят событием `server_status_changed`, а текущая проблема —
+//! баннером в `get_app_status` (выход патча обычно совпадает с работами на серверах).
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::http_body;
+use crate::models::ChampionMeta;
+use crate::patch_version::PatchVersion;
+use crate::scraper::Scraper;
+
+pub const DEFAULT_PLATFORM: &str = "euw1";
+/// Платформенные маршруты League of Legends.
+pub const PLATFORMS: [&str; 15] = [
+    "br1", "eun1", "euw1", "jp1", "kr", "la1", "la2", "me1", "na1", "oc1", "ru", "sg2", "tr1", "tw2", "vn2",
+];
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Региональный маршрут для account-v1: у него только americas, asia и europe.
+fn regional_route(platform: &str) -> &'static str {
+    match platform {
+        "br1" | "la1" | "la2" | "na1" => "americas",
+        "eun1" | "euw1" | "me1" | "ru" | "tr1" => "europe",
+        _ => "asia",
+    }
+}
+
+/// Регион для match-v5: в отличие от account-v1, у Океании и Юго-Восточной Азии свой `sea`.
+fn match_route(platform: &str) -> &'static str {
+    match platform {
+        "oc1" | "sg2" | "tw2" | "vn2" => "sea",
+        _ => regional_route(platform),
+    }
+}
+
+/// Куда отправлять запрос: на платформу (`euw1`) или в её регион (`europe`, для матчей — `sea` и т. п.).
+#[derive(Debug, Clone, Copy)]
+enum Route {
+    Platform,
+    Regional,
+    Match,
+}
+
+/// Ключ и платформа; хранятся в настройках фронтенда и передаются при старте (`set_riot_api_settings`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RiotApiSettings {
+    pub api_key: String,
+    pub platform: String,
+}
+
+impl Default for RiotApiSettings {
+    fn default() -> Self {
+        Self {
+            api_key: String::new(),
+            platform: DEFAULT_PLATFORM.to_string(),
+        }
+    }
+}
+
+impl RiotApiSettings {
+    /// Обрезает ключ и заменяет неизвестную платформу на платформу по умолчанию.
+    pub fn normalized(self) -> Self {
+        let platform = self.platform.trim().to_lowercase();
+        Self {
+            api_key: self.api_key.trim().to_string(),
+            platform: if PLATFORMS.contains(&platform.as_str()) {
+                platform
+            } else {
+                DEFAULT_PLATFORM.to_string()
+            },
+        }
+    }
+
+    pub fn is_configured(&self) -> bool {
+        !self.api_key.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct RotationChampion {
+    /// Числовой ключ Riot
+    pub key: i32,
+    /// DDragon id, если чемпион есть в каталоге
+    pub id: Option<String>,
+    pub name_en: Option<String>,
+    pub name_ru: Option<String>,
+}
+
+/// Аккаунт Riot по Riot ID.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RiotAccount {
+    pub puuid: String,
+    pub game_name: String,
+    pub tag_line: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MasteryChampion {
+    pub key: i32,
+    pub id: Option<String>,
+    pub name_en: Option<String>,
+    pub name_ru: Option<String>,
+    pub level: i32,
+    pub points: i64,
+    /// Последняя игра на чемпионе, мс Unix
+    #[ts(type = "number")]
+    pub last_play_time: i64,
+}
+
+/// Рейтинговый матч глазами игрока: его чемпион и результат.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RankedMatch {
+    pub match_id: String,
+    /// Числовой ключ Riot
+    pub champion_key: i32,
+    /// DDragon id (`championName` в Match-V5)
+    pub champion_id: String,
+    pub win: bool,
+    /// TOP / JUNGLE / MIDDLE / BOTTOM / UTILITY
+    pub role: Option<String>,
+    pub queue_id: i32,
+    pub game_start: DateTime<Utc>,
+    /// Версия клиента ("15.22.715.1234")
+    pub game_version: String,
+    /// Номер патча как на сайте ("25.22")
+    pub patch_version: String,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ChampionRotation {
+    pub platform: String,
+    pub champions: Vec<RotationChampion>,
+    /// Ротация для новых игроков до `max_new_player_level`
+    pub new_player_champions: Vec<RotationChampion>,
+    pub max_new_player_level: i32,
+    pub fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "lowercase")]
+#[ts(export)]
+pub enum ServerHealth {
+    Online,
+    /// Есть инциденты
+    Degraded,
+    /// Идут технические работы
+    Maintenance,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct StatusIssue {
+    pub id: i64,
+    /// `maintenance` или `incident`
+    pub kind: String,
+    /// info / warning / critical — только у инцидентов
+    pub severity: Option<String>,
+    /// scheduled / in_progress / complete — только у работ
+    pub status: Option<String>,
+    pub title: String,
+    /// Последнее сообщение Riot по проблеме
+    pub latest_update: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PlatformStatus {
+    pub platform: String,
+    pub name: String,
+    pub health: ServerHealth,
+    pub issues: Vec<StatusIssue>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Баннер о проблеме на серверах для `get_app_status`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ServerBanner {
+    pub platform: String,
+    pub name: String,
+    pub health: ServerHealth,
+    /// Заголовок первой проблемы: сначала идущие работы, затем инциденты
+    pub title: String,
+    pub issues: usize,
+}
+
+impl PlatformStatus {
+    /// `None`, когда серверы работают штатно.
+    pub fn banner(&self) -> Option<ServerBanner> {
+        if self.health == ServerHealth::Online {
+            return None;
+        }
+        let title = self
+            .issues
+            .iter()
+            .find(|i| i.status.as_deref() == Some("in_progress"))
+            .or_else(|| self.issues.iter().find(|i| i.kind == "incident"))
+            .map(|i| i.title.clone())
+            .unwrap_or_default();
+        Some(ServerBanner {
+            platform: self.platform.clone(),
+            name: self.name.clone(),
+            health: self.health,
+            title,
+            issues: self.issues.len(),
+        })
+    }
+}
+
+/// Проблемы, которых не было в прошлом опросе; начало запланированных работ тоже считается новым.
+pub fn new_issues(previous: Option<&PlatformStatus>, current: &PlatformStatus) -> Vec<StatusIssue> {
+    let known = |issue: &StatusIssue| {
+        previous.is_some_and(|p| {
+            p.platform == current.platform
+                && p.issues
+                    .iter()
+                    .any(|i| i.kind == issue.kind && i.id == issue.id && i.status == issue.status)
+        })
+    };
+    current.issues.iter().filter(|i| !known(i)).cloned().collect()
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawRotation {
+    free_champion_ids: Vec<i32>,
+    free_champion_ids_for_new_players: Vec<i32>,
+    max_new_player_level: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAccount {
+    puuid: String,
+    #[serde(default)]
+    game_name: Option<String>,
+    #[serde(default)]
+    tag_line: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMastery {
+    champion_id: i32,
+    champion_level: i32,
+    champion_points: i64,
+    #[serde(default)]
+    last_play_time: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawParticipant {
+    puuid: String,
+    champion_id: i32,
+    champion_name: String,
+    win: bool,
+    #[serde(default)]
+    team_position: String,
+    #[serde(default)]
+    game_ended_in_early_surrender: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMatchInfo {
+    game_creation: i64,
+    game_version: String,
+    queue_id: i32,
+    participants: Vec<RawParticipant>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMatchMetadata {
+    match_id: String,
+}
+
+#[derive(Deserialize)]
+struct RawMatch {
+    metadata: RawMatchMetadata,
+    info: RawMatchInfo,
+}
+
+/// Участник `puuid`; ремейки (досрочная сдача) не считаются.
+fn ranked_match_from(raw: RawMatch, puuid: &str) -> Option<RankedMatch> {
+    let p = raw.info.participants.into_iter().find(|p| p.puuid == puuid)?;
+    if p.game_ended_in_early_surrender {
+        return None;
+    }
+    let patch_version = PatchVersion::parse(&raw.info.game_version)?.base().to_string();
+    Some(RankedMatch {
+        match_id: raw.metadata.match_id,
+        champion_key: p.champion_id,
+        champion_id: p.champion_name,
+        win: p.win,
+        role: Some(p.team_position).filter(|r| !r.is_empty()),
+        queue_id: raw.info.queue_id,
+        game_start: DateTime::from_timestamp_millis(raw.info.game_creation)?,
+        game_version: raw.info.game_version,
+        patch_version,
+    })
+}
+
+#[derive(Deserialize)]
+struct RawTranslation {
+    locale: String,
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct RawUpdate {
+    #[serde(default)]
+    translations: Vec<RawTranslation>,
+}
+
+#[derive(Deserialize)]
+struct RawStatus {
+    id: i64,
+    maintenance_status: Option<String>,
+    incident_severity: Option<String>,
+    #[serde(default)]
+    titles: Vec<RawTranslation>,
+    #[serde(default)]
+    updates: Vec<RawUpdate>,
+    created_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawPlatformData {
+    id: String,
+    name: String,
+    #[serde(default)]
+    maintenances: Vec<RawStatus>,
+    #[serde(default)]
+    incidents: Vec<RawStatus>,
+}
+
+/// Текст на языке приложения, иначе английский, иначе первый.
+fn localized(translations: &[RawTranslation], lang: &str) -> Option<String> {
+    let wanted = if lang.starts_with("ru") { "ru_RU" } else { "en_US" };
+    translations
+        .iter()
+        .find(|t| t.locale == wanted)
+        .or_else(|| translations.iter().find(|t| t.locale == "en_US"))
+        .or_else(|| translations.first())
+        .map(|t| t.content.trim().to_string())
+}
+
+fn issue(raw: RawStatus, kind: &str, lang: &str) -> StatusIssue {
+    StatusIssue {
+        id: raw.id,
+        kind: kind.to_string(),
+        severity: raw.incident_severity,
+        status: raw.maintenance_status,
+        title: localized(&raw.titles, lang).unwrap_or_default(),
+        latest_update: raw.updates.first().and_then(|u| localized(&u.translations, lang)),
+        created_at: raw.created_at,
+    }
+}
+
+fn platform_status_from(raw: RawPlatformData, lang: &str) -> PlatformStatus {
+    let maintenance = raw
+        .maintenances
+        .iter()
+        .any(|m| m.maintenance_status.as_deref() == Some("in_progress"));
+    let health = if maintenance {
+        ServerHealth::Maintenance
+    } else if !raw.incidents.is_empty() {
+        ServerHealth::Degraded
+    } else {
+        ServerHealth::Online
+    };
+    let issues = raw
+        .maintenances
+        .into_iter()
+        .map(|m| issue(m, "maintenance", lang))
+        .chain(raw.incidents.into_iter().map(|i| issue(i, "incident", lang)))
+        .collect();
+    PlatformStatus {
+        platform: raw.id.to_lowercase(),
+        name: raw.name,
+        health,
+        issues,
+        fetched_at: Utc::now(),
+    }
+}
+
+#[derive(Default)]
+pub struct RiotApi {
+    settings: RwLock<RiotApiSettings>,
+    /// Последний полученный статус платформы
+    last_status: RwLock<Option<PlatformStatus>>,
+}
+
+impl RiotApi {
+    pub fn settings(&self) -> RiotApiSettings {
+        self.settings.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Применяет настройки и возвращает действующие.
+    pub fn set_settings(&self, settings: RiotApiSettings) -> RiotApiSettings {
+        let settings = settings.normalized();
+        let mut current = self.settings.write().unwrap_or_else(|e| e.into_inner());
+        if *current != settings {
+            *self.last_status.write().unwrap_or_else(|e| e.into_inner()) = None;
+        }
+        *current = settings.clone();
+        settings
+    }
+
+    pub fn last_status(&self) -> Option<PlatformStatus> {
+        self.last_status.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// `segments` экранируются: в Riot ID бывают пробелы и не-ASCII.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        client: &reqwest::Client,
+        route: Route,
+        segments: &[&str],
+        query: &[(&str, &str)],
+    ) -> Result<(String, T)> {
+        let settings = self.settings();
+        if !settings.is_configured() {
+            bail!("Riot API key is not set");
+        }
+        let host = match route {
+            Route::Platform => settings.platform.as_str(),
+            Route::Regional => regional_route(&settings.platform),
+            Route::Match => match_route(&settings.platform),
+        };
+        let mut url = Url::parse(&format!("https://{}.api.riotgames.com/", host))?;
+        url.path_segments_mut()
+            .map_err(|_| anyhow::anyhow!("invalid Riot API url"))?
+            .pop_if_empty()
+            .extend(segments);
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        let resp = client.get(url).header("X-Riot-Token", &settings.api_key).send().await?;
+        let status = resp.status();
+        match status.as_u16() {
+            404 => bail!("Riot API: not found"),
+            401 | 403 => bail!("Riot API key was rejected ({}): development keys expire after 24 hours", status),
+            429 => bail!("Riot API rate limit exceeded, try again later"),
+            _ if !status.is_success() => {
+                let text = http_body::read_text(resp).await.unwrap_or_default();
+                bail!("Riot API {}: {}", status, text.chars().take(280).collect::<String>());
+            }
+            _ => Ok((settings.platform, http_body::read_json::<T>(resp).await?)),
+        }
+    }
+
+    /// Бесплатные чемпионы недели; имена — из каталога DDragon.
+    pub async fn champion_rotation(&self, client: &reqwest::Client, db: &Database) -> Result<ChampionRotation> {
+        let (platform, raw) = self
+            .get_json::<RawRotation>(client, Route::Platform, &["lol", "platform", "v3", "champion-rotations"], &[])
+            .await?;
+        let catalog = champion_catalog(db).await?;
+        let champion = |key: i32| {
+            let meta = catalog.iter().find(|c| c.key == key.to_string());
+            RotationChampion {
+                key,
+                id: meta.map(|c| c.id.clone()),
+                name_en: meta.map(|c| c.name_en.clone()),
+                name_ru: meta.map(|c| c.name_ru.clone()),
+            }
+        };
+        Ok(ChampionRotation {
+            platform,
+            champions: raw.free_champion_ids.into_iter().map(champion).collect(),
+            new_player_champions: raw.free_champion_ids_for_new_players.into_iter().map(champion).collect(),
+            max_new_player_level: raw.max_new_player_level,
+            fetched_at: Utc::now(),
+        })
+    }
+
+    /// Статус платформы: технические работы и инциденты с текстами на языке `lang`.
+    pub async fn platform_status(&self, client: &reqwest::Client, lang: &str) -> Result<PlatformStatus> {
+        let (_, raw) = self
+            .get_json::<RawPlatformData>(client, Route::Platform, &["lol", "status", "v4", "platform-data"], &[])
+            .await?;
+        let status = platform_status_from(raw, lang);
+        *self.last_status.write().unwrap_or_else(|e| e.into_inner()) = Some(status.clone());
+        Ok(status)
+    }
+
+    pub async fn account_by_riot_id(&self, client: &reqwest::Client, game_name: &str, tag_line: &str) -> Result<RiotAccount> {
+        let (_, raw) = self
+            .get_json::<RawAccount>(
+                client,
+                Route::Regional,
+                &["riot", "account", "v1", "accounts", "by-riot-id", game_name, tag_line],
+                &[],
+            )
+            .await?;
+        Ok(RiotAccount {
+            puuid: raw.puuid,
+            game_name: raw.game_name.unwrap_or_else(|| game_name.to_string()),
+            tag_line: raw.tag_line.unwrap_or_else(|| tag_line.to_string()),
+        })
+    }
+
+    /// Чемпионы с наибольшим мастерством на текущей платформе.
+    pub async fn top_masteries(
+        &self,
+        client: &reqwest::Client,
+        db: &Database,
+        puuid: &str,
+        count: usize,
+    ) -> Result<Vec<MasteryChampion>> {
+        let count = count.to_string();
+        let (_, raw) = self
+            .get_json::<Vec<RawMastery>>(
+                client,
+                Route::Platform,
+                &["lol", "champion-mastery", "v4", "champion-masteries", "by-puuid", puuid, "top"],
+                &[("count", &count)],
+            )
+            .await?;
+        let catalog = champion_catalog(db).await?;
+        Ok(raw
+            .into_iter()
+            .map(|m| {
+                let meta = catalog.iter().find(|c| c.key == m.champion_id.to_string());
+                MasteryChampion {
+                    key: m.champion_id,
+                    id: meta.map(|c| c.id.clone()),
+                    name_en: meta.map(|c| c.name_en.clone()),
+                    name_ru: meta.map(|c| c.name_ru.clone()),
+                    level: m.champion_level,
+                    points: m.champion_points,
+                    last_play_time: m.last_play_time,
+                }
+            })
+            .collect())
+    }
+
+    /// Id последних рейтинговых матчей (одиночная и флекс очереди), новые первыми; не больше 100 за запрос.
+    pub async fn ranked_match_ids(&self, client: &reqwest::Client, puuid: &str, count: usize) -> Result<Vec<String>> {
+        let count = count.clamp(1, 100).to_string();
+        let (_, ids) = self
+            .get_json::<Vec<String>>(
+                client,
+                Route::Match,
+                &["lol", "match", "v5", "matches", "by-puuid", puuid, "ids"],
+                &[("type", "ranked"), ("start", "0"), ("count", &count)],
+            )
+            .await?;
+        Ok(ids)
+    }
+
+    /// Матч `match_id` глазами `puuid`; `None` для ремейка.
+    pub async fn ranked_match(&self, client: &reqwest::Client, match_id: &str, puuid: &str) -> Result<Option<RankedMatch>> {
+        let (_, raw) = self
+            .get_json::<RawMatch>(client, Route::Match, &["lol", "match", "v5", "matches", match_id], &[])
+            .await?;
+        Ok(ranked_match_from(raw, puuid))
+    }
+}
+
+pub(crate) async fn champion_catalog(db: &Database) -> Result<Vec<ChampionMeta>> {
+    Ok(db
+        .get_static_catalog_kind("champion")
+        .await?
+        .iter()
+        .filter_map(ChampionMeta::from_catalog_row)
+        .collect())
+}
+
+/// Фоновый опрос статуса; без ключа запросы не отправляются.
+pub async fn run_status_loop(app: AppHandle, scraper: Arc<Scraper>) {
+    let mut interval = tokio::time::interval(STATUS_POLL_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        crate::poll_server_status(&app, &scraper).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_status_prefers_maintenance_and_app_language() {
+        let raw: RawPlatformData = serde_json::from_str(
+            r#"{
+                "id": "EUW1", "name": "EU West", "locales": ["en_US"],
+                "maintenances": [{
+                    "id": 1, "maintenance_status": "in_progress", "incident_severity": null,
+                    "titles": [{"locale": "en_US", "content": "Ranked disabled"}, {"locale": "ru_RU", "content": "Рейтинговые игры отключены"}],
+                    "updates": [{"translations": [{"locale": "en_US", "content": "Investigating"}]}],
+                    "created_at": "2025-11-04T10:00:00Z", "platforms": ["windows"]
+                }],
+                "incidents": [{
+                    "id": 2, "maintenance_status": null, "incident_severity": "warning",
+                    "titles": [{"locale": "en_US", "content": "Login issues"}], "updates": []
+                }]
+            }"#,
+        )
+        .unwrap();
+        let status = platform_status_from(raw, "ru");
+        assert_eq!(status.platform, "euw1");
+        assert_eq!(status.health, ServerHealth::Maintenance);
+        assert_eq!(status.issues[0].title, "Рейтинговые игры отключены");
+        assert_eq!(status.issues[0].latest_update.as_deref(), Some("Investigating"));
+        assert_eq!(status.issues[1].kind, "incident");
+        let banner = status.banner().unwrap();
+        assert_eq!((banner.health, banner.title.as_str()), (ServerHealth::Maintenance, "Рейтинговые игры отключены"));
+
+        // Та же проблема повторно не сообщается, а смена статуса работ — сообщается
+        assert_eq!(new_issues(None, &status).len(), 2);
+        assert!(new_issues(Some(&status), &status).is_empty());
+        let mut completed = status.clone();
+        completed.issues[0].status = Some("complete".into());
+        assert_eq!(new_issues(Some(&status), &completed).len(), 1);
+
+        let settings = RiotApiSettings {
+            api_key: " RGAPI-x ".into(),
+            platform: "XX9".into(),
+        }
+        .normalized();
+        assert_eq!((settings.api_key.as_str(), settings.platform.as_str()), ("RGAPI-x", DEFAULT_PLATFORM));
+    }
+}
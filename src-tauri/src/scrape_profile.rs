@@ -0,0 +1,166 @@
+//! Время этапов загрузки патча (ожидание лимита Riot, скачивание, разбор, классификация, картинки,
+//! сохранение) по последним патчам — для `get_scrape_profile`, когда синхронизация «идёт десять минут».
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+/// Сколько последних патчей помнить (полная синхронизация — до 100 версий).
+const PROFILE_CAPACITY: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrapePhase {
+    /// Ожидание общего ограничителя частоты запросов к Riot
+    Throttle,
+    Download,
+    Parse,
+    /// Теги, оценки изменений, привязка к сущностям
+    Classify,
+    /// Скачивание картинок в локальный кэш
+    Assets,
+    Save,
+}
+
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct PhaseTimings {
+    #[ts(type = "number")]
+    pub throttle_ms: u64,
+    #[ts(type = "number")]
+    pub download_ms: u64,
+    #[ts(type = "number")]
+    pub parse_ms: u64,
+    #[ts(type = "number")]
+    pub classify_ms: u64,
+    #[ts(type = "number")]
+    pub assets_ms: u64,
+    #[ts(type = "number")]
+    pub save_ms: u64,
+}
+
+impl PhaseTimings {
+    fn add(&mut self, phase: ScrapePhase, elapsed: Duration) {
+        let ms = elapsed.as_millis() as u64;
+        let slot = match phase {
+            ScrapePhase::Throttle => &mut self.throttle_ms,
+            ScrapePhase::Download => &mut self.download_ms,
+            ScrapePhase::Parse => &mut self.parse_ms,
+            ScrapePhase::Classify => &mut self.classify_ms,
+            ScrapePhase::Assets => &mut self.assets_ms,
+            ScrapePhase::Save => &mut self.save_ms,
+        };
+        *slot += ms;
+    }
+
+    fn merge(&mut self, other: &PhaseTimings) {
+        self.throttle_ms += other.throttle_ms;
+        self.download_ms += other.download_ms;
+        self.parse_ms += other.parse_ms;
+        self.classify_ms += other.classify_ms;
+        self.assets_ms += other.assets_ms;
+        self.save_ms += other.save_ms;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PatchScrapeProfile {
+    pub version: String,
+    pub locale: String,
+    pub updated_at: DateTime<Utc>,
+    pub timings: PhaseTimings,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ScrapeProfileReport {
+    /// Новые сначала
+    pub patches: Vec<PatchScrapeProfile>,
+    /// Сумма по всем патчам отчёта
+    pub totals: PhaseTimings,
+}
+
+#[derive(Default)]
+pub struct ScrapeProfiler {
+    entries: Mutex<VecDeque<PatchScrapeProfile>>,
+}
+
+impl ScrapeProfiler {
+    pub fn record(&self, version: &str, locale: &str, phase: ScrapePhase, elapsed: Duration) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let idx = entries
+            .iter()
+            .position(|p| p.version == version && p.locale == locale);
+        let mut entry = match idx.and_then(|i| entries.remove(i)) {
+            Some(entry) => entry,
+            None => PatchScrapeProfile {
+                version: version.to_string(),
+                locale: locale.to_string(),
+                updated_at: Utc::now(),
+                timings: PhaseTimings::default(),
+            },
+        };
+        entry.timings.add(phase, elapsed);
+        entry.updated_at = Utc::now();
+        entries.push_front(entry);
+        entries.truncate(PROFILE_CAPACITY);
+    }
+
+    /// Замеряет `f` и записывает время в этап патча.
+    pub async fn time<T>(
+        &self,
+        version: &str,
+        locale: &str,
+        phase: ScrapePhase,
+        f: impl std::future::Future<Output = T>,
+    ) -> T {
+        let started = Instant::now();
+        let out = f.await;
+        self.record(version, locale, phase, started.elapsed());
+        out
+    }
+
+    pub fn report(&self) -> ScrapeProfileReport {
+        let patches: Vec<PatchScrapeProfile> = self
+            .entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect();
+        let mut totals = PhaseTimings::default();
+        for p in &patches {
+            totals.merge(&p.timings);
+        }
+        ScrapeProfileReport { patches, totals }
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulates_phases_per_patch_newest_first() {
+        let profiler = ScrapeProfiler::default();
+        profiler.record("25.21", "ru", ScrapePhase::Download, Duration::from_millis(300));
+        profiler.record("25.22", "ru", ScrapePhase::Download, Duration::from_millis(200));
+        profiler.record("25.21", "ru", ScrapePhase::Parse, Duration::from_millis(40));
+        profiler.record("25.21", "ru", ScrapePhase::Download, Duration::from_millis(100));
+
+        let report = profiler.report();
+        assert_eq!(report.patches.len(), 2);
+        assert_eq!(report.patches[0].version, "25.21");
+        assert_eq!(report.patches[0].timings.download_ms, 400);
+        assert_eq!(report.patches[0].timings.parse_ms, 40);
+        assert_eq!(report.totals.download_ms, 600);
+    }
+}
@@ -1,19 +1,41 @@
 use std::collections::HashSet;
-use std::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use reqwest::Url;
 use reqwest::header;
 use scraper::{Html, Selector, ElementRef};
 use anyhow::Result;
 use crate::models::{
-    ChampionStats, ChangeBlock, ChangeType, ItemStat, LaneRole, MayhemAugmentation, PatchCategory,
-    PatchData, PatchNoteEntry,
+    ChampionStats, ChangeBlock, ChangeType, DevArticle, ItemStat, LaneRole, MayhemAugmentation, PatchCategory,
+    PatchData, PatchNoteEntry, RuneEntry,
 };
-use crate::patch_version::ddragon_pair_to_display;
 use crate::patch_change_trend::analyze_change_trend;
-use chrono::Utc;
+use crate::in_flight::InFlight;
+use crate::i18n::{self, Lang};
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource, UnsupportedLayoutEvent};
+use crate::http_body;
+use crate::page_layout::{self, PageLayout};
+use crate::parse_coverage::{self, ParseCoverage, ParsedSection};
+use crate::scrape_profile::{ScrapePhase, ScrapeProfiler};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use once_cell::sync::Lazy;
 use regex::Regex;
 
+/// Метаданные статьи патч-нотов (шапка страницы Riot).
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PatchArticleMeta {
+    pub banner_url: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub author: Option<String>,
+    pub season: Option<String>,
+    /// Номер патча из заголовка статьи или адреса страницы ("25.22", "25.22b")
+    pub version: Option<String>,
+}
+
 fn patch_category_from_section_h2_id(id: &str, champion_slugs: &HashSet<String>) -> PatchCategory {
     let id = id.to_lowercase();
     if id == "patch-upcoming-skins-and-chromas" {
@@ -37,6 +59,34 @@ fn patch_category_from_section_h2_id(id: &str, champion_slugs: &HashSet<String>)
     if id.contains("bug") {
         return PatchCategory::BugFixes;
     }
+    if id.contains("store")
+        || id.contains("shop")
+        || id.contains("mythic")
+        || id.contains("bundle")
+        || id.contains("battle-pass")
+        || id.contains("event-pass")
+    {
+        return PatchCategory::Store;
+    }
+    if id.contains("behavior")
+        || id.contains("honor")
+        || id.contains("penalt")
+        || id.contains("leaver")
+        || id.contains("vanguard")
+    {
+        return PatchCategory::Behavioral;
+    }
+    if id.contains("jungle")
+        || id.contains("objective")
+        || id.contains("dragon")
+        || id.contains("baron")
+        || id.contains("herald")
+        || id.contains("grub")
+        || id.contains("atakhan")
+        || id.contains("epic-monster")
+    {
+        return PatchCategory::Objectives;
+    }
     if id.contains("mayhem") || id.contains("chaos") || id.contains("aram-chaos") {
         return PatchCategory::ModeAramChaos;
     }
@@ -49,12 +99,15 @@ fn patch_category_from_section_h2_id(id: &str, champion_slugs: &HashSet<String>)
     if id.contains("mode") {
         return PatchCategory::Modes;
     }
-    if id.contains("clash")
-        || id.contains("ranked")
-        || id.contains("swiftplay")
+    if id.contains("ranked")
         || id.contains("matchmaking")
         || id.contains("autofill")
+        || id.contains("duo")
+        || id.contains("-lp")
     {
+        return PatchCategory::Ranked;
+    }
+    if id.contains("clash") || id.contains("swiftplay") {
         return PatchCategory::Modes;
     }
     if id.contains("system") || id.contains("qol") {
@@ -77,7 +130,62 @@ fn patch_category_from_section_h2_id(id: &str, champion_slugs: &HashSet<String>)
     PatchCategory::Unknown
 }
 
-fn sanitize_upcoming_skin_image_url(u: String) -> String {
+/// Минимум блоков способностей с удалёнными и новыми эффектами, чтобы считать запись переработкой.
+const REWORK_MIN_REPLACED_BLOCKS: usize = 3;
+
+static REWORK_SUMMARY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(rework|переработ|визуально(го)? и игрово(го)? обновлени|игрово(е|го) обновлени|gameplay update|\bvgu\b)",
+    )
+    .unwrap()
+});
+static REWORK_REMOVED_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(удален|удалён|убран|removed|no longer)").unwrap());
+static REWORK_NEW_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(^|\s)(новое|новый|новая|new)\b|новый эффект|new effect").unwrap());
+static PATCH_NOTES_SLUG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"patch-(\d+)-(\d+)-notes").unwrap());
+static ARTICLE_SEASON_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\b(\d{2})\.S(\d+)\.\d+").unwrap());
+static ARTICLE_VERSION_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(\d{2}\.(?:S\d+\.)?\d{1,2}[a-z]?)\b").unwrap());
+static ENTRY_REMOVAL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(удал(яем|ён|ен|ено|ены|ении|ение)|убир(аем|ем)|сним(аем|ем)|отключ(аем|ен|ено)|больше не\s+(будет|существ|действ|доступ)|исчез(нет|ла|ают)?|will be removed|has been removed|removed from|no longer (available|appears|in ))",
+    )
+    .unwrap()
+});
+static ENTRY_NEW_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(добавляем|добавлен(о|ы)?|впервые|новый\s|новая\s|новое\s|новые\s|теперь доступн|появ(ится|ились|ятся)|introducing|we are adding|we're adding|new to league)",
+    )
+    .unwrap()
+});
+
+/// Переработка: явное упоминание в заголовке/описании или много способностей,
+/// где одновременно что-то удалено и добавлено.
+fn is_rework(entry: &PatchNoteEntry) -> bool {
+    if REWORK_SUMMARY_RE.is_match(&entry.summary) || REWORK_SUMMARY_RE.is_match(&entry.title) {
+        return true;
+    }
+    if entry.category == PatchCategory::Champions {
+        let title = entry.title.to_lowercase();
+        if title.contains("обновлени") || title.contains("update") {
+            return true;
+        }
+    }
+
+    let ability_blocks = entry.details.iter().filter(|b| b.title.is_some()).count();
+    let replaced_blocks = entry
+        .details
+        .iter()
+        .filter(|b| {
+            let changes = b.all_changes();
+            changes.iter().any(|c| REWORK_REMOVED_RE.is_match(c)) && changes.iter().any(|c| REWORK_NEW_RE.is_match(c))
+        })
+        .count();
+    replaced_blocks >= REWORK_MIN_REPLACED_BLOCKS && replaced_blocks * 2 >= ability_blocks
+}
+
+pub(crate) fn sanitize_upcoming_skin_image_url(u: String) -> String {
     if u.contains("akamaihd.net") && u.contains("?f=") {
         if let Some(pos) = u.find("?f=") {
             return u[pos + 3..].to_string();
@@ -127,6 +235,13 @@ fn append_upcoming_skins_h4_fallback(el: ElementRef<'_>, notes: &mut Vec<PatchNo
             summary: String::new(),
             details: Vec::new(),
             icon_candidates: None,
+            title_en: None,
+            title_ru: None,
+            tags: vec![],
+            entity_id: None,
+            raw_html: Some(h4.html()),
+            grade: None,
+            mentions: vec![],
         });
     }
 }
@@ -169,10 +284,149 @@ fn append_upcoming_skins_chromas_notes(el: ElementRef<'_>, notes: &mut Vec<Patch
             summary: String::new(),
             details: Vec::new(),
             icon_candidates: None,
+            title_en: None,
+            title_ru: None,
+            tags: vec![],
+            entity_id: None,
+            raw_html: Some(box_el.html()),
+            grade: None,
+            mentions: vec![],
         });
     }
 }
 
+/// Текст `<li>` без вложенных списков.
+fn li_own_text(li: ElementRef<'_>) -> String {
+    let mut text = String::new();
+    for node in li.children() {
+        if let Some(el) = ElementRef::wrap(node) {
+            if matches!(el.value().name(), "ul" | "ol") {
+                continue;
+            }
+            text.extend(el.text());
+        } else if let Some(t) = node.value().as_text() {
+            text.push_str(t);
+        }
+    }
+    text.trim().to_string()
+}
+
+/// Строки списка изменений; `<li>` со вложенным списком становится подблоком
+/// (заголовок — текст пункта, строки — вложенные пункты).
+fn parse_change_list(ul: ElementRef<'_>) -> (Vec<String>, Vec<ChangeBlock>) {
+    let mut changes = Vec::new();
+    let mut children = Vec::new();
+    for li in ul.children().filter_map(ElementRef::wrap).filter(|e| e.value().name() == "li") {
+        let own = li_own_text(li);
+        let nested = li
+            .children()
+            .filter_map(ElementRef::wrap)
+            .find(|e| matches!(e.value().name(), "ul" | "ol"));
+        match nested {
+            Some(nested) => {
+                let (sub_changes, sub_children) = parse_change_list(nested);
+                children.push(ChangeBlock {
+                    title: (!own.is_empty()).then_some(own),
+                    icon_url: None,
+                    changes: sub_changes,
+                    children: sub_children,
+                });
+            }
+            None if !own.is_empty() => changes.push(own),
+            None => {}
+        }
+    }
+    (changes, children)
+}
+
+/// Заголовок столбца «было» / «стало» в таблице сравнения значений.
+fn is_table_header_of(header: &str, keys: &[&str]) -> bool {
+    let h = header.trim().to_lowercase();
+    keys.iter().any(|k| h == *k || (k.chars().count() > 3 && h.starts_with(k)))
+}
+
+/// Таблица значений (модификаторы ARAM, сравнения предметов) — в строки изменений и подблоки:
+/// таблица «было/стало» даёт строки `Имя: было ⇒ стало`, таблица из двух столбцов — `Имя: значение`,
+/// таблица с заголовком и несколькими столбцами — подблок на строку (заголовок — первая ячейка,
+/// строки — `Столбец: значение`); без заголовка ячейки строки соединяются через « — ».
+fn parse_change_table(table: ElementRef<'_>) -> (Vec<String>, Vec<ChangeBlock>) {
+    let Ok(tr_sel) = Selector::parse("tr") else {
+        return (Vec::new(), Vec::new());
+    };
+    let text_of = |e: ElementRef<'_>| e.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut header: Option<Vec<String>> = None;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for tr in table.select(&tr_sel) {
+        let cells: Vec<ElementRef<'_>> = tr
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|c| matches!(c.value().name(), "th" | "td"))
+            .collect();
+        let texts: Vec<String> = cells.iter().map(|c| text_of(*c)).collect();
+        if texts.iter().all(|t| t.is_empty()) {
+            continue;
+        }
+        let in_thead = tr.parent().and_then(ElementRef::wrap).is_some_and(|p| p.value().name() == "thead");
+        if header.is_none() && rows.is_empty() && (in_thead || cells.iter().all(|c| c.value().name() == "th")) {
+            header = Some(texts);
+        } else {
+            rows.push(texts);
+        }
+    }
+
+    let mut changes = Vec::new();
+    let mut children = Vec::new();
+    let before_after = header.as_ref().is_some_and(|h| {
+        h.len() == 3
+            && is_table_header_of(&h[1], &["old", "before", "current", "previous", "было", "текущ", "прежн", "до"])
+            && is_table_header_of(&h[2], &["new", "after", "updated", "стало", "новое", "нов", "после"])
+    });
+    for row in rows {
+        let cells: Vec<&String> = row.iter().filter(|c| !c.is_empty()).collect();
+        match (&header, row.len()) {
+            _ if before_after && row.len() == 3 => changes.push(format!("{}: {} ⇒ {}", row[0], row[1], row[2])),
+            (_, 2) => changes.push(cells.iter().map(|c| c.as_str()).collect::<Vec<_>>().join(": ")),
+            (Some(h), n) if n > 2 && h.len() == n => {
+                let lines: Vec<String> = h
+                    .iter()
+                    .zip(&row)
+                    .skip(1)
+                    .filter(|(_, v)| !v.is_empty())
+                    .map(|(name, v)| if name.is_empty() { v.clone() } else { format!("{}: {}", name, v) })
+                    .collect();
+                children.push(ChangeBlock {
+                    title: (!row[0].is_empty()).then(|| row[0].clone()),
+                    icon_url: None,
+                    changes: lines,
+                    children: vec![],
+                });
+            }
+            _ => changes.push(cells.iter().map(|c| c.as_str()).collect::<Vec<_>>().join(" — ")),
+        }
+    }
+    (changes, children)
+}
+
+/// Таблица — сам элемент или единственная таблица в обёртке (`<div class="table-wrapper">`, `<figure>`).
+fn change_table_of(el: ElementRef<'_>) -> Option<ElementRef<'_>> {
+    match el.value().name() {
+        "table" => Some(el),
+        "div" | "figure" => {
+            let sel = Selector::parse("table").ok()?;
+            el.select(&sel).next()
+        }
+        _ => None,
+    }
+}
+
+/// `<p><strong>Усиленная форма</strong></p>` — подзаголовок внутри блока способности.
+fn strong_only_paragraph_title(p: ElementRef<'_>) -> Option<String> {
+    let text = p.text().collect::<String>().trim().to_string();
+    let strong_sel = Selector::parse("strong").ok()?;
+    let strong_text: String = p.select(&strong_sel).flat_map(|s| s.text()).collect();
+    (!text.is_empty() && strong_text.trim() == text).then_some(text)
+}
+
 /// ARAM / Arena / Mayhem на riotgames: `content-border` → `white-stone` без `.patch-change-block`,
 /// только `h4.change-detail-title`, затем пары `<p><strong>Имя</strong></p>` + `<ul>`.
 fn append_flat_mode_style_notes(
@@ -219,28 +473,32 @@ fn append_flat_mode_style_notes(
             continue;
         }
 
-        if tag == "ul" {
+        let table = change_table_of(child_el);
+        if tag == "ul" || table.is_some() {
             let Some(title) = pending_title.take() else {
                 continue;
             };
-            let mut changes = Vec::new();
-            for li in child_el.select(&li_sel) {
-                let text = li.text().collect::<String>().trim().to_string();
-                if !text.is_empty() {
-                    changes.push(text);
-                }
-            }
-            if changes.is_empty() {
+            let (changes, children) = match table {
+                Some(table) => parse_change_table(table),
+                None => (
+                    child_el
+                        .select(&li_sel)
+                        .map(|li| li.text().collect::<String>().trim().to_string())
+                        .filter(|text| !text.is_empty())
+                        .collect(),
+                    Vec::new(),
+                ),
+            };
+            if changes.is_empty() && children.is_empty() {
                 continue;
             }
-            let change_type = scraper.determine_change_type(
-                "",
-                &[ChangeBlock {
-                    title: None,
-                    icon_url: None,
-                    changes: changes.clone(),
-                }],
-            );
+            let details = vec![ChangeBlock {
+                title: None,
+                icon_url: None,
+                changes,
+                children,
+            }];
+            let change_type = scraper.determine_change_type("", &details);
             notes.push(PatchNoteEntry {
                 id: format!("flat-mode-{}-{}", notes.len(), title),
                 title,
@@ -248,19 +506,320 @@ fn append_flat_mode_style_notes(
                 category: category.clone(),
                 change_type,
                 summary: String::new(),
-                details: vec![ChangeBlock {
-                    title: None,
-                    icon_url: None,
-                    changes,
-                }],
+                details,
                 icon_candidates: None,
+                title_en: None,
+                title_ru: None,
+                tags: vec![],
+                entity_id: None,
+                raw_html: Some(child_el.html()),
+                grade: None,
+                mentions: vec![],
             });
         }
     }
 }
 
+/// Разделы магазина (ротация мифического магазина, пропуски событий) вёрстаются не блоками
+/// изменений, а абзацами и списками: заголовок (h3/h4 или абзац из одного `<strong>`) открывает
+/// запись, абзацы идут в summary (там даты), пункты списков — в содержимое.
+fn append_store_notes(el: ElementRef<'_>, section_title: &str, notes: &mut Vec<PatchNoteEntry>) {
+    let Ok(inner_sel) = Selector::parse(".white-stone > div") else {
+        return;
+    };
+    let Ok(strong_sel) = Selector::parse("strong") else {
+        return;
+    };
+    let Ok(li_sel) = Selector::parse("li") else {
+        return;
+    };
+    let Ok(img_sel) = Selector::parse("img") else {
+        return;
+    };
+    let inner = el.select(&inner_sel).next().unwrap_or(el);
+    let text_of = |e: ElementRef<'_>| e.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut current: Option<PatchNoteEntry> = None;
+    let flush = |entry: Option<PatchNoteEntry>, notes: &mut Vec<PatchNoteEntry>| {
+        if let Some(entry) = entry.filter(|e| !e.summary.is_empty() || !e.details.is_empty()) {
+            notes.push(entry);
+        }
+    };
+    let new_entry = |title: String, idx: usize| PatchNoteEntry {
+        id: format!("store-{}-{}", idx, title),
+        title,
+        image_url: None,
+        category: PatchCategory::Store,
+        change_type: ChangeType::New,
+        summary: String::new(),
+        details: Vec::new(),
+        icon_candidates: None,
+        title_en: None,
+        title_ru: None,
+        tags: vec![],
+        entity_id: None,
+        raw_html: None,
+        grade: None,
+        mentions: vec![],
+    };
+
+    for node in inner.children() {
+        let Some(child_el) = ElementRef::wrap(node) else {
+            continue;
+        };
+        let tag = child_el.value().name();
+        let text = text_of(child_el);
+        let strong_only = tag == "p"
+            && child_el
+                .select(&strong_sel)
+                .next()
+                .is_some_and(|st| text_of(st) == text);
+        if (matches!(tag, "h3" | "h4") || strong_only) && !text.is_empty() {
+            flush(current.take(), notes);
+            current = Some(new_entry(text, notes.len()));
+            continue;
+        }
+        let entry = current.get_or_insert_with(|| new_entry(section_title.to_string(), notes.len()));
+        if entry.image_url.is_none() {
+            entry.image_url = child_el.select(&img_sel).next().and_then(img_url_from_element);
+        }
+        match tag {
+            "p" if !text.is_empty() => {
+                if !entry.summary.is_empty() {
+                    entry.summary.push(' ');
+                }
+                entry.summary.push_str(&text);
+            }
+            "ul" | "ol" => {
+                let items: Vec<String> = child_el.select(&li_sel).map(text_of).filter(|t| !t.is_empty()).collect();
+                if !items.is_empty() {
+                    entry.details.push(ChangeBlock {
+                        title: None,
+                        icon_url: None,
+                        changes: items,
+                        children: vec![],
+                    });
+                }
+            }
+            _ => continue,
+        }
+        entry.raw_html.get_or_insert_with(String::new).push_str(&child_el.html());
+    }
+    flush(current, notes);
+}
+
+/// Разделы ранкеда и поведения игроков — в основном текст: одна запись на раздел (заголовок h2),
+/// блоки открываются заголовками h3/h4 или абзацем из одного `<strong>`, абзацы и пункты списков —
+/// строки изменений блока (по ним строится история систем в `player_systems`).
+fn append_system_prose_notes(
+    scraper: &Scraper,
+    el: ElementRef<'_>,
+    section_title: &str,
+    category: &PatchCategory,
+    notes: &mut Vec<PatchNoteEntry>,
+) {
+    let Ok(inner_sel) = Selector::parse(".white-stone > div") else {
+        return;
+    };
+    let Ok(li_sel) = Selector::parse("li") else {
+        return;
+    };
+    let inner = el.select(&inner_sel).next().unwrap_or(el);
+    let text_of = |e: ElementRef<'_>| e.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut details: Vec<ChangeBlock> = Vec::new();
+    let mut raw_html = String::new();
+    let mut current = ChangeBlock {
+        title: None,
+        icon_url: None,
+        changes: vec![],
+        children: vec![],
+    };
+    for node in inner.children() {
+        let Some(child_el) = ElementRef::wrap(node) else {
+            continue;
+        };
+        let tag = child_el.value().name();
+        let text = text_of(child_el);
+        let heading = matches!(tag, "h3" | "h4") || (tag == "p" && strong_only_paragraph_title(child_el).is_some());
+        if heading && !text.is_empty() {
+            let done = std::mem::replace(
+                &mut current,
+                ChangeBlock {
+                    title: Some(text),
+                    icon_url: None,
+                    changes: vec![],
+                    children: vec![],
+                },
+            );
+            if !done.changes.is_empty() || !done.children.is_empty() {
+                details.push(done);
+            }
+        } else if let Some(table) = change_table_of(child_el) {
+            let (changes, children) = parse_change_table(table);
+            current.changes.extend(changes);
+            current.children.extend(children);
+        } else {
+            match tag {
+                "p" if !text.is_empty() => current.changes.push(text),
+                "ul" | "ol" => current
+                    .changes
+                    .extend(child_el.select(&li_sel).map(text_of).filter(|t| !t.is_empty())),
+                _ => continue,
+            }
+        }
+        raw_html.push_str(&child_el.html());
+    }
+    if !current.changes.is_empty() || !current.children.is_empty() {
+        details.push(current);
+    }
+    if details.is_empty() {
+        return;
+    }
+    notes.push(PatchNoteEntry {
+        id: format!("system-prose-{}-{}", notes.len(), section_title),
+        title: section_title.to_string(),
+        image_url: None,
+        category: category.clone(),
+        change_type: scraper.determine_change_type("", &details),
+        summary: String::new(),
+        details,
+        icon_candidates: None,
+        title_en: None,
+        title_ru: None,
+        tags: vec![],
+        entity_id: None,
+        raw_html: Some(raw_html),
+        grade: None,
+        mentions: vec![],
+    });
+}
+
+/// id раздела для категории: у старой вёрстки h2 часто без id — тогда `patch-<заголовок>`.
+fn section_h2_id(h2: ElementRef<'_>) -> String {
+    match h2.value().id() {
+        Some(id) if !id.is_empty() => id.to_string(),
+        _ => format!("patch-{}", slugify_augment_id(&h2.text().collect::<String>())),
+    }
+}
+
+/// Старая вёрстка (`PageLayout::Legacy`): h2 — раздел, h3/h4 — запись, пункты списков и таблицы — изменения.
+/// Строки до первого h3 раздела собираются в запись с названием раздела.
+fn append_legacy_layout_notes(
+    scraper: &Scraper,
+    body: ElementRef<'_>,
+    champion_slugs: &HashSet<String>,
+    notes: &mut Vec<PatchNoteEntry>,
+) {
+    let text_of = |e: ElementRef<'_>| e.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut category = PatchCategory::Unknown;
+    let mut section_title = String::new();
+    let mut current: Option<(String, Option<String>, ChangeBlock)> = None;
+    let flush = |current: &mut Option<(String, Option<String>, ChangeBlock)>,
+                 category: &PatchCategory,
+                 notes: &mut Vec<PatchNoteEntry>| {
+        let Some((title, image_url, block)) = current.take() else {
+            return;
+        };
+        if block.changes.is_empty() && block.children.is_empty() {
+            return;
+        }
+        let details = vec![block];
+        notes.push(PatchNoteEntry {
+            id: format!("legacy-{}-{}", notes.len(), title),
+            title,
+            image_url,
+            category: category.clone(),
+            change_type: scraper.determine_change_type("", &details),
+            summary: String::new(),
+            details,
+            icon_candidates: None,
+            title_en: None,
+            title_ru: None,
+            tags: vec![],
+            entity_id: None,
+            raw_html: None,
+            grade: None,
+            mentions: vec![],
+        });
+    };
+    let empty_block = || ChangeBlock {
+        title: None,
+        icon_url: None,
+        changes: vec![],
+        children: vec![],
+    };
+    for el in body.descendants().filter_map(ElementRef::wrap) {
+        match el.value().name() {
+            "h2" => {
+                flush(&mut current, &category, notes);
+                category = patch_category_from_section_h2_id(&section_h2_id(el), champion_slugs);
+                section_title = text_of(el);
+            }
+            "h3" | "h4" => {
+                flush(&mut current, &category, notes);
+                let title = text_of(el);
+                if !title.is_empty() {
+                    current = Some((title, None, empty_block()));
+                }
+            }
+            "img" => {
+                if let Some((_, image_url @ None, _)) = current.as_mut() {
+                    *image_url = img_url_from_element(el).map(|u| Scraper::clean_cdn_image_url(&u));
+                }
+            }
+            // Вложенные списки разберёт их собственный `li`
+            "li" if !el.children().filter_map(ElementRef::wrap).any(|c| matches!(c.value().name(), "ul" | "ol")) => {
+                let text = text_of(el);
+                if text.is_empty() || el.ancestors().filter_map(ElementRef::wrap).any(|a| a.value().name() == "table") {
+                    continue;
+                }
+                current
+                    .get_or_insert_with(|| (section_title.clone(), None, empty_block()))
+                    .2
+                    .changes
+                    .push(text);
+            }
+            "table" => {
+                let (changes, children) = parse_change_table(el);
+                let block = &mut current.get_or_insert_with(|| (section_title.clone(), None, empty_block())).2;
+                block.changes.extend(changes);
+                block.children.extend(children);
+            }
+            _ => {}
+        }
+    }
+    flush(&mut current, &category, notes);
+}
+
+/// Разделы статьи (h2 в контейнере текущей или теле старой вёрстки) с категориями, как их видит парсер.
+pub(crate) fn patch_note_sections(html: &str, champion_slugs: &HashSet<String>) -> Vec<ParsedSection> {
+    let document = Html::parse_document(html);
+    let Ok(h2_sel) = Selector::parse("h2") else {
+        return Vec::new();
+    };
+    let (root, legacy) = match page_layout::detect(&document) {
+        PageLayout::Current => (page_layout::current_container(&document), false),
+        PageLayout::Legacy => (page_layout::legacy_body(&document), true),
+        PageLayout::Unsupported => return Vec::new(),
+    };
+    let Some(root) = root else {
+        return Vec::new();
+    };
+    root.select(&h2_sel)
+        .map(|h2| {
+            let id = if legacy { section_h2_id(h2) } else { h2.value().id().unwrap_or("").to_string() };
+            ParsedSection {
+                category: patch_category_from_section_h2_id(&id, champion_slugs),
+                title: h2.text().collect::<String>().trim().to_string(),
+                id,
+            }
+        })
+        .collect()
+}
+
 /// src / data-src / data-lazy-src / первый URL из srcset (часто у картинок Riot только srcset).
-fn img_url_from_element(img: ElementRef) -> Option<String> {
+pub(crate) fn img_url_from_element(img: ElementRef) -> Option<String> {
     let v = img.value();
     for attr in ["src", "data-src", "data-lazy-src"] {
         if let Some(s) = v.attr(attr) {
@@ -690,6 +1249,7 @@ fn mayhem_augmentations_to_patch_notes(rows: &[MayhemAugmentation]) -> Vec<Patch
                     title: None,
                     icon_url: None,
                     changes: vec![effect_plain],
+                    children: vec![],
                 }]
             };
             PatchNoteEntry {
@@ -701,13 +1261,152 @@ fn mayhem_augmentations_to_patch_notes(rows: &[MayhemAugmentation]) -> Vec<Patch
                 summary,
                 details,
                 icon_candidates: None,
+                title_en: None,
+                title_ru: None,
+                tags: vec![],
+                entity_id: None,
+                raw_html: Some(m.effect_html.clone()).filter(|h| !h.is_empty()),
+                grade: None,
+                mentions: vec![],
             }
         })
         .collect()
 }
 
+/// Настройки вежливости загрузки; меняются из настроек приложения (`set_scrape_settings`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ScrapeSettings {
+    /// Одновременных загрузок патчей при полной синхронизации
+    pub concurrency: u32,
+    /// Минимальный интервал между запросами к leagueoflegends.com — общий для всех параллельных задач
+    pub request_gap_ms: u32,
+    /// Повторы запроса при обрыве соединения, таймауте, 429 и 5xx
+    pub retries: u32,
+}
+
+impl Default for ScrapeSettings {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            request_gap_ms: 400,
+            retries: 2,
+        }
+    }
+}
+
+impl ScrapeSettings {
+    /// Приводит значения к допустимым пределам.
+    pub fn clamped(self) -> Self {
+        Self {
+            concurrency: self.concurrency.clamp(1, 8),
+            request_gap_ms: self.request_gap_ms.min(5_000),
+            retries: self.retries.min(5),
+        }
+    }
+
+    pub fn request_gap(&self) -> Duration {
+        Duration::from_millis(self.request_gap_ms as u64)
+    }
+
+    /// Пауза перед повтором `attempt` (с 1): интервал запросов, удваиваемый с каждой попыткой, не меньше 500 мс.
+    fn retry_backoff(&self, attempt: u32) -> Duration {
+        self.request_gap().max(Duration::from_millis(500)) * 2u32.saturating_pow(attempt - 1)
+    }
+}
+
+/// User-Agent по умолчанию для запросов вне `Scraper::request` (DDragon, Riot API).
+const DEFAULT_USER_AGENT: &str =
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36";
+const DEFAULT_ACCEPT_LANGUAGE: &str = "en-US,en;q=0.9,ru;q=0.8";
+
+/// Браузер из пула: User-Agent и соответствующие ему client hints.
+struct BrowserProfile {
+    user_agent: &'static str,
+    sec_ch_ua: &'static str,
+    platform: &'static str,
+}
+
+/// Пул User-Agent; следующий берётся, когда сайт отвечает 403 (Riot иногда блокирует устаревшие UA).
+const USER_AGENT_POOL: [BrowserProfile; 3] = [
+    BrowserProfile {
+        user_agent: DEFAULT_USER_AGENT,
+        sec_ch_ua: r#""Google Chrome";v="131", "Chromium";v="131", "Not_A Brand";v="24""#,
+        platform: "\"Windows\"",
+    },
+    BrowserProfile {
+        user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36 Edg/131.0.0.0",
+        sec_ch_ua: r#""Microsoft Edge";v="131", "Chromium";v="131", "Not_A Brand";v="24""#,
+        platform: "\"Windows\"",
+    },
+    BrowserProfile {
+        user_agent: "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36",
+        sec_ch_ua: r#""Google Chrome";v="131", "Chromium";v="131", "Not_A Brand";v="24""#,
+        platform: "\"macOS\"",
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExtraHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// Заголовки запросов к сайтам Riot и вики; меняются из настроек приложения (`set_request_header_settings`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RequestHeaderSettings {
+    /// Пустой — User-Agent из пула браузеров (со сменой при 403)
+    pub user_agent: String,
+    /// Пустой — `en-US,en;q=0.9,ru;q=0.8`
+    pub accept_language: String,
+    /// Добавляются к каждому запросу; заголовки с неверным именем или значением отбрасываются
+    pub extra_headers: Vec<ExtraHeader>,
+}
+
+impl RequestHeaderSettings {
+    /// Обрезает пробелы и отбрасывает значения, которые нельзя отправить в заголовке.
+    pub fn normalized(self) -> Self {
+        let valid_value = |v: String| {
+            let v = v.trim().to_string();
+            if header::HeaderValue::from_str(&v).is_ok() { v } else { String::new() }
+        };
+        Self {
+            user_agent: valid_value(self.user_agent),
+            accept_language: valid_value(self.accept_language),
+            extra_headers: self
+                .extra_headers
+                .into_iter()
+                .map(|h| ExtraHeader {
+                    name: h.name.trim().to_string(),
+                    value: h.value.trim().to_string(),
+                })
+                .filter(|h| {
+                    header::HeaderName::from_bytes(h.name.as_bytes()).is_ok()
+                        && header::HeaderValue::from_str(&h.value).is_ok()
+                })
+                .collect(),
+        }
+    }
+}
+
 pub struct Scraper {
     client: reqwest::Client,
+    /// Время последнего запроса к сайту Riot (общий ограничитель частоты)
+    riot_last_request: tokio::sync::Mutex<Option<Instant>>,
+    /// То же для сторонних сайтов (op.gg, probuilds.net) — своя очередь, чтобы не задерживать Riot
+    third_party_last_request: tokio::sync::Mutex<Option<Instant>>,
+    /// Идущие загрузки патчей по (версия, локаль): UI и синхронизация не качают один патч дважды
+    patch_fetches: InFlight<(String, String), Result<PatchData, String>>,
+    /// Время этапов по последним патчам (`get_scrape_profile`)
+    profile: ScrapeProfiler,
+    settings: std::sync::RwLock<ScrapeSettings>,
+    /// Запасной список патчей из БД, новые первыми (`set_fallback_patches`)
+    fallback_patches: std::sync::RwLock<Vec<String>>,
+    headers: std::sync::RwLock<RequestHeaderSettings>,
+    /// Текущий браузер из `USER_AGENT_POOL`
+    user_agent_index: AtomicUsize,
 }
 
 fn wrap_wiki_parse_fragment_as_document(fragment: &str) -> String {
@@ -721,37 +1420,153 @@ impl Scraper {
         &self.client
     }
 
+    pub fn profile(&self) -> &ScrapeProfiler {
+        &self.profile
+    }
+
+    pub fn settings(&self) -> ScrapeSettings {
+        *self.settings.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Применяет настройки (с ограничением пределов) и возвращает действующие.
+    pub fn set_settings(&self, settings: ScrapeSettings) -> ScrapeSettings {
+        let settings = settings.clamped();
+        *self.settings.write().unwrap_or_else(|e| e.into_inner()) = settings;
+        settings
+    }
+
+    pub fn header_settings(&self) -> RequestHeaderSettings {
+        self.headers.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Применяет заголовки (неверные отбрасываются) и возвращает действующие.
+    pub fn set_header_settings(&self, settings: RequestHeaderSettings) -> RequestHeaderSettings {
+        let settings = settings.normalized();
+        *self.headers.write().unwrap_or_else(|e| e.into_inner()) = settings.clone();
+        settings
+    }
+
+    /// GET с заголовками из `RequestHeaderSettings`; они заменяют заголовки клиента по умолчанию.
+    fn request(&self, url: impl reqwest::IntoUrl) -> reqwest::RequestBuilder {
+        let settings = self.header_settings();
+        let mut builder = self.client.get(url);
+        if settings.user_agent.is_empty() {
+            let profile = &USER_AGENT_POOL[self.user_agent_index.load(Ordering::Relaxed) % USER_AGENT_POOL.len()];
+            builder = builder
+                .header(header::USER_AGENT, profile.user_agent)
+                .header("sec-ch-ua", profile.sec_ch_ua)
+                .header("sec-ch-ua-mobile", "?0")
+                .header("sec-ch-ua-platform", profile.platform);
+        } else {
+            builder = builder.header(header::USER_AGENT, settings.user_agent);
+        }
+        if !settings.accept_language.is_empty() {
+            builder = builder.header(header::ACCEPT_LANGUAGE, settings.accept_language);
+        }
+        for h in settings.extra_headers {
+            builder = builder.header(h.name, h.value);
+        }
+        builder
+    }
+
+    /// Переходит к следующему браузеру из пула (если User-Agent не задан вручную).
+    fn rotate_user_agent(&self) {
+        if self.header_settings().user_agent.is_empty() {
+            let next = self.user_agent_index.fetch_add(1, Ordering::Relaxed) + 1;
+            logger::log(
+                LogLevel::Warn,
+                LogSource::Scraper,
+                &format!("HTTP 403, switching User-Agent to {}", USER_AGENT_POOL[next % USER_AGENT_POOL.len()].user_agent),
+            );
+        }
+    }
+
+    pub fn set_fallback_patches(&self, patches: Vec<String>) {
+        *self.fallback_patches.write().unwrap_or_else(|e| e.into_inner()) = patches;
+    }
+
+    /// Патчи из `versions.json` DDragon в нумерации сайта, новые первыми.
+    pub async fn fetch_ddragon_patches(&self) -> Result<Vec<String>> {
+        let resp = self
+            .get_with_retries("https://ddragon.leagueoflegends.com/api/versions.json")
+            .await?
+            .error_for_status()?;
+        let versions = http_body::read_json::<Vec<String>>(resp).await?;
+        Ok(crate::fallback_patches::from_ddragon_versions(&versions))
+    }
+
+    /// GET с повторами по `ScrapeSettings::retries`: обрыв, таймаут, 429 и 5xx повторяются с паузой,
+    /// 403 — со следующим User-Agent из пула.
+    async fn get_with_retries(&self, url: &str) -> reqwest::Result<reqwest::Response> {
+        crate::maintenance::touch();
+        let settings = self.settings();
+        let mut attempt = 0;
+        loop {
+            let result = self.request(url).send().await;
+            let retryable = match &result {
+                Ok(resp) => {
+                    let status = resp.status();
+                    if status == reqwest::StatusCode::FORBIDDEN && attempt < settings.retries {
+                        self.rotate_user_agent();
+                    }
+                    status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        || status == reqwest::StatusCode::FORBIDDEN
+                        || status.is_server_error()
+                }
+                Err(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            };
+            if !retryable || attempt >= settings.retries {
+                return result;
+            }
+            attempt += 1;
+            tokio::time::sleep(settings.retry_backoff(attempt)).await;
+        }
+    }
+
     pub fn new() -> Result<Self> {
         let mut headers = header::HeaderMap::new();
-        headers.insert(
-            header::USER_AGENT,
-            header::HeaderValue::from_static(
-                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36",
-            ),
-        );
+        headers.insert(header::USER_AGENT, header::HeaderValue::from_static(DEFAULT_USER_AGENT));
         headers.insert(header::ACCEPT, header::HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,*/*;q=0.8"));
-        headers.insert(header::ACCEPT_LANGUAGE, header::HeaderValue::from_static("en-US,en;q=0.9,ru;q=0.8"));
-        headers.insert(
-            header::HeaderName::from_static("sec-ch-ua"),
-            header::HeaderValue::from_static(r#""Google Chrome";v="131", "Chromium";v="131", "Not_A Brand";v="24""#),
-        );
-        headers.insert(
-            header::HeaderName::from_static("sec-ch-ua-mobile"),
-            header::HeaderValue::from_static("?0"),
-        );
-        headers.insert(
-            header::HeaderName::from_static("sec-ch-ua-platform"),
-            header::HeaderValue::from_static("\"Windows\""),
-        );
+        headers.insert(header::ACCEPT_LANGUAGE, header::HeaderValue::from_static(DEFAULT_ACCEPT_LANGUAGE));
 
-        let client = reqwest::Client::builder()
+        let client = crate::http_client::tuned_builder()
             .default_headers(headers)
             .cookie_store(true)
             .connect_timeout(Duration::from_secs(20))
             .timeout(Duration::from_secs(90))
             .build()?;
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            riot_last_request: tokio::sync::Mutex::new(None),
+            third_party_last_request: tokio::sync::Mutex::new(None),
+            patch_fetches: InFlight::new(),
+            profile: ScrapeProfiler::default(),
+            settings: std::sync::RwLock::new(ScrapeSettings::default()),
+            fallback_patches: std::sync::RwLock::new(Vec::new()),
+            headers: std::sync::RwLock::new(RequestHeaderSettings::default()),
+            user_agent_index: AtomicUsize::new(0),
+        })
+    }
+
+    /// Ждёт, пока с прошлого запроса к Riot пройдёт `ScrapeSettings::request_gap_ms`.
+    /// Блокировка держится во время ожидания, поэтому параллельные загрузки идут по очереди.
+    async fn throttle_riot(&self) {
+        self.throttle(&self.riot_last_request).await;
+    }
+
+    /// Интервал `request_gap_ms` между запросами одной очереди.
+    async fn throttle(&self, last_request: &tokio::sync::Mutex<Option<Instant>>) {
+        crate::maintenance::touch();
+        let gap = self.settings().request_gap();
+        let mut last = last_request.lock().await;
+        if let Some(prev) = *last {
+            let wait = gap.saturating_sub(prev.elapsed());
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+        *last = Some(Instant::now());
     }
 
     /// MediaWiki API — чаще проходит Cloudflare, чем сырой HTML (меньше 403 у клиентов).
@@ -770,11 +1585,11 @@ impl Scraper {
                 ("format", "json"),
             ],
         )?;
-        let resp = self.client.get(url).send().await?;
+        let resp = self.request(url).send().await?;
         if !resp.status().is_success() {
             anyhow::bail!("wiki parse API HTTP {}", resp.status());
         }
-        let v: serde_json::Value = resp.json().await?;
+        let v: serde_json::Value = http_body::read_json(resp).await?;
         if let Some(err) = v.get("error").and_then(|e| e.get("info")).and_then(|x| x.as_str()) {
             anyhow::bail!("wiki parse API error: {err}");
         }
@@ -787,8 +1602,7 @@ impl Scraper {
     async fn get_league_wiki_html(&self, wiki_path: &str) -> Result<String> {
         let url = format!("{LEAGUE_WIKI_ORIGIN}{wiki_path}");
         let resp = self
-            .client
-            .get(&url)
+            .request(&url)
             .header(
                 header::REFERER,
                 header::HeaderValue::from_static("https://wiki.leagueoflegends.com/en-us/"),
@@ -798,14 +1612,13 @@ impl Scraper {
         if !resp.status().is_success() {
             anyhow::bail!("wiki augments HTTP {}", resp.status());
         }
-        Ok(resp.text().await?)
+        http_body::read_text(resp).await
     }
 
     async fn get_league_wiki_html_with_extra_headers(&self, wiki_path: &str) -> Result<String> {
         let url = format!("{LEAGUE_WIKI_ORIGIN}{wiki_path}");
         let resp = self
-            .client
-            .get(&url)
+            .request(&url)
             .header(
                 header::REFERER,
                 header::HeaderValue::from_static("https://wiki.leagueoflegends.com/en-us/"),
@@ -819,7 +1632,7 @@ impl Scraper {
         if !resp.status().is_success() {
             anyhow::bail!("wiki augments HTTP {}", resp.status());
         }
-        Ok(resp.text().await?)
+        http_body::read_text(resp).await
     }
 
     async fn load_aram_mayhem_augments_wiki_html(&self) -> Result<String> {
@@ -869,7 +1682,7 @@ impl Scraper {
 
     pub async fn fetch_all_champions_ddragon(&self) -> Result<Vec<(String, String, String, String, String)>> {
         let ver_url = "https://ddragon.leagueoflegends.com/api/versions.json";
-        let versions: Vec<String> = self.client.get(ver_url).send().await?.json().await?;
+        let versions: Vec<String> = http_body::read_json(self.get_with_retries(ver_url).await?).await?;
         let latest = versions.first().map(|s| s.as_str()).unwrap_or("14.23.1");
 
         let ru_url = format!(
@@ -882,12 +1695,12 @@ impl Scraper {
         );
 
         let (ru_resp, en_resp) = tokio::try_join!(
-            self.client.get(&ru_url).send(),
-            self.client.get(&en_url).send(),
+            self.request(&ru_url).send(),
+            self.request(&en_url).send(),
         )?;
 
-        let ru_json: serde_json::Value = ru_resp.json().await?;
-        let en_json: serde_json::Value = en_resp.json().await?;
+        let ru_json: serde_json::Value = http_body::read_json(ru_resp).await?;
+        let en_json: serde_json::Value = http_body::read_json(en_resp).await?;
 
         let mut champs = Vec::new();
         if let Some(data_ru) = ru_json.get("data").and_then(|d| d.as_object()) {
@@ -929,9 +1742,9 @@ impl Scraper {
 
     pub async fn fetch_latest_ddragon_version(&self) -> Result<Option<String>> {
         let url = "https://ddragon.leagueoflegends.com/api/versions.json";
-        match self.client.get(url).send().await {
+        match self.request(url).send().await {
             Ok(resp) => {
-                if let Ok(versions) = resp.json::<Vec<String>>().await {
+                if let Ok(versions) = http_body::read_json::<Vec<String>>(resp).await {
                     if let Some(latest) = versions.first() {
                         return Ok(Some(latest.clone()));
                     }
@@ -943,18 +1756,17 @@ impl Scraper {
     }
 
     async fn patch_tags_list_contains_version(&self, tags_url: &str, version: &str) -> bool {
-        let Ok(resp) = self.client.get(tags_url).send().await else {
+        let Ok(resp) = self.request(tags_url).send().await else {
             return false;
         };
-        let Ok(text) = resp.text().await else {
+        let Ok(text) = http_body::read_text(resp).await else {
             return false;
         };
         let document = Html::parse_document(&text);
         let link_selector = Selector::parse("a[href*='patch-']").unwrap();
-        let re = Regex::new(r"patch-(\d+)-(\d+)-notes").unwrap();
         for link in document.select(&link_selector) {
             if let Some(href) = link.value().attr("href") {
-                if let Some(caps) = re.captures(href) {
+                if let Some(caps) = PATCH_NOTES_SLUG_RE.captures(href) {
                     let patch_version = format!("{}.{}", &caps[1], &caps[2]);
                     if patch_version == version {
                         return true;
@@ -982,48 +1794,16 @@ impl Scraper {
     }
 
     pub async fn fetch_available_patches_with_limit(&self, limit: usize) -> Result<Vec<String>> {
-        // Используем патчи из DDragon для согласования с форматом статистики
-        let ver_url = "https://ddragon.leagueoflegends.com/api/versions.json";
-        let mut patches = Vec::new();
-        
-        if let Ok(resp) = self.client.get(ver_url).send().await {
-            if let Ok(versions) = resp.json::<Vec<String>>().await {
-                for version in versions {
-                    let parts: Vec<&str> = version.split('.').collect();
-                    if parts.len() >= 2 {
-                        let major: i32 = parts[0].parse().unwrap_or(-1);
-                        let minor: i32 = parts[1].parse().unwrap_or(-1);
-                        if major < 0 || minor < 0 {
-                            continue;
-                        }
-                        let patch = ddragon_pair_to_display(major, minor);
-                        if !patches.contains(&patch) {
-                            patches.push(patch);
-                        }
-                    }
-                }
-            }
+        // Используем патчи из DDragon для согласования с форматом статистики;
+        // если DDragon недоступен — запасной список из БД, а без него встроенный
+        let mut patches = self.fetch_ddragon_patches().await.unwrap_or_default();
+        if patches.is_empty() {
+            patches = self.fallback_patches.read().unwrap_or_else(|e| e.into_inner()).clone();
         }
-        
-        // Если DDragon недоступен, используем fallback
         if patches.is_empty() {
-            patches = (14..=24)
-                .rev()
-                .map(|min| ddragon_pair_to_display(15, min))
-                .collect();
+            patches = crate::fallback_patches::builtin();
         }
 
-        patches.sort_by(|a, b| {
-            let parts_a: Vec<&str> = a.split('.').collect();
-            let parts_b: Vec<&str> = b.split('.').collect();
-            let major_a = parts_a.get(0).unwrap_or(&"0").parse::<i32>().unwrap_or(0);
-            let minor_a = parts_a.get(1).unwrap_or(&"0").parse::<i32>().unwrap_or(0);
-            let major_b = parts_b.get(0).unwrap_or(&"0").parse::<i32>().unwrap_or(0);
-            let minor_b = parts_b.get(1).unwrap_or(&"0").parse::<i32>().unwrap_or(0);
-
-            if major_a != major_b { major_b.cmp(&major_a) } else { minor_b.cmp(&minor_a) }
-        });
-
         let safe_limit = limit.clamp(1, 100);
         patches.truncate(safe_limit);
 
@@ -1034,24 +1814,64 @@ impl Scraper {
         self.fetch_available_patches_with_limit(20).await
     }
 
-    pub async fn fetch_current_meta(&self, patch_version: &str, patch_notes_locale: &str) -> Result<PatchData> {
-        let mut champions = match self.scrape_leagueofgraphs().await {
-            Ok(c) if !c.is_empty() => c,
-            _ => vec![]
-        };
-        
-        if champions.is_empty() {
-             if let Ok(c) = self.scrape_metasrc().await {
-                 if !c.is_empty() { champions = c; }
-             }
+    /// Текущая статистика чемпионов: League of Graphs, при пустом ответе — MetaSrc.
+    pub async fn fetch_champion_stats(&self) -> Vec<ChampionStats> {
+        if let Ok(c) = self.scrape_leagueofgraphs().await {
+            if !c.is_empty() {
+                return c;
+            }
         }
+        self.scrape_metasrc().await.unwrap_or_default()
+    }
 
-        let loc = normalize_patch_notes_locale(patch_notes_locale);
-        let (patch_notes, banner_url) = self
-            .scrape_riot_patch_notes(patch_version, loc)
+    /// Статистика и патч-ноты версии. Одновременные вызовы для той же версии и локали ждут одну загрузку.
+    pub async fn fetch_current_meta(&self, patch_version: &str, patch_notes_locale: &str) -> Result<PatchData> {
+        let key = (
+            patch_version.to_string(),
+            normalize_patch_notes_locale(patch_notes_locale).to_string(),
+        );
+        self.patch_fetches
+            .run(key, || async {
+                self.scrape_current_meta(patch_version, patch_notes_locale)
+                    .await
+                    .map_err(|e| format!("{:#}", e))
+            })
             .await
-            .unwrap_or_else(|_| (vec![], None));
+            .map_err(anyhow::Error::msg)
+    }
 
+    async fn scrape_current_meta(&self, patch_version: &str, patch_notes_locale: &str) -> Result<PatchData> {
+        let loc = normalize_patch_notes_locale(patch_notes_locale);
+        let champions = self
+            .profile
+            .time(patch_version, loc, ScrapePhase::Download, self.fetch_champion_stats())
+            .await;
+
+        let (mut patch_notes, article) = self.scrape_riot_patch_notes(patch_version, loc).await?;
+        let classify_started = Instant::now();
+        Self::classify_notes(&mut patch_notes);
+        self.profile
+            .record(patch_version, loc, ScrapePhase::Classify, classify_started.elapsed());
+        Ok(Self::assemble_patch_data(patch_version, loc, champions, patch_notes, article))
+    }
+
+    /// Теги, масштаб изменений и пользовательские хуки для только что разобранных записей.
+    fn classify_notes(patch_notes: &mut [PatchNoteEntry]) {
+        for note in patch_notes.iter_mut() {
+            note.tags = crate::patch_tags::auto_tags(note);
+        }
+        crate::patch_change_trend::fill_note_grades(patch_notes);
+        crate::script_hooks::after_classify_change(patch_notes);
+    }
+
+    /// Собирает `PatchData`; без статистики чемпионы берутся из записей патча.
+    fn assemble_patch_data(
+        patch_version: &str,
+        loc: &str,
+        mut champions: Vec<ChampionStats>,
+        patch_notes: Vec<PatchNoteEntry>,
+        article: PatchArticleMeta,
+    ) -> PatchData {
         if champions.is_empty() && !patch_notes.is_empty() {
             for note in &patch_notes {
                 if note.category == PatchCategory::Champions {
@@ -1066,22 +1886,83 @@ impl Scraper {
                         image_url: note.image_url.clone(),
                         core_items: vec![],
                         popular_runes: vec![],
+                        total_matches: 0,
+                        role_share: 1.0,
                     });
                 }
             }
         }
 
-        Ok(PatchData {
+        let fetched_at = Utc::now();
+        let split = crate::patch_version::season_split(
+            patch_version,
+            article.season.as_deref(),
+            article.published_at.unwrap_or(fetched_at),
+        );
+        PatchData {
             version: patch_version.to_string(),
-            fetched_at: Utc::now(),
+            fetched_at,
+            split,
             champions,
             patch_notes,
-            banner_url,
+            banner_url: article.banner_url,
             patch_notes_locale: Some(loc.to_string()),
-        })
+            published_at: article.published_at,
+            author: article.author,
+            season: article.season,
+            parent_version: crate::patch_version::hotfix_parent(patch_version),
+            machine_translation: None,
+        }
+    }
+
+    /// Страница по произвольному адресу (зеркало, региональный домен) с повторами при сбоях.
+    pub async fn fetch_page(&self, url: &str) -> Result<String> {
+        let resp = self.get_with_retries(url).await?.error_for_status()?;
+        http_body::read_text(resp).await
+    }
+
+    /// Страница стороннего сайта: с заголовками из настроек и общим для таких сайтов интервалом запросов.
+    pub async fn fetch_third_party(&self, url: &str) -> Result<String> {
+        self.throttle(&self.third_party_last_request).await;
+        self.fetch_page(url).await
+    }
+
+    /// Разбирает готовую страницу патч-нотов (зеркало, сохранённый файл) тем же парсером, что и
+    /// страницы сайта Riot. `version` — если не указана, берётся из заголовка или адреса статьи.
+    pub async fn parse_patch_page(
+        &self,
+        html: &str,
+        version: Option<&str>,
+        patch_notes_locale: &str,
+    ) -> Result<PatchData> {
+        let loc = normalize_patch_notes_locale(patch_notes_locale);
+        let article = Self::extract_article_meta(html);
+        let version = match version.map(str::trim).filter(|v| !v.is_empty()) {
+            Some(v) => crate::patch_version::PatchVersion::parse(v)
+                .ok_or_else(|| anyhow::anyhow!("invalid patch version: {}", v))?
+                .to_string(),
+            None => article
+                .version
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("patch version not found in the page, specify it explicitly"))?,
+        };
+        // Хотфикс публикуется в статье своего патча
+        if let Some(detected) = article.version.as_deref() {
+            let base = |v: &str| crate::patch_version::PatchVersion::parse(v).map(|p| p.base());
+            if base(detected) != base(&version) {
+                return Err(anyhow::anyhow!("page is about patch {}, not {}", detected, version));
+            }
+        }
+        let champion_slugs = self.fetch_champion_slug_set().await;
+        let mut patch_notes = self.parse_riot_patch_notes_html(html, &champion_slugs, loc);
+        if patch_notes.is_empty() {
+            return Err(anyhow::anyhow!("no patch notes found in the page"));
+        }
+        Self::classify_notes(&mut patch_notes);
+        Ok(Self::assemble_patch_data(&version, loc, Vec::new(), patch_notes, article))
     }
 
-    fn clean_cdn_image_url(url: &str) -> String {
+    pub(crate) fn clean_cdn_image_url(url: &str) -> String {
         let u = url.trim();
         if u.contains("akamaihd.net") && u.contains("?f=") {
             if let Some(pos) = u.find("?f=") {
@@ -1115,6 +1996,73 @@ impl Scraper {
         None
     }
 
+    /// Баннер, дата публикации, автор и сезон/сплит из шапки статьи.
+    pub(crate) fn extract_article_meta(html: &str) -> PatchArticleMeta {
+        let document = Html::parse_document(html);
+        let meta_content = |keys: &[&str]| -> Option<String> {
+            let sel = Selector::parse("meta").ok()?;
+            document.select(&sel).find_map(|m| {
+                let prop = m.value().attr("property").or_else(|| m.value().attr("name"))?;
+                if !keys.contains(&prop) {
+                    return None;
+                }
+                m.value()
+                    .attr("content")
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+            })
+        };
+
+        let published_at = meta_content(&["article:published_time", "og:article:published_time"])
+            .or_else(|| {
+                let sel = Selector::parse("time[datetime]").ok()?;
+                document
+                    .select(&sel)
+                    .next()
+                    .and_then(|t| t.value().attr("datetime"))
+                    .map(|s| s.to_string())
+            })
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc));
+
+        let author = meta_content(&["author", "article:author"]).or_else(|| {
+            let sel = Selector::parse("[data-testid='article-author'], [rel='author']").ok()?;
+            document
+                .select(&sel)
+                .next()
+                .map(|e| e.text().collect::<String>().trim().to_string())
+                .filter(|s| !s.is_empty())
+        });
+
+        let title = meta_content(&["og:title"]).or_else(|| {
+            let sel = Selector::parse("title").ok()?;
+            document.select(&sel).next().map(|t| t.text().collect::<String>())
+        });
+        let season = title.as_deref().and_then(|t| {
+            ARTICLE_SEASON_RE
+                .captures(t)
+                .map(|c| format!("{}.S{}", &c[1], &c[2]))
+        });
+        let version = title
+            .as_deref()
+            .and_then(|t| ARTICLE_VERSION_RE.captures(t).map(|c| c[1].to_string()))
+            .or_else(|| {
+                PATCH_NOTES_SLUG_RE
+                    .captures(html)
+                    .map(|c| format!("{}.{}", &c[1], &c[2]))
+            })
+            .and_then(|v| crate::patch_version::PatchVersion::parse(&v))
+            .map(|v| v.to_string());
+
+        PatchArticleMeta {
+            banner_url: Self::extract_article_banner(html),
+            published_at,
+            author,
+            season,
+            version,
+        }
+    }
+
     #[allow(dead_code)] // тесты + совместимость
     pub(crate) fn parse_aram_mayhem_augments_wiki_html(html: &str) -> Vec<PatchNoteEntry> {
         mayhem_augmentations_to_patch_notes(&parse_aram_mayhem_augmentations_detailed(html))
@@ -1143,11 +2091,8 @@ impl Scraper {
         Ok(self.fetch_aram_mayhem_augmentations_bundle_ru().await?.0)
     }
 
-    async fn scrape_riot_patch_notes(
-        &self,
-        version: &str,
-        patch_notes_locale: &str,
-    ) -> Result<(Vec<PatchNoteEntry>, Option<String>)> {
+    /// Адреса статьи патча на сайте Riot: сначала регион локали патч-нотов, затем второй.
+    fn patch_notes_urls(version: &str, patch_notes_locale: &str) -> Vec<String> {
         let slug = version.replace(".", "-");
         let primary = riot_news_region_path(patch_notes_locale);
         let secondary = if primary == "ru-ru" { "en-gb" } else { "ru-ru" };
@@ -1162,24 +2107,164 @@ impl Scraper {
                 region, slug
             ));
         }
-        for url in urls {
-            let Ok(resp) = self.client.get(&url).send().await else {
+        urls
+    }
+
+    /// Пробный разбор без сохранения: скачивает статью патча и считает покрытие парсера
+    /// (`parse_coverage`). Если ни на одной странице записей нет, отчёт — по первой скачанной.
+    pub async fn validate_parse(&self, version: &str, patch_notes_locale: &str) -> Result<ParseCoverage> {
+        let loc = normalize_patch_notes_locale(patch_notes_locale);
+        let champion_slugs = self.fetch_champion_slug_set().await;
+        let mut report = None;
+        for url in Self::patch_notes_urls(version, loc) {
+            self.throttle_riot().await;
+            let Ok(resp) = self.get_with_retries(&url).await else {
                 continue;
             };
             if !resp.status().is_success() {
                 continue;
             }
-            let Ok(text) = resp.text().await else {
+            let Ok(html) = http_body::read_text(resp).await else {
                 continue;
             };
-            let banner = Self::extract_article_banner(&text);
-            let champion_slugs = self.fetch_champion_slug_set().await;
+            let notes = self.parse_riot_patch_notes_html(&html, &champion_slugs, loc);
+            let found = !notes.is_empty();
+            if found || report.is_none() {
+                report = Some(parse_coverage::measure(
+                    version,
+                    loc,
+                    &url,
+                    page_layout::detect(&Html::parse_document(&html)),
+                    &notes,
+                    patch_note_sections(&html, &champion_slugs),
+                ));
+            }
+            if found {
+                break;
+            }
+        }
+        report.ok_or_else(|| anyhow::anyhow!("patch notes page for {} not found", version))
+    }
+
+    async fn scrape_riot_patch_notes(
+        &self,
+        version: &str,
+        patch_notes_locale: &str,
+    ) -> Result<(Vec<PatchNoteEntry>, PatchArticleMeta)> {
+        let mut unsupported = Vec::new();
+        for url in Self::patch_notes_urls(version, patch_notes_locale) {
+            self.profile
+                .time(version, patch_notes_locale, ScrapePhase::Throttle, self.throttle_riot())
+                .await;
+            let page = async {
+                let resp = match self.get_with_retries(&url).await {
+                    Ok(resp) => resp,
+                    Err(e) => {
+                        logger::log_with(
+                            LogLevel::Debug,
+                            LogSource::Scraper,
+                            "patch notes request failed",
+                            &[("url", &url), ("error", &e)],
+                        );
+                        return None;
+                    }
+                };
+                if !resp.status().is_success() {
+                    logger::log_with(
+                        LogLevel::Debug,
+                        LogSource::Scraper,
+                        "patch notes page skipped",
+                        &[("url", &url), ("status", &resp.status())],
+                    );
+                    return None;
+                }
+                http_body::read_text(resp).await.ok()
+            };
+            let Some(text) = self
+                .profile
+                .time(version, patch_notes_locale, ScrapePhase::Download, page)
+                .await
+            else {
+                continue;
+            };
+            let champion_slugs = self
+                .profile
+                .time(version, patch_notes_locale, ScrapePhase::Download, self.fetch_champion_slug_set())
+                .await;
+            let parse_started = Instant::now();
+            let article = Self::extract_article_meta(&text);
             let notes = self.parse_riot_patch_notes_html(&text, &champion_slugs, patch_notes_locale);
+            self.profile
+                .record(version, patch_notes_locale, ScrapePhase::Parse, parse_started.elapsed());
+            logger::log_with(
+                LogLevel::Debug,
+                LogSource::Scraper,
+                "patch notes page parsed",
+                &[("url", &url), ("notes", &notes.len()), ("bytes", &text.len())],
+            );
             if !notes.is_empty() {
-                return Ok((notes, banner));
+                return Ok((notes, article));
+            }
+            if page_layout::detect(&Html::parse_document(&text)) == PageLayout::Unsupported {
+                unsupported.push(url);
+            }
+        }
+        if !unsupported.is_empty() {
+            logger::log_with(
+                LogLevel::Error,
+                LogSource::Scraper,
+                "unsupported patch notes layout",
+                &[("version", &version), ("urls", &unsupported.join(" "))],
+            );
+            logger::emit(UnsupportedLayoutEvent {
+                version: version.to_string(),
+                patch_notes_locale: patch_notes_locale.to_string(),
+                urls: unsupported.clone(),
+            });
+            return Err(anyhow::anyhow!(
+                "unsupported patch notes layout for {}: {}",
+                version,
+                unsupported.join(", ")
+            ));
+        }
+        Ok((vec![], PatchArticleMeta::default()))
+    }
+
+    /// Новые статьи разработчиков (до `limit`), кроме уже сохранённых `known_urls`.
+    pub async fn fetch_dev_articles(
+        &self,
+        locale: &str,
+        limit: usize,
+        known_urls: &HashSet<String>,
+    ) -> Result<Vec<DevArticle>> {
+        self.throttle_riot().await;
+        let list_html = self
+            .get_with_retries(&crate::dev_articles::dev_news_url(locale))
+            .await?
+            .error_for_status()?;
+        let list_html = http_body::read_text(list_html).await?;
+        let mut out = Vec::new();
+        for url in crate::dev_articles::parse_dev_article_list(&list_html)
+            .into_iter()
+            .filter(|u| !known_urls.contains(u))
+            .take(limit)
+        {
+            self.throttle_riot().await;
+            let Ok(resp) = self.get_with_retries(&url).await else {
+                continue;
+            };
+            if !resp.status().is_success() {
+                continue;
+            }
+            let Ok(text) = http_body::read_text(resp).await else {
+                continue;
+            };
+            let article = crate::dev_articles::parse_dev_article_html(&text, &url, locale);
+            if !article.sections.is_empty() {
+                out.push(article);
             }
         }
-        Ok((vec![], None))
+        Ok(out)
     }
 
     async fn fetch_champion_slug_set(&self) -> HashSet<String> {
@@ -1192,10 +2277,10 @@ impl Scraper {
             "https://ddragon.leagueoflegends.com/cdn/{}/data/en_US/champion.json",
             ver
         );
-        let Ok(resp) = self.client.get(&url).send().await else {
+        let Ok(resp) = self.request(&url).send().await else {
             return set;
         };
-        let Ok(json) = resp.json::<serde_json::Value>().await else {
+        let Ok(json) = http_body::read_json::<serde_json::Value>(resp).await else {
             return set;
         };
         if let Some(data) = json.get("data").and_then(|d| d.as_object()) {
@@ -1209,24 +2294,20 @@ impl Scraper {
         set
     }
 
-    pub(crate) fn parse_riot_patch_notes_html(
+    pub fn parse_riot_patch_notes_html(
         &self,
         html: &str,
         champion_slugs: &HashSet<String>,
         patch_notes_locale: &str,
     ) -> Vec<PatchNoteEntry> {
-        let bugfix_entry_title = if patch_notes_locale == "en" {
-            "Bug fix"
-        } else {
-            "Исправление ошибки"
-        };
+        let bugfix_entry_title = i18n::tr(Lang::from_code(patch_notes_locale), "note.bugfix_title");
         let document = Html::parse_document(html);
         let mut notes = Vec::new();
-        
-        let container_sel = Selector::parse("#patch-notes-container").unwrap();
-        
-        if let Some(container) = document.select(&container_sel).next() {
+
+        // Парсер по вёрстке в порядке `page_layout::detect`: текущая, старая, иначе записей нет
+        if let Some(container) = page_layout::current_container(&document) {
             let mut current_category = PatchCategory::Unknown;
+            let mut current_section_title = String::new();
             
                     let h2_sel = Selector::parse("h2").unwrap();
                     let change_block_sel = Selector::parse(".patch-change-block").unwrap();
@@ -1241,6 +2322,15 @@ impl Scraper {
                     if let Some(h2) = h2_el {
                         let id = h2.value().id().unwrap_or("");
                         current_category = patch_category_from_section_h2_id(id, champion_slugs);
+                        current_section_title = h2.text().collect::<String>().trim().to_string();
+                        if current_category == PatchCategory::Unknown {
+                            logger::log_with(
+                                LogLevel::Debug,
+                                LogSource::Scraper,
+                                "unknown patch notes section",
+                                &[("h2_id", &id)],
+                            );
+                        }
                     }
                     
                     // Helper to clean URLs from Riot's proxy
@@ -1293,14 +2383,33 @@ impl Scraper {
                                     );
                                 }
                                 // Case 1c: <p><a class="reference-link"><img>…</a></p> (напр. патч 26.8+)
-                                else if tag == "p" {
-                                    if let Some(alink) = child_el.select(&ref_link_sel).next() {
-                                        pending_icon = clean_url(
-                                            alink
-                                                .select(&img_sel)
-                                                .next()
-                                                .and_then(img_url_from_element),
-                                        );
+                                else if let Some(alink) =
+                                    child_el.select(&ref_link_sel).next().filter(|_| tag == "p")
+                                {
+                                    pending_icon = clean_url(
+                                        alink
+                                            .select(&img_sel)
+                                            .next()
+                                            .and_then(img_url_from_element),
+                                    );
+                                }
+                                // Case 4b: подраздел способности (h5 или <p><strong>…</strong></p>) -> вложенный блок
+                                else if tag == "h5" || tag == "p" {
+                                    let sub_title = if tag == "h5" {
+                                        Some(child_el.text().collect::<String>().trim().to_string())
+                                            .filter(|t| !t.is_empty())
+                                    } else {
+                                        strong_only_paragraph_title(child_el)
+                                    };
+                                    if let (Some(sub_title), Some(entry)) = (sub_title, current_entry.as_mut()) {
+                                        if let Some(parent) = entry.details.last_mut().filter(|b| b.title.is_some()) {
+                                            parent.children.push(ChangeBlock {
+                                                title: Some(sub_title),
+                                                icon_url: None,
+                                                changes: Vec::new(),
+                                                children: vec![],
+                                            });
+                                        }
                                     }
                                 }
                                 // Case 2: Title (H3 or .change-title) -> New Entry
@@ -1323,6 +2432,13 @@ impl Scraper {
                                             summary: String::new(),
                                             details: Vec::new(),
                                             icon_candidates: None,
+                                            title_en: None,
+                                            title_ru: None,
+                                            tags: vec![],
+                                            entity_id: None,
+                                            raw_html: None,
+                                            grade: None,
+                                            mentions: vec![],
                                         });
                                     }
                                 }
@@ -1347,45 +2463,68 @@ impl Scraper {
                                             title: Some(detail_title),
                                             icon_url: detail_icon,
                                             changes: Vec::new(),
+                                            children: vec![],
                                         });
                                     }
                                 }
-                                // Case 5: Changes List (UL)
-                                else if tag == "ul" {
+                                // Case 5: Changes List (UL) или таблица значений
+                                else if let Some(list) = (tag == "ul")
+                                    .then_some(child_el)
+                                    .or_else(|| change_table_of(child_el))
+                                {
                                     if let Some(entry) = current_entry.as_mut() {
-                                        let mut changes = Vec::new();
-                                        for li in child_el.select(&li_sel) {
-                                            let text = li.text().collect::<String>().trim().to_string();
-                                            if !text.is_empty() { changes.push(text); }
-                                        }
-                                        
-                                        if !changes.is_empty() {
-                                            // Attach to last block, or create new nameless block
+                                        let (changes, children) = if list.value().name() == "table" {
+                                            parse_change_table(list)
+                                        } else {
+                                            parse_change_list(list)
+                                        };
+
+                                        if !changes.is_empty() || !children.is_empty() {
+                                            // Attach to last block (or its open subsection), or create new nameless block
                                             if let Some(last_block) = entry.details.last_mut() {
-                                                last_block.changes.extend(changes);
+                                                let target = match last_block.children.last_mut() {
+                                                    Some(sub) => sub,
+                                                    None => last_block,
+                                                };
+                                                target.changes.extend(changes);
+                                                target.children.extend(children);
                                             } else {
                                                 entry.details.push(ChangeBlock {
                                                     title: None,
                                                     icon_url: None,
                                                     changes,
+                                                    children,
                                                 });
                                             }
                                         }
                                     }
                                 }
+
+                                // Исходный HTML записи; иконка следующего чемпиона сюда не попадает
+                                if pending_icon.is_none() {
+                                    if let Some(entry) = current_entry.as_mut() {
+                                        entry.raw_html.get_or_insert_with(String::new).push_str(&child_el.html());
+                                    }
+                                }
                             }
                         }
                         
                         // Push the final entry from this block
                         if let Some(mut entry) = current_entry {
-                            entry.change_type = self.determine_change_type(&entry.summary, &entry.details);
+                            entry.change_type = if is_rework(&entry) {
+                                ChangeType::Rework
+                            } else {
+                                self.determine_change_type(&entry.summary, &entry.details)
+                            };
                             notes.push(entry);
                         }
                     }
                     } else if current_category == PatchCategory::UpcomingSkinsChromas {
                         append_upcoming_skins_chromas_notes(el, &mut notes);
                     } else if el.value().has_class("content-border", scraper::CaseSensitivity::CaseSensitive) {
-                        if current_category == PatchCategory::BugFixes {
+                        if current_category == PatchCategory::Store {
+                            append_store_notes(el, &current_section_title, &mut notes);
+                        } else if current_category == PatchCategory::BugFixes {
                             for ul in el.select(&ul_sel) {
                                 for li in ul.select(&li_sel) {
                                     let text = li.text().collect::<String>().trim().to_string();
@@ -1403,8 +2542,16 @@ impl Scraper {
                                             title: None,
                                             icon_url: None,
                                             changes: vec![text],
+                                            children: vec![],
                                         }],
                                         icon_candidates: None,
+                                        title_en: None,
+                                        title_ru: None,
+                                        tags: vec![],
+                                        entity_id: None,
+                                        raw_html: Some(li.html()),
+                                        grade: None,
+                                        mentions: vec![],
                                     });
                                 }
                             }
@@ -1416,18 +2563,41 @@ impl Scraper {
                                 | PatchCategory::Modes
                         ) {
                             append_flat_mode_style_notes(self, el, &current_category, &mut notes);
+                        } else if matches!(current_category, PatchCategory::Ranked | PatchCategory::Behavioral) {
+                            append_system_prose_notes(self, el, &current_section_title, &current_category, &mut notes);
                         }
                     }
                 }
             }
+        } else if let Some(body) = page_layout::legacy_body(&document) {
+            append_legacy_layout_notes(self, body, champion_slugs, &mut notes);
+        } else {
+            logger::log(LogLevel::Debug, LogSource::Scraper, "unsupported patch notes layout");
+        }
+        if logger::enabled(LogLevel::Debug, LogSource::Scraper) {
+            let mut by_category: Vec<(String, usize)> = Vec::new();
+            for note in &notes {
+                let name = format!("{:?}", note.category);
+                match by_category.iter_mut().find(|(c, _)| *c == name) {
+                    Some((_, n)) => *n += 1,
+                    None => by_category.push((name, 1)),
+                }
+            }
+            let summary: Vec<String> = by_category.iter().map(|(c, n)| format!("{}={}", c, n)).collect();
+            logger::log_with(
+                LogLevel::Debug,
+                LogSource::Scraper,
+                "patch notes parsed",
+                &[("locale", &patch_notes_locale), ("categories", &summary.join(","))],
+            );
         }
         notes
     }
     
     async fn scrape_leagueofgraphs(&self) -> Result<Vec<ChampionStats>> {
         let url = "https://www.leagueofgraphs.com/ru/champions/tier-list";
-        if let Ok(resp) = self.client.get(url).send().await {
-            if let Ok(text) = resp.text().await {
+        if let Ok(resp) = self.request(url).send().await {
+            if let Ok(text) = http_body::read_text(resp).await {
                 let _document = Html::parse_document(&text);
                 return Ok(vec![]); 
             }
@@ -1440,7 +2610,7 @@ impl Scraper {
     fn determine_change_type(&self, summary: &str, details: &[ChangeBlock]) -> ChangeType {
         let detail_text = details
             .iter()
-            .flat_map(|b| b.changes.iter().cloned())
+            .flat_map(|b| b.all_changes().into_iter().cloned())
             .collect::<Vec<_>>()
             .join(" ");
         let text = format!("{} {}", summary, detail_text);
@@ -1449,17 +2619,9 @@ impl Scraper {
         if text.is_empty() {
             return ChangeType::Adjusted;
         }
-        let removal_re = Regex::new(
-            r"(?i)(удал(яем|ён|ен|ено|ены|ении|ение)|убир(аем|ем)|сним(аем|ем)|отключ(аем|ен|ено)|больше не\s+(будет|существ|действ|доступ)|исчез(нет|ла|ают)?|will be removed|has been removed|removed from|no longer (available|appears|in ))",
-        )
-        .unwrap();
-        let new_re = Regex::new(
-            r"(?i)(добавляем|добавлен(о|ы)?|впервые|новый\s|новая\s|новое\s|новые\s|теперь доступн|появ(ится|ились|ятся)|introducing|we are adding|we're adding|new to league)",
-        )
-        .unwrap();
-        if removal_re.is_match(text) {
+        if ENTRY_REMOVAL_RE.is_match(text) {
             ChangeType::Removed
-        } else if new_re.is_match(text) {
+        } else if ENTRY_NEW_RE.is_match(text) {
             ChangeType::New
         } else {
             let mut has_buff = false;
@@ -1467,7 +2629,7 @@ impl Scraper {
 
             for trend in details
                 .iter()
-                .flat_map(|b| b.changes.iter())
+                .flat_map(|b| b.all_changes())
                 .map(|s| analyze_change_trend(s))
             {
                 match trend {
@@ -1496,7 +2658,7 @@ impl Scraper {
         }
     }
     
-    pub async fn scrape_champion_details(&self, _name: &str, _role: &LaneRole) -> Result<(Vec<ItemStat>, Vec<String>)> {
+    pub async fn scrape_champion_details(&self, _name: &str, _role: &LaneRole) -> Result<(Vec<ItemStat>, Vec<RuneEntry>)> {
         Ok((vec![], vec![]))
     }
 }
@@ -1522,6 +2684,36 @@ mod tests {
         assert!(u.contains("x.jpg"));
     }
 
+    #[test]
+    fn extracts_article_date_author_and_season() {
+        let html = r#"<!DOCTYPE html><html><head>
+<meta property="og:title" content="Patch 25.S1.3 Notes">
+<meta property="article:published_time" content="2025-02-04T18:00:00.000Z">
+<meta name="author" content="Riot Phroxzon">
+<meta property="og:image" content="https://cmsassets.rgpub.io/sanity/images/y.jpg">
+</head><body></body></html>"#;
+        let m = Scraper::extract_article_meta(html);
+        assert_eq!(m.season.as_deref(), Some("25.S1"));
+        assert_eq!(m.author.as_deref(), Some("Riot Phroxzon"));
+        assert_eq!(
+            m.published_at.map(|d| d.to_rfc3339()),
+            Some("2025-02-04T18:00:00+00:00".to_string())
+        );
+        assert!(m.banner_url.unwrap().contains("y.jpg"));
+    }
+
+    #[test]
+    fn detects_patch_version_from_title_or_page_url() {
+        let m = Scraper::extract_article_meta("<html><head><title>Обновление 25.22b</title></head></html>");
+        assert_eq!(m.version.as_deref(), Some("25.22b"));
+        let m = Scraper::extract_article_meta(
+            r#"<html><head><title>Patch notes</title>
+<link rel="canonical" href="https://www.leagueoflegends.com/en-gb/news/game-updates/patch-25-21-notes/">
+</head></html>"#,
+        );
+        assert_eq!(m.version.as_deref(), Some("25.21"));
+    }
+
     #[test]
     fn parses_sibling_header_then_content_border_blocks() {
         let html = r###"<!DOCTYPE html><html><body>
@@ -1546,6 +2738,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nests_ability_subsections_and_nested_lists() {
+        let html = r###"<div id="patch-notes-container">
+<header class="header-primary"><h2 id="patch-champions">Champions</h2></header>
+<div class="content-border"><div class="patch-change-block white-stone"><div>
+<h3 class="change-title">Karma</h3>
+<h4 class="change-detail-title ability-title">Q - Inner Flame</h4>
+<ul><li>Damage: 40 ⇒ 50</li></ul>
+<p><strong>Mantra Empowered</strong></p>
+<ul><li>Bonus damage: 25 ⇒ 40</li></ul>
+<h5>ARAM</h5>
+<ul><li>Cooldown: 9 ⇒ 8<ul><li>Level 1: 9 ⇒ 8</li></ul></li></ul>
+</div></div></div>
+</div>"###;
+        let s = Scraper::new().unwrap();
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        let q = &notes[0].details[0];
+        assert_eq!(q.changes, vec!["Damage: 40 ⇒ 50".to_string()]);
+        assert_eq!(q.children.len(), 2);
+        assert_eq!(q.children[0].title.as_deref(), Some("Mantra Empowered"));
+        assert_eq!(q.children[0].changes, vec!["Bonus damage: 25 ⇒ 40".to_string()]);
+        assert_eq!(q.children[1].title.as_deref(), Some("ARAM"));
+        assert!(q.children[1].changes.is_empty());
+        assert_eq!(q.children[1].children[0].title.as_deref(), Some("Cooldown: 9 ⇒ 8"));
+        assert_eq!(q.all_changes().len(), 3);
+    }
+
+    #[test]
+    fn keeps_raw_html_per_entry() {
+        let html = r###"<div id="patch-notes-container">
+<header class="header-primary"><h2 id="patch-champions">Champions</h2></header>
+<div class="content-border"><div class="patch-change-block white-stone"><div>
+<h3 class="change-title">Ahri</h3>
+<ul><li>Damage: 40 ⇒ 50</li></ul>
+<a class="reference-link" href="#"><img src="https://cdn.example/zed.png"></a>
+<h3 class="change-title">Zed</h3>
+<ul><li>Armor: 30 ⇒ 35</li></ul>
+</div></div></div>
+</div>"###;
+        let s = Scraper::new().unwrap();
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        let ahri = notes[0].raw_html.as_deref().unwrap();
+        assert!(ahri.contains("Damage: 40 ⇒ 50") && !ahri.contains("zed.png"));
+        let zed = notes[1].raw_html.as_deref().unwrap();
+        assert!(zed.starts_with("<h3") && zed.contains("Armor: 30 ⇒ 35"));
+    }
+
     fn minimal_patch_block(title: &str, h2_id: &str) -> String {
         format!(
             r###"<div id="patch-notes-container">
@@ -1617,21 +2856,118 @@ mod tests {
     }
 
     #[test]
-    fn categorizes_clash_ranked_matchmaking_as_modes() {
+    fn categorizes_clash_as_modes_and_ranked_matchmaking_as_ranked() {
         let s = Scraper::new().unwrap();
         let slugs = non_empty_champion_slugs();
-        for id in [
-            "patch-clash-summoner",
-            "patch-ranked",
-            "patch-sr-ranked-and-matchmaking",
-            "patch-lane-based-autofill-matchmaking",
-            "patch-swiftplay",
+        for (id, expected) in [
+            ("patch-clash-summoner", PatchCategory::Modes),
+            ("patch-swiftplay", PatchCategory::Modes),
+            ("patch-ranked", PatchCategory::Ranked),
+            ("patch-sr-ranked-and-matchmaking", PatchCategory::Ranked),
+            ("patch-lane-based-autofill-matchmaking", PatchCategory::Ranked),
+            ("patch-duo-restrictions", PatchCategory::Ranked),
         ] {
             let notes = s.parse_riot_patch_notes_html(&minimal_patch_block("M", id), &slugs, "ru");
-            assert_eq!(notes[0].category, PatchCategory::Modes, "id={id}");
+            assert_eq!(notes[0].category, expected, "id={id}");
         }
     }
 
+    #[test]
+    fn parses_stat_tables_into_changes() {
+        let s = Scraper::new().unwrap();
+        let html = r###"<div id="patch-notes-container">
+<header class="header-primary"><h2 id="patch-items">Items</h2></header>
+<div class="content-border"><div class="patch-change-block white-stone"><div>
+<h3 class="change-title">Infinity Edge</h3>
+<div class="table-wrapper"><table>
+<thead><tr><th>Stat</th><th>Old</th><th>New</th></tr></thead>
+<tbody><tr><td>Attack Damage</td><td>65</td><td>70</td></tr><tr><td>Cost</td><td>3400</td><td>3450</td></tr></tbody>
+</table></div>
+</div></div></div>
+<header class="header-primary"><h2 id="patch-aram">ARAM</h2></header>
+<div class="content-border"><div class="white-stone accent-before"><div>
+<p><strong>Champion Balance</strong></p>
+<table>
+<tr><th>Champion</th><th>Damage Dealt</th><th>Damage Taken</th></tr>
+<tr><td>Ahri</td><td>+5%</td><td>-5%</td></tr>
+<tr><td>Zed</td><td>-5%</td><td></td></tr>
+</table>
+</div></div></div>
+</div>"###;
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        assert_eq!(notes.len(), 2, "notes: {:?}", notes);
+        assert_eq!(notes[0].details[0].changes, ["Attack Damage: 65 ⇒ 70", "Cost: 3400 ⇒ 3450"]);
+
+        assert_eq!(notes[1].title, "Champion Balance");
+        assert_eq!(notes[1].category, PatchCategory::ModeAram);
+        let rows: Vec<(Option<&str>, &[String])> = notes[1].details[0]
+            .children
+            .iter()
+            .map(|b| (b.title.as_deref(), b.changes.as_slice()))
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], (Some("Ahri"), &["Damage Dealt: +5%".to_string(), "Damage Taken: -5%".to_string()][..]));
+        assert_eq!(rows[1], (Some("Zed"), &["Damage Dealt: -5%".to_string()][..]));
+    }
+
+    #[test]
+    fn parses_legacy_layout_without_container() {
+        let s = Scraper::new().unwrap();
+        let html = r###"<div data-testid="rich-text-html">
+<h2>Champions</h2>
+<h3>Ahri</h3>
+<img src="https://cdn.example.com/ahri.png">
+<ul><li>Q damage: 40 ⇒ 50</li><li>Passive<ul><li>Heal: 3 ⇒ 5</li></ul></li></ul>
+<h2>Items</h2>
+<ul><li>Boots cost reduced</li></ul>
+</div>"###;
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        assert_eq!(notes.len(), 2, "notes: {:?}", notes);
+        assert_eq!((notes[0].title.as_str(), &notes[0].category), ("Ahri", &PatchCategory::Champions));
+        assert_eq!(notes[0].image_url.as_deref(), Some("https://cdn.example.com/ahri.png"));
+        assert_eq!(notes[0].details[0].changes, ["Q damage: 40 ⇒ 50", "Heal: 3 ⇒ 5"]);
+        assert_eq!((notes[1].title.as_str(), &notes[1].category), ("Items", &PatchCategory::Items));
+
+        let redesign = r#"<main><section class="patch-grid"><div class="card">Ahri</div></section></main>"#;
+        assert!(s.parse_riot_patch_notes_html(redesign, &HashSet::new(), "en").is_empty());
+    }
+
+    #[test]
+    fn parses_ranked_prose_section_into_blocks() {
+        let s = Scraper::new().unwrap();
+        let html = r###"<div id="patch-notes-container">
+<header class="header-primary"><h2 id="patch-ranked-updates">Ranked Updates</h2></header>
+<div class="content-border"><div class="white-stone accent-before"><div>
+<p>We're making a few changes to ranked this patch.</p>
+<p><strong>Duo Restrictions</strong></p>
+<p>Master+ players can no longer queue as a duo.</p>
+<ul><li>Applies to Solo/Duo only</li></ul>
+<h4>LP Gains</h4>
+<ul><li>Win streaks now grant more LP</li></ul>
+</div></div></div>
+</div>"###;
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        assert_eq!(notes.len(), 1, "notes: {:?}", notes);
+        assert_eq!(notes[0].title, "Ranked Updates");
+        assert_eq!(notes[0].category, PatchCategory::Ranked);
+        let blocks: Vec<(Option<&str>, Vec<&str>)> = notes[0]
+            .details
+            .iter()
+            .map(|b| (b.title.as_deref(), b.changes.iter().map(String::as_str).collect()))
+            .collect();
+        assert_eq!(
+            blocks,
+            [
+                (None, vec!["We're making a few changes to ranked this patch."]),
+                (
+                    Some("Duo Restrictions"),
+                    vec!["Master+ players can no longer queue as a duo.", "Applies to Solo/Duo only"]
+                ),
+                (Some("LP Gains"), vec!["Win streaks now grant more LP"]),
+            ]
+        );
+    }
+
     #[test]
     fn categorizes_system_sections_from_patch_26_1_ids() {
         let s = Scraper::new().unwrap();
@@ -1640,17 +2976,35 @@ mod tests {
             ("patch-role-quests", PatchCategory::Systems),
             (
                 "patch-lobby-hostage-taking-and-player-behavior-updates",
-                PatchCategory::Systems,
+                PatchCategory::Behavioral,
             ),
             ("patch-demacia-rising", PatchCategory::Systems),
             ("patch-systems", PatchCategory::Systems),
-            ("patch-atakhan-blood-roses-and-feats", PatchCategory::Systems),
+            ("patch-atakhan-blood-roses-and-feats", PatchCategory::Objectives),
         ] {
             let notes = s.parse_riot_patch_notes_html(&minimal_patch_block("S", id), &slugs, "ru");
             assert_eq!(notes[0].category, expected, "id={id}");
         }
     }
 
+    #[test]
+    fn categorizes_objectives_behavior_and_store_sections() {
+        let s = Scraper::new().unwrap();
+        let slugs = non_empty_champion_slugs();
+        for (id, expected) in [
+            ("patch-jungle", PatchCategory::Objectives),
+            ("patch-dragons-and-baron", PatchCategory::Objectives),
+            ("patch-void-grubs", PatchCategory::Objectives),
+            ("patch-honor-updates", PatchCategory::Behavioral),
+            ("patch-leaver-penalties", PatchCategory::Behavioral),
+            ("patch-store-updates", PatchCategory::Store),
+            ("patch-mythic-shop", PatchCategory::Store),
+        ] {
+            let notes = s.parse_riot_patch_notes_html(&minimal_patch_block("X", id), &slugs, "ru");
+            assert_eq!(notes[0].category, expected, "id={id}");
+        }
+    }
+
     #[test]
     fn categorizes_champion_h2_by_ddragon_slug() {
         let s = Scraper::new().unwrap();
@@ -1659,6 +3013,38 @@ mod tests {
         assert_eq!(notes[0].category, PatchCategory::Champions);
     }
 
+    #[test]
+    fn parses_mythic_shop_rotation_paragraphs_and_lists() {
+        let s = Scraper::new().unwrap();
+        let html = r###"<div id="patch-notes-container">
+<header class="header-primary"><h2 id="patch-mythic-shop">Mythic Shop</h2></header>
+<div class="content-border"><div class="white-stone accent-before"><div>
+<p>The Mythic Shop rotates on November 5 and stays until December 3.</p>
+<ul><li>Prestige Ahri</li><li>Prestige Zed</li></ul>
+<h4>Event Pass</h4>
+<p>Available until <strong>December 9</strong>.</p>
+<ul><li>Orb of Fate</li></ul>
+</div></div></div>
+</div>"###;
+        let notes = s.parse_riot_patch_notes_html(html, &HashSet::new(), "en");
+        let parsed: Vec<(&str, &str, Vec<String>)> = notes
+            .iter()
+            .map(|n| (n.title.as_str(), n.summary.as_str(), n.details.iter().flat_map(|b| b.changes.clone()).collect()))
+            .collect();
+        assert_eq!(
+            parsed,
+            [
+                (
+                    "Mythic Shop",
+                    "The Mythic Shop rotates on November 5 and stays until December 3.",
+                    vec!["Prestige Ahri".to_string(), "Prestige Zed".to_string()]
+                ),
+                ("Event Pass", "Available until December 9.", vec!["Orb of Fate".to_string()]),
+            ]
+        );
+        assert!(notes.iter().all(|n| n.category == PatchCategory::Store));
+    }
+
     #[test]
     fn parses_upcoming_skins_skin_box() {
         let s = Scraper::new().unwrap();
@@ -1745,6 +3131,7 @@ mod tests {
             title: None,
             icon_url: None,
             changes: changes.iter().map(|s| s.to_string()).collect(),
+            children: vec![],
         }]
     }
 
@@ -1797,4 +3184,80 @@ mod tests {
         );
         assert_eq!(ty, ChangeType::Buff);
     }
+
+    fn rework_entry(title: &str, summary: &str, details: Vec<ChangeBlock>) -> PatchNoteEntry {
+        PatchNoteEntry {
+            id: "r".into(),
+            title: title.into(),
+            category: PatchCategory::Champions,
+            summary: summary.into(),
+            details,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn detects_rework_by_replaced_ability_blocks() {
+        let block = |t: &str| ChangeBlock {
+            title: Some(t.into()),
+            icon_url: None,
+            changes: vec![
+                "УДАЛЕНО: замедление при попадании".into(),
+                "НОВОЕ: теперь накладывает метку".into(),
+            ],
+            children: vec![],
+        };
+        let e = rework_entry("Мордекайзер", "", vec![block("Q"), block("W"), block("E"), block("R")]);
+        assert!(is_rework(&e));
+
+        let tweak = rework_entry(
+            "Мордекайзер",
+            "Ослабим E.",
+            detail_block(&["Перезарядка: 10 → 12 секунд"]),
+        );
+        assert!(!is_rework(&tweak));
+    }
+
+    #[test]
+    fn detects_rework_from_summary() {
+        let e = rework_entry("Skarner", "Skarner's visual and gameplay update is here.", vec![]);
+        assert!(is_rework(&e));
+        let ru = rework_entry("Скарнер", "Визуальное и игровое обновление Скарнера.", vec![]);
+        assert!(is_rework(&ru));
+    }
+
+    #[test]
+    fn scrape_settings_are_clamped_and_backoff_doubles() {
+        let s = ScrapeSettings {
+            concurrency: 0,
+            request_gap_ms: 60_000,
+            retries: 9,
+        }
+        .clamped();
+        assert_eq!((s.concurrency, s.request_gap_ms, s.retries), (1, 5_000, 5));
+
+        let fast = ScrapeSettings {
+            request_gap_ms: 100,
+            ..ScrapeSettings::default()
+        };
+        assert_eq!(fast.retry_backoff(1), Duration::from_millis(500));
+        assert_eq!(fast.retry_backoff(3), Duration::from_millis(2_000));
+    }
+
+    #[test]
+    fn request_header_settings_drop_invalid_headers() {
+        let header = |name: &str, value: &str| ExtraHeader {
+            name: name.into(),
+            value: value.into(),
+        };
+        let s = RequestHeaderSettings {
+            user_agent: "  MyAgent/1.0 ".into(),
+            accept_language: "ru-RU\n".into(),
+            extra_headers: vec![header(" X-Test ", "1"), header("bad name", "2"), header("X-Other", "a\nb")],
+        }
+        .normalized();
+        assert_eq!(s.user_agent, "MyAgent/1.0");
+        assert_eq!(s.accept_language, "ru-RU");
+        assert_eq!(s.extra_headers, [header("X-Test", "1")]);
+    }
 }
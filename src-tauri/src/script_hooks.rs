@@ -0,0 +1,274 @@
+//! Пользовательские скрипты Rhai для своей разметки и метрик. Скрипт может объявить хуки:
+//! - `fn after_classify_change(change)` — после автотегов и оценки масштаба записи при разборе патча;
+//!   вернуть массив строк — заменить теги записи, `()` — оставить как есть;
+//! - `fn after_patch_analyzed(patch)` — при расчёте сводки патча; вернуть map «метрика → число»,
+//!   результат попадает в `PatchSummary::script_scores` под именем скрипта.
+//!
+//! `change` и `patch` — `PatchNoteEntry` и `PatchData` в виде map (поля как в JSON).
+//! Скрипты лежат в `<app_data>/scripts/*.rhai`; ошибка скрипта не мешает разбору патча.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::Lazy;
+use rhai::{Dynamic, Engine, Scope, AST};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::logger;
+use crate::models::events::{LogLevel, LogSource};
+use crate::models::{PatchData, PatchNoteEntry};
+
+pub const HOOK_AFTER_CLASSIFY_CHANGE: &str = "after_classify_change";
+pub const HOOK_AFTER_PATCH_ANALYZED: &str = "after_patch_analyzed";
+const SCRIPT_EXTENSION: &str = "rhai";
+/// Каталог скриптов в данных приложения.
+pub const SCRIPTS_DIR_NAME: &str = "scripts";
+
+/// Предел операций на вызов хука: зациклившийся скрипт не вешает синхронизацию.
+const MAX_OPERATIONS: u64 = 500_000;
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ScriptInfo {
+    pub name: String,
+    /// Объявленные хуки
+    pub hooks: Vec<String>,
+    /// Последняя ошибка выполнения
+    pub last_error: Option<String>,
+}
+
+struct LoadedScript {
+    name: String,
+    ast: AST,
+    hooks: Vec<String>,
+    last_error: Option<String>,
+}
+
+impl LoadedScript {
+    fn has_hook(&self, hook: &str) -> bool {
+        self.hooks.iter().any(|h| h == hook)
+    }
+
+    fn info(&self) -> ScriptInfo {
+        ScriptInfo {
+            name: self.name.clone(),
+            hooks: self.hooks.clone(),
+            last_error: self.last_error.clone(),
+        }
+    }
+}
+
+struct ScriptHost {
+    engine: Engine,
+    scripts: RwLock<Vec<LoadedScript>>,
+}
+
+static HOST: Lazy<ScriptHost> = Lazy::new(|| {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    ScriptHost {
+        engine,
+        scripts: RwLock::new(Vec::new()),
+    }
+});
+
+/// Имя файла без расширения; только буквы, цифры, `-` и `_`.
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(anyhow!("script name must be non-empty and contain only a-z, 0-9, '-' or '_'"));
+    }
+    Ok(())
+}
+
+fn compile(name: &str, source: &str) -> Result<LoadedScript> {
+    let ast = HOST
+        .engine
+        .compile(source)
+        .map_err(|e| anyhow!("{}: {}", name, e))?;
+    let hooks: Vec<String> = [HOOK_AFTER_CLASSIFY_CHANGE, HOOK_AFTER_PATCH_ANALYZED]
+        .into_iter()
+        .filter(|hook| ast.iter_functions().any(|f| f.name == *hook && f.params.len() == 1))
+        .map(str::to_string)
+        .collect();
+    if hooks.is_empty() {
+        return Err(anyhow!(
+            "{}: script declares no hooks ({} or {})",
+            name,
+            HOOK_AFTER_CLASSIFY_CHANGE,
+            HOOK_AFTER_PATCH_ANALYZED
+        ));
+    }
+    Ok(LoadedScript {
+        name: name.to_string(),
+        ast,
+        hooks,
+        last_error: None,
+    })
+}
+
+fn register(script: LoadedScript) -> ScriptInfo {
+    let info = script.info();
+    let mut scripts = HOST.scripts.write().unwrap_or_else(|e| e.into_inner());
+    scripts.retain(|s| s.name != script.name);
+    scripts.push(script);
+    scripts.sort_by(|a, b| a.name.cmp(&b.name));
+    info
+}
+
+/// Компилирует скрипт, сохраняет в `dir` и подключает (скрипт с тем же именем заменяется).
+pub fn load_script(dir: &Path, name: &str, source: &str) -> Result<ScriptInfo> {
+    validate_name(name)?;
+    let script = compile(name, source)?;
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join(format!("{}.{}", name, SCRIPT_EXTENSION)), source)?;
+    Ok(register(script))
+}
+
+pub fn remove_script(dir: &Path, name: &str) -> Result<()> {
+    validate_name(name)?;
+    HOST.scripts
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|s| s.name != name);
+    let path = dir.join(format!("{}.{}", name, SCRIPT_EXTENSION));
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Отключает все скрипты (файлы не трогает) — после удаления каталога при полной очистке.
+pub fn unload_all() {
+    HOST.scripts.write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Подключает все скрипты каталога при запуске. Возвращает ошибки компиляции по файлам.
+pub fn load_scripts_dir(dir: &Path) -> Vec<String> {
+    let mut errors = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return errors;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(SCRIPT_EXTENSION) {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let loaded = validate_name(name)
+            .and_then(|_| Ok(std::fs::read_to_string(&path)?))
+            .and_then(|source| compile(name, &source));
+        match loaded {
+            Ok(script) => {
+                register(script);
+            }
+            Err(e) => errors.push(format!("{:#}", e)),
+        }
+    }
+    errors
+}
+
+pub fn list_scripts() -> Vec<ScriptInfo> {
+    HOST.scripts
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(LoadedScript::info)
+        .collect()
+}
+
+/// Вызывает хук во всех скриптах, где он есть; ошибки запоминаются в `last_error` скрипта.
+fn run_hook(hook: &str, arg: &Dynamic) -> Vec<(String, Dynamic)> {
+    let mut scripts = HOST.scripts.write().unwrap_or_else(|e| e.into_inner());
+    let mut results = Vec::new();
+    for script in scripts.iter_mut().filter(|s| s.has_hook(hook)) {
+        match HOST
+            .engine
+            .call_fn::<Dynamic>(&mut Scope::new(), &script.ast, hook, (arg.clone(),))
+        {
+            Ok(value) => results.push((script.name.clone(), value)),
+            Err(e) => {
+                let error = format!("{}: {}", hook, e);
+                logger::log_with(LogLevel::Warn, LogSource::Scripts, &error, &[("script", &script.name)]);
+                script.last_error = Some(error);
+            }
+        }
+    }
+    results
+}
+
+fn any_script_has(hook: &str) -> bool {
+    HOST.scripts
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .any(|s| s.has_hook(hook))
+}
+
+/// Хук «после классификации записи»: скрипты по очереди могут заменить теги.
+pub fn after_classify_change(notes: &mut [PatchNoteEntry]) {
+    if !any_script_has(HOOK_AFTER_CLASSIFY_CHANGE) {
+        return;
+    }
+    for note in notes {
+        let Ok(arg) = rhai::serde::to_dynamic(&*note) else {
+            continue;
+        };
+        for (_, value) in run_hook(HOOK_AFTER_CLASSIFY_CHANGE, &arg) {
+            if value.is_unit() {
+                continue;
+            }
+            if let Ok(tags) = rhai::serde::from_dynamic::<Vec<String>>(&value) {
+                note.tags = tags;
+            }
+        }
+    }
+}
+
+/// Хук «после анализа патча»: метрики каждого скрипта под его именем.
+pub fn after_patch_analyzed(patch: &PatchData) -> HashMap<String, HashMap<String, f64>> {
+    if !any_script_has(HOOK_AFTER_PATCH_ANALYZED) {
+        return HashMap::new();
+    }
+    let Ok(arg) = rhai::serde::to_dynamic(patch) else {
+        return HashMap::new();
+    };
+    run_hook(HOOK_AFTER_PATCH_ANALYZED, &arg)
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let map = value.try_cast::<rhai::Map>()?;
+            let scores: HashMap<String, f64> = map
+                .into_iter()
+                .filter_map(|(k, v)| {
+                    let n = v.as_float().ok().or_else(|| v.as_int().ok().map(|i| i as f64))?;
+                    Some((k.to_string(), n))
+                })
+                .collect();
+            Some((name, scores))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compile_detects_hooks_and_rejects_scripts_without_them() {
+        let script = compile(
+            "retag",
+            r#"
+                fn after_classify_change(change) { change.tags + ["custom"] }
+                fn after_patch_analyzed(patch) { #{ notes: patch.patch_notes.len() } }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(script.hooks, vec![HOOK_AFTER_CLASSIFY_CHANGE, HOOK_AFTER_PATCH_ANALYZED]);
+        assert!(compile("empty", "fn helper(x) { x }").is_err());
+        assert!(validate_name("../evil").is_err());
+    }
+}
@@ -0,0 +1,216 @@
+//! Ротации магазина из раздела Store патч-нотов: мифический магазин, пропуски событий, бандлы.
+//! Даты берутся из текста записи («from November 5 to December 3», «до 3 декабря»), год — по дате
+//! статьи патча. За `REMIND_BEFORE_DAYS` до окончания ротации приходит `store_rotation_ending` —
+//! один раз на ротацию.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+use tauri::AppHandle;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{PatchCategory, PatchData};
+
+/// Сколько последних патчей просматривать по умолчанию: ротация мифического магазина длится дольше патча.
+pub const DEFAULT_PATCHES: usize = 4;
+pub const REMIND_BEFORE_DAYS: i64 = 3;
+const CHECK_INTERVAL: Duration = Duration::from_secs(3 * 60 * 60);
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(2 * 60);
+
+static DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b((?:jan|feb|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)[a-z]*)\.?\s+(\d{1,2})\b|\b(\d{1,2})\s+([а-яё]{3,8})\b",
+    )
+    .unwrap()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum StoreContentKind {
+    MythicShop,
+    EventPass,
+    Bundle,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct StoreRotation {
+    /// Версия патча и заголовок записи — по нему напоминание не повторяется
+    pub key: String,
+    pub title: String,
+    pub kind: StoreContentKind,
+    pub patch_version: String,
+    pub announced_at: DateTime<Utc>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub items: Vec<String>,
+}
+
+fn content_kind(text: &str) -> StoreContentKind {
+    let text = text.to_lowercase();
+    if text.contains("mythic") || text.contains("мифическ") {
+        StoreContentKind::MythicShop
+    } else if text.contains("pass") || text.contains("пропуск") {
+        StoreContentKind::EventPass
+    } else if text.contains("bundle") || text.contains("набор") {
+        StoreContentKind::Bundle
+    } else {
+        StoreContentKind::Other
+    }
+}
+
+/// Месяц по английскому названию (полному или от трёх букв) или по русскому в родительном падеже.
+fn month_number(word: &str) -> Option<u32> {
+    const EN: [&str; 12] = [
+        "january", "february", "march", "april", "may", "june", "july", "august", "september", "october", "november",
+        "december",
+    ];
+    const RU: [&str; 12] = [
+        "января", "февраля", "марта", "апреля", "мая", "июня", "июля", "августа", "сентября", "октября", "ноября",
+        "декабря",
+    ];
+    let word = word.to_lowercase();
+    EN.iter()
+        .position(|m| word.len() >= 3 && m.starts_with(&word))
+        .or_else(|| RU.iter().position(|m| *m == word))
+        .map(|i| i as u32 + 1)
+}
+
+/// Даты в тексте с позициями; без года — ближайший к дате патча (декабрьский патч и январская дата).
+fn dates_in(text: &str, announced_at: DateTime<Utc>) -> Vec<(usize, DateTime<Utc>)> {
+    DATE_RE
+        .captures_iter(text)
+        .filter_map(|c| {
+            let (month, day) = match (c.get(1), c.get(2)) {
+                (Some(m), Some(d)) => (m.as_str(), d.as_str()),
+                _ => (c.get(4)?.as_str(), c.get(3)?.as_str()),
+            };
+            let month = month_number(month)?;
+            let day: u32 = day.parse().ok()?;
+            let year = announced_at.year();
+            let date = [year, year + 1]
+                .into_iter()
+                .filter_map(|y| NaiveDate::from_ymd_opt(y, month, day))
+                .find(|d| *d >= announced_at.date_naive() - chrono::Duration::days(60))?;
+            Some((c.get(0)?.start(), date.and_hms_opt(12, 0, 0)?.and_utc()))
+        })
+        .collect()
+}
+
+/// Одна дата — начало, если перед ней не стоит «until»/«до»; две и больше — первая и последняя.
+pub fn date_range(text: &str, announced_at: DateTime<Utc>) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    let dates = dates_in(text, announced_at);
+    match dates.as_slice() {
+        [] => (None, None),
+        [(pos, date)] => {
+            let before = text[..*pos].to_lowercase();
+            let is_end = before
+                .split_whitespace()
+                .rev()
+                .take(2)
+                .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+                .any(|w| matches!(w, "until" | "till" | "through" | "ends" | "by" | "до" | "по"));
+            if is_end {
+                (None, Some(*date))
+            } else {
+                (Some(*date), None)
+            }
+        }
+        [(_, first), .., (_, last)] if last >= first => (Some(*first), Some(*last)),
+        [(_, first), ..] => (Some(*first), None),
+    }
+}
+
+pub fn rotations_from_patch(patch: &PatchData) -> Vec<StoreRotation> {
+    let announced_at = patch.release_date();
+    patch
+        .patch_notes
+        .iter()
+        .filter(|n| n.category == PatchCategory::Store)
+        .map(|note| {
+            let text = format!("{} {}", note.title, note.summary);
+            let (starts_at, ends_at) = date_range(&text, announced_at);
+            StoreRotation {
+                key: format!("{}|{}", patch.version, note.title),
+                title: note.title.clone(),
+                kind: content_kind(&text),
+                patch_version: patch.version.clone(),
+                announced_at,
+                starts_at,
+                ends_at,
+                items: note.details.iter().flat_map(|b| b.all_changes()).cloned().collect(),
+            }
+        })
+        .collect()
+}
+
+/// Ротации из `patches` последних патчей, новые патчи первыми.
+pub async fn store_rotations(db: &Database, patches: usize) -> anyhow::Result<Vec<StoreRotation>> {
+    Ok(db
+        .get_patches_newest_versions_first(patches.max(1) as i64)
+        .await?
+        .iter()
+        .flat_map(rotations_from_patch)
+        .collect())
+}
+
+/// Ротации, которые заканчиваются в ближайшие `REMIND_BEFORE_DAYS` дней.
+pub fn ending_soon(rotations: &[StoreRotation], now: DateTime<Utc>) -> Vec<&StoreRotation> {
+    let horizon = now + chrono::Duration::days(REMIND_BEFORE_DAYS);
+    rotations
+        .iter()
+        .filter(|r| r.ends_at.is_some_and(|end| end > now && end <= horizon))
+        .collect()
+}
+
+/// Фоновая проверка окончания ротаций.
+pub async fn run_reminder_loop(app: AppHandle, db: Arc<Database>) {
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + FIRST_CHECK_DELAY, CHECK_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        interval.tick().await;
+        crate::remind_store_rotations(&app, &db).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(&format!("{}T12:00:00Z", d)).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn date_ranges_in_english_and_russian_text() {
+        let patch_day = day("2025-12-10");
+        assert_eq!(
+            date_range("The Mythic Shop rotates on December 10 and stays until Jan. 7.", patch_day),
+            (Some(day("2025-12-10")), Some(day("2026-01-07")))
+        );
+        assert_eq!(date_range("Пропуск события доступен до 14 января.", patch_day), (None, Some(day("2026-01-14"))));
+        assert_eq!(date_range("Available from 12 мая", patch_day), (Some(day("2026-05-12")), None));
+        assert_eq!(date_range("Prestige Ahri returns", patch_day), (None, None));
+        assert_eq!(content_kind("Мифический магазин"), StoreContentKind::MythicShop);
+
+        let rotation = |ends_at| StoreRotation {
+            key: String::new(),
+            title: String::new(),
+            kind: StoreContentKind::Other,
+            patch_version: "25.24".into(),
+            announced_at: patch_day,
+            starts_at: None,
+            ends_at,
+            items: vec![],
+        };
+        let rotations = [rotation(Some(day("2026-01-07"))), rotation(Some(day("2026-01-20"))), rotation(None)];
+        assert_eq!(ending_soon(&rotations, day("2026-01-05")).len(), 1);
+    }
+}
@@ -0,0 +1,121 @@
+//! Привязанный аккаунт игрока (Riot ID через ключ Riot API): чемпионы с наибольшим мастерством
+//! попадают в список наблюдения, а сводки по патчу (`get_watchlist_digest`, `watchlist_alert`)
+//! начинаются с изменений этих чемпионов.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::EntityRef;
+use crate::riot_api::{MasteryChampion, RiotAccount, RiotApi};
+use crate::watchlist::WatchHit;
+
+/// Сколько любимых чемпионов хранить и ставить первыми.
+pub const TOP_CHAMPIONS: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct LinkedSummoner {
+    pub account: RiotAccount,
+    pub platform: String,
+    pub top_champions: Vec<MasteryChampion>,
+    pub linked_at: DateTime<Utc>,
+    pub refreshed_at: DateTime<Utc>,
+}
+
+impl LinkedSummoner {
+    /// DDragon id любимых чемпионов по убыванию мастерства.
+    pub fn top_champion_ids(&self) -> Vec<String> {
+        self.top_champions.iter().filter_map(|c| c.id.clone()).collect()
+    }
+}
+
+/// «Name#TAG» → (имя, тег); пробелы вокруг частей отбрасываются.
+pub fn parse_riot_id(riot_id: &str) -> Result<(String, String)> {
+    let Some((name, tag)) = riot_id.rsplit_once('#') else {
+        bail!("Riot ID must look like Name#TAG");
+    };
+    let (name, tag) = (name.trim(), tag.trim());
+    if name.is_empty() || !(2..=5).contains(&tag.chars().count()) {
+        bail!("Riot ID must look like Name#TAG");
+    }
+    Ok((name.to_string(), tag.to_string()))
+}
+
+/// Ставит изменения любимых чемпионов первыми (по мастерству) и отмечает их место в `top_rank`.
+pub fn lead_with_top(hits: &mut [WatchHit], top: &[String]) {
+    for hit in hits.iter_mut() {
+        hit.top_rank = match &hit.entity {
+            EntityRef::Champion { id } => top.iter().position(|t| t.eq_ignore_ascii_case(id)).map(|i| i as u32 + 1),
+            _ => None,
+        };
+    }
+    hits.sort_by_key(|h| h.top_rank.unwrap_or(u32::MAX));
+}
+
+/// Привязывает (или обновляет) аккаунт и добавляет любимых чемпионов в список наблюдения.
+pub async fn link(api: &RiotApi, client: &reqwest::Client, db: &Database, riot_id: &str) -> Result<LinkedSummoner> {
+    let (name, tag) = parse_riot_id(riot_id)?;
+    let account = api.account_by_riot_id(client, &name, &tag).await?;
+    let top_champions = api.top_masteries(client, db, &account.puuid, TOP_CHAMPIONS).await?;
+    let now = Utc::now();
+    let linked_at = db
+        .get_linked_summoner()
+        .await?
+        .filter(|s| s.account.puuid == account.puuid)
+        .map_or(now, |s| s.linked_at);
+    let summoner = LinkedSummoner {
+        account,
+        platform: api.settings().platform,
+        top_champions,
+        linked_at,
+        refreshed_at: now,
+    };
+    db.set_linked_summoner(Some(&summoner)).await?;
+
+    let watched = db.get_watchlist().await?;
+    for id in summoner.top_champion_ids() {
+        let entity = EntityRef::Champion { id };
+        if !watched.iter().any(|w| w.entity == entity) {
+            db.set_watch(&entity, true, None).await?;
+        }
+    }
+    Ok(summoner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ChangeType, PatchCategory};
+
+    fn hit(entity: EntityRef) -> WatchHit {
+        WatchHit {
+            entity,
+            note_id: String::new(),
+            title: String::new(),
+            category: PatchCategory::Champions,
+            change_type: ChangeType::Buff,
+            grade: None,
+            trend_score: 0,
+            top_rank: None,
+        }
+    }
+
+    #[test]
+    fn riot_id_parsing_and_top_champions_first() {
+        assert_eq!(parse_riot_id(" Hide on bush #KR1").unwrap(), ("Hide on bush".into(), "KR1".into()));
+        assert!(parse_riot_id("NoTag").is_err());
+        assert!(parse_riot_id("Name#").is_err());
+
+        let mut hits = vec![
+            hit(EntityRef::Item { id: "6617".into() }),
+            hit(EntityRef::Champion { id: "Ahri".into() }),
+            hit(EntityRef::Champion { id: "Zed".into() }),
+        ];
+        lead_with_top(&mut hits, &["Zed".into(), "Ahri".into()]);
+        let ranks: Vec<Option<u32>> = hits.iter().map(|h| h.top_rank).collect();
+        assert_eq!(ranks, [Some(1), Some(2), None]);
+    }
+}
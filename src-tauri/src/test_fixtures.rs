@@ -0,0 +1,56 @@
+//! Заготовки моделей для модульных тестов: остальные поля берутся из `Default`, поэтому новое поле
+//! модели не требует правок в тестах.
+
+use crate::models::{ChampionStats, ChangeBlock, ChangeType, LaneRole, PatchCategory, PatchData, PatchNoteEntry};
+
+/// Блок без заголовка со строками `changes`.
+pub fn block(changes: &[&str]) -> ChangeBlock {
+    ChangeBlock {
+        changes: changes.iter().map(|s| s.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+/// Запись с одним блоком изменений; id совпадает с заголовком.
+pub fn note(title: &str, category: PatchCategory, change_type: ChangeType, changes: &[&str]) -> PatchNoteEntry {
+    PatchNoteEntry {
+        id: title.into(),
+        title: title.into(),
+        category,
+        change_type,
+        details: vec![block(changes)],
+        ..Default::default()
+    }
+}
+
+/// Английский патч, скачанный сейчас; у хотфикса проставлен родитель, как при чтении из БД.
+pub fn patch(version: &str, notes: Vec<PatchNoteEntry>) -> PatchData {
+    PatchData {
+        version: version.into(),
+        fetched_at: chrono::Utc::now(),
+        patch_notes: notes,
+        patch_notes_locale: Some("en".into()),
+        parent_version: crate::patch_version::hotfix_parent(version),
+        ..Default::default()
+    }
+}
+
+/// Патч только со статистикой чемпионов.
+pub fn patch_with_stats(version: &str, champions: Vec<ChampionStats>) -> PatchData {
+    PatchData {
+        champions,
+        ..patch(version, vec![])
+    }
+}
+
+/// Строка статистики чемпиона в роли с пик рейтом 5%.
+pub fn champion(id: &str, role: LaneRole, win_rate: f64) -> ChampionStats {
+    ChampionStats {
+        id: id.into(),
+        name: id.into(),
+        role,
+        win_rate,
+        pick_rate: 5.0,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,263 @@
+//! Тир-лист изменений: вклад каждого патча считается один раз при сохранении
+//! (`tier_contributions` в БД), а запрос тир-листа только складывает вклады патчей окна.
+//! Чемпионам добавляется оценка тир-листа сообщества (`community_tiers`) для сверки.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::community_tiers::{self, CommunityTierChange};
+use crate::db::Database;
+use crate::models::{ChampionMeta, ChangeGrade, ChangeType, PatchCategory, PatchData};
+use crate::patch_version;
+use crate::patch_change_trend::analyze_change_trend;
+
+#[derive(Serialize, Clone, TS)]
+#[ts(export)]
+pub struct TierEntry {
+    pub name: String,
+    pub category: PatchCategory,
+    pub buffs: u32,
+    pub nerfs: u32,
+    pub adjusted: u32,
+    /// Переработки считаются отдельно: их десятки строк не попадают в buffs/nerfs
+    pub reworks: u32,
+    /// Записи с масштабом Major (переработки, удаление эффектов, крупные правки чисел)
+    pub major_changes: u32,
+    /// Самый крупный масштаб изменений в окне
+    pub max_grade: Option<ChangeGrade>,
+    pub icon_url: Option<String>,
+    pub champion_meta: Option<ChampionMeta>,
+    /// buffs − nerfs по каждому патчу окна, от старого к новому (для спарклайна)
+    pub sparkline: Vec<i32>,
+    /// Тир op.gg в основной роли: последний сохранённый патч и предыдущий
+    pub community_tier: Option<CommunityTierChange>,
+}
+
+/// Итог одной сущности в одном патче.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TierContribution {
+    /// DDragon id ("champion:Ahri") или заголовок записи, если id не найден
+    pub key: String,
+    pub category: PatchCategory,
+    pub name: String,
+    pub icon_url: Option<String>,
+    pub buffs: u32,
+    pub nerfs: u32,
+    pub adjusted: u32,
+    pub reworks: u32,
+    pub major_changes: u32,
+    pub max_grade: Option<ChangeGrade>,
+}
+
+/// Сохранённые вклады одного патча (таблица `tier_contributions`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PatchTierContributions {
+    pub version: String,
+    /// Сезон/сплит патча — для фильтра тир-листа без чтения data_json
+    pub split: String,
+    pub contributions: Vec<TierContribution>,
+}
+
+/// Вклады патча в тир-лист (без скинов и аугментов); порядок — как у первых упоминаний в патче.
+pub fn patch_contributions(patch: &PatchData) -> Vec<TierContribution> {
+    let mut out: Vec<TierContribution> = Vec::new();
+    for note in &patch.patch_notes {
+        if note.category == PatchCategory::UpcomingSkinsChromas
+            || note.category == PatchCategory::ModeAramAugments
+        {
+            continue;
+        }
+        // Ключ по DDragon id, чтобы RU/EN и переименования не дробили строку тир-листа
+        let key = note.entity_id.clone().unwrap_or_else(|| note.title.clone());
+        let idx = match out.iter().position(|c| c.key == key && c.category == note.category) {
+            Some(idx) => idx,
+            None => {
+                out.push(TierContribution {
+                    key,
+                    category: note.category.clone(),
+                    name: note.title.clone(),
+                    icon_url: None,
+                    buffs: 0,
+                    nerfs: 0,
+                    adjusted: 0,
+                    reworks: 0,
+                    major_changes: 0,
+                    max_grade: None,
+                });
+                out.len() - 1
+            }
+        };
+        let entry = &mut out[idx];
+
+        // Сохраняем иконку из патч-нотов (берем последнюю найденную)
+        if let Some(ref icon) = note.image_url {
+            entry.icon_url = Some(icon.clone());
+        }
+        if let Some(grade) = note.grade {
+            entry.max_grade = entry.max_grade.max(Some(grade));
+            if grade == ChangeGrade::Major {
+                entry.major_changes += 1;
+            }
+        }
+        if note.change_type == ChangeType::Rework {
+            entry.reworks += 1;
+            continue;
+        }
+        for change in note.details.iter().flat_map(|b| b.all_changes()) {
+            match analyze_change_trend(change) {
+                1 => entry.buffs += 1,
+                -1 => entry.nerfs += 1,
+                _ => entry.adjusted += 1,
+            }
+        }
+    }
+    out
+}
+
+/// Складывает вклады патчей окна. `window` — (точка окна, вклады), точка 0 — самый старый патч.
+pub fn aggregate(
+    window: &[(usize, Vec<TierContribution>)],
+    window_len: usize,
+    include_modes: bool,
+) -> Vec<TierEntry> {
+    let mut map: HashMap<(String, PatchCategory), TierEntry> = HashMap::new();
+    // Имя и иконку берём из самого нового патча, поэтому идём от старого к новому
+    let mut ordered: Vec<&(usize, Vec<TierContribution>)> = window.iter().collect();
+    ordered.sort_by_key(|(point, _)| *point);
+    for (point, contributions) in ordered {
+        for c in contributions {
+            if !include_modes && c.category.is_mode_only() {
+                continue;
+            }
+            let entry = map
+                .entry((c.key.clone(), c.category.clone()))
+                .or_insert_with(|| TierEntry {
+                    name: c.name.clone(),
+                    category: c.category.clone(),
+                    buffs: 0,
+                    nerfs: 0,
+                    adjusted: 0,
+                    reworks: 0,
+                    major_changes: 0,
+                    max_grade: None,
+                    icon_url: None,
+                    champion_meta: None,
+                    sparkline: vec![0; window_len],
+                    community_tier: None,
+                });
+            entry.name = c.name.clone();
+            if c.icon_url.is_some() {
+                entry.icon_url = c.icon_url.clone();
+            }
+            entry.buffs += c.buffs;
+            entry.nerfs += c.nerfs;
+            entry.adjusted += c.adjusted;
+            entry.reworks += c.reworks;
+            entry.major_changes += c.major_changes;
+            entry.max_grade = entry.max_grade.max(c.max_grade);
+            entry.sparkline[*point] += c.buffs as i32 - c.nerfs as i32;
+        }
+    }
+
+    let mut list: Vec<TierEntry> = map.into_values().collect();
+    list.sort_by(|a, b| {
+        let score_a = a.buffs as i32 - a.nerfs as i32;
+        let score_b = b.buffs as i32 - b.nerfs as i32;
+        score_b
+            .cmp(&score_a)
+            .then_with(|| b.buffs.cmp(&a.buffs))
+            .then_with(|| a.nerfs.cmp(&b.nerfs))
+    });
+    list
+}
+
+/// Тир-лист по последним `window_size` патчам (хотфиксы — в точке своего патча) из сохранённых вкладов.
+pub async fn load(
+    db: &Database,
+    window_size: Option<u32>,
+    include_modes: Option<bool>,
+    split: Option<&str>,
+) -> anyhow::Result<Vec<TierEntry>> {
+    let limit = window_size.unwrap_or(20).clamp(1, 50) as usize;
+    let include_modes = include_modes.unwrap_or(true);
+    let stored = db.get_tier_contributions().await?;
+
+    // Хотфикс занимает одну точку окна вместе со своим патчем
+    let mut bases: Vec<String> = Vec::new();
+    let mut newest_first: Vec<(usize, Vec<TierContribution>)> = Vec::new();
+    for patch in stored {
+        if split.is_some_and(|s| s != patch.split) {
+            continue;
+        }
+        let base = patch_version::hotfix_parent(&patch.version).unwrap_or(patch.version);
+        if bases.last() != Some(&base) {
+            if bases.len() == limit {
+                break;
+            }
+            bases.push(base);
+        }
+        newest_first.push((bases.len() - 1, patch.contributions));
+    }
+
+    // В спарклайне старый патч первым
+    let window_len = bases.len();
+    let window: Vec<(usize, Vec<TierContribution>)> = newest_first
+        .into_iter()
+        .map(|(newest_idx, contributions)| (window_len - 1 - newest_idx, contributions))
+        .collect();
+    let mut list = aggregate(&window, window_len, include_modes);
+
+    let meta_index = db.get_champion_meta_index().await.unwrap_or_default();
+    let community = db.get_community_tier_lists(community_tiers::OPGG_SOURCE).await.unwrap_or_default();
+    let community_changes = community
+        .first()
+        .map(|current| community_tiers::tier_changes(current, community.get(1)))
+        .unwrap_or_default();
+    for entry in &mut list {
+        if entry.category == PatchCategory::Champions {
+            entry.champion_meta = meta_index.get(&entry.name.to_lowercase()).cloned();
+            entry.community_tier = entry
+                .champion_meta
+                .as_ref()
+                .and_then(|m| m.key.parse::<i32>().ok())
+                .and_then(|key| community_changes.get(&key).cloned());
+        }
+    }
+    Ok(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contribution(key: &str, name: &str, buffs: u32, nerfs: u32) -> TierContribution {
+        TierContribution {
+            key: key.into(),
+            category: PatchCategory::Champions,
+            name: name.into(),
+            icon_url: None,
+            buffs,
+            nerfs,
+            adjusted: 0,
+            reworks: 0,
+            major_changes: 0,
+            max_grade: None,
+        }
+    }
+
+    #[test]
+    fn aggregates_window_points_and_keeps_newest_name() {
+        let window = vec![
+            (1, vec![contribution("champion:Ahri", "Ари", 2, 0)]),
+            (0, vec![contribution("champion:Ahri", "Ahri", 0, 1), contribution("Zed", "Zed", 0, 3)]),
+        ];
+        let list = aggregate(&window, 2, true);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].name, "Ари");
+        assert_eq!((list[0].buffs, list[0].nerfs), (2, 1));
+        assert_eq!(list[0].sparkline, vec![-1, 2]);
+        assert_eq!(list[1].sparkline, vec![-3, 0]);
+    }
+}
@@ -0,0 +1,163 @@
+//! Раздел «Предстоящие образы и хромы» как список образов: чемпион и линейка по названию
+//! (имена чемпионов — из static_catalog), картинки из блока статьи и окно выхода — от даты патча
+//! до следующего патча по расписанию (`patch_calendar`). Riot выпускает образы в течение патча,
+//! в котором их анонсировали, точный день в статье не указан.
+
+use chrono::{DateTime, Utc};
+use scraper::{Html, Selector};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::Database;
+use crate::models::{PatchCategory, PatchData, PatchNoteEntry};
+use crate::patch_calendar::{self, CalendarEvent};
+use crate::patch_version::versions_match;
+use crate::scraper::{img_url_from_element, sanitize_upcoming_skin_image_url};
+
+/// Сколько последних патчей просматривать по умолчанию.
+pub const DEFAULT_PATCHES: usize = 4;
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct UpcomingSkin {
+    pub name: String,
+    /// Id DDragon (`Swain`), если чемпион узнан по названию
+    pub champion_id: Option<String>,
+    pub champion: Option<String>,
+    /// Название без имени чемпиона: «Король жареных цыплят»
+    pub skin_line: Option<String>,
+    pub images: Vec<String>,
+    pub patch_version: String,
+    /// Дата статьи патча
+    pub announced_at: DateTime<Utc>,
+    /// Следующий патч (вышедший или ожидаемый) — образ выходит до него
+    pub release_by: Option<DateTime<Utc>>,
+}
+
+/// Чемпион в названии образа целым словом; самые длинные имена проверяются первыми.
+/// `champions` — (id, имя) во всех локалях.
+pub fn split_title(title: &str, champions: &[(String, String)]) -> (Option<(String, String)>, Option<String>) {
+    let lower = title.to_lowercase();
+    let mut sorted: Vec<&(String, String)> = champions.iter().filter(|(_, n)| !n.is_empty()).collect();
+    sorted.sort_by_key(|(_, n)| std::cmp::Reverse(n.chars().count()));
+    for (id, name) in sorted {
+        let needle = name.to_lowercase();
+        let Some(start) = lower.match_indices(&needle).map(|(i, _)| i).find(|&i| {
+            let before = lower[..i].chars().next_back();
+            let after = lower[i + needle.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        }) else {
+            continue;
+        };
+        // Индексы по строке в нижнем регистре: у имён и названий образов длина при смене регистра не меняется
+        let (head, tail) = (title.get(..start), title.get(start + needle.len()..));
+        let rest = format!("{} {}", head.unwrap_or(""), tail.unwrap_or(""));
+        let line = rest
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '–' | ':' | ','))
+            .to_string();
+        let champion = title.get(start..start + needle.len()).unwrap_or(name).to_string();
+        return (Some((id.clone(), champion)), (!line.is_empty()).then_some(line));
+    }
+    (None, None)
+}
+
+fn note_images(note: &PatchNoteEntry) -> Vec<String> {
+    let mut images: Vec<String> = note.image_url.iter().cloned().collect();
+    if let (Some(html), Ok(img_sel)) = (note.raw_html.as_deref(), Selector::parse("img")) {
+        let fragment = Html::parse_fragment(html);
+        for url in fragment
+            .select(&img_sel)
+            .filter_map(img_url_from_element)
+            .map(sanitize_upcoming_skin_image_url)
+        {
+            if !images.contains(&url) {
+                images.push(url);
+            }
+        }
+    }
+    images
+}
+
+/// Первая дата в расписании после выхода патча.
+fn next_patch_date(schedule: &[CalendarEvent], released: DateTime<Utc>, version: &str) -> Option<DateTime<Utc>> {
+    schedule
+        .iter()
+        .filter(|e| !versions_match(&e.version, version) && e.date > released)
+        .map(|e| e.date)
+        .min()
+}
+
+pub fn skins_from_patch(patch: &PatchData, champions: &[(String, String)], schedule: &[CalendarEvent]) -> Vec<UpcomingSkin> {
+    let announced_at = patch.release_date();
+    let release_by = next_patch_date(schedule, announced_at, &patch.version);
+    patch
+        .patch_notes
+        .iter()
+        .filter(|n| n.category == PatchCategory::UpcomingSkinsChromas)
+        .map(|note| {
+            let (champion, skin_line) = split_title(&note.title, champions);
+            UpcomingSkin {
+                name: note.title.clone(),
+                champion_id: champion.as_ref().map(|(id, _)| id.clone()),
+                champion: champion.map(|(_, name)| name),
+                skin_line,
+                images: note_images(note),
+                patch_version: patch.version.clone(),
+                announced_at,
+                release_by,
+            }
+        })
+        .collect()
+}
+
+/// Образы из `patches` последних патчей, новые патчи первыми.
+pub async fn upcoming_skins(db: &Database, patches: usize) -> anyhow::Result<Vec<UpcomingSkin>> {
+    let champions: Vec<(String, String)> = db
+        .get_static_catalog_kind("champion")
+        .await?
+        .into_iter()
+        .flat_map(|r| [(r.stable_id.clone(), r.name_en), (r.stable_id, r.name_ru)])
+        .collect();
+    let schedule = patch_calendar::patch_schedule(db, 1).await?;
+    Ok(db
+        .get_patches_newest_versions_first(patches.max(1) as i64)
+        .await?
+        .iter()
+        .flat_map(|p| skins_from_patch(p, &champions, &schedule))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn champion_and_skin_line_split_from_title() {
+        let champions: Vec<(String, String)> = [("Swain", "Swain"), ("Swain", "Свейн"), ("Vi", "Vi"), ("Viego", "Viego")]
+            .iter()
+            .map(|(id, n)| (id.to_string(), n.to_string()))
+            .collect();
+        let (champion, line) = split_title("Fried Chicken King Swain", &champions);
+        assert_eq!(champion, Some(("Swain".into(), "Swain".into())));
+        assert_eq!(line.as_deref(), Some("Fried Chicken King"));
+
+        let (champion, line) = split_title("Свейн Король жареных цыплят", &champions);
+        assert_eq!(champion.map(|(_, n)| n).as_deref(), Some("Свейн"));
+        assert_eq!(line.as_deref(), Some("Король жареных цыплят"));
+
+        // «Vi» не находится внутри «Viego» и «Victorious»
+        let (champion, _) = split_title("Victorious Viego", &champions);
+        assert_eq!(champion.map(|(id, _)| id).as_deref(), Some("Viego"));
+        assert_eq!(split_title("Prestige Edition", &champions), (None, None));
+
+        let day = |d: &str| DateTime::parse_from_rfc3339(&format!("{}T18:00:00Z", d)).unwrap().with_timezone(&Utc);
+        let schedule = vec![
+            CalendarEvent { version: "25.22".into(), date: day("2025-11-04"), expected: false },
+            CalendarEvent { version: "25.23".into(), date: day("2025-11-18"), expected: true },
+        ];
+        assert_eq!(next_patch_date(&schedule, day("2025-11-04"), "25.22"), Some(day("2025-11-18")));
+    }
+}
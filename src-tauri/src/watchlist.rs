@@ -0,0 +1,112 @@
+//! Список отслеживаемых сущностей: чемпионы, предметы, руны и объекты карты. У записи может быть порог масштаба
+//! (`min_grade`) — мелкие правки ниже него не попадают ни в сводку патча (`get_watchlist_digest`),
+//! ни в событие `watchlist_alert` после загрузки нового патча.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::models::{ChangeGrade, ChangeType, EntityRef, PatchCategory, PatchData, PatchNoteEntry};
+use crate::patch_change_trend::note_grade;
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct WatchEntry {
+    pub entity: EntityRef,
+    /// `None` — любые изменения
+    pub min_grade: Option<ChangeGrade>,
+    pub added_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct WatchHit {
+    pub entity: EntityRef,
+    pub note_id: String,
+    pub title: String,
+    pub category: PatchCategory,
+    pub change_type: ChangeType,
+    pub grade: Option<ChangeGrade>,
+    /// `PatchNoteEntry::trend_score` записи
+    pub trend_score: i32,
+    /// Место чемпиона среди любимых у привязанного аккаунта (`summoner`), с 1
+    pub top_rank: Option<u32>,
+}
+
+/// Записи патча, попавшие под список; сначала самые заметные.
+pub fn matches(patch: &PatchData, watchlist: &[WatchEntry]) -> Vec<WatchHit> {
+    let mut hits: Vec<WatchHit> = Vec::new();
+    for note in &patch.patch_notes {
+        let Some(entry) = watchlist.iter().find(|w| entry_matches(w, note)) else {
+            continue;
+        };
+        let grade = note.grade.or_else(|| note_grade(note));
+        if entry.min_grade.is_some_and(|min| grade.is_none_or(|g| g < min)) {
+            continue;
+        }
+        hits.push(WatchHit {
+            entity: entry.entity.clone(),
+            note_id: note.id.clone(),
+            title: note.title.clone(),
+            category: note.category.clone(),
+            change_type: note.change_type.clone(),
+            grade,
+            trend_score: note.trend_score(),
+            top_rank: None,
+        });
+    }
+    hits.sort_by_key(|h| std::cmp::Reverse((h.grade, h.trend_score.abs())));
+    hits
+}
+
+fn entry_matches(entry: &WatchEntry, note: &PatchNoteEntry) -> bool {
+    if let EntityRef::Objective { id } = &entry.entity {
+        return crate::objectives::Objective::from_id(id).is_some_and(|o| crate::objectives::mentions(note, o));
+    }
+    if let EntityRef::PlayerSystem { id } = &entry.entity {
+        return crate::player_systems::PlayerSystem::from_id(id)
+            .is_some_and(|s| crate::player_systems::mentions(note, s));
+    }
+    entry.entity.matches_category(&note.category)
+        && crate::db::note_matches_name(note, &entry.entity.key().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{note, patch};
+
+    fn nerf(title: &str, category: PatchCategory, entity_id: &str, changes: &[&str]) -> PatchNoteEntry {
+        PatchNoteEntry {
+            entity_id: Some(entity_id.into()),
+            ..note(title, category, ChangeType::Nerf, changes)
+        }
+    }
+
+    #[test]
+    fn items_and_runes_match_with_grade_threshold() {
+        let patch = patch(
+            "25.22",
+            vec![
+                nerf("Moonstone Renewer", PatchCategory::Items, "item:6617", &["Heal: 70 → 60"]),
+                nerf("Guardian", PatchCategory::Runes, "rune:8465", &["Shield: 50 → 45"]),
+                nerf("Ahri", PatchCategory::Champions, "champion:Ahri", &["Damage: 50 → 45"]),
+            ],
+        );
+        let watch = |entity: EntityRef, min_grade| WatchEntry {
+            entity,
+            min_grade,
+            added_at: Utc::now(),
+        };
+        let list = [
+            watch(EntityRef::Item { id: "6617".into() }, None),
+            watch(EntityRef::Rune { id: "guardian".into() }, None),
+            watch(EntityRef::Champion { id: "Ahri".into() }, Some(ChangeGrade::Major)),
+        ];
+        let hits = matches(&patch, &list);
+        let titles: Vec<&str> = hits.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(titles.len(), 2);
+        assert!(titles.contains(&"Moonstone Renewer") && titles.contains(&"Guardian"));
+        assert!(hits.iter().all(|h| h.trend_score < 0));
+    }
+}
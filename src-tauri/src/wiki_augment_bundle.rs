@@ -1,4 +1,5 @@
 //! Локальный бандл аугментов (wiki modules + category galleries), без сети в рантайме приложения.
+use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -234,9 +235,11 @@ fn extract_field_string(inner: &str, field: &str) -> Option<String> {
     None
 }
 
+static NOTES_LONG_BRACKET_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?s)\["notes"\]\s*=\s*\[=*\[(.*?)\]=*\]"#).unwrap());
+
 fn extract_notes_long_bracket(inner: &str) -> Option<String> {
-    let re = Regex::new(r#"(?s)\["notes"\]\s*=\s*\[=*\[(.*?)\]=*\]"#).ok()?;
-    let cap = re.captures(inner)?;
+    let cap = NOTES_LONG_BRACKET_RE.captures(inner)?;
     let s = cap.get(1)?.as_str().trim();
     if s.is_empty() {
         None
@@ -615,6 +618,7 @@ pub fn enrich_patch_notes_from_bundle(notes: &mut [PatchNoteEntry], augments: &[
                 title: Some(WIKI_AUGMENT_DETAIL_TITLE.to_string()),
                 icon_url,
                 changes: vec![wiki_text],
+                children: vec![],
             },
         );
     }
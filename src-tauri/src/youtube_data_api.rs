@@ -21,7 +21,7 @@ pub async fn search_first_video_in_channel(
 
     let resp = client.get(url).send().await.map_err(|e| e.to_string())?;
     let status = resp.status();
-    let text = resp.text().await.map_err(|e| e.to_string())?;
+    let text = crate::http_body::read_text(resp).await.map_err(|e| e.to_string())?;
     if !status.is_success() {
         return Err(format!(
             "YouTube Data API {}: {}",
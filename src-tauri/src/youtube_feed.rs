@@ -1,7 +1,10 @@
+use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::Serialize;
+use ts_rs::TS;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
 pub struct YoutubeFeedItem {
     pub video_id: String,
     pub title: String,
@@ -14,20 +17,22 @@ pub async fn fetch_youtube_feed_async(channel_id: &str) -> Result<Vec<YoutubeFee
         "https://www.youtube.com/feeds/videos.xml?channel_id={}",
         channel_id.trim()
     );
-    let client = reqwest::Client::builder()
-        .user_agent("PatchAnalyzer/1.0")
-        .build()
+    let resp = crate::http_client::api_client()
+        .map_err(|e| e.to_string())?
+        .get(&url)
+        .send()
+        .await
         .map_err(|e| e.to_string())?;
-    let resp = client.get(&url).send().await.map_err(|e| e.to_string())?;
     if !resp.status().is_success() {
         return Err(format!("YouTube RSS HTTP {}", resp.status()));
     }
-    let text = resp.text().await.map_err(|e| e.to_string())?;
+    let text = crate::http_body::read_text(resp).await.map_err(|e| e.to_string())?;
     Ok(parse_youtube_atom(&text))
 }
 
+static THUMB_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"media:thumbnail[^>]*url="([^"]+)""#).unwrap());
+
 fn parse_youtube_atom(xml: &str) -> Vec<YoutubeFeedItem> {
-    let thumb_re = Regex::new(r#"media:thumbnail[^>]*url="([^"]+)""#).unwrap();
     let mut out = Vec::new();
     for chunk in xml.split("<entry>") {
         let Some(end) = chunk.find("</entry>") else {
@@ -42,7 +47,7 @@ fn parse_youtube_atom(xml: &str) -> Vec<YoutubeFeedItem> {
             continue;
         }
         let published = extract_simple_tag(e, "published").unwrap_or_default();
-        let thumbnail_url = thumb_re
+        let thumbnail_url = THUMB_RE
             .captures(e)
             .and_then(|c| c.get(1))
             .map(|m| m.as_str().to_string())
@@ -34,8 +34,11 @@ import {
   ArrowRightLeft,
   X,
   Sparkles,
+  Eye,
 } from "lucide-react";
 import { ErrorBoundary } from "@/components/error-boundary";
+import { invokeChunked } from "@/lib/chunked-invoke";
+import { invokeEncoded } from "@/lib/packed-ipc";
 
 const SettingsPage = lazy(async () => {
   const m = await import("@/pages/settings-page");
@@ -59,6 +62,9 @@ import { buildSkinSpotlightYoutubeSearch } from "@/lib/youtube-match";
 import { useYoutubeFeed } from "@/hooks/use-youtube-feed";
 import { StartupRouteSync } from "@/components/startup-route";
 import { Toaster } from "@/components/ui/sonner";
+import { ServerStatusIndicator } from "@/components/server-status-indicator";
+import { ServerStatusBanner } from "@/components/server-status-banner";
+import { ProBuildsPanel } from "@/components/pro-builds-panel";
 import { Button } from "@/components/ui/button";
 import {
   DropdownMenu,
@@ -112,25 +118,39 @@ import { cn } from "@/lib/utils";
 import {
   cleanUrl,
   highlightSpecialTags,
+  changeBlockLines,
   compareVersions,
   patchNoteCategoryLabel,
   WIKI_AUGMENT_DETAIL_TITLE,
   PATCH_NOTE_CATEGORY_TAB_ORDER,
+  restoreOriginalNotes,
 } from "@/lib/patch-utils";
+import { APP_EVENTS, type AppEventPayloads } from "@/lib/app-events";
 import { wikiAugmentToPlain } from "@/lib/wiki-augment-plain";
 import { loadItemsRunesHybrid } from "@/lib/catalog-from-tauri";
 import { wikiEmbedOpen } from "@/lib/wiki-embed";
 import type {
   PatchData,
   PatchNoteEntry,
+  ChangeBlock,
   ChampionHistoryEntry,
+  DevArticle,
+  EntityRef,
   ChampionListItem,
   RuneListItem,
   ItemListItem,
   TierEntry,
+  ChangeGrade,
   ChangeTrend,
   ThemeOption,
 } from "@/types/patch";
+import type { CompetitiveImpact } from "@/types/bindings/CompetitiveImpact";
+import type { PatchReaction } from "@/types/bindings/PatchReaction";
+import type { EntityDiff } from "@/types/bindings/EntityDiff";
+import type { DiffLine } from "@/types/bindings/DiffLine";
+import type { WatchEntry } from "@/types/bindings/WatchEntry";
+import type { ObjectiveInfo } from "@/types/bindings/ObjectiveInfo";
+import type { PlayerSystemInfo } from "@/types/bindings/PlayerSystemInfo";
 
 function RouteFallback() {
   return (
@@ -146,15 +166,6 @@ const TIER_WINDOW_OPTIONS = [5, 10, 20] as const;
 const TIER_HISTORY_TOP_LIMIT = 10;
 const TIER_ARCHIVE_SORT_OPTIONS = ["score", "buffs", "nerfs"] as const;
 const PREVIOUS_PATCH_TARGET_OPTIONS = [50, 60, 70, 80, 90, 100] as const;
-const PREVIOUS_PATCH_SAVED_EVENT = "previous_patch_saved";
-type PreviousPatchSavedPayload = {
-  version: string;
-  processed: number;
-  total: number;
-  downloaded: number;
-  skipped: number;
-  saved: boolean;
-};
 
 const LOL_WIKI_ENTRIES = [
   { url: "https://wiki.leagueoflegends.com/en-us/", labelKey: "lolWiki.main" },
@@ -267,13 +278,15 @@ function App() {
     if (!isTauri()) return;
     let unlisten: UnlistenFn | undefined;
     void (async () => {
-      unlisten = await listen<PreviousPatchSavedPayload>(PREVIOUS_PATCH_SAVED_EVENT, async (event) => {
-        setPreviousSyncProgress({
-          processed: event.payload.processed,
-          total: event.payload.total,
-          downloaded: event.payload.downloaded,
-          skipped: event.payload.skipped,
-        });
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.syncProgress]>(APP_EVENTS.syncProgress, async (event) => {
+        if (event.payload.scope === "previous") {
+          setPreviousSyncProgress({
+            processed: event.payload.processed,
+            total: event.payload.total,
+            downloaded: event.payload.downloaded,
+            skipped: event.payload.skipped,
+          });
+        }
         try {
           const list = await invoke<string[]>("get_cached_patch_versions");
           setPatchesList(list);
@@ -286,6 +299,70 @@ function App() {
       unlisten?.();
     };
   }, []);
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    let unlisten: UnlistenFn | undefined;
+    void (async () => {
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.watchlistAlert]>(APP_EVENTS.watchlistAlert, (event) => {
+        toast.info(t("watchlist.alert", {
+          version: event.payload.version,
+          titles: event.payload.hits.map((h) => h.title).join(", "),
+        }));
+      });
+    })();
+    return () => {
+      unlisten?.();
+    };
+  }, [t]);
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    let unlisten: UnlistenFn | undefined;
+    void (async () => {
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.patchNotesUpdated]>(APP_EVENTS.patchNotesUpdated, (event) => {
+        const { version, changes } = event.payload.revision;
+        toast.info(t("patchRevisions.updated", {
+          version,
+          count: changes.length,
+          titles: changes.slice(0, 5).map((c) => c.title).join(", "),
+        }));
+      });
+    })();
+    return () => {
+      unlisten?.();
+    };
+  }, [t]);
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    let unlisten: UnlistenFn | undefined;
+    void (async () => {
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.storeRotationEnding]>(APP_EVENTS.storeRotationEnding, (event) => {
+        const { title, ends_at } = event.payload.rotation;
+        toast.info(t("community.storeEndingToast", {
+          title,
+          date: ends_at ? formatAppDate(ends_at, loadAppPreferences().dateFormat, i18n.language) : "",
+        }));
+      });
+    })();
+    return () => {
+      unlisten?.();
+    };
+  }, [t, i18n.language]);
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    let unlisten: UnlistenFn | undefined;
+    void (async () => {
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.unsupportedLayout]>(APP_EVENTS.unsupportedLayout, (event) => {
+        toast.error(t("toasts.unsupportedLayout", { version: event.payload.version }));
+      });
+    })();
+    return () => {
+      unlisten?.();
+    };
+  }, [t]);
   useEffect(() => {
     applyDomFromPreferences(loadAppPreferences());
     document.documentElement.lang = i18n.language?.startsWith("en") ? "en" : "ru";
@@ -383,6 +460,7 @@ function App() {
       show: i18n.t("tray.show"),
       quit: i18n.t("tray.quit"),
     }).catch(() => { });
+    void invoke("set_app_language", { language: i18n.language }).catch(() => { });
   }, [i18n.language]);
 
   async function refreshPatchesStatus(list: string[]) {
@@ -402,7 +480,7 @@ function App() {
       if (showSpinner) setLoading(true);
       else setLoading(false);
       try {
-        const patchResult = await invoke<PatchData>("get_patch_by_version", {
+        const patchResult = await invokeChunked<PatchData>("get_patch_by_version", {
           version: ver,
           patchNotesLocale,
           allowNetwork,
@@ -627,6 +705,7 @@ function App() {
                   </nav>
                 </div>
                 <div className="flex shrink-0 items-center gap-0.5 rounded-2xl border border-border/50 bg-muted/15 p-1">
+                  <ServerStatusIndicator />
                   <Tooltip>
                     <TooltipTrigger asChild>
                       <Button
@@ -776,6 +855,7 @@ function App() {
                 </div>
               </div>
             </header>
+            <ServerStatusBanner />
             <main className="mx-auto w-full max-w-6xl flex-1 px-4 py-5 sm:px-6 sm:py-8">
               <ErrorBoundary>
                 <Suspense fallback={<RouteFallback />}>
@@ -926,7 +1006,7 @@ function TierListView() {
     setLoading(true);
     try {
       const [tierEntries, cachedVersions] = await Promise.all([
-        invoke<TierEntry[]>("get_tier_list", { windowSize: tierWindow }),
+        invokeEncoded<TierEntry[]>("get_tier_list", { windowSize: tierWindow }),
         invoke<string[]>("get_cached_patch_versions").catch(() => []),
       ]);
       setData(tierEntries);
@@ -1195,7 +1275,27 @@ function TierListView() {
                                 />
                                 <div className="min-w-0">
                                   <div className="truncate font-medium text-foreground">{name}</div>
-                                  <div className="text-xs text-muted-foreground">{categoryLabel(entry)}</div>
+                                  <div className="flex items-center gap-1.5 text-xs text-muted-foreground">
+                                    {categoryLabel(entry)}
+                                    {entry.major_changes > 0 && (
+                                      <ChangeGradeBadge grade="Major" count={entry.major_changes} />
+                                    )}
+                                    {entry.community_tier && (
+                                      <span
+                                        className="font-mono"
+                                        title={t("tier.communityTierHint", {
+                                          role: entry.community_tier.role,
+                                          version: entry.community_tier.version,
+                                        })}
+                                      >
+                                        {t("tier.communityTier", {
+                                          tier: entry.community_tier.previous_tier
+                                            ? `${entry.community_tier.previous_tier} → ${entry.community_tier.tier}`
+                                            : entry.community_tier.tier,
+                                        })}
+                                      </span>
+                                    )}
+                                  </div>
                                 </div>
                               </div>
                             </TableCell>
@@ -1463,7 +1563,7 @@ function TierListView() {
 function ChampionHistoryView() {
   const { t, i18n } = useTranslation();
   const [dateFmt, setDateFmt] = useState(() => loadAppPreferences().dateFormat);
-  const [entityType, setEntityType] = useState<"champion" | "rune" | "item">("champion");
+  const [entityType, setEntityType] = useState<"champion" | "rune" | "item" | "objective" | "player_system">("champion");
   const location = useLocation();
 
   useEffect(() => {
@@ -1475,6 +1575,10 @@ function ChampionHistoryView() {
   const [champion, setChampion] = useState<ChampionListItem | null>(null);
   const [selectedRune, setSelectedRune] = useState<RuneListItem | null>(null);
   const [selectedItem, setSelectedItem] = useState<ItemListItem | null>(null);
+  const [objectives, setObjectives] = useState<ObjectiveInfo[]>([]);
+  const [selectedObjective, setSelectedObjective] = useState<ObjectiveInfo | null>(null);
+  const [playerSystems, setPlayerSystems] = useState<PlayerSystemInfo[]>([]);
+  const [selectedPlayerSystem, setSelectedPlayerSystem] = useState<PlayerSystemInfo | null>(null);
 
   const [allChamps, setAllChamps] = useState<ChampionListItem[]>([]);
   const [allRunes, setAllRunes] = useState<RuneListItem[]>([]);
@@ -1540,6 +1644,10 @@ function ChampionHistoryView() {
   }, [uniqueItems, changedTitlesSet]);
 
   const [history, setHistory] = useState<ChampionHistoryEntry[]>([]);
+  const [devArticles, setDevArticles] = useState<DevArticle[]>([]);
+  const [diffVersions, setDiffVersions] = useState<{ a: string; b: string } | null>(null);
+  const [watchlist, setWatchlist] = useState<WatchEntry[]>([]);
+  const [entityDiff, setEntityDiff] = useState<EntityDiff | null>(null);
   const [aggregatedGroups, setAggregatedGroups] = useState<{ title: string | null, icon: string | null, changes: string[] }[]>([]);
   const [aggregatedChangeTrends, setAggregatedChangeTrends] = useState<ChangeTrend[][]>([]);
   const [loading, setLoading] = useState(false);
@@ -1554,6 +1662,12 @@ function ChampionHistoryView() {
     invoke<ChampionListItem[]>("get_all_champions")
       .then(setAllChamps)
       .catch(e => toast.error(String(e)));
+    invoke<ObjectiveInfo[]>("list_objectives")
+      .then(setObjectives)
+      .catch(e => console.error(e));
+    invoke<PlayerSystemInfo[]>("list_player_systems")
+      .then(setPlayerSystems)
+      .catch(e => console.error(e));
   }, []);
 
   // Читаем query (?type=&name=) при заходе со страницы тир-листа
@@ -1628,7 +1742,9 @@ function ChampionHistoryView() {
     if (entityType === "champion") {
       if (!champion) { setHistory([]); return; }
       setLoading(true);
-      invoke<ChampionHistoryEntry[]>("get_champion_history", { championName: champion.name })
+      invoke<ChampionHistoryEntry[]>("get_entity_history", {
+        entity: { kind: "champion", id: champion.name } satisfies EntityRef,
+      })
         .then(setHistory)
         .catch(e => toast.error(String(e)))
         .finally(() => setLoading(false));
@@ -1638,7 +1754,9 @@ function ChampionHistoryView() {
     if (entityType === "rune") {
       if (!selectedRune) { setHistory([]); return; }
       setLoading(true);
-      invoke<ChampionHistoryEntry[]>("get_rune_history", { runeName: selectedRune.name })
+      invoke<ChampionHistoryEntry[]>("get_entity_history", {
+        entity: { kind: "rune", id: selectedRune.name } satisfies EntityRef,
+      })
         .then(setHistory)
         .catch(e => toast.error(String(e)))
         .finally(() => setLoading(false));
@@ -1648,13 +1766,111 @@ function ChampionHistoryView() {
     if (entityType === "item") {
       if (!selectedItem) { setHistory([]); return; }
       setLoading(true);
-      invoke<ChampionHistoryEntry[]>("get_item_history", { itemName: selectedItem.name })
+      invoke<ChampionHistoryEntry[]>("get_entity_history", {
+        entity: { kind: "item", id: selectedItem.name } satisfies EntityRef,
+      })
         .then(setHistory)
         .catch(e => toast.error(String(e)))
         .finally(() => setLoading(false));
       return;
     }
-  }, [entityType, champion, selectedRune, selectedItem]);
+
+    if (entityType === "objective") {
+      if (!selectedObjective) { setHistory([]); return; }
+      setLoading(true);
+      invoke<ChampionHistoryEntry[]>("get_objective_history", { objective: selectedObjective.id })
+        .then(setHistory)
+        .catch(e => toast.error(String(e)))
+        .finally(() => setLoading(false));
+      return;
+    }
+
+    if (entityType === "player_system") {
+      if (!selectedPlayerSystem) { setHistory([]); return; }
+      setLoading(true);
+      invoke<ChampionHistoryEntry[]>("get_player_system_history", { system: selectedPlayerSystem.id })
+        .then(setHistory)
+        .catch(e => toast.error(String(e)))
+        .finally(() => setLoading(false));
+      return;
+    }
+  }, [entityType, champion, selectedRune, selectedItem, selectedObjective, selectedPlayerSystem]);
+
+  const historyVersions = useMemo(
+    () => [...new Set(history.map((h) => h.patch_version))].sort((a, b) => compareVersions(b, a)),
+    [history],
+  );
+  const currentEntity = useMemo((): EntityRef | null => {
+    if (entityType === "champion" && champion) return { kind: "champion", id: champion.name };
+    if (entityType === "rune" && selectedRune) return { kind: "rune", id: selectedRune.name };
+    if (entityType === "item" && selectedItem) return { kind: "item", id: selectedItem.name };
+    if (entityType === "objective" && selectedObjective) return { kind: "objective", id: selectedObjective.id };
+    if (entityType === "player_system" && selectedPlayerSystem) return { kind: "player_system", id: selectedPlayerSystem.id };
+    return null;
+  }, [entityType, champion, selectedRune, selectedItem, selectedObjective, selectedPlayerSystem]);
+
+  useEffect(() => {
+    if (!isTauri()) return;
+    invoke<WatchEntry[]>("get_watchlist")
+      .then(setWatchlist)
+      .catch(e => console.error(e));
+  }, []);
+
+  const currentWatch = currentEntity
+    ? watchlist.find((w) => w.entity.kind === currentEntity.kind
+      && "id" in w.entity && "id" in currentEntity && w.entity.id === currentEntity.id)
+    : undefined;
+
+  const setWatch = (watch: boolean, minGrade: ChangeGrade | null) => {
+    if (!currentEntity) return;
+    invoke<WatchEntry[]>("set_watch", { entity: currentEntity, watch, minGrade })
+      .then(setWatchlist)
+      .catch(e => toast.error(String(e)));
+  };
+
+  // По умолчанию сравниваются два последних патча с изменениями сущности
+  useEffect(() => {
+    setDiffVersions(historyVersions.length >= 2 ? { a: historyVersions[1], b: historyVersions[0] } : null);
+  }, [historyVersions]);
+
+  useEffect(() => {
+    if (!currentEntity || !diffVersions || diffVersions.a === diffVersions.b) { setEntityDiff(null); return; }
+    invoke<EntityDiff>("get_entity_diff", {
+      entity: currentEntity,
+      versionA: diffVersions.a,
+      versionB: diffVersions.b,
+    })
+      .then(setEntityDiff)
+      .catch(e => { console.error(e); setEntityDiff(null); });
+  }, [currentEntity, diffVersions]);
+
+  const renderDiffLines = (lines: DiffLine[]) => (
+    <ul className="overflow-x-auto rounded-xl border border-border/50 bg-card/90 py-2 font-mono text-xs">
+      {lines.map((line, i) => (
+        <li
+          key={i}
+          className={cn(
+            "whitespace-pre px-3 py-0.5",
+            line.op === "added" && "bg-emerald-50 text-emerald-900 dark:bg-emerald-950/40 dark:text-emerald-100",
+            line.op === "removed" && "bg-red-50 text-red-900 dark:bg-red-950/40 dark:text-red-100",
+            line.op === "same" && "text-muted-foreground",
+          )}
+        >
+          {`${line.op === "added" ? "+" : line.op === "removed" ? "-" : " "} ${line.text}`}
+        </li>
+      ))}
+    </ul>
+  );
+
+  // Статьи разработчиков с упоминанием чемпиона — контекст к таймлайну
+  useEffect(() => {
+    if (entityType !== "champion" || !champion) { setDevArticles([]); return; }
+    invoke<DevArticle[]>("get_entity_dev_articles", {
+      entity: { kind: "champion", id: champion.id } satisfies EntityRef,
+    })
+      .then(setDevArticles)
+      .catch(e => console.error(e));
+  }, [entityType, champion]);
 
   // Fallback для иконок рун/предметов
   const getFallbackIcon = (title: string | null, patchIcon?: string | null): string | null => {
@@ -1677,7 +1893,7 @@ function ChampionHistoryView() {
 
   // Обновление иконки для рун/предметов
   useEffect(() => {
-    if (entityType === "champion") {
+    if (entityType === "champion" || entityType === "objective" || entityType === "player_system") {
       setIconUrl(null);
       setUseFallback(false);
       return;
@@ -1722,7 +1938,7 @@ function ChampionHistoryView() {
             groups.get(key)!.icon = detailIcon;
           }
           if (d.changes) {
-            d.changes.forEach(c => {
+            changeBlockLines(d).forEach(c => {
               groups.get(key)!.rawChanges.push({ date: new Date(h.date), text: c });
             });
           }
@@ -1847,11 +2063,13 @@ function ChampionHistoryView() {
             <Tabs
               value={entityType}
               onValueChange={(v) => {
-                setEntityType(v as "champion" | "rune" | "item");
+                setEntityType(v as "champion" | "rune" | "item" | "objective" | "player_system");
                 setHistory([]);
                 setChampion(null);
                 setSelectedRune(null);
                 setSelectedItem(null);
+                setSelectedObjective(null);
+                setSelectedPlayerSystem(null);
               }}
               className="w-full lg:w-auto"
             >
@@ -1874,6 +2092,18 @@ function ChampionHistoryView() {
                 >
                   {t("tier.items")}
                 </TabsTrigger>
+                <TabsTrigger
+                  value="objective"
+                  className="flex-1 rounded-lg px-3 py-2.5 text-sm font-medium data-[state=active]:shadow-sm sm:flex-initial sm:px-4"
+                >
+                  {t("history.objectives")}
+                </TabsTrigger>
+                <TabsTrigger
+                  value="player_system"
+                  className="flex-1 rounded-lg px-3 py-2.5 text-sm font-medium data-[state=active]:shadow-sm sm:flex-initial sm:px-4"
+                >
+                  {t("history.playerSystems")}
+                </TabsTrigger>
               </TabsList>
             </Tabs>
           </div>
@@ -1901,6 +2131,34 @@ function ChampionHistoryView() {
                   setSelectedItem(null);
                 }}
               />
+            ) : entityType === "objective" ? (
+              <div className="flex flex-wrap gap-2">
+                {objectives.map((o) => (
+                  <Button
+                    key={o.id}
+                    type="button"
+                    size="sm"
+                    variant={selectedObjective?.id === o.id ? "default" : "outline"}
+                    onClick={() => setSelectedObjective(o)}
+                  >
+                    {i18n.language.startsWith("ru") ? o.name_ru : o.name_en}
+                  </Button>
+                ))}
+              </div>
+            ) : entityType === "player_system" ? (
+              <div className="flex flex-wrap gap-2">
+                {playerSystems.map((s) => (
+                  <Button
+                    key={s.id}
+                    type="button"
+                    size="sm"
+                    variant={selectedPlayerSystem?.id === s.id ? "default" : "outline"}
+                    onClick={() => setSelectedPlayerSystem(s)}
+                  >
+                    {i18n.language.startsWith("ru") ? s.name_ru : s.name_en}
+                  </Button>
+                ))}
+              </div>
             ) : (
               <ItemSelect
                 items={filteredItems}
@@ -1954,12 +2212,79 @@ function ChampionHistoryView() {
                     {entityType === "champion" && champion && champion.name}
                     {entityType === "rune" && selectedRune && selectedRune.name}
                     {entityType === "item" && selectedItem && selectedItem.name}
+                    {entityType === "objective" && selectedObjective &&
+                      (i18n.language.startsWith("ru") ? selectedObjective.name_ru : selectedObjective.name_en)}
+                    {entityType === "player_system" && selectedPlayerSystem &&
+                      (i18n.language.startsWith("ru") ? selectedPlayerSystem.name_ru : selectedPlayerSystem.name_en)}
                   </h3>
                   <UiBadge variant="secondary" className="mt-2 rounded-full font-normal">
                     {t("history.summaryBadge")}
                   </UiBadge>
                 </div>
+                {currentEntity && isTauri() && (
+                  <DropdownMenu>
+                    <DropdownMenuTrigger asChild>
+                      <Button
+                        type="button"
+                        size="sm"
+                        variant={currentWatch ? "secondary" : "outline"}
+                        className="gap-1.5 sm:ml-auto"
+                      >
+                        <Eye className="h-4 w-4" />
+                        {t(currentWatch ? "watchlist.watching" : "watchlist.watch")}
+                      </Button>
+                    </DropdownMenuTrigger>
+                    <DropdownMenuContent align="end">
+                      {([null, "Moderate", "Major"] as const).map((grade) => (
+                        <DropdownMenuItem
+                          key={grade ?? "any"}
+                          onClick={() => setWatch(true, grade)}
+                          className={cn(currentWatch && currentWatch.min_grade === grade && "bg-accent")}
+                        >
+                          {t(grade === null ? "watchlist.anyChange" : grade === "Moderate" ? "watchlist.moderateUp" : "watchlist.majorOnly")}
+                        </DropdownMenuItem>
+                      ))}
+                      {currentWatch && (
+                        <DropdownMenuItem onClick={() => setWatch(false, null)}>
+                          {t("watchlist.stop")}
+                        </DropdownMenuItem>
+                      )}
+                    </DropdownMenuContent>
+                  </DropdownMenu>
+                )}
+                {entityType === "champion" && champion && isTauri() && (
+                  <div className="flex gap-2">
+                    {(["csv", "json"] as const).map((format) => (
+                      <Button
+                        key={format}
+                        type="button"
+                        size="sm"
+                        variant="outline"
+                        onClick={() => {
+                          void invoke<string>("export_champion_timeline", { champion: champion.id, format })
+                            .then((path) => toast.success(t("history.timelineExported", { path })))
+                            .catch((e) => toast.error(String(e)));
+                        }}
+                      >
+                        {t(format === "csv" ? "history.exportTimelineCsv" : "history.exportTimelineJson")}
+                      </Button>
+                    ))}
+                    <Button
+                      type="button"
+                      size="sm"
+                      variant="secondary"
+                      onClick={() => {
+                        void invoke<string>("export_champion_report", { champion: champion.id })
+                          .then((path) => toast.success(t("history.reportExported", { path })))
+                          .catch((e) => toast.error(String(e)));
+                      }}
+                    >
+                      {t("history.exportReport")}
+                    </Button>
+                  </div>
+                )}
               </div>
+              {entityType === "champion" && champion && <ProBuildsPanel champion={champion.id} />}
               <div className="relative z-10 space-y-5 rounded-xl border border-border/50 bg-card/90 p-4 text-foreground shadow-inner sm:p-5">
                 {aggregatedGroups.map((group, i) => (
                   <div key={i}>
@@ -2029,6 +2354,64 @@ function ChampionHistoryView() {
               {t("history.noData")}
             </div>
           )}
+          {devArticles.length > 0 && (
+            <div className="border-t border-border/40 pt-2">
+              <p className="mb-3 text-xs font-semibold uppercase tracking-[0.08em] text-muted-foreground">
+                {t("history.devContext")}
+              </p>
+              <ul className="space-y-2">
+                {[...devArticles].reverse().map(article => (
+                  <li key={article.url}>
+                    <button
+                      type="button"
+                      className="flex w-full items-center justify-between gap-3 rounded-lg border border-border/50 bg-card/80 px-3 py-2 text-left text-sm transition-colors hover:border-primary/30 hover:bg-accent/40"
+                      onClick={() => void openExternalUrl(article.url)}
+                    >
+                      <span className="min-w-0 truncate font-medium">{article.title}</span>
+                      {article.published_at && (
+                        <span className="shrink-0 text-xs text-muted-foreground">
+                          {formatAppDate(article.published_at, dateFmt, i18n.language)}
+                        </span>
+                      )}
+                    </button>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          )}
+          {diffVersions && (
+            <div className="border-t border-border/40 pt-2">
+              <p className="mb-3 text-xs font-semibold uppercase tracking-[0.08em] text-muted-foreground">
+                {t("history.diffTitle")}
+              </p>
+              <div className="mb-3 flex flex-wrap items-center gap-2">
+                <CustomPatchSelect
+                  value={diffVersions.a}
+                  options={historyVersions}
+                  onChange={(a) => setDiffVersions({ ...diffVersions, a })}
+                  loading={false}
+                />
+                <ArrowRightLeft className="h-4 w-4 text-muted-foreground" />
+                <CustomPatchSelect
+                  value={diffVersions.b}
+                  options={historyVersions}
+                  onChange={(b) => setDiffVersions({ ...diffVersions, b })}
+                  loading={false}
+                />
+              </div>
+              {entityDiff && entityDiff.changes.some((l) => l.op !== "same") ? (
+                renderDiffLines(entityDiff.changes)
+              ) : (
+                <p className="text-sm text-muted-foreground">{t("history.diffEmpty")}</p>
+              )}
+              {entityDiff && entityDiff.stats.length > 0 && (
+                <div className="mt-3 space-y-2">
+                  <p className="text-xs font-medium text-muted-foreground">{t("history.diffStats")}</p>
+                  {renderDiffLines(entityDiff.stats)}
+                </div>
+              )}
+            </div>
+          )}
           {history.length > 0 && (
             <div className="border-t border-border/40 pt-2">
               <p className="mb-6 text-xs font-semibold uppercase tracking-[0.08em] text-muted-foreground">
@@ -2056,7 +2439,10 @@ function ChampionHistoryView() {
                             </p>
                           )}
                         </div>
-                        <PatchNoteBadge type={item.change.change_type} />
+                        <div className="flex items-center gap-2">
+                          <PatchNoteBadge type={item.change.change_type} />
+                          <ChangeGradeBadge grade={item.change.grade} />
+                        </div>
                       </div>
                       <div className="space-y-6">
                         {Array.isArray(item.change.details) && item.change.details.map((block, i) => (
@@ -2228,7 +2614,136 @@ function PatchNoteLeadIcon({
       </div>
     );
   }
-  return <ChampionIcon candidates={note.icon_candidates} url={iconUrl} name={note.title} />;
+  return <ChampionIcon candidates={note.icon_candidates ?? undefined} url={iconUrl} name={note.title} />;
+}
+
+function CompetitiveImpactSection({ version, locale }: { version: string; locale: string }) {
+  const { t } = useTranslation();
+  const [impact, setImpact] = useState<CompetitiveImpact | null>(null);
+  useEffect(() => {
+    let cancelled = false;
+    setImpact(null);
+    invoke<CompetitiveImpact>("get_competitive_impact", { version, patchNotesLocale: locale })
+      .then((r) => {
+        if (!cancelled) setImpact(r);
+      })
+      .catch(() => { });
+    return () => {
+      cancelled = true;
+    };
+  }, [version, locale]);
+
+  if (!impact || impact.entries.length === 0) return null;
+  return (
+    <section className="space-y-3 border-b border-border/50 px-5 py-5 sm:px-8">
+      <div className="space-y-1">
+        <h3 className="text-sm font-semibold uppercase tracking-[0.06em] text-muted-foreground">
+          {t("patchView.competitiveTitle")}
+        </h3>
+      </div>
+      <ol className="space-y-1.5">
+        {impact.entries.map((e) => (
+          <li key={e.note_id} className="flex flex-wrap items-center gap-2 text-sm">
+            {e.trend > 0 ? (
+              <ArrowUp className="h-3.5 w-3.5 text-emerald-500" />
+            ) : e.trend < 0 ? (
+              <ArrowDown className="h-3.5 w-3.5 text-rose-500" />
+            ) : (
+              <ArrowRightLeft className="h-3.5 w-3.5 text-muted-foreground" />
+            )}
+            <span className="font-medium">{e.title}</span>
+            {e.reasons.map((r) => (
+              <UiBadge key={r} variant="outline" className="rounded-full px-2 text-[11px] font-normal">
+                {t(`patchView.competitiveReason.${r}`)}
+              </UiBadge>
+            ))}
+          </li>
+        ))}
+      </ol>
+    </section>
+  );
+}
+
+function PatchReactionSection({ version, locale }: { version: string; locale: string }) {
+  const { t } = useTranslation();
+  const [reaction, setReaction] = useState<PatchReaction | null>(null);
+  const [error, setError] = useState("");
+  const [refreshing, setRefreshing] = useState(false);
+  const load = useCallback(
+    (refresh: boolean) =>
+      invoke<PatchReaction>("get_patch_reaction", { version, patchNotesLocale: locale, refresh })
+        .then((r) => {
+          setReaction(r);
+          setError("");
+        })
+        .catch((e) => setError(String(e))),
+    [version, locale],
+  );
+  useEffect(() => {
+    setReaction(null);
+    setError("");
+    void load(false);
+  }, [load]);
+
+  const scoreClass = (score: number) =>
+    score > 0.15 ? "text-emerald-500" : score < -0.15 ? "text-rose-500" : "text-muted-foreground";
+  const signed = (score: number) => `${score > 0 ? "+" : ""}${score.toFixed(2)}`;
+
+  if (!reaction && !error) return null;
+  return (
+    <section className="space-y-3 border-b border-border/50 px-5 py-5 sm:px-8">
+      <div className="flex flex-wrap items-center gap-2">
+        <h3 className="text-sm font-semibold uppercase tracking-[0.06em] text-muted-foreground">
+          {t("patchView.reactionTitle")}
+        </h3>
+        {reaction ? (
+          <>
+            <span className={cn("font-mono text-sm", scoreClass(reaction.overall))}>{signed(reaction.overall)}</span>
+            <button
+              type="button"
+              className="text-xs text-muted-foreground underline-offset-2 hover:underline"
+              onClick={() => void openExternalUrl(reaction.thread_url)}
+            >
+              {t("patchView.reactionThread", { count: reaction.comments_analyzed })}
+            </button>
+          </>
+        ) : null}
+        <Button
+          type="button"
+          size="sm"
+          variant="ghost"
+          className="h-7 px-2 text-xs"
+          disabled={refreshing}
+          onClick={() => {
+            setRefreshing(true);
+            void load(true).finally(() => setRefreshing(false));
+          }}
+        >
+          {t("patchView.reactionRefresh")}
+        </Button>
+      </div>
+      {error && !reaction ? <p className="text-xs text-muted-foreground break-all">{error}</p> : null}
+      {reaction && reaction.entries.length === 0 ? (
+        <p className="text-xs text-muted-foreground">{t("patchView.reactionNoMentions")}</p>
+      ) : null}
+      <ol className="space-y-1.5">
+        {reaction?.entries.map((e) => (
+          <li key={e.note_id} className="flex flex-wrap items-center gap-2 text-sm">
+            <span className="font-medium">{e.title}</span>
+            <span className={cn("font-mono text-xs", scoreClass(e.score))}>{signed(e.score)}</span>
+            <span className="text-xs text-muted-foreground">
+              {t("patchView.reactionMentions", { count: e.mentions, positive: e.positive, negative: e.negative })}
+            </span>
+            {e.keywords.map((k) => (
+              <UiBadge key={k} variant="outline" className="rounded-full px-2 text-[11px] font-normal">
+                {k}
+              </UiBadge>
+            ))}
+          </li>
+        ))}
+      </ol>
+    </section>
+  );
 }
 
 function PatchReleaseView({ data, version, patchesList, onVersionChange, loading, newPatches, noLocalCache }: { data: PatchData | null, version: string, patchesList: string[], onVersionChange: (v: string) => void, loading: boolean, newPatches?: Set<string>, noLocalCache?: boolean }) {
@@ -2239,7 +2754,19 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
   const [lightboxUrl, setLightboxUrl] = useState<string | null>(null);
   const [categoryFilter, setCategoryFilter] = useState<string>("All");
   const [changeTypeFilter, setChangeTypeFilter] = useState<string>("All");
+  const [competitiveMode, setCompetitiveMode] = useState(() => loadAppPreferences().competitiveMode);
+  const [communityReaction, setCommunityReaction] = useState(() => loadAppPreferences().communityReaction);
+  const [showOriginal, setShowOriginal] = useState(false);
   const { items: skinYoutubeFeed } = useYoutubeFeed(YOUTUBE_CHANNEL_SKINSPOTLIGHTS);
+  useEffect(() => {
+    const onPrefs = () => {
+      const prefs = loadAppPreferences();
+      setCompetitiveMode(prefs.competitiveMode);
+      setCommunityReaction(prefs.communityReaction);
+    };
+    window.addEventListener("app-prefs-changed", onPrefs);
+    return () => window.removeEventListener("app-prefs-changed", onPrefs);
+  }, []);
   useEffect(() => {
     invoke<ChampionListItem[]>("get_all_champions")
       .then(setChampionList)
@@ -2267,6 +2794,7 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
   useEffect(() => {
     setCategoryFilter("All");
     setChangeTypeFilter("All");
+    setShowOriginal(false);
   }, [data?.version]);
 
   useEffect(() => {
@@ -2278,7 +2806,11 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
     return () => window.removeEventListener("keydown", onKey);
   }, [lightboxUrl]);
 
-  const patchNotes = data?.patch_notes ?? [];
+  const patchNotes = useMemo(() => {
+    const notes = data?.patch_notes ?? [];
+    const originals = data?.machine_translation?.originals;
+    return showOriginal && originals ? restoreOriginalNotes(notes, originals) : notes;
+  }, [data, showOriginal]);
   const skinYoutubeSearchQuery = (title: string) =>
     buildSkinSpotlightYoutubeSearch(title, championList)?.searchQuery ?? `SkinSpotlights ${title}`;
   const categoryCounts = useMemo(() => {
@@ -2337,6 +2869,8 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
   }
   if (!data) return <EmptyState />;
   const banner = cleanUrl(data.banner_url ?? undefined);
+  // Разделы патча считаются по сохранённым патч-нотам, а у машинного перевода они на исходном языке
+  const notesLocale = data.machine_translation?.source_locale ?? data.patch_notes_locale ?? "ru";
 
   const patchHeaderBar = (
     <div className="flex flex-col gap-4 sm:flex-row sm:items-end sm:justify-between">
@@ -2387,6 +2921,30 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
           <div className="border-b border-border/50 bg-muted/15 px-5 py-8 sm:px-8">{patchHeaderBar}</div>
         )}
 
+        {data.machine_translation ? (
+          <div className="flex flex-wrap items-center gap-3 border-b border-border/50 bg-muted/15 px-5 py-3 text-sm sm:px-8">
+            <UiBadge variant="warning">{t("patchView.machineTranslated")}</UiBadge>
+            <span className="text-muted-foreground">
+              {t("patchView.machineTranslatedHint", {
+                source: data.machine_translation.source_locale.toUpperCase(),
+                provider: data.machine_translation.provider,
+              })}
+              {data.machine_translation.untranslated > 0
+                ? ` · ${t("patchView.machineUntranslated", { count: data.machine_translation.untranslated })}`
+                : ""}
+            </span>
+            <Button type="button" size="sm" variant="outline" onClick={() => setShowOriginal((v) => !v)}>
+              {showOriginal ? t("patchView.showTranslation") : t("patchView.showOriginal")}
+            </Button>
+          </div>
+        ) : null}
+        {competitiveMode && data.patch_notes.length > 0 ? (
+          <CompetitiveImpactSection version={data.version} locale={notesLocale} />
+        ) : null}
+        {communityReaction && data.patch_notes.length > 0 ? (
+          <PatchReactionSection version={data.version} locale={notesLocale} />
+        ) : null}
+
         {data.patch_notes.length > 0 && (
           <Tabs value={categoryFilter} onValueChange={setCategoryFilter} className="w-full">
             <div className="sticky top-14 z-10 border-b border-border/50 bg-background/90 px-4 py-3 backdrop-blur-md sm:px-6">
@@ -2601,6 +3159,7 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
                           onClick={() => setChangeTypeFilter(note.change_type)}
                           active={changeTypeFilter === note.change_type}
                         />
+                        <ChangeGradeBadge grade={note.grade} />
                       </div>
                     </div>
                     <div className="ml-0 space-y-4 border-l-2 border-border/60 pl-4 sm:ml-2 sm:pl-5">
@@ -2651,8 +3210,15 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
                               );
                             })}
                           </ul>
+                          <ChangeBlockChildren blocks={block.children} />
                         </div>
                       ))}
+                      {note.raw_html && (
+                        <details className="text-xs text-muted-foreground">
+                          <summary className="cursor-pointer select-none">{t("patchView.rawText")}</summary>
+                          <p className="mt-1 whitespace-pre-line leading-relaxed">{rawHtmlToText(note.raw_html)}</p>
+                        </details>
+                      )}
                     </div>
                   </div>
                 );
@@ -2692,6 +3258,56 @@ function PatchReleaseView({ data, version, patchesList, onVersionChange, loading
   );
 }
 
+/** Текст исходного фрагмента без разметки: по строке на блочный элемент. */
+function rawHtmlToText(html: string): string {
+  const doc = new DOMParser().parseFromString(html, "text/html");
+  doc.querySelectorAll("h3, h4, h5, p, li").forEach((el) => el.append("\n"));
+  return (doc.body.textContent ?? "").replace(/\n\s*\n+/g, "\n").trim();
+}
+
+function ChangeBlockChildren({ blocks }: { blocks?: ChangeBlock[] }) {
+  if (!blocks?.length) return null;
+  return (
+    <div className="mt-2 space-y-3 border-l border-border/60 pl-3">
+      {blocks.map((child, i) => (
+        <div key={i}>
+          {child.title && (
+            <h5 className="mb-1 text-xs font-semibold uppercase tracking-wide text-muted-foreground">{child.title}</h5>
+          )}
+          <ul className="space-y-1.5">
+            {child.changes.map((change, j) => (
+              <li key={j} className="rounded-lg px-2.5 py-1 text-sm leading-relaxed text-foreground">
+                <span
+                  dangerouslySetInnerHTML={{
+                    __html: highlightSpecialTags(
+                      change
+                        .replace(/(\d+(\.\d+)?)/g, '<span class="font-bold">$1</span>')
+                        .replace(/⇒/g, '<span class="text-muted-foreground mx-1">→</span>')
+                    ),
+                  }}
+                />
+              </li>
+            ))}
+          </ul>
+          <ChangeBlockChildren blocks={child.children} />
+        </div>
+      ))}
+    </div>
+  );
+}
+
+/** Масштаб изменения; Minor не показываем, чтобы не шуметь. */
+function ChangeGradeBadge({ grade, count }: { grade?: ChangeGrade | null; count?: number }) {
+  const { t } = useTranslation();
+  if (!grade || grade === "Minor") return null;
+  return (
+    <UiBadge variant={grade === "Major" ? "destructive" : "outline"} className="font-normal">
+      {t(`grade.${grade}`)}
+      {count && count > 1 ? ` ×${count}` : null}
+    </UiBadge>
+  );
+}
+
 function PatchNoteBadge({
   type,
   onClick,
@@ -2707,6 +3323,7 @@ function PatchNoteBadge({
     Buff: "success",
     Nerf: "destructive",
     Adjusted: "warning",
+    Rework: "warning",
     New: "default",
     Removed: "outline",
     Fix: "secondary",
@@ -2715,6 +3332,7 @@ function PatchNoteBadge({
   const labels: Record<string, string> = {
     Removed: t("badge.Removed"),
     New: t("badge.New"),
+    Rework: t("badge.Rework"),
   };
   const variant = map[type] ?? "secondary";
   if (type === "None") return null;
@@ -0,0 +1,112 @@
+import { useCallback, useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { Badge } from "@/components/ui/badge"
+import { Button } from "@/components/ui/button"
+import type { ChampionPatchImpact } from "@/types/bindings/ChampionPatchImpact"
+import type { MatchImportResult } from "@/types/bindings/MatchImportResult"
+import type { PatchImpactReport } from "@/types/bindings/PatchImpactReport"
+import type { WinRecord } from "@/types/bindings/WinRecord"
+
+const CHANGE_VARIANT: Record<string, "success" | "destructive" | "warning" | "secondary"> = {
+  Buff: "success",
+  Nerf: "destructive",
+  Adjusted: "warning",
+  Rework: "warning",
+}
+
+/** «Как патчи повлияли на вас»: винрейт привязанного аккаунта до и после правок его чемпионов. */
+export function MatchImpactPanel() {
+  const { t, i18n } = useTranslation()
+  const [report, setReport] = useState<PatchImpactReport | null>(null)
+  const [busy, setBusy] = useState(false)
+  const [notice, setNotice] = useState("")
+  const [error, setError] = useState("")
+
+  const load = useCallback(() => {
+    void invoke<PatchImpactReport>("get_patch_impact_report")
+      .then((r) => {
+        setReport(r)
+        setError("")
+      })
+      .catch((e) => setError(String(e)))
+  }, [])
+
+  useEffect(() => {
+    if (isTauri()) load()
+  }, [load])
+
+  if (!isTauri()) {
+    return <p className="text-sm text-muted-foreground">{t("community.youtubeTauriOnly")}</p>
+  }
+
+  const importMatches = async () => {
+    setBusy(true)
+    setNotice("")
+    setError("")
+    try {
+      const r = await invoke<MatchImportResult>("import_ranked_matches")
+      setNotice(t("community.impactImported", { count: r.imported, total: r.total }))
+      load()
+    } catch (e) {
+      setError(String(e))
+    } finally {
+      setBusy(false)
+    }
+  }
+
+  const rate = (r: WinRecord) => (r.games > 0 ? `${Math.round((r.wins * 100) / r.games)}%` : "—")
+  const record = (r: WinRecord) => t("community.impactRecord", { rate: rate(r), wins: r.wins, games: r.games })
+  const name = (i: ChampionPatchImpact) =>
+    (i18n.language.startsWith("ru") ? i.name_ru : i.name_en) ?? i.champion_id
+
+  return (
+    <div className="space-y-4">
+      <div className="flex flex-wrap items-center gap-3">
+        <Button type="button" size="sm" disabled={busy || !report} onClick={() => void importMatches()}>
+          {busy ? t("community.impactImporting") : t("community.impactImport")}
+        </Button>
+        {report ? (
+          <span className="text-sm text-muted-foreground">
+            {t("community.impactSummary", { riotId: report.riot_id, record: record(report.overall) })}
+          </span>
+        ) : null}
+      </div>
+      <p className="text-xs text-muted-foreground">{t("community.impactHint")}</p>
+      {notice ? <p className="text-sm text-muted-foreground">{notice}</p> : null}
+      {error ? <p className="text-sm text-destructive break-all">{error}</p> : null}
+      {report && report.agreement !== null ? (
+        <p className="text-sm">
+          {t("community.impactAgreement", { percent: Math.round(report.agreement * 100) })}
+        </p>
+      ) : null}
+      {report && report.impacts.length === 0 ? (
+        <p className="text-sm text-muted-foreground">{t("community.noImpact")}</p>
+      ) : null}
+      <div className="space-y-2">
+        {report?.impacts.map((i) => (
+          <div
+            key={`${i.patch_version}-${i.champion_id}`}
+            className="flex flex-wrap items-center gap-x-3 gap-y-1 rounded-lg border border-border/60 px-3 py-2 text-sm"
+          >
+            <span className="font-mono text-xs text-muted-foreground">{i.patch_version}</span>
+            <span className="font-medium">{name(i)}</span>
+            <Badge variant={CHANGE_VARIANT[i.change.change_type] ?? "secondary"}>{i.change.change_type}</Badge>
+            <span className="text-muted-foreground">
+              {t("community.impactBefore", { version: i.previous_version ?? "—", record: record(i.before) })}
+            </span>
+            <span>{t("community.impactAfter", { record: record(i.after) })}</span>
+            {i.delta !== null ? (
+              <Badge variant={i.matches_expectation === false ? "warning" : "outline"}>
+                {i.delta > 0 ? "+" : ""}
+                {i.delta.toFixed(1)}
+              </Badge>
+            ) : (
+              <span className="text-xs text-muted-foreground">{t("community.impactFewGames")}</span>
+            )}
+          </div>
+        ))}
+      </div>
+    </div>
+  )
+}
@@ -0,0 +1,63 @@
+import { useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { Badge } from "@/components/ui/badge"
+import type { ProBuildsOverview } from "@/types/bindings/ProBuildsOverview"
+import type { ProItemUsage } from "@/types/bindings/ProItemUsage"
+
+/** Что про собирают на чемпионе в первые дни после патча (probuilds.net). */
+export function ProBuildsPanel({ champion }: { champion: string }) {
+  const { t, i18n } = useTranslation()
+  const [overview, setOverview] = useState<ProBuildsOverview | null>(null)
+
+  useEffect(() => {
+    if (!isTauri()) return
+    setOverview(null)
+    void invoke<ProBuildsOverview>("get_pro_builds", { champion })
+      .then(setOverview)
+      .catch(() => setOverview(null))
+  }, [champion])
+
+  const latest = overview?.snapshots[0]
+  if (!overview || !latest) return null
+
+  const name = (i: ProItemUsage) => (i18n.language.startsWith("ru") ? i.name_ru : i.name_en) ?? i.item_id
+  const wins = latest.games.filter((g) => g.win === true).length
+  const decided = latest.games.filter((g) => g.win !== null).length
+
+  return (
+    <div className="space-y-2 rounded-xl border border-border/50 bg-card/90 p-4 text-sm">
+      <div className="flex flex-wrap items-baseline gap-2">
+        <h3 className="font-semibold">{t("history.proBuildsTitle", { version: latest.patch_version })}</h3>
+        <span className="text-xs text-muted-foreground">
+          {t("history.proBuildsGames", { count: latest.games.length })}
+          {decided > 0 ? ` · ${t("history.proBuildsWins", { wins, games: decided })}` : ""}
+        </span>
+      </div>
+      <div className="flex flex-wrap gap-1.5">
+        {latest.core_items.map((i) => (
+          <Badge key={i.item_id} variant="secondary">
+            {name(i)} · {Math.round(i.share * 100)}%
+          </Badge>
+        ))}
+      </div>
+      {overview.added.length > 0 || overview.dropped.length > 0 ? (
+        <div className="flex flex-wrap gap-1.5">
+          {overview.added.map((i) => (
+            <Badge key={`+${i.item_id}`} variant="success">
+              + {name(i)}
+            </Badge>
+          ))}
+          {overview.dropped.map((i) => (
+            <Badge key={`-${i.item_id}`} variant="outline">
+              − {name(i)}
+            </Badge>
+          ))}
+          <span className="text-xs text-muted-foreground">
+            {t("history.proBuildsShift", { version: overview.snapshots[1]?.patch_version ?? "" })}
+          </span>
+        </div>
+      ) : null}
+    </div>
+  )
+}
@@ -0,0 +1,61 @@
+import { useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { Badge } from "@/components/ui/badge"
+import type { ChampionRotation } from "@/types/bindings/ChampionRotation"
+import type { RotationChampion } from "@/types/bindings/RotationChampion"
+
+/** Бесплатная ротация чемпионов из Riot API (ключ задаётся в настройках). */
+export function RotationPanel() {
+  const { t, i18n } = useTranslation()
+  const [rotation, setRotation] = useState<ChampionRotation | null>(null)
+  const [error, setError] = useState("")
+
+  useEffect(() => {
+    if (!isTauri()) return
+    void invoke<ChampionRotation>("get_rotation")
+      .then(setRotation)
+      .catch((e) => setError(String(e)))
+  }, [])
+
+  if (!isTauri()) {
+    return <p className="text-sm text-muted-foreground">{t("community.youtubeTauriOnly")}</p>
+  }
+  if (error) {
+    return (
+      <div className="space-y-1">
+        <p className="text-sm text-muted-foreground">{t("community.rotationNeedsKey")}</p>
+        <p className="text-xs text-destructive break-all">{error}</p>
+      </div>
+    )
+  }
+
+  const name = (c: RotationChampion) =>
+    (i18n.language.startsWith("ru") ? c.name_ru : c.name_en) ?? c.id ?? String(c.key)
+  const list = (champions: RotationChampion[]) => (
+    <div className="flex flex-wrap gap-1.5">
+      {champions.map((c) => (
+        <Badge key={c.key} variant="secondary">
+          {name(c)}
+        </Badge>
+      ))}
+    </div>
+  )
+
+  return rotation ? (
+    <div className="space-y-4">
+      <div className="space-y-2">
+        <h3 className="text-sm font-semibold">
+          {t("community.rotationTitle", { platform: rotation.platform.toUpperCase() })}
+        </h3>
+        {list(rotation.champions)}
+      </div>
+      <div className="space-y-2">
+        <h3 className="text-sm font-semibold">
+          {t("community.rotationNewPlayers", { level: rotation.max_new_player_level })}
+        </h3>
+        {list(rotation.new_player_champions)}
+      </div>
+    </div>
+  ) : null
+}
@@ -0,0 +1,85 @@
+import { useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { listen, type UnlistenFn } from "@tauri-apps/api/event"
+import { AlertTriangle, Wrench, X } from "lucide-react"
+import { toast } from "sonner"
+import { Button } from "@/components/ui/button"
+import { APP_EVENTS, type AppEventPayloads } from "@/lib/app-events"
+import { cn } from "@/lib/utils"
+import type { AppStatusPayload } from "@/types/bindings/AppStatusPayload"
+import type { ServerBanner } from "@/types/bindings/ServerBanner"
+
+/** Баннер о работах и инцидентах на серверах (`get_app_status`), обновляется по `server_status_changed`. */
+export function ServerStatusBanner() {
+  const { t } = useTranslation()
+  const [banner, setBanner] = useState<ServerBanner | null>(null)
+  const [dismissed, setDismissed] = useState("")
+
+  useEffect(() => {
+    if (!isTauri()) return
+    void invoke<AppStatusPayload>("get_app_status")
+      .then((s) => setBanner(s.server_banner))
+      .catch(() => setBanner(null))
+    let unlisten: UnlistenFn | undefined
+    void (async () => {
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.serverStatusChanged]>(
+        APP_EVENTS.serverStatusChanged,
+        (event) => {
+          const { status, new_issues } = event.payload
+          void invoke<AppStatusPayload>("get_app_status").then((s) => setBanner(s.server_banner))
+          if (new_issues.length === 0) {
+            toast.success(t("serverStatus.recovered", { name: status.name }))
+            return
+          }
+          for (const issue of new_issues) {
+            const text = t(issue.kind === "maintenance" ? "serverStatus.maintenanceToast" : "serverStatus.incidentToast", {
+              name: status.name,
+              title: issue.title,
+            })
+            if (issue.kind === "maintenance") toast.warning(text)
+            else toast.error(text)
+          }
+        },
+      )
+    })()
+    return () => {
+      unlisten?.()
+    }
+  }, [t])
+
+  const key = banner ? `${banner.platform}|${banner.health}|${banner.title}` : ""
+  if (!banner || dismissed === key) return null
+
+  const Icon = banner.health === "maintenance" ? Wrench : AlertTriangle
+  return (
+    <div
+      className={cn(
+        "border-b px-4 py-2 text-sm sm:px-6",
+        banner.health === "maintenance"
+          ? "border-destructive/30 bg-destructive/10 text-destructive"
+          : "border-amber-500/30 bg-amber-500/10 text-amber-800 dark:text-amber-300",
+      )}
+      role="status"
+    >
+      <div className="mx-auto flex max-w-6xl items-center gap-2">
+        <Icon className="h-4 w-4 shrink-0" aria-hidden />
+        <p className="min-w-0 flex-1 truncate">
+          {t(`serverStatus.banner.${banner.health}`, { name: banner.name })}
+          {banner.title ? `: ${banner.title}` : ""}
+          {banner.issues > 1 ? ` (+${banner.issues - 1})` : ""}
+        </p>
+        <Button
+          type="button"
+          variant="ghost"
+          size="icon"
+          className="h-7 w-7 shrink-0"
+          aria-label={t("serverStatus.dismiss")}
+          onClick={() => setDismissed(key)}
+        >
+          <X className="h-4 w-4" />
+        </Button>
+      </div>
+    </div>
+  )
+}
@@ -0,0 +1,99 @@
+import { useCallback, useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { listen, type UnlistenFn } from "@tauri-apps/api/event"
+import { Button } from "@/components/ui/button"
+import { Tooltip, TooltipContent, TooltipTrigger } from "@/components/ui/tooltip"
+import { APP_EVENTS, type AppEventPayloads } from "@/lib/app-events"
+import { loadAppPreferences } from "@/lib/app-preferences"
+import { cn } from "@/lib/utils"
+import type { PlatformStatus } from "@/types/bindings/PlatformStatus"
+import type { ServerHealth } from "@/types/bindings/ServerHealth"
+
+const DOT: Record<ServerHealth | "error", string> = {
+  online: "bg-emerald-500",
+  degraded: "bg-amber-500",
+  maintenance: "bg-destructive",
+  error: "bg-muted-foreground",
+}
+
+/** Статус серверов платформы из Riot API; скрыт, пока ключ не задан в настройках. Дальше статус
+ * приходит из фонового опроса backend (`server_status_changed`), по клику — запрашивается сразу. */
+export function ServerStatusIndicator() {
+  const { t } = useTranslation()
+  const [configured, setConfigured] = useState(() => Boolean(loadAppPreferences().riotApi))
+  const [status, setStatus] = useState<PlatformStatus | null>(null)
+  const [error, setError] = useState("")
+
+  const refresh = useCallback(async () => {
+    try {
+      setStatus(await invoke<PlatformStatus>("get_server_status"))
+      setError("")
+    } catch (e) {
+      setError(String(e))
+    }
+  }, [])
+
+  useEffect(() => {
+    const onChange = () => setConfigured(Boolean(loadAppPreferences().riotApi))
+    window.addEventListener("riot-api-changed", onChange)
+    return () => window.removeEventListener("riot-api-changed", onChange)
+  }, [])
+
+  useEffect(() => {
+    if (!isTauri() || !configured) return
+    void refresh()
+    let unlisten: UnlistenFn | undefined
+    void (async () => {
+      unlisten = await listen<AppEventPayloads[typeof APP_EVENTS.serverStatusChanged]>(
+        APP_EVENTS.serverStatusChanged,
+        (event) => {
+          setStatus(event.payload.status)
+          setError("")
+        },
+      )
+    })()
+    return () => {
+      unlisten?.()
+    }
+  }, [configured, refresh])
+
+  if (!isTauri() || !configured) return null
+
+  const health = error ? "error" : status?.health
+  return (
+    <Tooltip>
+      <TooltipTrigger asChild>
+        <Button
+          type="button"
+          variant="ghost"
+          size="icon"
+          className="h-9 w-9 rounded-xl"
+          aria-label={t("serverStatus.label")}
+          onClick={() => void refresh()}
+        >
+          <span className={cn("h-2.5 w-2.5 rounded-full", health ? DOT[health] : "bg-muted")} />
+        </Button>
+      </TooltipTrigger>
+      <TooltipContent side="bottom" className="max-w-xs">
+        {error ? (
+          <p className="break-words">{error}</p>
+        ) : status ? (
+          <div className="space-y-1">
+            <p className="font-medium">
+              {status.name}: {t(`serverStatus.${status.health}`)}
+            </p>
+            {status.issues.map((issue) => (
+              <p key={`${issue.kind}-${issue.id}`} className="text-xs">
+                {issue.title}
+                {issue.latest_update ? ` — ${issue.latest_update}` : ""}
+              </p>
+            ))}
+          </div>
+        ) : (
+          <p>{t("serverStatus.label")}</p>
+        )}
+      </TooltipContent>
+    </Tooltip>
+  )
+}
@@ -5,22 +5,49 @@ import { getCurrentWebview } from "@tauri-apps/api/webview";
 import { revealItemInDir } from "@tauri-apps/plugin-opener";
 import { disable, enable, isEnabled } from "@tauri-apps/plugin-autostart";
 import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
 import { Separator } from "@/components/ui/separator";
 import { Card, CardContent, CardHeader, CardTitle } from "@/components/ui/card";
 import {
   loadAppPreferences,
   saveAppPreferences,
+  PATCH_CACHE_BUDGET_PRESETS,
+  REQUEST_GAP_PRESETS_MS,
+  RETRY_PRESETS,
+  RIOT_PLATFORMS,
+  SYNC_CONCURRENCY_PRESETS,
   UI_SCALE_PRESETS,
   type AppPreferences,
 } from "@/lib/app-preferences";
 import type { ThemeOption } from "@/types/patch";
+import type { CacheStatsPayload } from "@/types/bindings/CacheStatsPayload";
+import type { QueryPlan } from "@/types/bindings/QueryPlan";
+import type { ScrapeProfileReport } from "@/types/bindings/ScrapeProfileReport";
+import type { ParseCoverage } from "@/types/bindings/ParseCoverage";
+import type { MaintenanceStatus } from "@/types/bindings/MaintenanceStatus";
+import type { LocalApiStatus } from "@/types/bindings/LocalApiStatus";
+import type { LogEvent } from "@/types/bindings/LogEvent";
+import type { WipeReport } from "@/types/bindings/WipeReport";
+import type { FallbackPatch } from "@/types/bindings/FallbackPatch";
+import type { PatchRevision } from "@/types/bindings/PatchRevision";
+import type { RiotApiSettings } from "@/types/bindings/RiotApiSettings";
+import type { TranslationSettings } from "@/types/bindings/TranslationSettings";
+import type { RequestHeaderSettings } from "@/types/bindings/RequestHeaderSettings";
+import type { ExtraHeader } from "@/types/bindings/ExtraHeader";
+import type { LinkedSummoner } from "@/types/bindings/LinkedSummoner";
+import type { LogFilter } from "@/types/bindings/LogFilter";
+import type { LogLevel } from "@/types/bindings/LogLevel";
+import type { ScrapeSettings } from "@/types/bindings/ScrapeSettings";
+import type { PatchData } from "@/types/bindings/PatchData";
 
 type Props = {
   theme: ThemeOption;
   onThemeChange: (t: ThemeOption) => void;
 };
 
+const LOG_LEVELS: LogLevel[] = ["DEBUG", "INFO", "WARN", "ERROR"];
+
 export function SettingsControls({ theme, onThemeChange }: Props) {
   const { t, i18n } = useTranslation();
   const [prefs, setPrefs] = useState<AppPreferences>(() => loadAppPreferences());
@@ -29,6 +56,63 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
   const [cacheBusy, setCacheBusy] = useState(false);
   const [cacheStatusJson, setCacheStatusJson] = useState<string>("");
   const [cacheValidationJson, setCacheValidationJson] = useState<string>("");
+  const [queryPlansJson, setQueryPlansJson] = useState<string>("");
+  const [cacheStatsJson, setCacheStatsJson] = useState<string>("");
+  const [scrapeProfileJson, setScrapeProfileJson] = useState<string>("");
+  const [maintenanceJson, setMaintenanceJson] = useState<string>("");
+  const [validateVersion, setValidateVersion] = useState(() => loadAppPreferences().lastPatchVersion);
+  const [parseCoverageJson, setParseCoverageJson] = useState<string>("");
+  const [profileCopied, setProfileCopied] = useState(false);
+  const [scrapeSettings, setScrapeSettings] = useState<ScrapeSettings | null>(null);
+  const [localApi, setLocalApi] = useState<LocalApiStatus | null>(null);
+  const [localApiError, setLocalApiError] = useState<string>("");
+  const [riotApi, setRiotApi] = useState<RiotApiSettings>(
+    () => loadAppPreferences().riotApi ?? { api_key: "", platform: "euw1" },
+  );
+  const [riotApiSaved, setRiotApiSaved] = useState(false);
+  const [riotApiError, setRiotApiError] = useState<string>("");
+  const [translation, setTranslation] = useState<TranslationSettings>(
+    () => loadAppPreferences().translation ?? { enabled: false, endpoint: "", api_key: "" },
+  );
+  const [translationError, setTranslationError] = useState<string>("");
+  const [requestHeaders, setRequestHeaders] = useState<RequestHeaderSettings>(
+    () => loadAppPreferences().requestHeaders ?? { user_agent: "", accept_language: "", extra_headers: [] },
+  );
+  const [extraHeadersText, setExtraHeadersText] = useState(() =>
+    requestHeaders.extra_headers.map((h) => `${h.name}: ${h.value}`).join("\n"),
+  );
+  const [requestHeadersSaved, setRequestHeadersSaved] = useState(false);
+  const [requestHeadersError, setRequestHeadersError] = useState<string>("");
+  const [summoner, setSummoner] = useState<LinkedSummoner | null>(null);
+  const [riotId, setRiotId] = useState("");
+  const [summonerBusy, setSummonerBusy] = useState(false);
+  const [summonerError, setSummonerError] = useState<string>("");
+  const [importSource, setImportSource] = useState<string>("");
+  const [importVersion, setImportVersion] = useState<string>("");
+  const [importBusy, setImportBusy] = useState(false);
+  const [importResult, setImportResult] = useState<string>("");
+  const [importError, setImportError] = useState<string>("");
+  const [fallbackPatches, setFallbackPatches] = useState<FallbackPatch[] | null>(null);
+  const [fallbackVersion, setFallbackVersion] = useState<string>("");
+  const [fallbackBusy, setFallbackBusy] = useState(false);
+  const [fallbackError, setFallbackError] = useState<string>("");
+  const [revisionCheck, setRevisionCheck] = useState<"idle" | "busy" | "done">("idle");
+  const [revisionResult, setRevisionResult] = useState<string>("");
+  const [feedPath, setFeedPath] = useState<string>("");
+  const [feedError, setFeedError] = useState<string>("");
+  const [calendarPath, setCalendarPath] = useState<string>("");
+  const [calendarError, setCalendarError] = useState<string>("");
+  const [overlayPath, setOverlayPath] = useState<string>("");
+  const [overlayError, setOverlayError] = useState<string>("");
+  const [logFilter, setLogFilter] = useState<LogFilter | null>(null);
+  const [recentLogs, setRecentLogs] = useState<LogEvent[] | null>(null);
+  const [logsPath, setLogsPath] = useState<string>("");
+  const [logsError, setLogsError] = useState<string>("");
+  const [crashReportStatus, setCrashReportStatus] = useState<"copied" | "none" | null>(null);
+  const [wipePlan, setWipePlan] = useState<WipeReport | null>(null);
+  const [wipeBusy, setWipeBusy] = useState(false);
+  const [wipeExtensions, setWipeExtensions] = useState(false);
+  const [wipeError, setWipeError] = useState<string>("");
   const [cacheWarmResult, setCacheWarmResult] = useState<string>("");
   const [cacheError, setCacheError] = useState<string>("");
 
@@ -44,6 +128,13 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
 
   useEffect(() => {
     if (!isTauri()) return;
+    void invoke<ScrapeSettings>("get_scrape_settings").then(setScrapeSettings).catch(() => setScrapeSettings(null));
+    void invoke<LocalApiStatus>("get_local_api_status").then(setLocalApi).catch(() => setLocalApi(null));
+    void invoke<LinkedSummoner | null>("get_linked_summoner").then(setSummoner).catch(() => setSummoner(null));
+    void invoke<LogFilter>("get_log_filter").then(setLogFilter).catch(() => setLogFilter(null));
+    void invoke<FallbackPatch[]>("get_fallback_patches")
+      .then(setFallbackPatches)
+      .catch(() => setFallbackPatches(null));
     void invoke<string>("get_database_path").then(setDbPath).catch(() => setDbPath(""));
     void isEnabled()
       .then(setAutostartOn)
@@ -134,6 +225,337 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
     setCacheValidationJson(JSON.stringify(payload, null, 2));
   }, []);
 
+  const runExplainQueries = useCallback(async () => {
+    if (!isTauri()) return;
+    const plans = await invoke<QueryPlan[]>("explain_database_queries");
+    setQueryPlansJson(JSON.stringify(plans, null, 2));
+  }, []);
+
+  const runCacheStats = useCallback(async () => {
+    if (!isTauri()) return;
+    const stats = await invoke<CacheStatsPayload>("get_cache_stats");
+    setCacheStatsJson(JSON.stringify(stats, null, 2));
+  }, []);
+
+  const runScrapeProfile = useCallback(async () => {
+    if (!isTauri()) return;
+    const report = await invoke<ScrapeProfileReport>("get_scrape_profile");
+    setScrapeProfileJson(JSON.stringify(report, null, 2));
+  }, []);
+
+  const runMaintenanceStatus = useCallback(async () => {
+    if (!isTauri()) return;
+    const status = await invoke<MaintenanceStatus>("get_maintenance_status");
+    setMaintenanceJson(JSON.stringify(status, null, 2));
+  }, []);
+
+  const runValidateParse = useCallback(async () => {
+    const version = validateVersion.trim();
+    if (!isTauri() || !version) return;
+    const report = await invoke<ParseCoverage>("validate_parse", { version, locale: i18n.language.startsWith("ru") ? "ru" : "en" });
+    setParseCoverageJson(JSON.stringify(report, null, 2));
+  }, [validateVersion, i18n.language]);
+
+  const copyScrapeProfile = async () => {
+    if (!isTauri()) return;
+    try {
+      const report = await invoke<ScrapeProfileReport>("get_scrape_profile");
+      await navigator.clipboard.writeText(JSON.stringify(report, null, 2));
+      setProfileCopied(true);
+    } catch (e) {
+      setCacheError(String(e));
+    }
+  };
+
+  const runImportPatch = async () => {
+    const source = importSource.trim();
+    if (!isTauri() || !source) return;
+    setImportBusy(true);
+    setImportError("");
+    setImportResult("");
+    try {
+      const fromUrl = /^https?:\/\//i.test(source);
+      const patch = await invoke<PatchData>(fromUrl ? "import_patch_from_url" : "import_patch_from_file", {
+        ...(fromUrl ? { url: source } : { path: source }),
+        version: importVersion.trim() || null,
+        patchNotesLocale: i18n.language.startsWith("en") ? "en" : "ru",
+      });
+      setImportResult(t("settings.importPatchDone", { version: patch.version, count: patch.patch_notes.length }));
+      window.dispatchEvent(new Event("tier-data-updated"));
+    } catch (e) {
+      setImportError(String(e));
+    } finally {
+      setImportBusy(false);
+    }
+  };
+
+  const runFallbackPatches = async (command: string, args?: Record<string, unknown>) => {
+    if (!isTauri()) return;
+    setFallbackBusy(true);
+    setFallbackError("");
+    try {
+      setFallbackPatches(await invoke<FallbackPatch[]>(command, args));
+      if (command === "add_fallback_patch") setFallbackVersion("");
+    } catch (e) {
+      setFallbackError(String(e));
+    } finally {
+      setFallbackBusy(false);
+    }
+  };
+
+  const checkPatchNoteEdits = async () => {
+    if (!isTauri()) return;
+    setRevisionCheck("busy");
+    try {
+      const revisions = await invoke<PatchRevision[]>("check_patch_note_edits_now");
+      setRevisionResult(
+        revisions.length
+          ? revisions.map((r) => t("patchRevisions.found", { version: r.version, count: r.changes.length })).join("; ")
+          : t("patchRevisions.none"),
+      );
+    } catch (e) {
+      setRevisionResult(String(e));
+    } finally {
+      setRevisionCheck("done");
+    }
+  };
+
+  const writePatchFeed = async () => {
+    if (!isTauri()) return;
+    setFeedError("");
+    try {
+      setFeedPath(await invoke<string>("write_patch_feed"));
+    } catch (e) {
+      setFeedError(String(e));
+    }
+  };
+
+  const exportPatchCalendar = async () => {
+    if (!isTauri()) return;
+    setCalendarError("");
+    try {
+      setCalendarPath(await invoke<string>("export_patch_calendar", { upcoming: null }));
+    } catch (e) {
+      setCalendarError(String(e));
+    }
+  };
+
+  const toggleCommunityTiers = async (enabled: boolean) => {
+    if (isTauri()) {
+      await invoke("set_community_tiers_enabled", { enabled }).catch((err) =>
+        console.error("set_community_tiers_enabled failed", err),
+      );
+    }
+    patchPrefs({ communityTiersEnabled: enabled });
+  };
+
+  const toggleProBuilds = async (enabled: boolean) => {
+    if (isTauri()) {
+      await invoke("set_pro_builds_enabled", { enabled }).catch((err) =>
+        console.error("set_pro_builds_enabled failed", err),
+      );
+    }
+    patchPrefs({ proBuildsEnabled: enabled });
+  };
+
+  const toggleOverlayFile = async (enabled: boolean) => {
+    if (!isTauri()) return;
+    setOverlayError("");
+    try {
+      const path = await invoke<string | null>("set_overlay_file_enabled", { enabled });
+      setOverlayPath(path ?? "");
+      patchPrefs({ overlayFileEnabled: enabled });
+    } catch (e) {
+      setOverlayError(String(e));
+    }
+  };
+
+  const setLogLevel = async (source: "SCRAPER" | null, level: LogLevel | null) => {
+    if (!isTauri()) return;
+    setLogFilter(await invoke<LogFilter>("set_log_level", { source, level }));
+  };
+
+  const loadRecentLogs = async () => {
+    if (!isTauri()) return;
+    setLogsError("");
+    try {
+      setRecentLogs(await invoke<LogEvent[]>("get_recent_logs", { level: null, limit: null }));
+    } catch (e) {
+      setLogsError(String(e));
+    }
+  };
+
+  const exportLogs = async () => {
+    if (!isTauri()) return;
+    setLogsError("");
+    try {
+      setLogsPath(await invoke<string>("export_logs"));
+    } catch (e) {
+      setLogsError(String(e));
+    }
+  };
+
+  const copyCrashReport = async () => {
+    if (!isTauri()) return;
+    setLogsError("");
+    try {
+      const text = await invoke<string | null>("copy_crash_report", { id: null });
+      if (text) await navigator.clipboard.writeText(text);
+      setCrashReportStatus(text ? "copied" : "none");
+    } catch (e) {
+      setLogsError(String(e));
+    }
+  };
+
+  const previewWipe = async () => {
+    if (!isTauri()) return;
+    setWipeError("");
+    try {
+      setWipePlan(await invoke<WipeReport>("wipe_all_data", { dryRun: true, includeExtensions: wipeExtensions }));
+    } catch (e) {
+      setWipeError(String(e));
+    }
+  };
+
+  const runWipe = async () => {
+    if (!isTauri()) return;
+    setWipeBusy(true);
+    setWipeError("");
+    try {
+      const report = await invoke<WipeReport>("wipe_all_data", {
+        dryRun: false,
+        includeExtensions: wipeExtensions,
+      });
+      if (report.errors.length > 0) {
+        setWipePlan(report);
+        setWipeError(report.errors.join("\n"));
+        return;
+      }
+      // Настройки интерфейса, язык и последний патч — тоже локальные данные
+      localStorage.clear();
+      window.location.reload();
+    } catch (e) {
+      setWipeError(String(e));
+    } finally {
+      setWipeBusy(false);
+    }
+  };
+
+  const toggleLocalApi = async (enabled: boolean) => {
+    if (!isTauri()) return;
+    setLocalApiError("");
+    try {
+      setLocalApi(await invoke<LocalApiStatus>("set_local_api_enabled", { enabled }));
+      patchPrefs({ localApiEnabled: enabled });
+    } catch (e) {
+      setLocalApiError(String(e));
+    }
+  };
+
+  const saveRiotApi = async (settings: RiotApiSettings) => {
+    setRiotApi(settings);
+    setRiotApiSaved(false);
+    setRiotApiError("");
+    if (!isTauri()) return;
+    try {
+      const applied = await invoke<RiotApiSettings>("set_riot_api_settings", { settings });
+      setRiotApi(applied);
+      patchPrefs({ riotApi: applied.api_key ? applied : null });
+      window.dispatchEvent(new Event("riot-api-changed"));
+      if (applied.api_key) {
+        await invoke("get_server_status");
+        setRiotApiSaved(true);
+      }
+    } catch (e) {
+      setRiotApiError(String(e));
+    }
+  };
+
+  const saveTranslation = async (settings: TranslationSettings) => {
+    setTranslation(settings);
+    setTranslationError("");
+    if (!isTauri()) return;
+    try {
+      const applied = await invoke<TranslationSettings>("set_translation_settings", { settings });
+      setTranslation(applied);
+      patchPrefs({ translation: applied.enabled || applied.endpoint ? applied : null });
+      if (settings.endpoint.trim() && !applied.endpoint) {
+        setTranslationError(t("settings.machineTranslationBadUrl"));
+      }
+    } catch (e) {
+      setTranslationError(String(e));
+    }
+  };
+
+  const saveRequestHeaders = async () => {
+    const extra_headers: ExtraHeader[] = extraHeadersText
+      .split("\n")
+      .map((line) => line.split(/:(.*)/s))
+      .filter((parts) => parts.length > 1 && parts[0].trim())
+      .map(([name, value]) => ({ name: name.trim(), value: (value ?? "").trim() }));
+    const settings = { ...requestHeaders, extra_headers };
+    setRequestHeadersSaved(false);
+    setRequestHeadersError("");
+    if (!isTauri()) return;
+    try {
+      const applied = await invoke<RequestHeaderSettings>("set_request_header_settings", { settings });
+      setRequestHeaders(applied);
+      setExtraHeadersText(applied.extra_headers.map((h) => `${h.name}: ${h.value}`).join("\n"));
+      const isDefault = !applied.user_agent && !applied.accept_language && applied.extra_headers.length === 0;
+      patchPrefs({ requestHeaders: isDefault ? null : applied });
+      setRequestHeadersSaved(true);
+    } catch (e) {
+      setRequestHeadersError(String(e));
+    }
+  };
+
+  const linkSummoner = async (id: string) => {
+    if (!isTauri()) return;
+    setSummonerBusy(true);
+    setSummonerError("");
+    try {
+      setSummoner(await invoke<LinkedSummoner>("link_summoner", { riotId: id }));
+      setRiotId("");
+    } catch (e) {
+      setSummonerError(String(e));
+    } finally {
+      setSummonerBusy(false);
+    }
+  };
+
+  const unlinkSummoner = async () => {
+    if (!isTauri()) return;
+    try {
+      await invoke("unlink_summoner");
+      setSummoner(null);
+    } catch (e) {
+      setSummonerError(String(e));
+    }
+  };
+
+  const updateScrapeSettings = async (partial: Partial<ScrapeSettings>) => {
+    if (!isTauri() || !scrapeSettings) return;
+    try {
+      const applied = await invoke<ScrapeSettings>("set_scrape_settings", {
+        settings: { ...scrapeSettings, ...partial },
+      });
+      setScrapeSettings(applied);
+      patchPrefs({ scrapeSettings: applied });
+    } catch (e) {
+      setCacheError(String(e));
+    }
+  };
+
+  const setPatchCacheBudget = async (megabytes: number) => {
+    patchPrefs({ patchCacheBudgetMb: megabytes });
+    if (!isTauri()) return;
+    try {
+      await invoke("set_cache_memory_budget", { megabytes });
+    } catch (e) {
+      setCacheError(String(e));
+    }
+  };
+
   const runWarmFullCache = useCallback(async () => {
     if (!isTauri()) return;
     await invoke("warm_full_cache");
@@ -291,6 +713,98 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
             </div>
           </div>
           <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.competitiveMode")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.competitiveModeHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!prefs.competitiveMode ? "default" : "outline"}
+                onClick={() => patchPrefs({ competitiveMode: false })}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={prefs.competitiveMode ? "default" : "outline"}
+                onClick={() => patchPrefs({ competitiveMode: true })}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.communityReaction")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.communityReactionHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!prefs.communityReaction ? "default" : "outline"}
+                onClick={() => patchPrefs({ communityReaction: false })}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={prefs.communityReaction ? "default" : "outline"}
+                onClick={() => patchPrefs({ communityReaction: true })}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.communityTiers")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.communityTiersHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!prefs.communityTiersEnabled ? "default" : "outline"}
+                onClick={() => void toggleCommunityTiers(false)}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={prefs.communityTiersEnabled ? "default" : "outline"}
+                onClick={() => void toggleCommunityTiers(true)}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.proBuilds")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.proBuildsHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!prefs.proBuildsEnabled ? "default" : "outline"}
+                onClick={() => void toggleProBuilds(false)}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={prefs.proBuildsEnabled ? "default" : "outline"}
+                onClick={() => void toggleProBuilds(true)}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+          </div>
+          <Separator />
           <div className="space-y-2">
             <Label>{t("settings.startupRoute")}</Label>
             <p className="text-sm text-muted-foreground">{t("settings.startupRouteHint")}</p>
@@ -445,39 +959,639 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
           </div>
           <Separator />
           <p className="text-sm font-semibold uppercase tracking-[0.06em] text-muted-foreground">
-            {t("settings.sectionData")}
+            {t("settings.sectionNetwork")}
           </p>
-          <div className="space-y-3">
-            <Label>{t("settings.dataLocation")}</Label>
-            <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
-              {dbPath || "—"}
-            </p>
-            <p className="text-sm text-muted-foreground">{t("settings.dataLocationHint")}</p>
-            <Button type="button" size="sm" variant="secondary" disabled={!dbPath} onClick={() => void onRevealDb()}>
-              {t("settings.openInExplorer")}
-            </Button>
+          <div className="space-y-2">
+            <Label>{t("settings.syncConcurrency")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.syncConcurrencyHint")}</p>
+            <div className="flex flex-wrap gap-1.5">
+              {SYNC_CONCURRENCY_PRESETS.map((v) => (
+                <Button
+                  key={v}
+                  type="button"
+                  size="sm"
+                  variant={scrapeSettings?.concurrency === v ? "default" : "outline"}
+                  className="min-w-12 px-2"
+                  disabled={!scrapeSettings}
+                  onClick={() => void updateScrapeSettings({ concurrency: v })}
+                >
+                  {v}
+                </Button>
+              ))}
+            </div>
           </div>
-          {isTauri() && import.meta.env.DEV ? (
-            <>
-              <Separator />
-              <div className="space-y-3">
-                <Label>{t("settings.devCacheToolsTitle")}</Label>
-                <p className="text-sm text-muted-foreground">
-                  {t("settings.devCacheToolsHint")}
-                </p>
-                <div className="flex flex-wrap gap-2">
-                  <Button
-                    type="button"
-                    size="sm"
-                    variant="secondary"
-                    disabled={cacheBusy}
-                    onClick={() => {
-                      setCacheBusy(true);
-                      setCacheError("");
-                      void runCacheStatus()
-                        .catch((e) => setCacheError(String(e)))
-                        .finally(() => setCacheBusy(false));
-                    }}
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.requestGap")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.requestGapHint")}</p>
+            <div className="flex flex-wrap gap-1.5">
+              {REQUEST_GAP_PRESETS_MS.map((v) => (
+                <Button
+                  key={v}
+                  type="button"
+                  size="sm"
+                  variant={scrapeSettings?.request_gap_ms === v ? "default" : "outline"}
+                  className="min-w-12 px-2"
+                  disabled={!scrapeSettings}
+                  onClick={() => void updateScrapeSettings({ request_gap_ms: v })}
+                >
+                  {t("settings.requestGapMs", { ms: v })}
+                </Button>
+              ))}
+            </div>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.retries")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.retriesHint")}</p>
+            <div className="flex flex-wrap gap-1.5">
+              {RETRY_PRESETS.map((v) => (
+                <Button
+                  key={v}
+                  type="button"
+                  size="sm"
+                  variant={scrapeSettings?.retries === v ? "default" : "outline"}
+                  className="min-w-12 px-2"
+                  disabled={!scrapeSettings}
+                  onClick={() => void updateScrapeSettings({ retries: v })}
+                >
+                  {v}
+                </Button>
+              ))}
+            </div>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.requestHeaders")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.requestHeadersHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Input
+                placeholder={t("settings.userAgentRotation")}
+                value={requestHeaders.user_agent}
+                onChange={(e) => setRequestHeaders({ ...requestHeaders, user_agent: e.target.value })}
+                className="h-9 w-96 max-w-full"
+              />
+              <Input
+                placeholder="en-US,en;q=0.9,ru;q=0.8"
+                value={requestHeaders.accept_language}
+                onChange={(e) => setRequestHeaders({ ...requestHeaders, accept_language: e.target.value })}
+                className="h-9 w-56"
+              />
+            </div>
+            <textarea
+              rows={3}
+              placeholder={t("settings.extraHeadersPlaceholder")}
+              value={extraHeadersText}
+              onChange={(e) => setExtraHeadersText(e.target.value)}
+              className="w-full max-w-xl rounded-md border border-input bg-transparent px-3 py-2 font-mono text-xs shadow-sm focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-ring"
+            />
+            <div className="flex flex-wrap items-center gap-2">
+              <Button type="button" size="sm" onClick={() => void saveRequestHeaders()}>
+                {t("settings.requestHeadersSave")}
+              </Button>
+              {requestHeadersSaved ? (
+                <span className="text-sm text-muted-foreground">{t("settings.requestHeadersSaved")}</span>
+              ) : null}
+            </div>
+            {requestHeadersError ? <p className="text-sm text-destructive break-all">{requestHeadersError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.localApi")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.localApiHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!localApi?.running ? "default" : "outline"}
+                disabled={!localApi}
+                onClick={() => void toggleLocalApi(false)}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={localApi?.running ? "default" : "outline"}
+                disabled={!localApi}
+                onClick={() => void toggleLocalApi(true)}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+            {localApi?.base_url ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {localApi.base_url}
+              </p>
+            ) : null}
+            {localApiError ? <p className="text-sm text-destructive break-all">{localApiError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.machineTranslation")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.machineTranslationHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Input
+                type="url"
+                placeholder="https://libretranslate.com/translate"
+                value={translation.endpoint}
+                onChange={(e) => setTranslation({ ...translation, endpoint: e.target.value })}
+                className="h-9 w-72"
+              />
+              <Input
+                type="password"
+                autoComplete="off"
+                placeholder={t("settings.machineTranslationKey")}
+                value={translation.api_key}
+                onChange={(e) => setTranslation({ ...translation, api_key: e.target.value })}
+                className="h-9 w-48"
+              />
+            </div>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={translation.enabled ? "outline" : "default"}
+                onClick={() => void saveTranslation({ ...translation, enabled: false })}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={translation.enabled ? "default" : "outline"}
+                disabled={!translation.endpoint.trim()}
+                onClick={() => void saveTranslation({ ...translation, enabled: true })}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+            {translationError ? <p className="text-sm text-destructive break-all">{translationError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.riotApi")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.riotApiHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Input
+                type="password"
+                autoComplete="off"
+                placeholder="RGAPI-…"
+                value={riotApi.api_key}
+                onChange={(e) => setRiotApi({ ...riotApi, api_key: e.target.value })}
+                className="h-9 w-72"
+              />
+              <Button type="button" size="sm" variant="secondary" onClick={() => void saveRiotApi(riotApi)}>
+                {t("settings.riotApiSave")}
+              </Button>
+            </div>
+            <div className="flex flex-wrap gap-1">
+              {RIOT_PLATFORMS.map((p) => (
+                <Button
+                  key={p}
+                  type="button"
+                  size="sm"
+                  variant={riotApi.platform === p ? "default" : "outline"}
+                  className="h-7 px-2 text-xs uppercase"
+                  onClick={() => void saveRiotApi({ ...riotApi, platform: p })}
+                >
+                  {p}
+                </Button>
+              ))}
+            </div>
+            {riotApiSaved ? <p className="text-sm text-muted-foreground">{t("settings.riotApiOk")}</p> : null}
+            {riotApiError ? <p className="text-sm text-destructive break-all">{riotApiError}</p> : null}
+          </div>
+          <div className="space-y-2">
+            <Label>{t("settings.summoner")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.summonerHint")}</p>
+            {summoner ? (
+              <div className="space-y-2 rounded-md border bg-muted/30 px-3 py-2">
+                <p className="text-sm font-medium">
+                  {summoner.account.game_name}#{summoner.account.tag_line}
+                  <span className="ml-2 text-xs uppercase text-muted-foreground">{summoner.platform}</span>
+                </p>
+                <p className="text-xs text-muted-foreground">
+                  {t("settings.summonerTop", {
+                    champions: summoner.top_champions
+                      .map((c) => (i18n.language.startsWith("ru") ? c.name_ru : c.name_en) ?? c.id ?? c.key)
+                      .join(", "),
+                  })}
+                </p>
+                <div className="flex flex-wrap gap-2">
+                  <Button
+                    type="button"
+                    size="sm"
+                    variant="outline"
+                    disabled={summonerBusy}
+                    onClick={() => void linkSummoner(`${summoner.account.game_name}#${summoner.account.tag_line}`)}
+                  >
+                    {t("settings.summonerRefresh")}
+                  </Button>
+                  <Button type="button" size="sm" variant="outline" onClick={() => void unlinkSummoner()}>
+                    {t("settings.summonerUnlink")}
+                  </Button>
+                </div>
+              </div>
+            ) : (
+              <div className="flex flex-wrap gap-2">
+                <Input
+                  placeholder="Name#TAG"
+                  value={riotId}
+                  onChange={(e) => setRiotId(e.target.value)}
+                  className="h-9 w-56"
+                />
+                <Button
+                  type="button"
+                  size="sm"
+                  variant="secondary"
+                  disabled={summonerBusy || !riotApi.api_key || !riotId.includes("#")}
+                  onClick={() => void linkSummoner(riotId)}
+                >
+                  {t("settings.summonerLink")}
+                </Button>
+              </div>
+            )}
+            {summonerError ? <p className="text-sm text-destructive break-all">{summonerError}</p> : null}
+          </div>
+          <Separator />
+          <p className="text-sm font-semibold uppercase tracking-[0.06em] text-muted-foreground">
+            {t("settings.sectionData")}
+          </p>
+          <div className="space-y-3">
+            <Label>{t("settings.dataLocation")}</Label>
+            <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+              {dbPath || "—"}
+            </p>
+            <p className="text-sm text-muted-foreground">{t("settings.dataLocationHint")}</p>
+            <Button type="button" size="sm" variant="secondary" disabled={!dbPath} onClick={() => void onRevealDb()}>
+              {t("settings.openInExplorer")}
+            </Button>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.importPatch")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.importPatchHint")}</p>
+            <Input
+              placeholder={t("settings.importPatchSourcePlaceholder")}
+              value={importSource}
+              onChange={(e) => setImportSource(e.target.value)}
+              className="h-9"
+            />
+            <div className="flex flex-wrap gap-2">
+              <Input
+                placeholder={t("settings.importPatchVersionPlaceholder")}
+                value={importVersion}
+                onChange={(e) => setImportVersion(e.target.value)}
+                className="h-9 w-40"
+              />
+              <Button
+                type="button"
+                size="sm"
+                variant="secondary"
+                disabled={importBusy || !importSource.trim()}
+                onClick={() => void runImportPatch()}
+              >
+                {t("settings.importPatchAction")}
+              </Button>
+            </div>
+            {importResult ? <p className="text-sm text-muted-foreground">{importResult}</p> : null}
+            {importError ? <p className="text-sm text-destructive break-all">{importError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.fallbackPatches")}</Label>
+            <p className="text-sm text-muted-foreground">
+              {t("settings.fallbackPatchesHint", {
+                count: fallbackPatches?.filter((p) => p.source === "ddragon").length ?? 0,
+              })}
+            </p>
+            {fallbackPatches?.some((p) => p.source === "manual") ? (
+              <div className="flex flex-wrap gap-2">
+                {fallbackPatches
+                  .filter((p) => p.source === "manual")
+                  .map((p) => (
+                    <Button
+                      key={p.version}
+                      type="button"
+                      size="sm"
+                      variant="outline"
+                      disabled={fallbackBusy}
+                      title={t("settings.fallbackPatchRemove")}
+                      onClick={() => void runFallbackPatches("remove_fallback_patch", { version: p.version })}
+                    >
+                      {p.version} ×
+                    </Button>
+                  ))}
+              </div>
+            ) : null}
+            <div className="flex flex-wrap gap-2">
+              <Input
+                placeholder={t("settings.fallbackPatchPlaceholder")}
+                value={fallbackVersion}
+                onChange={(e) => setFallbackVersion(e.target.value)}
+                className="h-9 w-40"
+              />
+              <Button
+                type="button"
+                size="sm"
+                variant="secondary"
+                disabled={fallbackBusy || !fallbackVersion.trim()}
+                onClick={() => void runFallbackPatches("add_fallback_patch", { version: fallbackVersion.trim() })}
+              >
+                {t("settings.fallbackPatchAdd")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant="outline"
+                disabled={fallbackBusy}
+                onClick={() => void runFallbackPatches("refresh_fallback_patches_now")}
+              >
+                {t("settings.fallbackPatchesRefresh")}
+              </Button>
+            </div>
+            {fallbackError ? <p className="text-sm text-destructive break-all">{fallbackError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("patchRevisions.title")}</Label>
+            <p className="text-sm text-muted-foreground">{t("patchRevisions.hint")}</p>
+            <Button
+              type="button"
+              size="sm"
+              variant="outline"
+              disabled={revisionCheck === "busy"}
+              onClick={() => void checkPatchNoteEdits()}
+            >
+              {t("patchRevisions.checkNow")}
+            </Button>
+            {revisionCheck === "done" ? <p className="text-sm text-muted-foreground">{revisionResult}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.patchFeed")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.patchFeedHint")}</p>
+            <Button type="button" size="sm" variant="secondary" onClick={() => void writePatchFeed()}>
+              {t("settings.patchFeedAction")}
+            </Button>
+            {feedPath ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {feedPath}
+              </p>
+            ) : null}
+            {localApi?.base_url ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {`${localApi.base_url}/feed.xml`}
+              </p>
+            ) : null}
+            {feedError ? <p className="text-sm text-destructive break-all">{feedError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.patchCalendar")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.patchCalendarHint")}</p>
+            <Button type="button" size="sm" variant="secondary" onClick={() => void exportPatchCalendar()}>
+              {t("settings.patchCalendarAction")}
+            </Button>
+            {calendarPath ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {calendarPath}
+              </p>
+            ) : null}
+            {localApi?.base_url ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {`${localApi.base_url}/calendar.ics`}
+              </p>
+            ) : null}
+            {calendarError ? <p className="text-sm text-destructive break-all">{calendarError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.overlay")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.overlayHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!prefs.overlayFileEnabled ? "default" : "outline"}
+                onClick={() => void toggleOverlayFile(false)}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={prefs.overlayFileEnabled ? "default" : "outline"}
+                onClick={() => void toggleOverlayFile(true)}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+            {overlayPath ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {overlayPath}
+              </p>
+            ) : null}
+            {localApi?.base_url ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {`${localApi.base_url}/overlay`}
+              </p>
+            ) : null}
+            {overlayError ? <p className="text-sm text-destructive break-all">{overlayError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.logLevel")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.logLevelHint")}</p>
+            <div className="flex flex-wrap gap-1.5">
+              {LOG_LEVELS.map((level) => (
+                <Button
+                  key={level}
+                  type="button"
+                  size="sm"
+                  variant={logFilter?.default_level === level ? "default" : "outline"}
+                  disabled={!logFilter}
+                  onClick={() => void setLogLevel(null, level)}
+                >
+                  {level}
+                </Button>
+              ))}
+            </div>
+            <Label>{t("settings.scraperDebugLog")}</Label>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={logFilter?.sources.SCRAPER !== "DEBUG" ? "default" : "outline"}
+                disabled={!logFilter}
+                onClick={() => void setLogLevel("SCRAPER", null)}
+              >
+                {t("settings.localApiOff")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={logFilter?.sources.SCRAPER === "DEBUG" ? "default" : "outline"}
+                disabled={!logFilter}
+                onClick={() => void setLogLevel("SCRAPER", "DEBUG")}
+              >
+                {t("settings.localApiOn")}
+              </Button>
+            </div>
+            <div className="flex flex-wrap gap-2">
+              <Button type="button" size="sm" variant="secondary" onClick={() => void loadRecentLogs()}>
+                {t("settings.recentLogsAction")}
+              </Button>
+              <Button type="button" size="sm" variant="secondary" onClick={() => void exportLogs()}>
+                {t("settings.exportLogsAction")}
+              </Button>
+              <Button type="button" size="sm" variant="secondary" onClick={() => void copyCrashReport()}>
+                {crashReportStatus === "copied" ? t("settings.scrapeProfileCopied") : t("settings.copyCrashReport")}
+              </Button>
+            </div>
+            {crashReportStatus === "none" ? (
+              <p className="text-sm text-muted-foreground">{t("settings.noCrashReports")}</p>
+            ) : null}
+            {recentLogs ? (
+              <pre className="max-h-60 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                {recentLogs.length === 0
+                  ? "—"
+                  : recentLogs
+                      .map((e) => {
+                        const fields = Object.entries(e.fields)
+                          .map(([k, v]) => ` ${k}=${v}`)
+                          .join("");
+                        return `${e.timestamp} [${e.level}] [${e.source}] ${e.message}${fields}`;
+                      })
+                      .join("\n")}
+              </pre>
+            ) : null}
+            {logsPath ? (
+              <p className="break-all rounded-md border bg-muted/30 px-2 py-1.5 font-mono text-xs text-muted-foreground">
+                {logsPath}
+              </p>
+            ) : null}
+            {logsError ? <p className="text-sm text-destructive break-all">{logsError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.wipeAllData")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.wipeAllDataHint")}</p>
+            <p className="text-sm text-muted-foreground">{t("settings.wipeExtensionsHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              {[false, true].map((include) => (
+                <Button
+                  key={String(include)}
+                  type="button"
+                  size="sm"
+                  variant={wipeExtensions === include ? "default" : "outline"}
+                  disabled={wipeBusy}
+                  onClick={() => {
+                    setWipeExtensions(include);
+                    setWipePlan(null);
+                  }}
+                >
+                  {t(include ? "settings.wipeExtensionsInclude" : "settings.wipeExtensionsKeep")}
+                </Button>
+              ))}
+            </div>
+            <Button type="button" size="sm" variant="secondary" disabled={wipeBusy} onClick={() => void previewWipe()}>
+              {t("settings.wipeAllDataPreview")}
+            </Button>
+            {wipePlan ? (
+              <>
+                <pre className="max-h-40 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                  {wipePlan.targets.length === 0
+                    ? "—"
+                    : wipePlan.targets
+                        .map((target) => `${target.kind}  ${(target.bytes / 1048576).toFixed(1)} MB  ${target.path}`)
+                        .join("\n")}
+                </pre>
+                <Button
+                  type="button"
+                  size="sm"
+                  variant="destructive"
+                  disabled={wipeBusy || !wipePlan.dry_run}
+                  onClick={() => void runWipe()}
+                >
+                  {t("settings.wipeAllDataAction", { size: (wipePlan.total_bytes / 1048576).toFixed(1) })}
+                </Button>
+              </>
+            ) : null}
+            {wipeError ? <p className="whitespace-pre-line text-sm text-destructive break-all">{wipeError}</p> : null}
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.patchCacheBudget")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.patchCacheBudgetHint")}</p>
+            <div className="flex flex-wrap gap-1.5">
+              {PATCH_CACHE_BUDGET_PRESETS.map((mb) => (
+                <Button
+                  key={mb}
+                  type="button"
+                  size="sm"
+                  variant={prefs.patchCacheBudgetMb === mb ? "default" : "outline"}
+                  className="min-w-12 px-2"
+                  onClick={() => void setPatchCacheBudget(mb)}
+                >
+                  {t("settings.patchCacheBudgetMb", { mb })}
+                </Button>
+              ))}
+            </div>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.scrapeProfile")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.scrapeProfileHint")}</p>
+            <Button type="button" size="sm" variant="secondary" disabled={!isTauri()} onClick={() => void copyScrapeProfile()}>
+              {profileCopied ? t("settings.scrapeProfileCopied") : t("settings.scrapeProfileCopy")}
+            </Button>
+          </div>
+          <Separator />
+          <div className="space-y-2">
+            <Label>{t("settings.binaryIpc")}</Label>
+            <p className="text-sm text-muted-foreground">{t("settings.binaryIpcHint")}</p>
+            <div className="flex flex-wrap gap-2">
+              <Button
+                type="button"
+                size="sm"
+                variant={!prefs.binaryIpc ? "default" : "outline"}
+                onClick={() => patchPrefs({ binaryIpc: false })}
+              >
+                {t("settings.binaryIpcJson")}
+              </Button>
+              <Button
+                type="button"
+                size="sm"
+                variant={prefs.binaryIpc ? "default" : "outline"}
+                onClick={() => patchPrefs({ binaryIpc: true })}
+              >
+                {t("settings.binaryIpcMsgpack")}
+              </Button>
+            </div>
+          </div>
+          {isTauri() && import.meta.env.DEV ? (
+            <>
+              <Separator />
+              <div className="space-y-3">
+                <Label>{t("settings.devCacheToolsTitle")}</Label>
+                <p className="text-sm text-muted-foreground">
+                  {t("settings.devCacheToolsHint")}
+                </p>
+                <div className="flex flex-wrap gap-2">
+                  <Button
+                    type="button"
+                    size="sm"
+                    variant="secondary"
+                    disabled={cacheBusy}
+                    onClick={() => {
+                      setCacheBusy(true);
+                      setCacheError("");
+                      void runCacheStatus()
+                        .catch((e) => setCacheError(String(e)))
+                        .finally(() => setCacheBusy(false));
+                    }}
                   >
                     {t("settings.devCacheActionStatus")}
                   </Button>
@@ -496,6 +1610,66 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
                   >
                     {t("settings.devCacheActionValidate")}
                   </Button>
+                  <Button
+                    type="button"
+                    size="sm"
+                    variant="secondary"
+                    disabled={cacheBusy}
+                    onClick={() => {
+                      setCacheBusy(true);
+                      setCacheError("");
+                      void runExplainQueries()
+                        .catch((e) => setCacheError(String(e)))
+                        .finally(() => setCacheBusy(false));
+                    }}
+                  >
+                    {t("settings.devCacheActionExplain")}
+                  </Button>
+                  <Button
+                    type="button"
+                    size="sm"
+                    variant="secondary"
+                    disabled={cacheBusy}
+                    onClick={() => {
+                      setCacheBusy(true);
+                      setCacheError("");
+                      void runCacheStats()
+                        .catch((e) => setCacheError(String(e)))
+                        .finally(() => setCacheBusy(false));
+                    }}
+                  >
+                    {t("settings.devCacheActionStats")}
+                  </Button>
+                  <Button
+                    type="button"
+                    size="sm"
+                    variant="secondary"
+                    disabled={cacheBusy}
+                    onClick={() => {
+                      setCacheBusy(true);
+                      setCacheError("");
+                      void runScrapeProfile()
+                        .catch((e) => setCacheError(String(e)))
+                        .finally(() => setCacheBusy(false));
+                    }}
+                  >
+                    {t("settings.devCacheActionProfile")}
+                  </Button>
+                  <Button
+                    type="button"
+                    size="sm"
+                    variant="secondary"
+                    disabled={cacheBusy}
+                    onClick={() => {
+                      setCacheBusy(true);
+                      setCacheError("");
+                      void runMaintenanceStatus()
+                        .catch((e) => setCacheError(String(e)))
+                        .finally(() => setCacheBusy(false));
+                    }}
+                  >
+                    {t("settings.devCacheActionMaintenance")}
+                  </Button>
                   <Button
                     type="button"
                     size="sm"
@@ -544,6 +1718,60 @@ export function SettingsControls({ theme, onThemeChange }: Props) {
                     {cacheValidationJson || "—"}
                   </pre>
                 </div>
+                <div className="space-y-2">
+                  <p className="text-sm font-medium text-muted-foreground">{t("settings.devCacheExplainResponse")}</p>
+                  <pre className="max-h-40 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                    {queryPlansJson || "—"}
+                  </pre>
+                </div>
+                <div className="space-y-2">
+                  <p className="text-sm font-medium text-muted-foreground">{t("settings.devCacheStatsResponse")}</p>
+                  <pre className="max-h-40 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                    {cacheStatsJson || "—"}
+                  </pre>
+                </div>
+                <div className="space-y-2">
+                  <p className="text-sm font-medium text-muted-foreground">{t("settings.devCacheProfileResponse")}</p>
+                  <pre className="max-h-40 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                    {scrapeProfileJson || "—"}
+                  </pre>
+                </div>
+                <div className="space-y-2">
+                  <p className="text-sm font-medium text-muted-foreground">{t("settings.devCacheMaintenanceResponse")}</p>
+                  <pre className="max-h-40 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                    {maintenanceJson || "—"}
+                  </pre>
+                </div>
+                <div className="space-y-2">
+                  <p className="text-sm font-medium text-muted-foreground">{t("settings.devValidateParse")}</p>
+                  <p className="text-sm text-muted-foreground">{t("settings.devValidateParseHint")}</p>
+                  <div className="flex flex-wrap gap-2">
+                    <Input
+                      placeholder="25.10"
+                      value={validateVersion}
+                      onChange={(e) => setValidateVersion(e.target.value)}
+                      className="h-9 w-28"
+                    />
+                    <Button
+                      type="button"
+                      size="sm"
+                      variant="secondary"
+                      disabled={cacheBusy || !validateVersion.trim()}
+                      onClick={() => {
+                        setCacheBusy(true);
+                        setCacheError("");
+                        void runValidateParse()
+                          .catch((e) => setCacheError(String(e)))
+                          .finally(() => setCacheBusy(false));
+                      }}
+                    >
+                      validate_parse
+                    </Button>
+                  </div>
+                  <pre className="max-h-60 overflow-auto rounded-md border bg-muted/30 p-2 text-xs">
+                    {parseCoverageJson || "—"}
+                  </pre>
+                </div>
               </div>
             </>
           ) : null}
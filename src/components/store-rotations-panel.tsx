@@ -0,0 +1,73 @@
+import { useEffect, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { Badge } from "@/components/ui/badge"
+import { Card, CardContent } from "@/components/ui/card"
+import { loadAppPreferences } from "@/lib/app-preferences"
+import { formatAppDate } from "@/lib/format-date"
+import type { StoreRotation } from "@/types/bindings/StoreRotation"
+
+const REMIND_BEFORE_MS = 3 * 24 * 60 * 60 * 1000
+
+/** Ротации мифического магазина и пропуски событий из раздела магазина в патч-нотах. */
+export function StoreRotationsPanel() {
+  const { t, i18n } = useTranslation()
+  const [rotations, setRotations] = useState<StoreRotation[] | null>(null)
+  const dateFmt = loadAppPreferences().dateFormat
+
+  useEffect(() => {
+    if (!isTauri()) return
+    void invoke<StoreRotation[]>("get_store_rotations", { patches: null })
+      .then(setRotations)
+      .catch(() => setRotations([]))
+  }, [])
+
+  if (!isTauri()) {
+    return <p className="text-sm text-muted-foreground">{t("community.youtubeTauriOnly")}</p>
+  }
+  if (rotations !== null && rotations.length === 0) {
+    return <p className="text-sm text-muted-foreground">{t("community.noStoreRotations")}</p>
+  }
+
+  const now = Date.now()
+  const date = (value: string) => formatAppDate(value, dateFmt, i18n.language)
+
+  return (
+    <div className="grid gap-3 sm:grid-cols-2">
+      {(rotations ?? []).map((rotation) => {
+        const endsAt = rotation.ends_at ? Date.parse(rotation.ends_at) : null
+        const ended = endsAt !== null && endsAt <= now
+        const endingSoon = endsAt !== null && !ended && endsAt - now <= REMIND_BEFORE_MS
+        return (
+          <Card key={rotation.key} className={ended ? "opacity-60" : undefined}>
+            <CardContent className="space-y-2 p-3">
+              <div className="flex flex-wrap items-center gap-2">
+                <p className="text-sm font-medium leading-snug">{rotation.title}</p>
+                <Badge variant="outline">{t(`community.storeKind.${rotation.kind}`)}</Badge>
+                {endingSoon ? <Badge variant="warning">{t("community.storeEndingSoon")}</Badge> : null}
+              </div>
+              <p className="text-xs text-muted-foreground">
+                {t("history.patchLabel", { version: rotation.patch_version })}
+                {" · "}
+                {rotation.starts_at && rotation.ends_at
+                  ? t("community.storeWindow", { from: date(rotation.starts_at), to: date(rotation.ends_at) })
+                  : rotation.ends_at
+                    ? t("community.storeUntil", { date: date(rotation.ends_at) })
+                    : rotation.starts_at
+                      ? t("community.storeFrom", { date: date(rotation.starts_at) })
+                      : t("community.storeNoDates")}
+              </p>
+              {rotation.items.length ? (
+                <ul className="list-disc space-y-0.5 pl-4 text-sm">
+                  {rotation.items.map((item) => (
+                    <li key={item}>{item}</li>
+                  ))}
+                </ul>
+              ) : null}
+            </CardContent>
+          </Card>
+        )
+      })}
+    </div>
+  )
+}
@@ -0,0 +1,74 @@
+import { useEffect, useMemo, useState } from "react"
+import { useTranslation } from "react-i18next"
+import { invoke, isTauri } from "@tauri-apps/api/core"
+import { Card, CardContent } from "@/components/ui/card"
+import { loadAppPreferences } from "@/lib/app-preferences"
+import { formatAppDate } from "@/lib/format-date"
+import { cleanUrl } from "@/lib/patch-utils"
+import type { UpcomingSkin } from "@/types/bindings/UpcomingSkin"
+
+/** Предстоящие образы по патчам: окно выхода — от статьи патча до следующего патча. */
+export function UpcomingSkinsPanel() {
+  const { t, i18n } = useTranslation()
+  const [skins, setSkins] = useState<UpcomingSkin[] | null>(null)
+  const dateFmt = loadAppPreferences().dateFormat
+
+  useEffect(() => {
+    if (!isTauri()) return
+    void invoke<UpcomingSkin[]>("get_upcoming_skins", { patches: null })
+      .then(setSkins)
+      .catch(() => setSkins([]))
+  }, [])
+
+  const byPatch = useMemo(() => {
+    const groups = new Map<string, UpcomingSkin[]>()
+    for (const skin of skins ?? []) {
+      groups.set(skin.patch_version, [...(groups.get(skin.patch_version) ?? []), skin])
+    }
+    return [...groups.entries()]
+  }, [skins])
+
+  if (!isTauri()) {
+    return <p className="text-sm text-muted-foreground">{t("community.youtubeTauriOnly")}</p>
+  }
+  if (skins !== null && byPatch.length === 0) {
+    return <p className="text-sm text-muted-foreground">{t("community.noUpcomingSkins")}</p>
+  }
+
+  return (
+    <div className="space-y-6">
+      {byPatch.map(([version, list]) => (
+        <div key={version} className="space-y-3">
+          <div className="flex flex-wrap items-baseline gap-x-3 gap-y-1">
+            <h3 className="text-sm font-semibold">{t("history.patchLabel", { version })}</h3>
+            <span className="text-xs text-muted-foreground">
+              {list[0].release_by
+                ? t("community.releaseWindow", {
+                  from: formatAppDate(list[0].announced_at, dateFmt, i18n.language),
+                  to: formatAppDate(list[0].release_by, dateFmt, i18n.language),
+                })
+                : formatAppDate(list[0].announced_at, dateFmt, i18n.language)}
+            </span>
+          </div>
+          <div className="grid gap-3 sm:grid-cols-2 lg:grid-cols-3">
+            {list.map((skin) => (
+              <Card key={skin.name} className="overflow-hidden">
+                {skin.images[0] ? (
+                  <img src={cleanUrl(skin.images[0])} alt="" className="aspect-video w-full bg-muted object-cover" />
+                ) : null}
+                <CardContent className="space-y-1 p-3">
+                  <p className="text-sm font-medium leading-snug">{skin.name}</p>
+                  {skin.champion || skin.skin_line ? (
+                    <p className="text-xs text-muted-foreground">
+                      {[skin.champion, skin.skin_line].filter(Boolean).join(" · ")}
+                    </p>
+                  ) : null}
+                </CardContent>
+              </Card>
+            ))}
+          </div>
+        </div>
+      ))}
+    </div>
+  )
+}
@@ -0,0 +1,37 @@
+// Имена событий backend -> frontend; должны совпадать с AppEvent::NAME в src-tauri/src/models/events.rs
+import type { AppReadyEvent } from "@/types/bindings/AppReadyEvent"
+import type { LogEvent } from "@/types/bindings/LogEvent"
+import type { NewPatchEvent } from "@/types/bindings/NewPatchEvent"
+import type { StatsUpdatedEvent } from "@/types/bindings/StatsUpdatedEvent"
+import type { SyncProgressEvent } from "@/types/bindings/SyncProgressEvent"
+import type { WatchlistAlertEvent } from "@/types/bindings/WatchlistAlertEvent"
+import type { PatchNotesUpdatedEvent } from "@/types/bindings/PatchNotesUpdatedEvent"
+import type { StoreRotationEndingEvent } from "@/types/bindings/StoreRotationEndingEvent"
+import type { ServerStatusChangedEvent } from "@/types/bindings/ServerStatusChangedEvent"
+import type { UnsupportedLayoutEvent } from "@/types/bindings/UnsupportedLayoutEvent"
+
+export const APP_EVENTS = {
+  log: "log_message",
+  syncProgress: "sync_progress",
+  newPatch: "new_patch",
+  statsUpdated: "stats_updated",
+  appReady: "app_ready",
+  watchlistAlert: "watchlist_alert",
+  patchNotesUpdated: "patch_notes_updated",
+  storeRotationEnding: "store_rotation_ending",
+  serverStatusChanged: "server_status_changed",
+  unsupportedLayout: "unsupported_layout",
+} as const
+
+export type AppEventPayloads = {
+  [APP_EVENTS.log]: LogEvent
+  [APP_EVENTS.syncProgress]: SyncProgressEvent
+  [APP_EVENTS.newPatch]: NewPatchEvent
+  [APP_EVENTS.statsUpdated]: StatsUpdatedEvent
+  [APP_EVENTS.appReady]: AppReadyEvent
+  [APP_EVENTS.watchlistAlert]: WatchlistAlertEvent
+  [APP_EVENTS.patchNotesUpdated]: PatchNotesUpdatedEvent
+  [APP_EVENTS.storeRotationEnding]: StoreRotationEndingEvent
+  [APP_EVENTS.serverStatusChanged]: ServerStatusChangedEvent
+  [APP_EVENTS.unsupportedLayout]: UnsupportedLayoutEvent
+}
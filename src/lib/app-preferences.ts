@@ -1,4 +1,8 @@
 import type { ThemeOption } from "@/types/patch";
+import type { ScrapeSettings } from "@/types/bindings/ScrapeSettings";
+import type { RiotApiSettings } from "@/types/bindings/RiotApiSettings";
+import type { TranslationSettings } from "@/types/bindings/TranslationSettings";
+import type { RequestHeaderSettings } from "@/types/bindings/RequestHeaderSettings";
 
 export type PatchDefaultMode = "alwaysLatest" | "rememberSelection";
 export type StartupRouteMode = "home" | "last";
@@ -9,6 +13,19 @@ export type DateFormatPref = "relative" | "absolute";
 export const UI_SCALE_PRESETS = [75, 80, 90, 100, 110, 125, 150] as const;
 export type UiScalePct = (typeof UI_SCALE_PRESETS)[number];
 
+/** Бюджет памяти LRU патчей в backend, МБ. */
+export const PATCH_CACHE_BUDGET_PRESETS = [16, 32, 64, 128, 256] as const;
+
+/** Пресеты загрузки; значения по умолчанию — `ScrapeSettings::default()` в backend. */
+export const SYNC_CONCURRENCY_PRESETS = [1, 2, 4, 8] as const;
+export const REQUEST_GAP_PRESETS_MS = [200, 400, 800, 1500, 3000] as const;
+export const RETRY_PRESETS = [0, 1, 2, 3, 5] as const;
+
+/** Платформы Riot API; совпадают с `riot_api::PLATFORMS` в backend. */
+export const RIOT_PLATFORMS = [
+  "br1", "eun1", "euw1", "jp1", "kr", "la1", "la2", "me1", "na1", "oc1", "ru", "sg2", "tr1", "tw2", "vn2",
+] as const;
+
 export const PREFS_STORAGE_KEY = "patch-analyzer:prefs-v1";
 
 export type AppPreferences = {
@@ -24,6 +41,28 @@ export type AppPreferences = {
   dateFormat: DateFormatPref;
   lastPatchVersion: string;
   lastPathname: string;
+  patchCacheBudgetMb: number;
+  binaryIpc: boolean;
+  /** `null` — настройки backend по умолчанию */
+  scrapeSettings: ScrapeSettings | null;
+  /** Локальный HTTP API на 127.0.0.1 для оверлеев и внешних программ */
+  localApiEnabled: boolean;
+  /** Раздел «влияние на про-сцену» в патче */
+  competitiveMode: boolean;
+  /** Реакция Reddit на патч (оценки по чемпионам) */
+  communityReaction: boolean;
+  /** Фоновое обновление тир-листа op.gg */
+  communityTiersEnabled: boolean;
+  /** Фоновый сбор сборок про с probuilds.net */
+  proBuildsEnabled: boolean;
+  /** Фоновая запись overlay.json для оверлея стрима */
+  overlayFileEnabled: boolean;
+  /** Ключ Riot API и платформа; `null` — не настроен */
+  riotApi: RiotApiSettings | null;
+  /** API машинного перевода патч-нотов; `null` — не настроен */
+  translation: TranslationSettings | null;
+  /** User-Agent, Accept-Language и доп. заголовки загрузки; `null` — по умолчанию */
+  requestHeaders: RequestHeaderSettings | null;
 };
 
 const DEFAULT_PREFS: AppPreferences = {
@@ -39,6 +78,18 @@ const DEFAULT_PREFS: AppPreferences = {
   dateFormat: "relative",
   lastPatchVersion: "",
   lastPathname: "/",
+  patchCacheBudgetMb: 64,
+  binaryIpc: false,
+  scrapeSettings: null,
+  localApiEnabled: false,
+  competitiveMode: false,
+  communityReaction: false,
+  communityTiersEnabled: false,
+  proBuildsEnabled: false,
+  overlayFileEnabled: false,
+  riotApi: null,
+  translation: null,
+  requestHeaders: null,
 };
 
 function clampScale(n: number): number {
@@ -1,4 +1,5 @@
 import { convertFileSrc, invoke, isTauri } from "@tauri-apps/api/core";
+import { invokeEncoded } from "@/lib/packed-ipc";
 import type { ItemListItem, RuneListItem } from "@/types/patch";
 
 export interface IconSourceEntry {
@@ -40,7 +41,7 @@ function firstUrl(sources: IconSourceEntry[]): string {
 export async function fetchItemListFromCatalog(): Promise<ItemListItem[] | null> {
   if (!isTauri()) return null;
   try {
-    const rows = await invoke<StaticCatalogRow[]>("get_static_catalog_rows", { kind: "item" });
+    const rows = await invokeEncoded<StaticCatalogRow[]>("get_static_catalog_rows", { kind: "item" });
     if (!rows?.length) return null;
     return rows.map((r) => ({
       id: r.stable_id,
@@ -74,7 +75,7 @@ export async function fetchItemListForMaps(mapIds: number[]): Promise<ItemListIt
 export async function fetchRuneListFromCatalog(): Promise<RuneListItem[] | null> {
   if (!isTauri()) return null;
   try {
-    const rows = await invoke<StaticCatalogRow[]>("get_static_catalog_rows", { kind: "rune" });
+    const rows = await invokeEncoded<StaticCatalogRow[]>("get_static_catalog_rows", { kind: "rune" });
     if (!rows?.length) return null;
     return rows.map((r) => {
       const m = (r.cd_meta ?? {}) as { style?: string; key?: string; id?: number };
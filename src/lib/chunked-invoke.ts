@@ -0,0 +1,31 @@
+// Команды с ChunkedResult (src-tauri/src/chunked_result.rs): крупный ответ приходит кусками JSON через Channel
+import { Channel, invoke, type InvokeArgs } from "@tauri-apps/api/core"
+import { decodeIpc, preferredIpcEncoding } from "@/lib/packed-ipc"
+import type { ChunkedResult } from "@/types/bindings/ChunkedResult"
+import type { ResultChunk } from "@/types/bindings/ResultChunk"
+
+export async function invokeChunked<T>(cmd: string, args: InvokeArgs = {}): Promise<T> {
+  const parts: string[] = []
+  let received = 0
+  let expected: number | undefined
+  let complete: (() => void) | undefined
+  const allReceived = new Promise<void>((resolve) => {
+    complete = resolve
+  })
+  const onChunk = new Channel<ResultChunk>()
+  onChunk.onmessage = (chunk) => {
+    parts[chunk.index] = chunk.data
+    received += 1
+    if (expected !== undefined && received >= expected) complete?.()
+  }
+
+  const result = decodeIpc<ChunkedResult<T>>(
+    await invoke<unknown>(cmd, { ...args, onChunk, encoding: preferredIpcEncoding() }),
+  )
+  if (result.kind === "inline") return result.value
+  // Ответ команды может прийти раньше последних сообщений канала
+  expected = result.chunks
+  if (received >= expected) complete?.()
+  await allReceived
+  return JSON.parse(parts.join("")) as T
+}
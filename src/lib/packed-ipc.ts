@@ -0,0 +1,20 @@
+// Ответы тяжёлых команд в MessagePack (src-tauri/src/packed_ipc.rs), если включено в настройках
+import { invoke, type InvokeArgs } from "@tauri-apps/api/core"
+import { decode } from "@msgpack/msgpack"
+import { loadAppPreferences } from "@/lib/app-preferences"
+import type { IpcEncoding } from "@/types/bindings/IpcEncoding"
+
+export function preferredIpcEncoding(): IpcEncoding {
+  return loadAppPreferences().binaryIpc ? "msgpack" : "json"
+}
+
+/** Двоичный ответ приходит как ArrayBuffer, JSON — уже разобранным. */
+export function decodeIpc<T>(raw: unknown): T {
+  if (raw instanceof ArrayBuffer) return decode(new Uint8Array(raw)) as T
+  return raw as T
+}
+
+export async function invokeEncoded<T>(cmd: string, args: InvokeArgs = {}): Promise<T> {
+  const raw = await invoke<unknown>(cmd, { ...args, encoding: preferredIpcEncoding() })
+  return decodeIpc<T>(raw)
+}
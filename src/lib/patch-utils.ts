@@ -1,5 +1,6 @@
 import type { TFunction } from "i18next";
 import { convertFileSrc, isTauri } from "@tauri-apps/api/core";
+import type { ChangeBlock, PatchNoteEntry } from "@/types/patch";
 
 function normalizeLocalPath(path: string): string {
   return path.replace(/\\/g, "/")
@@ -19,7 +20,11 @@ export const PATCH_NOTE_CATEGORY_TAB_ORDER: readonly string[] = [
   "ModeAram",
   "ModeArena",
   "Modes",
+  "Objectives",
+  "Ranked",
+  "Behavioral",
   "Systems",
+  "Store",
   "Skins",
   "Cosmetics",
   "BugFixes",
@@ -100,6 +105,29 @@ export function highlightSpecialTags(text: string): string {
   return text
 }
 
+/** Строки изменений блока вместе с вложенными подблоками. */
+export function changeBlockLines(block: ChangeBlock): string[] {
+  return [
+    ...(block.changes ?? []),
+    ...(block.children ?? []).flatMap(changeBlockLines),
+  ]
+}
+
+/** Записи машинного перевода с исходными строками (`MachineTranslation.originals`: перевод → оригинал). */
+export function restoreOriginalNotes(
+  notes: PatchNoteEntry[],
+  originals: { [key in string]?: string },
+): PatchNoteEntry[] {
+  const orig = (s: string) => originals[s] ?? s
+  const block = (b: ChangeBlock): ChangeBlock => ({
+    ...b,
+    title: b.title === null ? null : orig(b.title),
+    changes: b.changes.map(orig),
+    children: b.children.map(block),
+  })
+  return notes.map((n) => ({ ...n, title: orig(n.title), summary: orig(n.summary), details: n.details.map(block) }))
+}
+
 export function analyzeChangeTrend(text: string): "up" | "down" | "neutral" {
   const lower = text.toLowerCase()
 
@@ -180,5 +208,7 @@ export function compareVersions(v1: string, v2: string) {
     const n2 = p2[i] || 0
     if (n1 !== n2) return n1 - n2
   }
-  return 0
+  // Хотфикс ("25.22b") — сразу после своего патча
+  const hotfix = (v: string) => /[a-z]+$/i.exec(v.trim())?.[0].toLowerCase() ?? ""
+  return hotfix(v1).localeCompare(hotfix(v2))
 }
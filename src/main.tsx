@@ -3,9 +3,11 @@ import ReactDOM from "react-dom/client";
 import { HashRouter } from "react-router-dom";
 import { I18nextProvider } from "react-i18next";
 import { invoke, isTauri } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import i18n from "./i18n";
 import App from "./App";
 import { applyDomFromPreferences, loadAppPreferences } from "./lib/app-preferences";
+import { APP_EVENTS, type AppEventPayloads } from "./lib/app-events";
 import "./index.css";
 
 applyDomFromPreferences(loadAppPreferences());
@@ -25,6 +27,33 @@ function finishBootPhase() {
   hideBootLoader();
 }
 
+type StartupStatus = AppEventPayloads[typeof APP_EVENTS.appReady];
+
+/** БД открывается в фоне уже после показа окна: до `app_ready` команды backend недоступны. */
+async function waitForBackend(): Promise<StartupStatus> {
+  if (!isTauri()) return { error: null };
+  let resolveReady!: (status: StartupStatus) => void;
+  const ready = new Promise<StartupStatus>((resolve) => {
+    resolveReady = resolve;
+  });
+  // Сначала подписка, потом опрос: иначе событие может проскочить между ними
+  const unlisten = await listen<StartupStatus>(APP_EVENTS.appReady, (e) => resolveReady(e.payload));
+  try {
+    const status = await invoke<StartupStatus | null>("get_startup_status");
+    if (status) resolveReady(status);
+    return await ready;
+  } finally {
+    unlisten();
+  }
+}
+
+function showStartupError(message: string) {
+  window.clearTimeout(failSafeHideTimer);
+  const title = document.querySelector("#boot-loader .boot-title");
+  if (title) title.textContent = message;
+  document.querySelector("#boot-loader .boot-dots")?.remove();
+}
+
 if (isTauri() && import.meta.env.DEV) {
   document.addEventListener(
     "keydown",
@@ -39,21 +68,76 @@ if (isTauri() && import.meta.env.DEV) {
   );
 }
 
-ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
-  <React.StrictMode>
-    <I18nextProvider i18n={i18n}>
-      <HashRouter
-        future={{
-          v7_startTransition: true,
-          v7_relativeSplatPath: true,
-        }}
-      >
-        <App />
-      </HashRouter>
-    </I18nextProvider>
-  </React.StrictMode>,
-);
+void waitForBackend()
+  .then(({ error }) => {
+    if (error) {
+      showStartupError(error);
+      return;
+    }
+    const prefs = loadAppPreferences();
+    void invoke("set_cache_memory_budget", {
+      megabytes: prefs.patchCacheBudgetMb,
+    }).catch((err) => console.error("set_cache_memory_budget failed", err));
+    if (prefs.scrapeSettings) {
+      void invoke("set_scrape_settings", { settings: prefs.scrapeSettings }).catch((err) =>
+        console.error("set_scrape_settings failed", err),
+      );
+    }
+    if (prefs.riotApi) {
+      void invoke("set_riot_api_settings", { settings: prefs.riotApi }).catch((err) =>
+        console.error("set_riot_api_settings failed", err),
+      );
+    }
+    if (prefs.translation) {
+      void invoke("set_translation_settings", { settings: prefs.translation }).catch((err) =>
+        console.error("set_translation_settings failed", err),
+      );
+    }
+    if (prefs.requestHeaders) {
+      void invoke("set_request_header_settings", { settings: prefs.requestHeaders }).catch((err) =>
+        console.error("set_request_header_settings failed", err),
+      );
+    }
+    if (prefs.localApiEnabled) {
+      void invoke("set_local_api_enabled", { enabled: true }).catch((err) =>
+        console.error("set_local_api_enabled failed", err),
+      );
+    }
+    if (prefs.communityTiersEnabled) {
+      void invoke("set_community_tiers_enabled", { enabled: true }).catch((err) =>
+        console.error("set_community_tiers_enabled failed", err),
+      );
+    }
+    if (prefs.proBuildsEnabled) {
+      void invoke("set_pro_builds_enabled", { enabled: true }).catch((err) =>
+        console.error("set_pro_builds_enabled failed", err),
+      );
+    }
+    if (prefs.overlayFileEnabled) {
+      void invoke("set_overlay_file_enabled", { enabled: true }).catch((err) =>
+        console.error("set_overlay_file_enabled failed", err),
+      );
+    }
+    ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
+      <React.StrictMode>
+        <I18nextProvider i18n={i18n}>
+          <HashRouter
+            future={{
+              v7_startTransition: true,
+              v7_relativeSplatPath: true,
+            }}
+          >
+            <App />
+          </HashRouter>
+        </I18nextProvider>
+      </React.StrictMode>,
+    );
+    requestAnimationFrame(() => finishBootPhase());
+  })
+  .catch((err) => {
+    console.error("waitForBackend failed", err);
+    finishBootPhase();
+  });
 
 window.addEventListener("error", () => finishBootPhase(), { once: true });
 window.addEventListener("unhandledrejection", () => finishBootPhase(), { once: true });
-requestAnimationFrame(() => finishBootPhase());
@@ -1,6 +1,6 @@
 import { useCallback, useEffect, useMemo, useState } from "react"
 import { Link } from "react-router-dom"
-import { convertFileSrc, invoke, isTauri } from "@tauri-apps/api/core"
+import { convertFileSrc, isTauri } from "@tauri-apps/api/core"
 import { useTranslation } from "react-i18next"
 import { ArrowLeft, Search } from "lucide-react"
 import { Button } from "@/components/ui/button"
@@ -18,6 +18,7 @@ import {
 import { Tabs, TabsList, TabsTrigger } from "@/components/ui/tabs"
 import { Skeleton } from "@/components/ui/skeleton"
 import { cleanUrl } from "@/lib/patch-utils"
+import { invokeEncoded } from "@/lib/packed-ipc"
 import { wikiAugmentToPlain } from "@/lib/wiki-augment-plain"
 import { cn } from "@/lib/utils"
 import type { IconSourceEntry, StaticCatalogRow } from "@/lib/catalog-from-tauri"
@@ -253,7 +254,7 @@ export function AugmentsPage() {
   const loadCatalog = useCallback(async () => {
     if (!isTauri()) return
     try {
-      const augRows = await invoke<StaticCatalogRow[]>("get_static_catalog_rows", {
+      const augRows = await invokeEncoded<StaticCatalogRow[]>("get_static_catalog_rows", {
         kind: "augment",
       })
       setStaticAugments(Array.isArray(augRows) ? augRows : [])
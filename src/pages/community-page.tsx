@@ -4,6 +4,10 @@ import { ArrowLeft } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs"
 import { YoutubeChannelPanel } from "@/components/youtube-channel-panel"
+import { UpcomingSkinsPanel } from "@/components/upcoming-skins-panel"
+import { StoreRotationsPanel } from "@/components/store-rotations-panel"
+import { RotationPanel } from "@/components/rotation-panel"
+import { MatchImpactPanel } from "@/components/match-impact-panel"
 import {
   YOUTUBE_CHANNEL_SKINSPOTLIGHTS,
   YOUTUBE_CHANNEL_VANDIRIL,
@@ -35,6 +39,18 @@ export function CommunityPage() {
           <TabsTrigger value="skinspotlights" className="rounded-lg">
             {t("community.tabSkinSpotlights")}
           </TabsTrigger>
+          <TabsTrigger value="upcoming-skins" className="rounded-lg">
+            {t("community.tabUpcomingSkins")}
+          </TabsTrigger>
+          <TabsTrigger value="store" className="rounded-lg">
+            {t("community.tabStore")}
+          </TabsTrigger>
+          <TabsTrigger value="rotation" className="rounded-lg">
+            {t("community.tabRotation")}
+          </TabsTrigger>
+          <TabsTrigger value="impact" className="rounded-lg">
+            {t("community.tabImpact")}
+          </TabsTrigger>
         </TabsList>
         <TabsContent value="vandiril" className="mt-4">
           <YoutubeChannelPanel
@@ -50,6 +66,18 @@ export function CommunityPage() {
             heading={t("community.tabSkinSpotlights")}
           />
         </TabsContent>
+        <TabsContent value="upcoming-skins" className="mt-4">
+          <UpcomingSkinsPanel />
+        </TabsContent>
+        <TabsContent value="store" className="mt-4">
+          <StoreRotationsPanel />
+        </TabsContent>
+        <TabsContent value="rotation" className="mt-4">
+          <RotationPanel />
+        </TabsContent>
+        <TabsContent value="impact" className="mt-4">
+          <MatchImpactPanel />
+        </TabsContent>
       </Tabs>
     </div>
   )
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeType } from "./ChangeType";
+
+export type AbilityChange = { patch_version: string, date: string, change_type: ChangeType, 
+/**
+ * `ChangeBlock::trend_score` блока
+ */
+trend: number, lines: Array<string>, };
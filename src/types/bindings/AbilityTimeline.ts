@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AbilityChange } from "./AbilityChange";
+
+export type AbilityTimeline = { 
+/**
+ * Заголовок блока патч-нотов: «Q — Сфера обмана», «Базовые характеристики»
+ */
+ability: string, icon_url: string | null, 
+/**
+ * От старого патча к новому
+ */
+changes: Array<AbilityChange>, };
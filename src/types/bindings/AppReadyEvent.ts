@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Старт backend завершён: БД и скрейпер под управлением Tauri, команды доступны.
+ */
+export type AppReadyEvent = { 
+/**
+ * Текст ошибки, если БД открыть не удалось (команды тогда недоступны)
+ */
+error: string | null, };
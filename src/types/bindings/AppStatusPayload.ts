@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AppReadyEvent } from "./AppReadyEvent";
+import type { ServerBanner } from "./ServerBanner";
+
+export type AppStatusPayload = { startup: AppReadyEvent | null, 
+/**
+ * Проблема на серверах по последнему опросу Riot API; `None` — всё штатно или ключ не задан
+ */
+server_banner: ServerBanner | null, };
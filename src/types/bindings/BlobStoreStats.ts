@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Состояние хранилища картинок патчей для `get_cache_stats`.
+ */
+export type BlobStoreStats = { 
+/**
+ * URL в индексе
+ */
+urls: number, blob_files: number, blob_bytes: number, hits: number, misses: number, hit_rate: number, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlobStoreStats } from "./BlobStoreStats";
+import type { PatchCacheStats } from "./PatchCacheStats";
+
+export type CacheStatsPayload = { patch_cache: PatchCacheStats, 
+/**
+ * `None`, если каталог кэша картинок недоступен
+ */
+patch_assets: BlobStoreStats | null, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CacheStatusPayload = { patch_versions: number, patch_locales: Array<string>, static_catalog_rows: number, patch_asset_files: number, patch_asset_bytes: number, game_asset_files: number, game_asset_bytes: number, };
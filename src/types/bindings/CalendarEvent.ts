@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CalendarEvent = { version: string, date: string, 
+/**
+ * Прогноз по интервалу, а не вышедший патч
+ */
+expected: boolean, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchCategory } from "./PatchCategory";
+
+export type CategoryCoverage = { category: PatchCategory, entries: number, 
+/**
+ * Записи без блоков изменений
+ */
+empty_entries: number, 
+/**
+ * Записи с найденной картинкой
+ */
+images_resolved: number, };
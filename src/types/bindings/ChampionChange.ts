@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeGrade } from "./ChangeGrade";
+import type { ChangeType } from "./ChangeType";
+
+/**
+ * Правка чемпиона в патче по классификации анализатора.
+ */
+export type ChampionChange = { change_type: ChangeType, grade: ChangeGrade | null, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntityRef } from "./EntityRef";
+import type { PatchNoteEntry } from "./PatchNoteEntry";
+
+export type ChampionHistoryEntry = { patch_version: string, date: string, 
+/**
+ * Сезон/сплит патча ("25.S2")
+ */
+split: string, 
+/**
+ * Сущность записи (по `change.entity_id`), если она распознана
+ */
+entity: EntityRef | null, change: PatchNoteEntry, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Справочные данные чемпиона из DDragon (хранятся в `static_catalog.cd_meta`, обновляются вместе с каталогом).
+ */
+export type ChampionMeta = { 
+/**
+ * DDragon id ("MonkeyKing")
+ */
+id: string, 
+/**
+ * Числовой ключ ("62")
+ */
+key: string, name_en: string, name_ru: string, 
+/**
+ * Классы DDragon: Fighter, Mage, Assassin, ...
+ */
+tags: Array<string>, 
+/**
+ * partype: Mana, Energy, Fury, "None", ...
+ */
+resource_type: string | null, 
+/**
+ * "melee" | "ranged"
+ */
+range_type: string | null, 
+/**
+ * В DDragon отсутствует; заполняется, если источник её отдаёт
+ */
+release_date: string | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionChange } from "./ChampionChange";
+import type { WinRecord } from "./WinRecord";
+
+export type ChampionPatchImpact = { patch_version: string, 
+/**
+ * Предыдущий патч, на котором игрок играл этого чемпиона
+ */
+previous_version: string | null, champion_key: number, champion_id: string, name_en: string | null, name_ru: string | null, change: ChampionChange, before: WinRecord, after: WinRecord, 
+/**
+ * Разница винрейта в процентных пунктах; `None`, если с какой-то стороны меньше `MIN_GAMES` игр
+ */
+delta: number | null, 
+/**
+ * Совпал ли знак разницы с баффом или нерфом; `None` для прочих правок и без `delta`
+ */
+matches_expectation: boolean | null, };
@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AbilityTimeline } from "./AbilityTimeline";
+import type { ChampionHistoryEntry } from "./ChampionHistoryEntry";
+import type { ChampionStats } from "./ChampionStats";
+import type { DevArticle } from "./DevArticle";
+import type { ProBuildsOverview } from "./ProBuildsOverview";
+import type { TimelinePoint } from "./TimelinePoint";
+
+export type ChampionReport = { 
+/**
+ * id DDragon
+ */
+champion: string, generated_at: string, history: Array<ChampionHistoryEntry>, abilities: Array<AbilityTimeline>, timeline: Array<TimelinePoint>, 
+/**
+ * Патч, из которого взяты `current_stats`
+ */
+current_stats_patch: string | null, 
+/**
+ * По строке на роль: win/pick/ban rate, предметы и руны
+ */
+current_stats: Array<ChampionStats>, 
+/**
+ * Что про собирают в первые дни после патчей
+ */
+pro_builds: ProBuildsOverview, dev_articles: Array<DevArticle>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RotationChampion } from "./RotationChampion";
+
+export type ChampionRotation = { platform: string, champions: Array<RotationChampion>, 
+/**
+ * Ротация для новых игроков до `max_new_player_level`
+ */
+new_player_champions: Array<RotationChampion>, max_new_player_level: number, fetched_at: string, };
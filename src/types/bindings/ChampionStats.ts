@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ItemStat } from "./ItemStat";
+import type { LaneRole } from "./LaneRole";
+import type { RuneEntry } from "./RuneEntry";
+
+export type ChampionStats = { id: string, name: string, tier: string, role: LaneRole, win_rate: number, pick_rate: number, ban_rate: number, image_url: string | null, core_items: Array<ItemStat>, 
+/**
+ * Старые строки хранили здесь только названия рун — они читаются как RuneEntry без id.
+ */
+popular_runes: Array<RuneEntry>, 
+/**
+ * Число матчей, по которым посчитаны win/pick rate (0 — неизвестно)
+ */
+total_matches: number, 
+/**
+ * Доля игр чемпиона в этой роли (0..1). Один чемпион может иметь несколько строк — по строке на роль;
+ * строка с `LaneRole::Unknown` и долей 1.0 — агрегат по всем ролям.
+ */
+role_share: number, };
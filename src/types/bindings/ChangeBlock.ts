@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ChangeBlock = { title: string | null, icon_url: string | null, changes: Array<string>, 
+/**
+ * Подразделы блока: базовая/усиленная форма, значения для отдельных режимов и т.п.
+ */
+children: Array<ChangeBlock>, };
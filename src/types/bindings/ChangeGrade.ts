@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Масштаб изменения: срез 5 брони и удаление ключевого эффекта различаются,
+ * даже если оба — Nerf. Порядок вариантов задаёт сравнение (Minor < Major).
+ */
+export type ChangeGrade = "Minor" | "Moderate" | "Major";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Результат команды: целиком или «отправлено `chunks` кусков в канал».
+ */
+export type ChunkedResult<T> = { "kind": "inline", value: T, } | { "kind": "streamed", chunks: number, };
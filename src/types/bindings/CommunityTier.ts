@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LaneRole } from "./LaneRole";
+
+export type CommunityTier = { 
+/**
+ * Числовой ключ Riot
+ */
+champion_key: number, role: LaneRole, 
+/**
+ * S+, S, A, B, C, D
+ */
+tier: string, 
+/**
+ * Место в роли
+ */
+rank: number | null, pick_rate: number, win_rate: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LaneRole } from "./LaneRole";
+
+/**
+ * Оценка чемпиона в его основной роли (с наибольшим пикрейтом) на последнем и предыдущем сохранённых патчах.
+ */
+export type CommunityTierChange = { source: string, role: LaneRole, version: string, tier: string, previous_version: string | null, 
+/**
+ * `None`, если в прошлом списке чемпиона в этой роли не было
+ */
+previous_tier: string | null, };
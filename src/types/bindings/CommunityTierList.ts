@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommunityTier } from "./CommunityTier";
+
+export type CommunityTierList = { source: string, 
+/**
+ * Патч по нумерации сайта Riot
+ */
+version: string, fetched_at: string, tiers: Array<CommunityTier>, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CompetitiveReason } from "./CompetitiveReason";
+import type { PatchCategory } from "./PatchCategory";
+
+export type CompetitiveEntry = { note_id: string, title: string, category: PatchCategory, entity_id: string | null, 
+/**
+ * `PatchNoteEntry::trend_score` записи
+ */
+trend: number, 
+/**
+ * Итоговая значимость для сортировки
+ */
+impact: number, reasons: Array<CompetitiveReason>, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CrashKind } from "./CrashKind";
+import type { LogEvent } from "./LogEvent";
+
+export type CrashReport = { id: string, kind: CrashKind, 
+/**
+ * Подсистема (`startup`, `scraper`, `sync`) или место паники `file:line`
+ */
+context: string, message: string, backtrace: string | null, app_version: string, os: string, 
+/**
+ * Снимок состояния: БД, последняя загрузка патча, синхронизация
+ */
+status: { [key in string]?: string }, recent_logs: Array<LogEvent>, created_at: string, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DevArticleKind } from "./DevArticleKind";
+import type { DevArticleSection } from "./DevArticleSection";
+
+/**
+ * Статья разработчиков Riot (dev blog, «Quick Gameplay Thoughts») — не патч-ноты,
+ * а контекст о планах: показывается в таймлайне сущностей рядом с изменениями.
+ */
+export type DevArticle = { url: string, title: string, kind: DevArticleKind, 
+/**
+ * "ru" | "en"
+ */
+locale: string, published_at: string | null, author: string | null, banner_url: string | null, sections: Array<DevArticleSection>, 
+/**
+ * entity_id упомянутых сущностей ("champion:Ahri")
+ */
+mentions: Array<string>, fetched_at: string, };
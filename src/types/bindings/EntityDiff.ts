@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiffLine } from "./DiffLine";
+import type { EntityRef } from "./EntityRef";
+
+export type EntityDiff = { entity: EntityRef, version_a: string, version_b: string, 
+/**
+ * Есть ли записи о сущности в патч-нотах версий
+ */
+in_a: boolean, in_b: boolean, changes: Array<DiffLine>, 
+/**
+ * Только для чемпионов с сохранённой статистикой
+ */
+stats: Array<DiffLine>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Ссылка на сущность игры в API (история, поиск и т.п.).
+ * `id` — id DDragon (`Ahri`, `3031`, `8112`); имя в любой локали тоже принимается.
+ */
+export type EntityRef = { "kind": "champion", id: string, } | { "kind": "item", id: string, } | { "kind": "rune", id: string, } | { "kind": "system", key: string, } | { "kind": "objective", id: string, } | { "kind": "player_system", id: string, };
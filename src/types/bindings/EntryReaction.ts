@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type EntryReaction = { note_id: string, title: string, 
+/**
+ * Комментариев с упоминанием
+ */
+mentions: number, 
+/**
+ * От −1 (недовольство) до 1 (одобрение)
+ */
+score: number, positive: number, negative: number, 
+/**
+ * Частые слова из `KEYWORDS`
+ */
+keywords: Array<string>, };
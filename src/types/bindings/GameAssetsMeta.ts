@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type GameAssetsMeta = { ddragon_version: string | null, cdragon_synced_at: string | null, catalog_built_at: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ItemStat = { name: string, image_url: string | null, 
+/**
+ * DDragon id предмета ("3031")
+ */
+id: string | null, 
+/**
+ * Полная стоимость в золоте
+ */
+gold_total: number | null, 
+/**
+ * Строки характеристик: "+40 Attack Damage"
+ */
+stats: Array<string>, 
+/**
+ * id компонентов, из которых собирается предмет
+ */
+build_path: Array<string>, };
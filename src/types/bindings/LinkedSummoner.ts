@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MasteryChampion } from "./MasteryChampion";
+import type { RiotAccount } from "./RiotAccount";
+
+export type LinkedSummoner = { account: RiotAccount, platform: string, top_champions: Array<MasteryChampion>, linked_at: string, refreshed_at: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LocalApiStatus = { running: boolean, port: number | null, 
+/**
+ * Базовый адрес, например `http://127.0.0.1:47800/api`
+ */
+base_url: string | null, };
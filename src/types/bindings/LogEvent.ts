@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevel } from "./LogLevel";
+import type { LogSource } from "./LogSource";
+
+/**
+ * Строка журнала: уровень, источник и поля контекста (версия, адрес и т.п.).
+ */
+export type LogEvent = { level: LogLevel, source: LogSource, message: string, fields: { [key in string]?: string }, timestamp: string, };
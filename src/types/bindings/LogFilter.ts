@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogLevel } from "./LogLevel";
+import type { LogSource } from "./LogSource";
+
+export type LogFilter = { 
+/**
+ * Порог для источников без своего уровня
+ */
+default_level: LogLevel, sources: { [key in LogSource]?: LogLevel }, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Уровень записи журнала; порядок — по возрастанию важности (для фильтра).
+ */
+export type LogLevel = "DEBUG" | "INFO" | "SUCCESS" | "WARN" | "ERROR";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Подсистема, написавшая запись.
+ */
+export type LogSource = "APP" | "UPDATE" | "SCRAPER" | "SYNC" | "IMPORT" | "AUGMENTS" | "CACHE" | "DB" | "FEED" | "API" | "OVERLAY" | "MAINTENANCE" | "SCRIPTS";
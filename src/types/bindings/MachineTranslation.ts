@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Отметка о машинном переводе патча.
+ */
+export type MachineTranslation = { 
+/**
+ * Локаль патч-нотов, с которой переведено
+ */
+source_locale: string, provider: string, translated_at: string, 
+/**
+ * Перевод → оригинал для каждой переведённой строки
+ */
+originals: { [key in string]?: string }, 
+/**
+ * Строки, оставшиеся без перевода (нет сети или ошибка API)
+ */
+untranslated: number, };
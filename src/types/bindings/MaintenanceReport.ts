@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MaintenanceReport = { finished_at: string, duration_ms: number, vacuumed: boolean, 
+/**
+ * Удалено устаревших строк кэшей в БД
+ */
+pruned_rows: number, blobs_removed: number, icons_removed: number, bytes_freed: number, errors: Array<string>, };
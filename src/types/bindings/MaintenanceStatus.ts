@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MaintenanceReport } from "./MaintenanceReport";
+
+export type MaintenanceStatus = { vacuum_pending: boolean, idle_secs: number, last_run: MaintenanceReport | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type MasteryChampion = { key: number, id: string | null, name_en: string | null, name_ru: string | null, level: number, points: bigint, 
+/**
+ * Последняя игра на чемпионе, мс Unix
+ */
+last_play_time: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Полная строка таблицы ARAM: Mayhem / Augments (League Wiki).
+ */
+export type MayhemAugmentation = { id: string, title: string, icon_url: string | null, 
+/**
+ * HTML фрагмент ячейки Effect (ссылки на wiki, иконки в тексте).
+ */
+effect_html: string, tier: string, set_label: string, set_icon_url: string | null, };
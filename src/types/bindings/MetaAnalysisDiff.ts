@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionMeta } from "./ChampionMeta";
+
+export type MetaAnalysisDiff = { champion_name: string, role: string, win_rate_diff: number, pick_rate_diff: number, ban_rate_diff: number, predicted_change: string | null, champion_image_url: string | null, 
+/**
+ * Матчей в текущем / прошлом патче (0 — неизвестно)
+ */
+games_current: number, games_previous: number, 
+/**
+ * 95% доверительный интервал для win_rate_diff (в процентных пунктах)
+ */
+win_rate_diff_ci: [number, number] | null, 
+/**
+ * Интервал не содержит 0; false — разница в пределах шума или выборка неизвестна
+ */
+significant: boolean, champion_meta: ChampionMeta | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * В кэш сохранён патч, которого там раньше не было.
+ */
+export type NewPatchEvent = { version: string, patch_notes_locale: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DiffLine } from "./DiffLine";
+import type { RevisionChangeKind } from "./RevisionChangeKind";
+
+export type NoteRevisionChange = { kind: RevisionChangeKind, note_id: string, title: string, 
+/**
+ * Построчная разница блоков записи; у `Changed` — только изменившиеся строки
+ */
+lines: Array<DiffLine>, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type Objective = "baron" | "dragons" | "elder_dragon" | "rift_herald" | "void_grubs" | "atakhan" | "jungle_camps" | "scuttle_crab" | "turrets";
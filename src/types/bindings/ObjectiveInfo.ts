@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Objective } from "./Objective";
+
+/**
+ * Объект со строками названий для списка в интерфейсе.
+ */
+export type ObjectiveInfo = { objective: Objective, 
+/**
+ * Ключ для `EntityRef::Objective`
+ */
+id: string, name_en: string, name_ru: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchCategory } from "./PatchCategory";
+
+export type OverlayChange = { title: string, category: PatchCategory, 
+/**
+ * `PatchNoteEntry::trend_score` записи
+ */
+score: number, icon_url: string | null, };
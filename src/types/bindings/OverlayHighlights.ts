@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OverlayChange } from "./OverlayChange";
+
+export type OverlayHighlights = { version: string, released_at: string, days_since_release: bigint, buffs: Array<OverlayChange>, nerfs: Array<OverlayChange>, generated_at: string, };
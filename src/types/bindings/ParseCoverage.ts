@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CategoryCoverage } from "./CategoryCoverage";
+import type { PageLayout } from "./PageLayout";
+import type { ParsedSection } from "./ParsedSection";
+
+export type ParseCoverage = { version: string, locale: string, 
+/**
+ * Адрес разобранной статьи
+ */
+url: string, 
+/**
+ * Какой вёрсткой парсер разбирал статью
+ */
+layout: PageLayout, total_entries: number, images_resolved: number, 
+/**
+ * По категориям в порядке первой записи
+ */
+categories: Array<CategoryCoverage>, 
+/**
+ * Заголовки записей без блоков изменений
+ */
+empty_entries: Array<string>, sections: Array<ParsedSection>, 
+/**
+ * Разделы, которым парсер не нашёл категорию: «заголовок (#id)»
+ */
+unknown_headings: Array<string>, };
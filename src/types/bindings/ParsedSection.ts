@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchCategory } from "./PatchCategory";
+
+/**
+ * Раздел статьи (заголовок h2) и категория, которую ему присвоил парсер.
+ */
+export type ParsedSection = { id: string, title: string, category: PatchCategory, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Снимок состояния LRU для `get_cache_stats`.
+ */
+export type PatchCacheStats = { entries: number, 
+/**
+ * Оценка занятой памяти (по длине data_json)
+ */
+bytes: number, budget_bytes: number, hits: number, misses: number, evictions: number, 
+/**
+ * Доля попаданий, 0..1; 0 до первого обращения
+ */
+hit_rate: number, };
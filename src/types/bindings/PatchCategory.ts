@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PatchCategory = "Champions" | "Items" | "Runes" | "ItemsRunes" | "ModeAramChaos" | "ModeAramAugments" | "ModeAram" | "ModeArena" | "Modes" | "Objectives" | "Ranked" | "Behavioral" | "Store" | "Skins" | "Systems" | "BugFixes" | "NewContent" | "Cosmetics" | "UpcomingSkinsChromas" | "Unknown";
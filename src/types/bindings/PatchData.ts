@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionStats } from "./ChampionStats";
+import type { MachineTranslation } from "./MachineTranslation";
+import type { PatchNoteEntry } from "./PatchNoteEntry";
+
+export type PatchData = { version: string, fetched_at: string, champions: Array<ChampionStats>, patch_notes: Array<PatchNoteEntry>, banner_url: string | null, 
+/**
+ * "ru" | "en" — с какого региона Riot взяты patch_notes
+ */
+patch_notes_locale: string | null, 
+/**
+ * Дата публикации статьи Riot (article:published_time)
+ */
+published_at: string | null, author: string | null, 
+/**
+ * Сезон/сплит из заголовка статьи ("25.S1"), если Riot его указывает
+ */
+season: string | null, 
+/**
+ * Для хотфикса ("25.22b") — версия родительского патча; выводится из `version`
+ */
+parent_version: string | null, 
+/**
+ * Сезон/сплит ("25.S2") — из статьи, номера патча или даты выхода; см. `patch_version::season_split`
+ */
+split: string, 
+/**
+ * Патч переведён машинным переводом с другой локали; в БД не сохраняется
+ */
+machine_translation: MachineTranslation | null, };
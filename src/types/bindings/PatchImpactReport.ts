@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionPatchImpact } from "./ChampionPatchImpact";
+import type { WinRecord } from "./WinRecord";
+
+export type PatchImpactReport = { riot_id: string, overall: WinRecord, first_game: string | null, last_game: string | null, 
+/**
+ * Патчи с матчами, новые первыми
+ */
+patches: Array<string>, 
+/**
+ * Новые патчи первыми, внутри патча — по числу игр
+ */
+impacts: Array<ChampionPatchImpact>, 
+/**
+ * Доля правок с `matches_expectation == Some(true)` среди оценённых
+ */
+agreement: number | null, generated_at: string, };
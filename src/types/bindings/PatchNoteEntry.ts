@@ -0,0 +1,36 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeBlock } from "./ChangeBlock";
+import type { ChangeGrade } from "./ChangeGrade";
+import type { ChangeType } from "./ChangeType";
+import type { PatchCategory } from "./PatchCategory";
+
+export type PatchNoteEntry = { id: string, title: string, image_url: string | null, category: PatchCategory, change_type: ChangeType, summary: string, details: Array<ChangeBlock>, 
+/**
+ * Приоритетные URL иконок из static_catalog (DDragon / CD / вики); заполняется при отдаче патча.
+ */
+icon_candidates: Array<string> | null, 
+/**
+ * Каноничные названия из static_catalog (DDragon); заполняются при скрапе независимо от локали патч-нотов.
+ */
+title_en: string | null, title_ru: string | null, 
+/**
+ * Теги ("pro-play", "early-game", ...): автоматические или заданные пользователем
+ */
+tags: Array<string>, 
+/**
+ * Стабильный id сущности из DDragon: "champion:Ahri", "item:3031", "rune:8112"
+ */
+entity_id: string | null, 
+/**
+ * Исходный HTML-фрагмент статьи, из которого разобрана запись (для сверки и повторного разбора)
+ */
+raw_html: string | null, 
+/**
+ * Масштаб изменения по величине правок; `None` — записи без строк изменений (скины и т.п.)
+ */
+grade: ChangeGrade | null, 
+/**
+ * Сущности, названные в тексте исправления ошибки (`champion:Ahri`, `item:3031`): по ним
+ * исправление попадает в историю чемпиона или предмета
+ */
+mentions: Array<string>, };
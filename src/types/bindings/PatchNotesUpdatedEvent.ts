@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchRevision } from "./PatchRevision";
+
+/**
+ * Статью патча исправили после публикации: сохранена новая версия, прежняя — в `patch_revisions`.
+ */
+export type PatchNotesUpdatedEvent = { revision: PatchRevision, };
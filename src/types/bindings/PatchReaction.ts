@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EntryReaction } from "./EntryReaction";
+
+export type PatchReaction = { version: string, patch_notes_locale: string, thread_title: string, thread_url: string, comments_analyzed: number, overall: number, 
+/**
+ * По числу упоминаний
+ */
+entries: Array<EntryReaction>, fetched_at: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoteRevisionChange } from "./NoteRevisionChange";
+
+export type PatchRevision = { version: string, patch_notes_locale: string, 
+/**
+ * 1 — первая замеченная правка
+ */
+revision: number, detected_at: string, changes: Array<NoteRevisionChange>, };
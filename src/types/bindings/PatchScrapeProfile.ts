@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PhaseTimings } from "./PhaseTimings";
+
+export type PatchScrapeProfile = { version: string, locale: string, updated_at: string, timings: PhaseTimings, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeGrade } from "./ChangeGrade";
+import type { ChangeType } from "./ChangeType";
+import type { PatchCategory } from "./PatchCategory";
+import type { SummaryHighlight } from "./SummaryHighlight";
+
+/**
+ * Сводка патча для обзорного экрана (считается при сохранении, без полного PatchData).
+ */
+export type PatchSummary = { version: string, patch_notes_locale: string | null, release_date: string, total_notes: number, by_category: { [key in PatchCategory]?: number }, by_change_type: { [key in ChangeType]?: number }, by_grade: { [key in ChangeGrade]?: number }, 
+/**
+ * Число разных чемпионов/предметов/рун, затронутых патчем
+ */
+entities_touched: number, biggest_buff: SummaryHighlight | null, biggest_nerf: SummaryHighlight | null, 
+/**
+ * Метрики пользовательских скриптов (хук `after_patch_analyzed`): имя скрипта → метрика → значение
+ */
+script_scores: { [key in string]?: { [key in string]?: number } }, };
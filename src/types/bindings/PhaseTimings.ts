@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PhaseTimings = { throttle_ms: number, download_ms: number, parse_ms: number, classify_ms: number, assets_ms: number, save_ms: number, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ServerHealth } from "./ServerHealth";
+import type { StatusIssue } from "./StatusIssue";
+
+export type PlatformStatus = { platform: string, name: string, health: ServerHealth, issues: Array<StatusIssue>, fetched_at: string, };
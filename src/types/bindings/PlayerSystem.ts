@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PlayerSystem = "duo_queue" | "lp_gains" | "placements" | "decay" | "dodging" | "matchmaking" | "ranked_rewards" | "honor" | "penalties" | "chat_and_reports" | "vanguard";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlayerSystem } from "./PlayerSystem";
+
+/**
+ * Тема со строками названий для списка в интерфейсе.
+ */
+export type PlayerSystemInfo = { system: PlayerSystem, 
+/**
+ * Ключ для `EntityRef::PlayerSystem`
+ */
+id: string, name_en: string, name_ru: string, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProBuildsSnapshot } from "./ProBuildsSnapshot";
+import type { ProItemUsage } from "./ProItemUsage";
+
+/**
+ * Сборки про по патчам и сдвиг основной сборки между двумя последними.
+ */
+export type ProBuildsOverview = { 
+/**
+ * Новые патчи первыми
+ */
+snapshots: Array<ProBuildsSnapshot>, 
+/**
+ * Вошли в основную сборку на последнем патче
+ */
+added: Array<ProItemUsage>, 
+/**
+ * Выпали из неё
+ */
+dropped: Array<ProItemUsage>, };
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ProGame } from "./ProGame";
+import type { ProItemUsage } from "./ProItemUsage";
+
+export type ProBuildsSnapshot = { 
+/**
+ * id DDragon
+ */
+champion: string, 
+/**
+ * Патч без хотфикса
+ */
+patch_version: string, source: string, fetched_at: string, games: Array<ProGame>, core_items: Array<ProItemUsage>, };
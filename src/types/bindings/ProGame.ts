@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ProGame = { player: string, win: boolean | null, played_at: string | null, 
+/**
+ * DDragon id предметов в порядке на странице
+ */
+items: Array<string>, };
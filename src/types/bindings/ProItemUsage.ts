@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ProItemUsage = { item_id: string, name_en: string | null, name_ru: string | null, games: number, 
+/**
+ * Доля игр с предметом (0..1)
+ */
+share: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * План выполнения одного из типовых запросов (`EXPLAIN QUERY PLAN`).
+ */
+export type QueryPlan = { name: string, sql: string, plan: Array<string>, 
+/**
+ * Есть шаг с полным проходом таблицы без индекса
+ */
+full_scan: boolean, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Рейтинговый матч глазами игрока: его чемпион и результат.
+ */
+export type RankedMatch = { match_id: string, 
+/**
+ * Числовой ключ Riot
+ */
+champion_key: number, 
+/**
+ * DDragon id (`championName` в Match-V5)
+ */
+champion_id: string, win: boolean, 
+/**
+ * TOP / JUNGLE / MIDDLE / BOTTOM / UTILITY
+ */
+role: string | null, queue_id: number, game_start: string, 
+/**
+ * Версия клиента ("15.22.715.1234")
+ */
+game_version: string, 
+/**
+ * Номер патча как на сайте ("25.22")
+ */
+patch_version: string, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExtraHeader } from "./ExtraHeader";
+
+/**
+ * Заголовки запросов к сайтам Riot и вики; меняются из настроек приложения (`set_request_header_settings`).
+ */
+export type RequestHeaderSettings = { 
+/**
+ * Пустой — User-Agent из пула браузеров (со сменой при 403)
+ */
+user_agent: string, 
+/**
+ * Пустой — `en-US,en;q=0.9,ru;q=0.8`
+ */
+accept_language: string, 
+/**
+ * Добавляются к каждому запросу; заголовки с неверным именем или значением отбрасываются
+ */
+extra_headers: Array<ExtraHeader>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Кусок JSON-текста ответа; `index` с нуля.
+ */
+export type ResultChunk = { index: number, data: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Аккаунт Riot по Riot ID.
+ */
+export type RiotAccount = { puuid: string, game_name: string, tag_line: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Ключ и платформа; хранятся в настройках фронтенда и передаются при старте (`set_riot_api_settings`).
+ */
+export type RiotApiSettings = { api_key: string, platform: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RotationChampion = { 
+/**
+ * Числовой ключ Riot
+ */
+key: number, 
+/**
+ * DDragon id, если чемпион есть в каталоге
+ */
+id: string | null, name_en: string | null, name_ru: string | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type RuneEntry = { 
+/**
+ * DDragon id руны ("8112")
+ */
+id: string | null, name: string, 
+/**
+ * Ключ дерева: "Domination", "Precision", ...
+ */
+tree: string | null, 
+/**
+ * 0 — ключевая руна, 1..3 — ряды дерева
+ */
+slot: number | null, icon: string | null, };
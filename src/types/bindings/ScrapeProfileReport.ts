@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchScrapeProfile } from "./PatchScrapeProfile";
+import type { PhaseTimings } from "./PhaseTimings";
+
+export type ScrapeProfileReport = { 
+/**
+ * Новые сначала
+ */
+patches: Array<PatchScrapeProfile>, 
+/**
+ * Сумма по всем патчам отчёта
+ */
+totals: PhaseTimings, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Настройки вежливости загрузки; меняются из настроек приложения (`set_scrape_settings`).
+ */
+export type ScrapeSettings = { 
+/**
+ * Одновременных загрузок патчей при полной синхронизации
+ */
+concurrency: number, 
+/**
+ * Минимальный интервал между запросами к leagueoflegends.com — общий для всех параллельных задач
+ */
+request_gap_ms: number, 
+/**
+ * Повторы запроса при обрыве соединения, таймауте, 429 и 5xx
+ */
+retries: number, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ScriptInfo = { name: string, 
+/**
+ * Объявленные хуки
+ */
+hooks: Array<string>, 
+/**
+ * Последняя ошибка выполнения
+ */
+last_error: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ServerHealth } from "./ServerHealth";
+
+/**
+ * Баннер о проблеме на серверах для `get_app_status`.
+ */
+export type ServerBanner = { platform: string, name: string, health: ServerHealth, 
+/**
+ * Заголовок первой проблемы: сначала идущие работы, затем инциденты
+ */
+title: string, issues: number, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PlatformStatus } from "./PlatformStatus";
+import type { StatusIssue } from "./StatusIssue";
+
+/**
+ * Новые инциденты или технические работы на серверах (или их окончание: `new_issues` пуст).
+ */
+export type ServerStatusChangedEvent = { status: PlatformStatus, new_issues: Array<StatusIssue>, };
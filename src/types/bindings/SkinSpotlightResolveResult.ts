@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SkinSpotlightResolveResult = { video_id: string | null, video_title: string | null, 
+/**
+ * "cache" | "api" | "not_found" | "no_key" | "invalid_query" | "error"
+ */
+source: string, };
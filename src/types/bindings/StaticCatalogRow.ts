@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IconSourceEntry } from "./IconSourceEntry";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+export type StaticCatalogRow = { kind: string, stable_id: string, name_ru: string, name_en: string, riot_augment_id: string | null, cd_meta: JsonValue | null, icon_sources: Array<IconSourceEntry>, source: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Сохранённые патчи изменились: тир-лист, история и сводки стоит перезапросить.
+ */
+export type StatsUpdatedEvent = { versions: Array<string>, };
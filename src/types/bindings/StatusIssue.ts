@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StatusIssue = { id: bigint, 
+/**
+ * `maintenance` или `incident`
+ */
+kind: string, 
+/**
+ * info / warning / critical — только у инцидентов
+ */
+severity: string | null, 
+/**
+ * scheduled / in_progress / complete — только у работ
+ */
+status: string | null, title: string, 
+/**
+ * Последнее сообщение Riot по проблеме
+ */
+latest_update: string | null, created_at: string | null, };
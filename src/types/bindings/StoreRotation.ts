@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StoreContentKind } from "./StoreContentKind";
+
+export type StoreRotation = { 
+/**
+ * Версия патча и заголовок записи — по нему напоминание не повторяется
+ */
+key: string, title: string, kind: StoreContentKind, patch_version: string, announced_at: string, starts_at: string | null, ends_at: string | null, items: Array<string>, };
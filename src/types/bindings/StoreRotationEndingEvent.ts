@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StoreRotation } from "./StoreRotation";
+
+/**
+ * Ротация магазина или пропуск события заканчивается в ближайшие дни.
+ */
+export type StoreRotationEndingEvent = { rotation: StoreRotation, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PatchCategory } from "./PatchCategory";
+
+export type SummaryHighlight = { note_id: string, title: string, category: PatchCategory, entity_id: string | null, 
+/**
+ * `PatchNoteEntry::trend_score` записи
+ */
+score: number, };
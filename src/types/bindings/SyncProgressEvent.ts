@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SyncScope } from "./SyncScope";
+
+/**
+ * Прогресс синхронизации: шлётся до начала и после каждой версии.
+ */
+export type SyncProgressEvent = { scope: SyncScope, 
+/**
+ * Пусто для стартового события
+ */
+version: string, processed: number, total: number, downloaded: number, skipped: number, saved: boolean, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChampionMeta } from "./ChampionMeta";
+import type { ChangeGrade } from "./ChangeGrade";
+import type { CommunityTierChange } from "./CommunityTierChange";
+import type { PatchCategory } from "./PatchCategory";
+
+export type TierEntry = { name: string, category: PatchCategory, buffs: number, nerfs: number, adjusted: number, 
+/**
+ * Переработки считаются отдельно: их десятки строк не попадают в buffs/nerfs
+ */
+reworks: number, 
+/**
+ * Записи с масштабом Major (переработки, удаление эффектов, крупные правки чисел)
+ */
+major_changes: number, 
+/**
+ * Самый крупный масштаб изменений в окне
+ */
+max_grade: ChangeGrade | null, icon_url: string | null, champion_meta: ChampionMeta | null, 
+/**
+ * buffs − nerfs по каждому патчу окна, от старого к новому (для спарклайна)
+ */
+sparkline: Array<number>, 
+/**
+ * Тир op.gg в основной роли: последний сохранённый патч и предыдущий
+ */
+community_tier: CommunityTierChange | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeGrade } from "./ChangeGrade";
+import type { ChangeType } from "./ChangeType";
+
+export type TimelinePoint = { patch_version: string, date: string, split: string, 
+/**
+ * `None` — чемпиона в патче не меняли
+ */
+change_type: ChangeType | null, grade: ChangeGrade | null, 
+/**
+ * `PatchNoteEntry::trend_score` изменений чемпиона в патче
+ */
+trend_score: number, change_lines: number, 
+/**
+ * Роль с наибольшей долей игр; статистика ниже — по ней
+ */
+role: string | null, win_rate: number | null, pick_rate: number | null, ban_rate: number | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Настройки API перевода; хранятся в настройках фронтенда и передаются при старте (`set_translation_settings`).
+ */
+export type TranslationSettings = { enabled: boolean, 
+/**
+ * Адрес метода `/translate` LibreTranslate-совместимого сервера
+ */
+endpoint: string, 
+/**
+ * Пустой, если сервер не требует ключа
+ */
+api_key: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Статья патча скачана, но её вёрстку не узнал ни один парсер (`page_layout`): патч не сохранён.
+ */
+export type UnsupportedLayoutEvent = { version: string, patch_notes_locale: string, 
+/**
+ * Страницы с нераспознанной вёрсткой
+ */
+urls: Array<string>, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpcomingSkin = { name: string, 
+/**
+ * Id DDragon (`Swain`), если чемпион узнан по названию
+ */
+champion_id: string | null, champion: string | null, 
+/**
+ * Название без имени чемпиона: «Король жареных цыплят»
+ */
+skin_line: string | null, images: Array<string>, patch_version: string, 
+/**
+ * Дата статьи патча
+ */
+announced_at: string, 
+/**
+ * Следующий патч (вышедший или ожидаемый) — образ выходит до него
+ */
+release_by: string | null, };
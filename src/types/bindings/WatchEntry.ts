@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeGrade } from "./ChangeGrade";
+import type { EntityRef } from "./EntityRef";
+
+export type WatchEntry = { entity: EntityRef, 
+/**
+ * `None` — любые изменения
+ */
+min_grade: ChangeGrade | null, added_at: string, };
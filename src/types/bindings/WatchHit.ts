@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeGrade } from "./ChangeGrade";
+import type { ChangeType } from "./ChangeType";
+import type { EntityRef } from "./EntityRef";
+import type { PatchCategory } from "./PatchCategory";
+
+export type WatchHit = { entity: EntityRef, note_id: string, title: string, category: PatchCategory, change_type: ChangeType, grade: ChangeGrade | null, 
+/**
+ * `PatchNoteEntry::trend_score` записи
+ */
+trend_score: number, 
+/**
+ * Место чемпиона среди любимых у привязанного аккаунта (`summoner`), с 1
+ */
+top_rank: number | null, };
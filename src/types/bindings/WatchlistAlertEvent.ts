@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WatchHit } from "./WatchHit";
+
+/**
+ * В новом патче есть изменения отслеживаемых сущностей (`watchlist`).
+ */
+export type WatchlistAlertEvent = { version: string, hits: Array<WatchHit>, };
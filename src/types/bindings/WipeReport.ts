@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WipeTarget } from "./WipeTarget";
+
+export type WipeReport = { dry_run: boolean, targets: Array<WipeTarget>, total_bytes: number, 
+/**
+ * Что удалить не получилось (файл занят и т.п.)
+ */
+errors: Array<string>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WipeKind } from "./WipeKind";
+
+export type WipeTarget = { kind: WipeKind, path: string, files: number, bytes: number, };
@@ -1,43 +1,28 @@
-export interface PatchData {
-  version: string
-  patch_notes: PatchNoteEntry[]
-  /** og:image со страницы патч-нотов (баннер статьи) */
-  banner_url?: string | null
-  /** "ru" | "en" — регион источника patch notes (riot ru-ru / en-gb) */
-  patch_notes_locale?: string | null
-}
-
-export interface ChangeBlock {
-  title: string | null
-  icon_url: string | null
-  changes: string[]
-}
-
-export interface PatchNoteEntry {
-  id: string
-  title: string
-  image_url?: string
-  category: string
-  change_type: string
-  summary: string
-  details: ChangeBlock[]
-  /** Приоритетные URL иконок из каталога (Rust) */
-  icon_candidates?: string[]
-}
-
-export interface MetaAnalysisDiff {
-  champion_name: string
-  role: string
-  win_rate_diff: number
-  pick_rate_diff: number
-  predicted_change: string | null
-  champion_image_url?: string
-}
-
-export interface ChampionHistoryEntry {
-  patch_version: string
-  date: string
-  change: PatchNoteEntry
+// Сгенерированные из Rust типы — src/types/bindings (ts-rs, `npm run types:gen`)
+import type { EntityRef } from "./bindings/EntityRef"
+import type { PatchSummary } from "./bindings/PatchSummary"
+import type { DevArticle } from "./bindings/DevArticle"
+import type { ChangeGrade } from "./bindings/ChangeGrade"
+import type { PatchData } from "./bindings/PatchData"
+import type { PatchNoteEntry } from "./bindings/PatchNoteEntry"
+import type { ChangeBlock } from "./bindings/ChangeBlock"
+import type { MetaAnalysisDiff } from "./bindings/MetaAnalysisDiff"
+import type { ChampionMeta } from "./bindings/ChampionMeta"
+import type { ChampionHistoryEntry } from "./bindings/ChampionHistoryEntry"
+import type { TierEntry } from "./bindings/TierEntry"
+
+export type {
+  EntityRef,
+  PatchSummary,
+  DevArticle,
+  ChangeGrade,
+  PatchData,
+  PatchNoteEntry,
+  ChangeBlock,
+  MetaAnalysisDiff,
+  ChampionMeta,
+  ChampionHistoryEntry,
+  TierEntry,
 }
 
 export interface ChampionListItem {
@@ -70,15 +55,6 @@ export interface LogEntry {
   timestamp: string
 }
 
-export interface TierEntry {
-  name: string
-  category: string
-  buffs: number
-  nerfs: number
-  adjusted: number
-  icon_url?: string | null
-}
-
 export type ChangeTrend = "up" | "down" | "neutral"
 
 export type ThemeOption = "light" | "dark" | "system"